@@ -0,0 +1,34 @@
+use polymers::math::
+{
+    inverse_brent,
+    inverse_langevin,
+    inverse_langevin_derivative,
+    inverse_langevin_second_derivative
+};
+
+use std::
+{
+    hint::black_box,
+    time::Instant
+};
+
+fn benchmark(name: &str, function: &dyn Fn(&f64) -> f64, number_of_evaluations: usize)
+{
+    let start = Instant::now();
+    let mut sum = 0.0;
+    for index in 0..number_of_evaluations
+    {
+        let y = (index as f64 + 0.5)/(number_of_evaluations as f64);
+        sum += function(black_box(&y));
+    }
+    let nanoseconds = start.elapsed().as_nanos() as f64/(number_of_evaluations as f64);
+    black_box(sum);
+    println!("{:<40}{:>10.1} ns/evaluation", name, nanoseconds);
+}
+
+fn main() {
+    benchmark("inverse_langevin", &inverse_langevin, 10_000_000);
+    benchmark("inverse_langevin_derivative", &inverse_langevin_derivative, 10_000_000);
+    benchmark("inverse_langevin_second_derivative", &inverse_langevin_second_derivative, 10_000_000);
+    benchmark("inverse_brent (reference)", &|y: &f64| inverse_brent(y, &|x: &f64| 1.0/x.tanh() - 1.0/x, &(2.0*y), &(4.0*y/(1.0 - y)), &1e-12, &100).unwrap(), 1_000_000);
+}
@@ -0,0 +1,32 @@
+use polymers::
+{
+    math::Table,
+    physics::single_chain::ufjc::morse::thermodynamics::isotensional::nondimensional_end_to_end_length_per_link
+};
+
+use std::
+{
+    hint::black_box,
+    time::Instant
+};
+
+const NONDIMENSIONAL_LINK_STIFFNESS: f64 = 200.0;
+const NONDIMENSIONAL_LINK_ENERGY: f64 = 50.0;
+const NONDIMENSIONAL_FORCE_MAXIMUM: f64 = 25.0;
+const NUMBER_OF_EVALUATIONS: usize = 100_000;
+
+fn main() {
+    let function = |nondimensional_force: &f64| nondimensional_end_to_end_length_per_link(&NONDIMENSIONAL_LINK_STIFFNESS, &NONDIMENSIONAL_LINK_ENERGY, nondimensional_force).unwrap_or(f64::NAN);
+    let start = Instant::now();
+    let table = Table::init(&function, &0.0, &NONDIMENSIONAL_FORCE_MAXIMUM, &0.0, &1e-10).unwrap();
+    println!("{:<24}{:>12.3} ms ({} segments, error bound {:.1e})", "tabulation", start.elapsed().as_secs_f64()*1e3, table.number_of_segments(), table.error());
+    let arguments: Vec<f64> = (0..NUMBER_OF_EVALUATIONS).map(|index| NONDIMENSIONAL_FORCE_MAXIMUM*(index as f64 + 0.5)/(NUMBER_OF_EVALUATIONS as f64)).collect();
+    let start = Instant::now();
+    let direct: Vec<f64> = arguments.iter().map(|argument| function(black_box(argument))).collect();
+    println!("{:<24}{:>12.1} ns/evaluation", "direct", start.elapsed().as_nanos() as f64/(NUMBER_OF_EVALUATIONS as f64));
+    let start = Instant::now();
+    let tabulated: Vec<f64> = arguments.iter().map(|argument| table.value(black_box(argument))).collect();
+    println!("{:<24}{:>12.1} ns/evaluation", "tabulated", start.elapsed().as_nanos() as f64/(NUMBER_OF_EVALUATIONS as f64));
+    let error = direct.iter().zip(tabulated.iter()).fold(0.0_f64, |error, (direct_i, tabulated_i)| error.max((direct_i - tabulated_i).abs()));
+    println!("{:<24}{:>12.1e}", "maximum difference", error);
+}
@@ -2,6 +2,50 @@ module Polymers
 
 const PROJECT_ROOT = string(dirname(@__FILE__), "/../")
 
+"""
+The error thrown when a model parameter does not satisfy its requirement.
+"""
+struct ParameterError <: Exception
+    name::String
+    value::Real
+    requirement::String
+end
+
+Base.showerror(io::IO, error::ParameterError) = print(
+    io,
+    "The parameter $(error.name) = $(error.value) must be $(error.requirement).",
+)
+
+"""
+Validates that the number of links is nonzero.
+"""
+function validate_number_of_links(number_of_links::UInt8)
+    number_of_links > 0 ||
+        throw(ParameterError("number_of_links", number_of_links, "positive"))
+    return nothing
+end
+
+"""
+Validates that a parameter is positive and finite.
+"""
+function validate_positive(name::String, value::Float64)
+    value > 0.0 && isfinite(value) ||
+        throw(ParameterError(name, value, "positive and finite"))
+    return nothing
+end
+
+"""
+Validates that a parameter is nonnegative and finite.
+"""
+function validate_nonnegative(name::String, value::Float64)
+    value >= 0.0 && isfinite(value) ||
+        throw(ParameterError(name, value, "nonnegative and finite"))
+    return nothing
+end
+
+include("math/mod.jl")
 include("physics/mod.jl")
+include("constitutive/mod.jl")
+include("data/mod.jl")
 
 end
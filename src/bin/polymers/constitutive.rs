@@ -0,0 +1,141 @@
+use std::rc::Rc;
+use polymers::constitutive::
+{
+    StrainEnergy,
+    hyperelastic::
+    {
+        AffineMicrosphere,
+        BucheSilberstein,
+        EightChain,
+        Method,
+        NonaffineMicrosphere,
+        ThreeChain
+    }
+};
+use polymers::physics::single_chain::
+{
+    ForceLaw,
+    efjc::EFJC,
+    fjc::FJC,
+    ideal::Ideal,
+    swfjc::SWFJC,
+    ufjc::
+    {
+        lennard_jones::LENNARDJONESFJC,
+        log_squared::LOGSQUAREDFJC,
+        morse::MORSEFJC
+    },
+    wlc::WLC
+};
+use super::
+{
+    Failure,
+    Function,
+    Model,
+    Options
+};
+
+type Chain = Box<dyn ForceLaw + Send + Sync>;
+
+/// The hyperelastic network models, each under the path of its module, where the single-chain model is chosen by name.
+pub fn models() -> Vec<Model>
+{
+    vec![
+        Model
+        {
+            path: "hyperelastic eight_chain",
+            parameters: |options| chain_parameters(options, &[]),
+            functions: |options| functions(Rc::new(EightChain::try_init(chain(options)?, options.scalar("temperature", None)?)?))
+        },
+        Model
+        {
+            path: "hyperelastic three_chain",
+            parameters: |options| chain_parameters(options, &[]),
+            functions: |options| functions(Rc::new(ThreeChain::try_init(chain(options)?, options.scalar("temperature", None)?)?))
+        },
+        Model
+        {
+            path: "hyperelastic affine_microsphere",
+            parameters: |options| chain_parameters(options, &[]),
+            functions: |options| functions(Rc::new(AffineMicrosphere::try_init(chain(options)?, options.scalar("temperature", None)?)?))
+        },
+        Model
+        {
+            path: "hyperelastic nonaffine_microsphere",
+            parameters: |options| chain_parameters(options, &["nonaffine_stretch_parameter", "tube_geometry_parameter", "tube_area_exponent"]),
+            functions: |options| functions(Rc::new(NonaffineMicrosphere::try_init(
+                chain(options)?,
+                options.scalar("temperature", None)?,
+                options.scalar("nonaffine_stretch_parameter", None)?,
+                options.scalar("tube_geometry_parameter", None)?,
+                options.scalar("tube_area_exponent", None)?
+            )?))
+        },
+        Model
+        {
+            path: "hyperelastic buche_silberstein",
+            parameters: |_| vec!["method", "nondimensional_link_stiffness", "number_of_links"],
+            functions: |options| functions(Rc::new(BucheSilberstein::try_init(method(options)?, options.scalar("nondimensional_link_stiffness", None)?, options.number_of_links()?)?))
+        }
+    ]
+}
+
+/// The functions of a hyperelastic network model, in terms of the nondimensional Cauchy stress and strain energy density.
+fn functions<T: StrainEnergy + 'static>(model: Rc<T>) -> Result<Vec<Function>, Failure>
+{
+    let mut functions = vec![];
+    crate::functions!(functions, model, "";
+        uniaxial_tension => uniaxial_tension(stretch),
+        equibiaxial_tension => equibiaxial_tension(stretch),
+        uniaxial_tension_strain_energy_density => uniaxial_tension_strain_energy_density(stretch),
+        equibiaxial_tension_strain_energy_density => equibiaxial_tension_strain_energy_density(stretch),
+        nondimensional_shear_modulus => nondimensional_shear_modulus()
+    );
+    Ok(functions)
+}
+
+/// The parameters of the network model, including those of the single-chain model if it is given.
+fn chain_parameters(options: &Options, network_parameters: &[&'static str]) -> Vec<&'static str>
+{
+    let model_parameters: &[&'static str] = match options.get("chain")
+    {
+        Some("efjc") | Some("lennard_jones") | Some("log_squared") => &["link_stiffness"],
+        Some("swfjc") => &["well_width"],
+        Some("wlc") => &["persistance_length"],
+        Some("morse") => &["link_stiffness", "link_energy"],
+        _ => &[]
+    };
+    ["chain", "number_of_links", "link_length", "hinge_mass"].into_iter().chain(model_parameters.iter().copied()).chain(["temperature"]).chain(network_parameters.iter().copied()).collect()
+}
+
+/// The single-chain model chosen by name, with any parameters beyond the number of links, link length, and hinge mass.
+fn chain(options: &Options) -> Result<Chain, Failure>
+{
+    let number_of_links = options.number_of_links()?;
+    let link_length = options.scalar("link_length", Some(1.0))?;
+    let hinge_mass = options.scalar("hinge_mass", Some(1.0))?;
+    Ok(match options.get("chain").ok_or(Failure::Usage("Missing the parameter --chain.".to_string()))?
+    {
+        "ideal" => Box::new(Ideal::try_init(number_of_links, link_length, hinge_mass)?),
+        "fjc" => Box::new(FJC::try_init(number_of_links, link_length, hinge_mass)?),
+        "efjc" => Box::new(EFJC::try_init(number_of_links, link_length, hinge_mass, options.scalar("link_stiffness", None)?)?),
+        "swfjc" => Box::new(SWFJC::try_init(number_of_links, link_length, hinge_mass, options.scalar("well_width", None)?)?),
+        "wlc" => Box::new(WLC::try_init(number_of_links, link_length, hinge_mass, options.scalar("persistance_length", None)?)?),
+        "lennard_jones" => Box::new(LENNARDJONESFJC::try_init(number_of_links, link_length, hinge_mass, options.scalar("link_stiffness", None)?)?),
+        "log_squared" => Box::new(LOGSQUAREDFJC::try_init(number_of_links, link_length, hinge_mass, options.scalar("link_stiffness", None)?)?),
+        "morse" => Box::new(MORSEFJC::try_init(number_of_links, link_length, hinge_mass, options.scalar("link_stiffness", None)?, options.scalar("link_energy", None)?)?),
+        chain => return Err(Failure::Usage(format!("The option --chain = {} must be one of ideal, fjc, efjc, swfjc, wlc, lennard_jones, log_squared, or morse.", chain)))
+    })
+}
+
+/// The method of the Buche-Silberstein model chosen by name.
+fn method(options: &Options) -> Result<Method, Failure>
+{
+    match options.get("method")
+    {
+        Some("gibbs_legendre") => Ok(Method::GibbsLegendre),
+        Some("gibbs_legendre_gaussian") => Ok(Method::GibbsLegendreGaussian),
+        Some(method) => Err(Failure::Usage(format!("The option --method = {} must be one of gibbs_legendre or gibbs_legendre_gaussian.", method))),
+        None => Err(Failure::Usage("Missing the parameter --method.".to_string()))
+    }
+}
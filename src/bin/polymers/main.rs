@@ -0,0 +1,414 @@
+mod constitutive;
+mod monte_carlo;
+mod single_chain;
+mod test;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::process::ExitCode;
+use polymers::error::Error;
+
+const USAGE: &str = "Usage:
+    polymers <model> [<ensemble>...] <function> [--<name> <value>]... [--format csv|json]
+    polymers list [<model> [<ensemble>...]]
+
+Evaluates any function of the single-chain models in physics::single_chain, the hyperelastic network models in constitutive::hyperelastic,
+and the Monte Carlo samplers of the equilibrium radial distribution, writing a table of the arguments and values to stdout.
+Each argument of the function is given as a single value, a comma-separated list of values, or a range <start>:<stop>:<count> of evenly-spaced values,
+and the function is evaluated over every combination of the values. The parameters of the model are given as single values,
+where the link length and hinge mass default to unity.
+
+Examples:
+    polymers efjc isotensional nondimensional_end_to_end_length_per_link --number_of_links 8 --link_stiffness 1e6 --nondimensional_force 0:10:11 --temperature 300
+    polymers hyperelastic eight_chain uniaxial_tension --chain fjc --number_of_links 8 --temperature 300 --stretch 1:3:21 --format json
+    polymers frc isometric monte_carlo nondimensional_equilibrium_radial_distribution --number_of_links 8 --theta 0.785 --number_of_samples 1e6";
+
+/// The failures of the command-line tool, either from its usage or from the models.
+#[derive(Debug, PartialEq)]
+pub enum Failure
+{
+    Usage(String),
+    Model(Error)
+}
+
+impl fmt::Display for Failure
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            Failure::Usage(message) => write!(formatter, "{}", message),
+            Failure::Model(error) => write!(formatter, "{}", error)
+        }
+    }
+}
+
+impl From<Error> for Failure
+{
+    fn from(error: Error) -> Self
+    {
+        Failure::Model(error)
+    }
+}
+
+/// The evaluation of a function at the values of its arguments given in order.
+type Evaluate = Box<dyn Fn(&[f64]) -> Result<f64, Failure>>;
+
+/// A function of a model, under a path and with the names of its arguments.
+pub struct Function
+{
+    pub path: &'static str,
+    pub arguments: &'static [&'static str],
+    pub evaluate: Evaluate
+}
+
+/// Converts the values of functions that may or may not fail into results.
+pub trait IntoResult
+{
+    fn into_result(self) -> Result<f64, Failure>;
+}
+
+impl IntoResult for f64
+{
+    fn into_result(self) -> Result<f64, Failure>
+    {
+        Ok(self)
+    }
+}
+
+impl IntoResult for Result<f64, Error>
+{
+    fn into_result(self) -> Result<f64, Failure>
+    {
+        Ok(self?)
+    }
+}
+
+/// Collects functions of a model, each under a path and calling a method of the given field of the model with the given arguments.
+#[macro_export]
+macro_rules! functions
+{
+    ($functions: ident, $model: ident, $path: literal, $($field: ident).+; $($rest: tt)+) => {
+        $crate::functions!(@ $functions, $model, $path, [$($field)+]; $($rest)+)
+    };
+    ($functions: ident, $model: ident, $path: literal; $($rest: tt)+) => {
+        $crate::functions!(@ $functions, $model, $path, []; $($rest)+)
+    };
+    (@ $functions: ident, $model: ident, $path: literal, [$($field: ident)*];) => {};
+    (@ $functions: ident, $model: ident, $path: literal, [$($field: ident)*]; $function: ident => $method: ident($($argument: ident),*) $(, $($rest: tt)*)?) => {
+        let model = $model.clone();
+        $functions.push($crate::Function
+        {
+            path: concat!($path, stringify!($function)),
+            arguments: &[$(stringify!($argument)),*],
+            evaluate: Box::new(move |arguments: &[f64]| match arguments
+            {
+                [$($argument),*] => $crate::IntoResult::into_result(model$(.$field)*.$method($($argument),*)),
+                _ => unreachable!()
+            })
+        });
+        $crate::functions!(@ $functions, $model, $path, [$($field)*]; $($($rest)*)?);
+    }
+}
+
+/// The options given as `--<name> <value>` or `--<name>=<value>`, keyed by name with any hyphens replaced by underscores.
+#[derive(Debug, Default, PartialEq)]
+pub struct Options(BTreeMap<String, String>);
+
+impl Options
+{
+    /// Parses the options from the arguments following the path.
+    pub fn parse(arguments: &[String]) -> Result<Self, Failure>
+    {
+        let mut options = BTreeMap::new();
+        let mut arguments = arguments.iter();
+        while let Some(argument) = arguments.next()
+        {
+            let option = argument.strip_prefix("--").ok_or(Failure::Usage(format!("Expected an option, found {}.", argument)))?;
+            let (name, value) = match option.split_once('=')
+            {
+                Some((name, value)) => (name.to_string(), value.to_string()),
+                None => (option.to_string(), arguments.next().ok_or(Failure::Usage(format!("Missing a value for the option --{}.", option)))?.clone())
+            };
+            if options.insert(name.replace('-', "_"), value).is_some()
+            {
+                return Err(Failure::Usage(format!("The option --{} is given more than once.", option)))
+            }
+        }
+        Ok(Options(options))
+    }
+    /// Returns the value of an option, or nothing if it was not given.
+    pub fn get(&self, name: &str) -> Option<&str>
+    {
+        self.0.get(name).map(String::as_str)
+    }
+    /// Returns the names of the options.
+    pub fn names(&self) -> impl Iterator<Item = &str>
+    {
+        self.0.keys().map(String::as_str)
+    }
+    /// Returns the single value of a parameter, or the default if given and the parameter was not.
+    pub fn scalar(&self, name: &str, default: Option<f64>) -> Result<f64, Failure>
+    {
+        match (self.get(name), default)
+        {
+            (Some(value), _) => value.parse().map_err(|_| Failure::Usage(format!("The option --{} = {} must be a number.", name, value))),
+            (None, Some(default)) => Ok(default),
+            (None, None) => Err(Failure::Usage(format!("Missing the parameter --{}.", name)))
+        }
+    }
+    /// Returns the single value of a parameter that is an integer.
+    pub fn integer<T: std::str::FromStr>(&self, name: &str) -> Result<T, Failure>
+    {
+        let value = self.get(name).ok_or(Failure::Usage(format!("Missing the parameter --{}.", name)))?;
+        value.parse().or_else(|_| match value.parse::<f64>()
+        {
+            Ok(number) if number.fract() == 0.0 => format!("{}", number).parse().map_err(|_| ()),
+            _ => Err(())
+        }).map_err(|_| Failure::Usage(format!("The option --{} = {} must be an integer within range.", name, value)))
+    }
+    /// Returns the number of links.
+    pub fn number_of_links(&self) -> Result<u8, Failure>
+    {
+        self.integer("number_of_links")
+    }
+    /// Returns the values of an argument, given as a single value, a comma-separated list, or a range `<start>:<stop>:<count>`.
+    pub fn values(&self, name: &str) -> Result<Vec<f64>, Failure>
+    {
+        let value = self.get(name).ok_or(Failure::Usage(format!("Missing the argument --{}.", name)))?;
+        let invalid = || Failure::Usage(format!("The option --{} = {} must be a number, a comma-separated list of numbers, or a range <start>:<stop>:<count>.", name, value));
+        let number = |entry: &str| entry.trim().parse::<f64>().map_err(|_| invalid());
+        match value.split(':').collect::<Vec<&str>>()[..]
+        {
+            [start, stop, count] => {
+                let (start, stop) = (number(start)?, number(stop)?);
+                let count: usize = count.trim().parse().map_err(|_| invalid())?;
+                match count
+                {
+                    0 => Err(invalid()),
+                    1 => Ok(vec![start]),
+                    _ => Ok((0..count).map(|index| start + (stop - start) * (index as f64) / ((count - 1) as f64)).collect())
+                }
+            },
+            [_] => value.split(',').map(number).collect(),
+            _ => Err(invalid())
+        }
+    }
+    /// Valid placeholder values of every parameter, used to list the functions of the models.
+    fn placeholder() -> Self
+    {
+        Options([
+            ("chain", "fjc"),
+            ("hinge_mass", "1"),
+            ("link_energy", "1e5"),
+            ("link_length", "1"),
+            ("link_stiffness", "1e3"),
+            ("method", "gibbs_legendre"),
+            ("nonaffine_stretch_parameter", "1"),
+            ("nondimensional_link_stiffness", "1e2"),
+            ("number_of_links", "8"),
+            ("persistance_length", "1"),
+            ("temperature", "300"),
+            ("tube_area_exponent", "0.5"),
+            ("tube_geometry_parameter", "1"),
+            ("well_width", "0.5")
+        ].into_iter().map(|(name, value)| (name.to_string(), value.to_string())).collect())
+    }
+    /// Validates that every option is one of the given names or the output format.
+    pub fn validate(&self, names: &[&str]) -> Result<(), Failure>
+    {
+        match self.names().find(|name| name != &"format" && !names.contains(name))
+        {
+            Some(name) => Err(Failure::Usage(format!("The option --{} is not a parameter or an argument of the function.", name))),
+            None => Ok(())
+        }
+    }
+}
+
+/// The output formats of the table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format
+{
+    CSV,
+    JSON
+}
+
+impl Format
+{
+    fn parse(options: &Options) -> Result<Self, Failure>
+    {
+        match options.get("format")
+        {
+            None | Some("csv") => Ok(Format::CSV),
+            Some("json") => Ok(Format::JSON),
+            Some(format) => Err(Failure::Usage(format!("The option --format = {} must be csv or json.", format)))
+        }
+    }
+}
+
+/// A table of named columns.
+#[derive(Debug, PartialEq)]
+pub struct Table
+{
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<f64>>
+}
+
+impl Table
+{
+    /// Writes the table as comma-separated values with a header, or as a JSON array of objects, where non-finite values are null.
+    pub fn write(&self, format: Format) -> String
+    {
+        match format
+        {
+            Format::CSV => std::iter::once(self.columns.join(",")).chain(self.rows.iter().map(|row|
+                row.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(",")
+            )).map(|line| line + "\n").collect(),
+            Format::JSON => format!("[{}]\n", self.rows.iter().map(|row|
+                format!("{{{}}}", self.columns.iter().zip(row.iter()).map(|(column, value)|
+                    if value.is_finite()
+                    {
+                        format!("\"{}\":{}", column, value)
+                    }
+                    else
+                    {
+                        format!("\"{}\":null", column)
+                    }
+                ).collect::<Vec<String>>().join(","))
+            ).collect::<Vec<String>>().join(","))
+        }
+    }
+}
+
+/// Evaluates a function over every combination of the values of its arguments, where the first argument varies slowest.
+pub fn evaluate(function: &Function, options: &Options) -> Result<Table, Failure>
+{
+    let values = function.arguments.iter().map(|argument| options.values(argument)).collect::<Result<Vec<Vec<f64>>, Failure>>()?;
+    let mut rows: Vec<Vec<f64>> = vec![vec![]];
+    values.iter().for_each(|values_i|
+        rows = rows.iter().flat_map(|row| values_i.iter().map(move |value|{
+            let mut row = row.clone();
+            row.push(*value);
+            row
+        })).collect()
+    );
+    rows.iter_mut().try_for_each(|row|{
+        let value = (function.evaluate)(row)?;
+        row.push(value);
+        Ok::<(), Failure>(())
+    })?;
+    let function_name = function.path.rsplit(' ').next().unwrap();
+    Ok(Table
+    {
+        columns: function.arguments.iter().chain(std::iter::once(&function_name)).map(|column| column.to_string()).collect(),
+        rows
+    })
+}
+
+/// A model, with the names of its parameters and its functions given values of the parameters.
+pub struct Model
+{
+    pub path: &'static str,
+    pub parameters: fn(&Options) -> Vec<&'static str>,
+    pub functions: fn(&Options) -> Result<Vec<Function>, Failure>
+}
+
+/// All of the models with functions of scalar arguments.
+pub fn models() -> Vec<Model>
+{
+    single_chain::models().into_iter().chain(constitutive::models()).collect()
+}
+
+/// Splits the path into the model and the rest of the path, ignoring the thermodynamics module.
+fn split<'a>(models: &'a [Model], path: &[String]) -> Option<(&'a Model, String)>
+{
+    let path: Vec<&str> = path.iter().map(String::as_str).filter(|segment| segment != &"thermodynamics").collect();
+    models.iter().filter_map(|model|{
+        let length = model.path.split(' ').count();
+        (path.len() >= length && path[..length].join(" ") == model.path).then(|| (model, path[length..].join(" ")))
+    }).next()
+}
+
+/// Runs the command-line tool on the given arguments, returning the output.
+pub fn run(arguments: &[String]) -> Result<String, Failure>
+{
+    let index = arguments.iter().position(|argument| argument.starts_with("--")).unwrap_or(arguments.len());
+    let (path, options) = (&arguments[..index], Options::parse(&arguments[index..])?);
+    let format = Format::parse(&options)?;
+    let models = models();
+    match path.first().map(String::as_str)
+    {
+        None | Some("help") => Ok(format!("{}\n", USAGE)),
+        Some("list") => list(&models, &path[1..]),
+        _ => {
+            if let Some(table) = monte_carlo::run(path, &options)?
+            {
+                return Ok(table.write(format))
+            }
+            let (model, rest) = split(&models, path).ok_or(Failure::Usage(format!("Unknown model in {}.", path.join(" "))))?;
+            let parameters = (model.parameters)(&options);
+            let functions = (model.functions)(&options)?;
+            let function = functions.iter().find(|function| function.path == rest).ok_or(
+                Failure::Usage(format!("Unknown function {} of the model {}.", rest, model.path))
+            )?;
+            options.validate(&parameters.iter().chain(function.arguments.iter()).copied().collect::<Vec<&str>>())?;
+            Ok(evaluate(function, &options)?.write(format))
+        }
+    }
+}
+
+/// Whether the path is under the given prefix.
+fn under(path: &str, prefix: &[&str]) -> bool
+{
+    let segments: Vec<&str> = path.split(' ').collect();
+    prefix.len() <= segments.len() && segments.iter().zip(prefix.iter()).all(|(segment, prefix_i)| segment == prefix_i)
+}
+
+/// Lists the functions of the models under the given path, along with the parameters of the models and the arguments of the functions.
+fn list(models: &[Model], path: &[String]) -> Result<String, Failure>
+{
+    let prefix: Vec<&str> = path.iter().map(String::as_str).filter(|segment| segment != &"thermodynamics").collect();
+    let placeholder = Options::placeholder();
+    let mut lines = vec![];
+    models.iter().try_for_each(|model|{
+        (model.functions)(&placeholder)?.iter().for_each(|function|{
+            let full_path = format!("{} {}", model.path, function.path);
+            if under(&full_path, &prefix)
+            {
+                lines.push(format!("{} ({}) [{}]", full_path, function.arguments.join(", "), (model.parameters)(&Options::default()).join(", ")));
+            }
+        });
+        Ok::<(), Failure>(())
+    })?;
+    monte_carlo::SAMPLERS.iter().for_each(|(sampler_path, parameters)|
+        if under(sampler_path, &prefix)
+        {
+            lines.push(format!("{} () [{}]", sampler_path, parameters.join(", ")));
+        }
+    );
+    match lines.is_empty()
+    {
+        true => Err(Failure::Usage(format!("No functions under {}.", prefix.join(" ")))),
+        false => Ok(lines.into_iter().map(|line| line + "\n").collect())
+    }
+}
+
+fn main() -> ExitCode
+{
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    match run(&arguments)
+    {
+        Ok(output) => {
+            print!("{}", output);
+            ExitCode::SUCCESS
+        },
+        Err(failure) => {
+            eprintln!("{}", failure);
+            if let Failure::Usage(_) = failure
+            {
+                eprintln!("\n{}", USAGE);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use polymers::physics::single_chain::
+{
+    efjc::thermodynamics::isometric::monte_carlo as efjc,
+    efrc::thermodynamics::isometric::monte_carlo as efrc,
+    fjc::thermodynamics::isometric::monte_carlo as fjc,
+    frc::thermodynamics::isometric::monte_carlo as frc
+};
+use super::
+{
+    Failure,
+    Options,
+    Table
+};
+
+/// The number of bins of the sampled equilibrium radial distributions, matching the bundled datasets.
+pub const NUMBER_OF_BINS: usize = 1_000;
+
+/// The Monte Carlo samplers of the equilibrium radial distribution, each under the path of its module, along with its parameters.
+pub const SAMPLERS: [(&str, &[&str]); 4] = [
+    ("fjc isometric monte_carlo nondimensional_equilibrium_radial_distribution", &["number_of_links", "number_of_samples"]),
+    ("efjc isometric monte_carlo nondimensional_equilibrium_radial_distribution", &["number_of_links", "gamma_max", "kappa", "number_of_samples"]),
+    ("frc isometric monte_carlo nondimensional_equilibrium_radial_distribution", &["number_of_links", "theta", "number_of_samples"]),
+    ("efrc isometric monte_carlo nondimensional_equilibrium_radial_distribution", &["number_of_links", "gamma_max", "kappa", "theta", "number_of_samples"])
+];
+
+/// Dispatches a sampler to the number of links given at runtime, which must be one of the given numbers of links known at compile time.
+macro_rules! number_of_links
+{
+    ($sampler: ident, $options: ident, [$($number_of_links: literal),+]) => {
+        match $options.number_of_links()?
+        {
+            $($number_of_links => $sampler::<$number_of_links>($options),)+
+            number_of_links => Err(Failure::Usage(format!("The parameter --number_of_links = {} must be from 1 to 32 for the Monte Carlo samplers.", number_of_links)))
+        }
+    }
+}
+
+/// Samples the equilibrium radial distribution if the path is one of the Monte Carlo samplers, or returns nothing otherwise.
+pub fn run(path: &[String], options: &Options) -> Result<Option<Table>, Failure>
+{
+    let path = path.iter().map(String::as_str).filter(|segment| segment != &"thermodynamics").collect::<Vec<&str>>().join(" ");
+    let (sampler_path, parameters) = match SAMPLERS.iter().find(|(sampler_path, _)| sampler_path == &path)
+    {
+        Some(sampler) => sampler,
+        None => return Ok(None)
+    };
+    options.validate(parameters)?;
+    let (gamma, g_eq) = match sampler_path.split(' ').next().unwrap()
+    {
+        "fjc" => number_of_links!(sample_fjc, options, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32]),
+        "efjc" => number_of_links!(sample_efjc, options, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32]),
+        "frc" => number_of_links!(sample_frc, options, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32]),
+        _ => number_of_links!(sample_efrc, options, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32])
+    }?;
+    Ok(Some(Table
+    {
+        columns: vec!["nondimensional_end_to_end_length_per_link".to_string(), "nondimensional_equilibrium_radial_distribution".to_string()],
+        rows: gamma.iter().zip(g_eq.iter()).map(|(gamma_i, g_eq_i)| vec![*gamma_i, *g_eq_i]).collect()
+    }))
+}
+
+type Distribution = ([f64; NUMBER_OF_BINS], [f64; NUMBER_OF_BINS]);
+
+fn sample_fjc<const NUMBER_OF_LINKS: usize>(options: &Options) -> Result<Distribution, Failure>
+{
+    Ok(fjc::nondimensional_equilibrium_radial_distribution::<NUMBER_OF_BINS, NUMBER_OF_LINKS>(options.integer("number_of_samples")?))
+}
+
+fn sample_efjc<const NUMBER_OF_LINKS: usize>(options: &Options) -> Result<Distribution, Failure>
+{
+    Ok(efjc::try_nondimensional_equilibrium_radial_distribution::<NUMBER_OF_BINS, NUMBER_OF_LINKS>(&options.scalar("gamma_max", None)?, &options.scalar("kappa", None)?, options.integer("number_of_samples")?)?)
+}
+
+fn sample_frc<const NUMBER_OF_LINKS: usize>(options: &Options) -> Result<Distribution, Failure>
+{
+    Ok(frc::try_nondimensional_equilibrium_radial_distribution::<NUMBER_OF_BINS, NUMBER_OF_LINKS>(&options.scalar("theta", None)?, options.integer("number_of_samples")?)?)
+}
+
+fn sample_efrc<const NUMBER_OF_LINKS: usize>(options: &Options) -> Result<Distribution, Failure>
+{
+    Ok(efrc::try_nondimensional_equilibrium_radial_distribution::<NUMBER_OF_BINS, NUMBER_OF_LINKS>(&options.scalar("gamma_max", None)?, &options.scalar("kappa", None)?, &options.scalar("theta", None)?, options.integer("number_of_samples")?)?)
+}
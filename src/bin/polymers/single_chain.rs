@@ -0,0 +1,980 @@
+use std::rc::Rc;
+use polymers::physics::single_chain::
+{
+    ideal::Ideal,
+    fjc::FJC,
+    efjc::EFJC,
+    swfjc::SWFJC,
+    wlc::WLC,
+    ufjc::lennard_jones::LENNARDJONESFJC,
+    ufjc::log_squared::LOGSQUAREDFJC,
+    ufjc::morse::MORSEFJC
+};
+use super::
+{
+    Failure,
+    Function,
+    Model,
+    Options
+};
+
+/// The single-chain models, each under the path of its module.
+pub fn models() -> Vec<Model>
+{
+    vec![
+        Model
+        {
+            path: "ideal",
+            parameters: |_| vec!["number_of_links", "link_length", "hinge_mass"],
+            functions: ideal
+        },
+        Model
+        {
+            path: "fjc",
+            parameters: |_| vec!["number_of_links", "link_length", "hinge_mass"],
+            functions: fjc
+        },
+        Model
+        {
+            path: "efjc",
+            parameters: |_| vec!["number_of_links", "link_length", "hinge_mass", "link_stiffness"],
+            functions: efjc
+        },
+        Model
+        {
+            path: "swfjc",
+            parameters: |_| vec!["number_of_links", "link_length", "hinge_mass", "well_width"],
+            functions: swfjc
+        },
+        Model
+        {
+            path: "wlc",
+            parameters: |_| vec!["number_of_links", "link_length", "hinge_mass", "persistance_length"],
+            functions: wlc
+        },
+        Model
+        {
+            path: "ufjc lennard_jones",
+            parameters: |_| vec!["number_of_links", "link_length", "hinge_mass", "link_stiffness"],
+            functions: lennard_jones
+        },
+        Model
+        {
+            path: "ufjc log_squared",
+            parameters: |_| vec!["number_of_links", "link_length", "hinge_mass", "link_stiffness"],
+            functions: log_squared
+        },
+        Model
+        {
+            path: "ufjc morse",
+            parameters: |_| vec!["number_of_links", "link_length", "hinge_mass", "link_stiffness", "link_energy"],
+            functions: morse
+        }
+    ]
+}
+
+/// The functions of the ideal chain model.
+fn ideal(options: &Options) -> Result<Vec<Function>, Failure>
+{
+    let model = Rc::new(Ideal::try_init(options.number_of_links()?, options.scalar("link_length", Some(1.0))?, options.scalar("hinge_mass", Some(1.0))?)?);
+    let mut functions = vec![];
+    crate::functions!(functions, model, "isometric ", thermodynamics.isometric;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link),
+        end_to_end_length => end_to_end_length(force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link),
+        equilibrium_distribution => equilibrium_distribution(end_to_end_length),
+        nondimensional_equilibrium_distribution => nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link),
+        equilibrium_radial_distribution => equilibrium_radial_distribution(end_to_end_length),
+        nondimensional_equilibrium_radial_distribution => nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link),
+        stiffness => stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => nondimensional_stiffness(nondimensional_end_to_end_length_per_link)
+    );
+    crate::functions!(functions, model, "isotensional ", thermodynamics.isotensional;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force),
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force),
+        compliance => compliance(force, temperature),
+        compliance_per_link => compliance_per_link(force, temperature),
+        nondimensional_compliance => nondimensional_compliance(nondimensional_force),
+        nondimensional_compliance_per_link => nondimensional_compliance_per_link(nondimensional_force),
+        end_to_end_length_variance => end_to_end_length_variance(force, temperature),
+        nondimensional_end_to_end_length_variance => nondimensional_end_to_end_length_variance(nondimensional_force)
+    );
+    Ok(functions)
+}
+
+/// The functions of the freely-jointed chain (FJC) model.
+fn fjc(options: &Options) -> Result<Vec<Function>, Failure>
+{
+    let model = Rc::new(FJC::try_init(options.number_of_links()?, options.scalar("link_length", Some(1.0))?, options.scalar("hinge_mass", Some(1.0))?)?);
+    let mut functions = vec![];
+    crate::functions!(functions, model, "isometric ", thermodynamics.isometric;
+        force => try_force(end_to_end_length, temperature),
+        nondimensional_force => try_nondimensional_force(nondimensional_end_to_end_length_per_link),
+        end_to_end_length => end_to_end_length(force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force),
+        helmholtz_free_energy => try_helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => try_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => try_relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => try_relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => try_nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => try_nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => try_nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link),
+        nondimensional_relative_helmholtz_free_energy_per_link => try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link),
+        equilibrium_distribution => try_equilibrium_distribution(end_to_end_length),
+        nondimensional_equilibrium_distribution => try_nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link),
+        equilibrium_radial_distribution => try_equilibrium_radial_distribution(end_to_end_length),
+        nondimensional_equilibrium_radial_distribution => try_nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link),
+        stiffness => try_stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => try_nondimensional_stiffness(nondimensional_end_to_end_length_per_link)
+    );
+    crate::functions!(functions, model, "isometric legendre ", thermodynamics.isometric.legendre;
+        force => try_force(end_to_end_length, temperature),
+        nondimensional_force => try_nondimensional_force(nondimensional_end_to_end_length_per_link),
+        helmholtz_free_energy => try_helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => try_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => try_relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => try_relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => try_nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => try_nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => try_nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link),
+        nondimensional_relative_helmholtz_free_energy_per_link => try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link),
+        equilibrium_distribution => try_equilibrium_distribution(end_to_end_length),
+        nondimensional_equilibrium_distribution => try_nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link),
+        equilibrium_radial_distribution => try_equilibrium_radial_distribution(end_to_end_length),
+        nondimensional_equilibrium_radial_distribution => try_nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link),
+        gibbs_free_energy => try_gibbs_free_energy(end_to_end_length, temperature),
+        gibbs_free_energy_per_link => try_gibbs_free_energy_per_link(end_to_end_length, temperature),
+        relative_gibbs_free_energy => try_relative_gibbs_free_energy(end_to_end_length, temperature),
+        relative_gibbs_free_energy_per_link => try_relative_gibbs_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_gibbs_free_energy => try_nondimensional_gibbs_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_gibbs_free_energy_per_link => try_nondimensional_gibbs_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_gibbs_free_energy => try_nondimensional_relative_gibbs_free_energy(nondimensional_end_to_end_length_per_link),
+        nondimensional_relative_gibbs_free_energy_per_link => try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_end_to_end_length_per_link),
+        stiffness => try_stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => try_nondimensional_stiffness(nondimensional_end_to_end_length_per_link)
+    );
+    crate::functions!(functions, model, "isotensional ", thermodynamics.isotensional;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force),
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force),
+        compliance => compliance(force, temperature),
+        compliance_per_link => compliance_per_link(force, temperature),
+        nondimensional_compliance => nondimensional_compliance(nondimensional_force),
+        nondimensional_compliance_per_link => nondimensional_compliance_per_link(nondimensional_force),
+        end_to_end_length_variance => end_to_end_length_variance(force, temperature),
+        nondimensional_end_to_end_length_variance => nondimensional_end_to_end_length_variance(nondimensional_force)
+    );
+    crate::functions!(functions, model, "isotensional legendre ", thermodynamics.isotensional.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force)
+    );
+    crate::functions!(functions, model, "modified_canonical ", thermodynamics.modified_canonical;
+        end_to_end_length => end_to_end_length(potential_distance, potential_stiffness, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(potential_distance, potential_stiffness, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        force => force(potential_distance, potential_stiffness, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        helmholtz_free_energy => helmholtz_free_energy(potential_distance, potential_stiffness, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(potential_distance, potential_stiffness, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(potential_distance, potential_stiffness, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(potential_distance, potential_stiffness, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        gibbs_free_energy => gibbs_free_energy(potential_distance, potential_stiffness, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(potential_distance, potential_stiffness, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(potential_distance, potential_stiffness, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(potential_distance, potential_stiffness, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness)
+    );
+    crate::functions!(functions, model, "modified_canonical asymptotic weak_potential ", thermodynamics.modified_canonical.asymptotic.weak_potential;
+        end_to_end_length => end_to_end_length(potential_distance, potential_stiffness, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(potential_distance, potential_stiffness, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        force => force(potential_distance, potential_stiffness),
+        nondimensional_force => nondimensional_force(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        gibbs_free_energy => gibbs_free_energy(potential_distance, potential_stiffness, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(potential_distance, potential_stiffness, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(potential_distance, potential_stiffness, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(potential_distance, potential_stiffness, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness)
+    );
+    crate::functions!(functions, model, "modified_canonical asymptotic strong_potential ", thermodynamics.modified_canonical.asymptotic.strong_potential;
+        force => force(potential_distance, potential_stiffness, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        helmholtz_free_energy => helmholtz_free_energy(potential_distance, potential_stiffness, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(potential_distance, potential_stiffness, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(potential_distance, potential_stiffness, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(potential_distance, potential_stiffness, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness)
+    );
+    Ok(functions)
+}
+
+/// The functions of the extensible freely-jointed chain (EFJC) model.
+fn efjc(options: &Options) -> Result<Vec<Function>, Failure>
+{
+    let model = Rc::new(EFJC::try_init(options.number_of_links()?, options.scalar("link_length", Some(1.0))?, options.scalar("hinge_mass", Some(1.0))?, options.scalar("link_stiffness", None)?)?);
+    let mut functions = vec![];
+    crate::functions!(functions, model, "isometric asymptotic alternative legendre ", thermodynamics.isometric.asymptotic.alternative.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isometric asymptotic reduced legendre ", thermodynamics.isometric.asymptotic.reduced.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isometric asymptotic legendre ", thermodynamics.isometric.asymptotic.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        stiffness => stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => nondimensional_stiffness(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isotensional ", thermodynamics.isotensional;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        compliance => compliance(force, temperature),
+        compliance_per_link => compliance_per_link(force, temperature),
+        nondimensional_compliance => nondimensional_compliance(nondimensional_force, temperature),
+        nondimensional_compliance_per_link => nondimensional_compliance_per_link(nondimensional_force, temperature),
+        end_to_end_length_variance => end_to_end_length_variance(force, temperature),
+        nondimensional_end_to_end_length_variance => nondimensional_end_to_end_length_variance(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic ", thermodynamics.isotensional.asymptotic;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic alternative ", thermodynamics.isotensional.asymptotic.alternative;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic alternative legendre ", thermodynamics.isotensional.asymptotic.alternative.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic reduced ", thermodynamics.isotensional.asymptotic.reduced;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic reduced legendre ", thermodynamics.isotensional.asymptotic.reduced.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic legendre ", thermodynamics.isotensional.asymptotic.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional legendre ", thermodynamics.isotensional.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    Ok(functions)
+}
+
+/// The functions of the square-well freely-jointed chain (SWFJC) model.
+fn swfjc(options: &Options) -> Result<Vec<Function>, Failure>
+{
+    let model = Rc::new(SWFJC::try_init(options.number_of_links()?, options.scalar("link_length", Some(1.0))?, options.scalar("hinge_mass", Some(1.0))?, options.scalar("well_width", None)?)?);
+    let mut functions = vec![];
+    crate::functions!(functions, model, "isometric legendre ", thermodynamics.isometric.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link),
+        equilibrium_distribution => equilibrium_distribution(end_to_end_length),
+        nondimensional_equilibrium_distribution => nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link),
+        equilibrium_radial_distribution => equilibrium_radial_distribution(end_to_end_length),
+        nondimensional_equilibrium_radial_distribution => nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link),
+        stiffness => stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => nondimensional_stiffness(nondimensional_end_to_end_length_per_link)
+    );
+    crate::functions!(functions, model, "isotensional ", thermodynamics.isotensional;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force),
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force),
+        compliance => compliance(force, temperature),
+        compliance_per_link => compliance_per_link(force, temperature),
+        nondimensional_compliance => nondimensional_compliance(nondimensional_force),
+        nondimensional_compliance_per_link => nondimensional_compliance_per_link(nondimensional_force),
+        end_to_end_length_variance => end_to_end_length_variance(force, temperature),
+        nondimensional_end_to_end_length_variance => nondimensional_end_to_end_length_variance(nondimensional_force)
+    );
+    crate::functions!(functions, model, "isotensional legendre ", thermodynamics.isotensional.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force)
+    );
+    Ok(functions)
+}
+
+/// The functions of the worm-like chain (WLC) model.
+fn wlc(options: &Options) -> Result<Vec<Function>, Failure>
+{
+    let model = Rc::new(WLC::try_init(options.number_of_links()?, options.scalar("link_length", Some(1.0))?, options.scalar("hinge_mass", Some(1.0))?, options.scalar("persistance_length", None)?)?);
+    let mut functions = vec![];
+    crate::functions!(functions, model, "isometric ", thermodynamics.isometric;
+        force => try_force(end_to_end_length, temperature),
+        nondimensional_force => try_nondimensional_force(nondimensional_end_to_end_length_per_link),
+        end_to_end_length => end_to_end_length(force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force),
+        helmholtz_free_energy => try_helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => try_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => try_relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => try_relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => try_nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => try_nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => try_nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link),
+        nondimensional_relative_helmholtz_free_energy_per_link => try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link),
+        equilibrium_distribution => try_equilibrium_distribution(end_to_end_length),
+        nondimensional_equilibrium_distribution => try_nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link),
+        equilibrium_radial_distribution => try_equilibrium_radial_distribution(end_to_end_length),
+        nondimensional_equilibrium_radial_distribution => try_nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link),
+        stiffness => try_stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => try_nondimensional_stiffness(nondimensional_end_to_end_length_per_link)
+    );
+    crate::functions!(functions, model, "isometric legendre ", thermodynamics.isometric.legendre;
+        gibbs_free_energy => try_gibbs_free_energy(end_to_end_length, temperature),
+        gibbs_free_energy_per_link => try_gibbs_free_energy_per_link(end_to_end_length, temperature),
+        relative_gibbs_free_energy => try_relative_gibbs_free_energy(end_to_end_length, temperature),
+        relative_gibbs_free_energy_per_link => try_relative_gibbs_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_gibbs_free_energy => try_nondimensional_gibbs_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_gibbs_free_energy_per_link => try_nondimensional_gibbs_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_gibbs_free_energy => try_nondimensional_relative_gibbs_free_energy(nondimensional_end_to_end_length_per_link),
+        nondimensional_relative_gibbs_free_energy_per_link => try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_end_to_end_length_per_link)
+    );
+    crate::functions!(functions, model, "isotensional ", thermodynamics.isotensional;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force),
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force),
+        compliance => compliance(force, temperature),
+        compliance_per_link => compliance_per_link(force, temperature),
+        nondimensional_compliance => nondimensional_compliance(nondimensional_force),
+        nondimensional_compliance_per_link => nondimensional_compliance_per_link(nondimensional_force),
+        end_to_end_length_variance => end_to_end_length_variance(force, temperature),
+        nondimensional_end_to_end_length_variance => nondimensional_end_to_end_length_variance(nondimensional_force)
+    );
+    crate::functions!(functions, model, "isotensional legendre ", thermodynamics.isotensional.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force)
+    );
+    Ok(functions)
+}
+
+/// The functions of the Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model.
+fn lennard_jones(options: &Options) -> Result<Vec<Function>, Failure>
+{
+    let model = Rc::new(LENNARDJONESFJC::try_init(options.number_of_links()?, options.scalar("link_length", Some(1.0))?, options.scalar("hinge_mass", Some(1.0))?, options.scalar("link_stiffness", None)?)?);
+    let mut functions = vec![];
+    crate::functions!(functions, model, "rupture ", rupture;
+        energy_barrier => energy_barrier(force, temperature),
+        nondimensional_energy_barrier => nondimensional_energy_barrier(nondimensional_force, temperature),
+        link_breaking_rate => link_breaking_rate(force, temperature),
+        nondimensional_link_breaking_rate => nondimensional_link_breaking_rate(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "rupture isometric ", rupture.isometric;
+        breaking_rate => breaking_rate(end_to_end_length, temperature),
+        nondimensional_breaking_rate => nondimensional_breaking_rate(nondimensional_end_to_end_length_per_link, temperature),
+        survival_probability => survival_probability(end_to_end_length, time, temperature),
+        nondimensional_survival_probability => nondimensional_survival_probability(nondimensional_end_to_end_length_per_link, nondimensional_time, temperature),
+        most_probable_rupture_force => most_probable_rupture_force(extension_rate, temperature),
+        nondimensional_most_probable_rupture_force => nondimensional_most_probable_rupture_force(nondimensional_extension_rate, temperature)
+    );
+    crate::functions!(functions, model, "rupture isotensional ", rupture.isotensional;
+        breaking_rate => breaking_rate(force, temperature),
+        nondimensional_breaking_rate => nondimensional_breaking_rate(nondimensional_force, temperature),
+        survival_probability => survival_probability(force, time, temperature),
+        nondimensional_survival_probability => nondimensional_survival_probability(nondimensional_force, nondimensional_time, temperature),
+        most_probable_rupture_force => most_probable_rupture_force(loading_rate, temperature),
+        nondimensional_most_probable_rupture_force => nondimensional_most_probable_rupture_force(nondimensional_loading_rate, temperature)
+    );
+    crate::functions!(functions, model, "isometric ", thermodynamics.isometric;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        end_to_end_length => end_to_end_length(force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        equilibrium_distribution => equilibrium_distribution(end_to_end_length, temperature),
+        nondimensional_equilibrium_distribution => nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link, temperature),
+        equilibrium_radial_distribution => equilibrium_radial_distribution(end_to_end_length, temperature),
+        nondimensional_equilibrium_radial_distribution => nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isometric asymptotic reduced legendre ", thermodynamics.isometric.asymptotic.reduced.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isometric asymptotic legendre ", thermodynamics.isometric.asymptotic.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        stiffness => stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => nondimensional_stiffness(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isotensional ", thermodynamics.isotensional;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        compliance => compliance(force, temperature),
+        compliance_per_link => compliance_per_link(force, temperature),
+        nondimensional_compliance => nondimensional_compliance(nondimensional_force, temperature),
+        nondimensional_compliance_per_link => nondimensional_compliance_per_link(nondimensional_force, temperature),
+        end_to_end_length_variance => end_to_end_length_variance(force, temperature),
+        nondimensional_end_to_end_length_variance => nondimensional_end_to_end_length_variance(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic ", thermodynamics.isotensional.asymptotic;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic reduced ", thermodynamics.isotensional.asymptotic.reduced;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic reduced legendre ", thermodynamics.isotensional.asymptotic.reduced.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic legendre ", thermodynamics.isotensional.asymptotic.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional legendre ", thermodynamics.isotensional.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    Ok(functions)
+}
+
+/// The functions of the log-squared link potential freely-jointed chain (log-squared-FJC) model.
+fn log_squared(options: &Options) -> Result<Vec<Function>, Failure>
+{
+    let model = Rc::new(LOGSQUAREDFJC::try_init(options.number_of_links()?, options.scalar("link_length", Some(1.0))?, options.scalar("hinge_mass", Some(1.0))?, options.scalar("link_stiffness", None)?)?);
+    let mut functions = vec![];
+    crate::functions!(functions, model, "isometric ", thermodynamics.isometric;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        end_to_end_length => end_to_end_length(force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        equilibrium_distribution => equilibrium_distribution(end_to_end_length, temperature),
+        nondimensional_equilibrium_distribution => nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link, temperature),
+        equilibrium_radial_distribution => equilibrium_radial_distribution(end_to_end_length, temperature),
+        nondimensional_equilibrium_radial_distribution => nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isometric asymptotic reduced legendre ", thermodynamics.isometric.asymptotic.reduced.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isometric asymptotic legendre ", thermodynamics.isometric.asymptotic.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        stiffness => stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => nondimensional_stiffness(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isotensional ", thermodynamics.isotensional;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        compliance => compliance(force, temperature),
+        compliance_per_link => compliance_per_link(force, temperature),
+        nondimensional_compliance => nondimensional_compliance(nondimensional_force, temperature),
+        nondimensional_compliance_per_link => nondimensional_compliance_per_link(nondimensional_force, temperature),
+        end_to_end_length_variance => end_to_end_length_variance(force, temperature),
+        nondimensional_end_to_end_length_variance => nondimensional_end_to_end_length_variance(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic ", thermodynamics.isotensional.asymptotic;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic reduced ", thermodynamics.isotensional.asymptotic.reduced;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic reduced legendre ", thermodynamics.isotensional.asymptotic.reduced.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic legendre ", thermodynamics.isotensional.asymptotic.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional legendre ", thermodynamics.isotensional.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    Ok(functions)
+}
+
+/// The functions of the Morse link potential freely-jointed chain (Morse-FJC) model.
+fn morse(options: &Options) -> Result<Vec<Function>, Failure>
+{
+    let model = Rc::new(MORSEFJC::try_init(options.number_of_links()?, options.scalar("link_length", Some(1.0))?, options.scalar("hinge_mass", Some(1.0))?, options.scalar("link_stiffness", None)?, options.scalar("link_energy", None)?)?);
+    let mut functions = vec![];
+    crate::functions!(functions, model, "rupture ", rupture;
+        energy_barrier => energy_barrier(force, temperature),
+        nondimensional_energy_barrier => nondimensional_energy_barrier(nondimensional_force, temperature),
+        link_breaking_rate => link_breaking_rate(force, temperature),
+        nondimensional_link_breaking_rate => nondimensional_link_breaking_rate(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "rupture isometric ", rupture.isometric;
+        breaking_rate => breaking_rate(end_to_end_length, temperature),
+        nondimensional_breaking_rate => nondimensional_breaking_rate(nondimensional_end_to_end_length_per_link, temperature),
+        survival_probability => survival_probability(end_to_end_length, time, temperature),
+        nondimensional_survival_probability => nondimensional_survival_probability(nondimensional_end_to_end_length_per_link, nondimensional_time, temperature),
+        most_probable_rupture_force => most_probable_rupture_force(extension_rate, temperature),
+        nondimensional_most_probable_rupture_force => nondimensional_most_probable_rupture_force(nondimensional_extension_rate, temperature)
+    );
+    crate::functions!(functions, model, "rupture isotensional ", rupture.isotensional;
+        breaking_rate => breaking_rate(force, temperature),
+        nondimensional_breaking_rate => nondimensional_breaking_rate(nondimensional_force, temperature),
+        survival_probability => survival_probability(force, time, temperature),
+        nondimensional_survival_probability => nondimensional_survival_probability(nondimensional_force, nondimensional_time, temperature),
+        most_probable_rupture_force => most_probable_rupture_force(loading_rate, temperature),
+        nondimensional_most_probable_rupture_force => nondimensional_most_probable_rupture_force(nondimensional_loading_rate, temperature)
+    );
+    crate::functions!(functions, model, "isometric ", thermodynamics.isometric;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        end_to_end_length => end_to_end_length(force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        equilibrium_distribution => equilibrium_distribution(end_to_end_length, temperature),
+        nondimensional_equilibrium_distribution => nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link, temperature),
+        equilibrium_radial_distribution => equilibrium_radial_distribution(end_to_end_length, temperature),
+        nondimensional_equilibrium_radial_distribution => nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isometric asymptotic reduced legendre ", thermodynamics.isometric.asymptotic.reduced.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isometric asymptotic legendre ", thermodynamics.isometric.asymptotic.legendre;
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        helmholtz_free_energy => helmholtz_free_energy(end_to_end_length, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(end_to_end_length, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(end_to_end_length, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature),
+        stiffness => stiffness(end_to_end_length, temperature),
+        nondimensional_stiffness => nondimensional_stiffness(nondimensional_end_to_end_length_per_link, temperature)
+    );
+    crate::functions!(functions, model, "isotensional ", thermodynamics.isotensional;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        force => force(end_to_end_length, temperature),
+        nondimensional_force => nondimensional_force(nondimensional_end_to_end_length_per_link, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        compliance => compliance(force, temperature),
+        compliance_per_link => compliance_per_link(force, temperature),
+        nondimensional_compliance => nondimensional_compliance(nondimensional_force, temperature),
+        nondimensional_compliance_per_link => nondimensional_compliance_per_link(nondimensional_force, temperature),
+        end_to_end_length_variance => end_to_end_length_variance(force, temperature),
+        nondimensional_end_to_end_length_variance => nondimensional_end_to_end_length_variance(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic ", thermodynamics.isotensional.asymptotic;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic reduced ", thermodynamics.isotensional.asymptotic.reduced;
+        end_to_end_length => end_to_end_length(force, temperature),
+        end_to_end_length_per_link => end_to_end_length_per_link(force, temperature),
+        nondimensional_end_to_end_length => nondimensional_end_to_end_length(nondimensional_force, temperature),
+        nondimensional_end_to_end_length_per_link => nondimensional_end_to_end_length_per_link(nondimensional_force, temperature),
+        gibbs_free_energy => gibbs_free_energy(force, temperature),
+        gibbs_free_energy_per_link => gibbs_free_energy_per_link(force, temperature),
+        relative_gibbs_free_energy => relative_gibbs_free_energy(force, temperature),
+        relative_gibbs_free_energy_per_link => relative_gibbs_free_energy_per_link(force, temperature),
+        nondimensional_gibbs_free_energy => nondimensional_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_gibbs_free_energy_per_link => nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy => nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_gibbs_free_energy_per_link => nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic reduced legendre ", thermodynamics.isotensional.asymptotic.reduced.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional asymptotic legendre ", thermodynamics.isotensional.asymptotic.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    crate::functions!(functions, model, "isotensional legendre ", thermodynamics.isotensional.legendre;
+        helmholtz_free_energy => helmholtz_free_energy(force, temperature),
+        helmholtz_free_energy_per_link => helmholtz_free_energy_per_link(force, temperature),
+        relative_helmholtz_free_energy => relative_helmholtz_free_energy(force, temperature),
+        relative_helmholtz_free_energy_per_link => relative_helmholtz_free_energy_per_link(force, temperature),
+        nondimensional_helmholtz_free_energy => nondimensional_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_helmholtz_free_energy_per_link => nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy => nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature),
+        nondimensional_relative_helmholtz_free_energy_per_link => nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
+    );
+    Ok(functions)
+}
@@ -0,0 +1,135 @@
+#![cfg(test)]
+use super::*;
+fn arguments(line: &str) -> Vec<String>
+{
+    line.split_whitespace().map(String::from).collect()
+}
+mod options
+{
+    use super::*;
+    #[test]
+    fn values()
+    {
+        let options = Options::parse(&arguments("--a 1.5 --b=1,2,3 --c 0:1:5 --d-e 2:4:1")).unwrap();
+        assert_eq!(options.values("a").unwrap(), vec![1.5]);
+        assert_eq!(options.values("b").unwrap(), vec![1.0, 2.0, 3.0]);
+        assert_eq!(options.values("c").unwrap(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(options.values("d_e").unwrap(), vec![2.0]);
+    }
+    #[test]
+    fn scalars()
+    {
+        let options = Options::parse(&arguments("--number_of_links 8 --number_of_samples 1e3 --link_length 0.5")).unwrap();
+        assert_eq!(options.number_of_links().unwrap(), 8);
+        assert_eq!(options.integer::<usize>("number_of_samples").unwrap(), 1000);
+        assert_eq!(options.scalar("link_length", Some(1.0)).unwrap(), 0.5);
+        assert_eq!(options.scalar("hinge_mass", Some(1.0)).unwrap(), 1.0);
+        assert!(options.scalar("temperature", None).is_err());
+    }
+    #[test]
+    fn invalid()
+    {
+        assert!(Options::parse(&arguments("--a")).is_err());
+        assert!(Options::parse(&arguments("a 1")).is_err());
+        assert!(Options::parse(&arguments("--a 1 --a 2")).is_err());
+        let options = Options::parse(&arguments("--a x --b 0:1:0 --c 0:1 --number_of_links 300")).unwrap();
+        assert!(options.values("a").is_err());
+        assert!(options.values("b").is_err());
+        assert!(options.values("c").is_err());
+        assert!(options.number_of_links().is_err());
+    }
+}
+mod run
+{
+    use super::*;
+    use polymers::physics::single_chain::
+    {
+        efjc::EFJC,
+        fjc::FJC
+    };
+    #[test]
+    fn single_chain()
+    {
+        let model = EFJC::init(8, 1.0, 1.0, 1e6);
+        let output = run(&arguments("efjc isotensional nondimensional_end_to_end_length_per_link --number_of_links 8 --link_stiffness 1e6 --nondimensional_force 1:3:3 --temperature 300")).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(lines.next().unwrap(), "nondimensional_force,temperature,nondimensional_end_to_end_length_per_link");
+        lines.zip([1.0, 2.0, 3.0]).for_each(|(line, nondimensional_force)|
+            assert_eq!(line, format!("{},300,{}", nondimensional_force, model.thermodynamics.isotensional.nondimensional_end_to_end_length_per_link(&nondimensional_force, &300.0)))
+        );
+    }
+    #[test]
+    fn thermodynamics()
+    {
+        assert_eq!(
+            run(&arguments("fjc thermodynamics isometric legendre nondimensional_force --number_of_links 8 --nondimensional_end_to_end_length_per_link 0.5")),
+            run(&arguments("fjc isometric legendre nondimensional_force --number_of_links 8 --nondimensional_end_to_end_length_per_link 0.5"))
+        );
+    }
+    #[test]
+    fn combinations()
+    {
+        let model = FJC::init(8, 1.0, 1.0);
+        let output = run(&arguments("fjc isotensional end_to_end_length --number_of_links 8 --force 1,2 --temperature 200:300:3")).unwrap();
+        let rows: Vec<Vec<f64>> = output.lines().skip(1).map(|line| line.split(',').map(|entry| entry.parse().unwrap()).collect()).collect();
+        assert_eq!(rows.len(), 6);
+        rows.iter().zip([[1.0, 200.0], [1.0, 250.0], [1.0, 300.0], [2.0, 200.0], [2.0, 250.0], [2.0, 300.0]]).for_each(|(row, [force, temperature])|
+            assert_eq!(row, &vec![force, temperature, model.thermodynamics.isotensional.end_to_end_length(&force, &temperature)])
+        );
+    }
+    #[test]
+    fn json()
+    {
+        assert_eq!(
+            run(&arguments("hyperelastic eight_chain uniaxial_tension --chain fjc --number_of_links 8 --temperature 300 --stretch 1 --format json")).unwrap(),
+            "[{\"stretch\":1,\"uniaxial_tension\":0}]\n"
+        );
+        let table = Table
+        {
+            columns: vec!["x".to_string(), "y".to_string()],
+            rows: vec![vec![1.0, f64::NAN], vec![2.0, f64::INFINITY]]
+        };
+        assert_eq!(table.write(Format::JSON), "[{\"x\":1,\"y\":null},{\"x\":2,\"y\":null}]\n");
+        assert_eq!(table.write(Format::CSV), "x,y\n1,NaN\n2,inf\n");
+    }
+    #[test]
+    fn constitutive()
+    {
+        let output = run(&arguments("hyperelastic nonaffine_microsphere nondimensional_shear_modulus --chain efjc --number_of_links 8 --link_stiffness 1e6 --temperature 300 --nonaffine_stretch_parameter 1.5 --tube_geometry_parameter 0.5 --tube_area_exponent 0.5")).unwrap();
+        let nondimensional_shear_modulus: f64 = output.lines().nth(1).unwrap().parse().unwrap();
+        assert!(nondimensional_shear_modulus > 0.0);
+    }
+    #[test]
+    fn monte_carlo()
+    {
+        let output = run(&arguments("frc isometric monte_carlo nondimensional_equilibrium_radial_distribution --number_of_links 8 --theta 0.785 --number_of_samples 1e4")).unwrap();
+        let rows: Vec<Vec<f64>> = output.lines().skip(1).map(|line| line.split(',').map(|entry| entry.parse().unwrap()).collect()).collect();
+        assert_eq!(rows.len(), monte_carlo::NUMBER_OF_BINS);
+        let bin_width = rows[1][0] - rows[0][0];
+        assert!((rows.iter().map(|row| row[1]).sum::<f64>() * bin_width - 1.0).abs() <= 1e-2);
+    }
+    #[test]
+    fn list()
+    {
+        let output = run(&arguments("list")).unwrap();
+        assert!(output.lines().count() > 700);
+        assert!(output.contains("efjc isotensional nondimensional_end_to_end_length_per_link (nondimensional_force, temperature) [number_of_links, link_length, hinge_mass, link_stiffness]\n"));
+        assert!(output.contains("ufjc morse rupture isometric nondimensional_survival_probability (nondimensional_end_to_end_length_per_link, nondimensional_time, temperature)"));
+        assert!(output.contains("efrc isometric monte_carlo nondimensional_equilibrium_radial_distribution () [number_of_links, gamma_max, kappa, theta, number_of_samples]\n"));
+        assert!(run(&arguments("list wlc isometric")).unwrap().lines().all(|line| line.starts_with("wlc isometric ")));
+        assert!(run(&arguments("list nonexistent")).is_err());
+    }
+    #[test]
+    fn errors()
+    {
+        assert!(matches!(run(&arguments("nonexistent function --a 1")), Err(Failure::Usage(_))));
+        assert!(matches!(run(&arguments("fjc isometric nonexistent --number_of_links 8")), Err(Failure::Usage(_))));
+        assert!(matches!(run(&arguments("fjc isometric nondimensional_force --number_of_links 8")), Err(Failure::Usage(_))));
+        assert!(matches!(run(&arguments("fjc isometric nondimensional_force --number_of_links 8 --nondimensional_end_to_end_length_per_link 0.5 --typo 1")), Err(Failure::Usage(_))));
+        assert!(matches!(run(&arguments("fjc isometric nondimensional_force --number_of_links 8 --nondimensional_end_to_end_length_per_link 0.5 --format xml")), Err(Failure::Usage(_))));
+        assert!(matches!(run(&arguments("efjc isotensional nondimensional_force --number_of_links 8 --link_stiffness -1 --nondimensional_end_to_end_length_per_link 0.5 --temperature 300")), Err(Failure::Model(_))));
+        assert!(matches!(run(&arguments("fjc isometric nondimensional_force --number_of_links 8 --nondimensional_end_to_end_length_per_link 1.5")), Err(Failure::Model(_))));
+        assert!(matches!(run(&arguments("hyperelastic eight_chain uniaxial_tension --chain nonexistent --number_of_links 8 --temperature 300 --stretch 1")), Err(Failure::Usage(_))));
+        assert!(matches!(run(&arguments("fjc isometric monte_carlo nondimensional_equilibrium_radial_distribution --number_of_links 64 --number_of_samples 10")), Err(Failure::Usage(_))));
+    }
+}
@@ -0,0 +1,280 @@
+mod test;
+
+use serde::
+{
+    Deserialize,
+    Serialize
+};
+use crate::constitutive::
+{
+    gel::FloryRehner,
+    hyperelastic::
+    {
+        AffineMicrosphere,
+        BucheSilberstein,
+        EightChain,
+        NonaffineMicrosphere,
+        PolydisperseBucheSilberstein,
+        ThreeChain
+    },
+    hyperelastic_damage,
+    viscoelastic::TransientNetwork
+};
+use crate::physics::single_chain::
+{
+    ForceLaw,
+    efjc::EFJC,
+    fjc::FJC,
+    ideal::Ideal,
+    swfjc::SWFJC,
+    ufjc::
+    {
+        lennard_jones::LENNARDJONESFJC,
+        log_squared::LOGSQUAREDFJC,
+        morse::MORSEFJC
+    },
+    wlc::WLC
+};
+
+/// Implements serialization of a model as its parameters, and deserialization of a model by initializing it from its parameters,
+/// where the parameters are given in the order of the arguments of the constructor and any invalid parameters are reported as errors.
+macro_rules! parameters
+{
+    ($model: ident$(<$generic: ident$(: $bound: ident)?>)?, $constructor: ident, $($parameter: ident: $type: ty),+) => {
+        impl$(<$generic: serde::Serialize$( + $bound)?>)? serde::Serialize for $model$(<$generic>)?
+        {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            {
+                use serde::ser::SerializeStruct;
+                let mut state = serializer.serialize_struct(stringify!($model), [$(stringify!($parameter)),+].len())?;
+                $(state.serialize_field(stringify!($parameter), &self.$parameter)?;)+
+                state.end()
+            }
+        }
+        impl<'de$(, $generic: serde::Deserialize<'de>$( + $bound)?)?> serde::Deserialize<'de> for $model$(<$generic>)?
+        {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+            {
+                #[derive(serde::Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct Parameters$(<$generic>)?
+                {
+                    $($parameter: $type),+
+                }
+                let parameters = Parameters::deserialize(deserializer)?;
+                Self::$constructor($(parameters.$parameter),+).map_err(serde::de::Error::custom)
+            }
+        }
+    }
+}
+pub(crate) use parameters;
+
+/// The single-chain models, named by their modules, for use within the network constitutive models.
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "model", content = "parameters")]
+pub enum Chain
+{
+    #[serde(rename = "ideal")]
+    Ideal(Ideal),
+    #[serde(rename = "fjc")]
+    FJC(FJC),
+    #[serde(rename = "efjc")]
+    EFJC(EFJC),
+    #[serde(rename = "swfjc")]
+    SWFJC(SWFJC),
+    #[serde(rename = "wlc")]
+    WLC(WLC),
+    #[serde(rename = "lennard_jones")]
+    LennardJonesFJC(LENNARDJONESFJC),
+    #[serde(rename = "log_squared")]
+    LogSquaredFJC(LOGSQUAREDFJC),
+    #[serde(rename = "morse")]
+    MorseFJC(MORSEFJC)
+}
+
+/// The implemented functionality of the single-chain models.
+impl Chain
+{
+    fn force_law(&self) -> &dyn ForceLaw
+    {
+        match self
+        {
+            Chain::Ideal(model) => model,
+            Chain::FJC(model) => model,
+            Chain::EFJC(model) => model,
+            Chain::SWFJC(model) => model,
+            Chain::WLC(model) => model,
+            Chain::LennardJonesFJC(model) => model,
+            Chain::LogSquaredFJC(model) => model,
+            Chain::MorseFJC(model) => model
+        }
+    }
+}
+
+/// The force law of the chosen single-chain model.
+impl ForceLaw for Chain
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.force_law().number_of_links()
+    }
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.force_law().nondimensional_force(nondimensional_end_to_end_length_per_link, temperature)
+    }
+    fn nondimensional_reference_end_to_end_length_per_link(&self) -> f64
+    {
+        self.force_law().nondimensional_reference_end_to_end_length_per_link()
+    }
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.force_law().nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    }
+    fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.force_law().nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link, temperature)
+    }
+}
+
+/// The single-chain and constitutive models, named by their modules, where the network constitutive models contain a single-chain model.
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "model", content = "parameters")]
+pub enum Model
+{
+    #[serde(rename = "ideal")]
+    Ideal(Ideal),
+    #[serde(rename = "fjc")]
+    FJC(FJC),
+    #[serde(rename = "efjc")]
+    EFJC(EFJC),
+    #[serde(rename = "swfjc")]
+    SWFJC(SWFJC),
+    #[serde(rename = "wlc")]
+    WLC(WLC),
+    #[serde(rename = "lennard_jones")]
+    LennardJonesFJC(LENNARDJONESFJC),
+    #[serde(rename = "log_squared")]
+    LogSquaredFJC(LOGSQUAREDFJC),
+    #[serde(rename = "morse")]
+    MorseFJC(MORSEFJC),
+    #[serde(rename = "eight_chain")]
+    EightChain(EightChain<Chain>),
+    #[serde(rename = "three_chain")]
+    ThreeChain(ThreeChain<Chain>),
+    #[serde(rename = "affine_microsphere")]
+    AffineMicrosphere(AffineMicrosphere<Chain>),
+    #[serde(rename = "nonaffine_microsphere")]
+    NonaffineMicrosphere(NonaffineMicrosphere<Chain>),
+    #[serde(rename = "buche_silberstein")]
+    BucheSilberstein(BucheSilberstein),
+    #[serde(rename = "polydisperse_buche_silberstein")]
+    PolydisperseBucheSilberstein(PolydisperseBucheSilberstein),
+    #[serde(rename = "buche_silberstein_damage")]
+    BucheSilbersteinDamage(hyperelastic_damage::BucheSilberstein),
+    #[serde(rename = "polydisperse_buche_silberstein_damage")]
+    PolydisperseBucheSilbersteinDamage(hyperelastic_damage::PolydisperseBucheSilberstein),
+    #[serde(rename = "transient_network")]
+    TransientNetwork(TransientNetwork<Chain>),
+    #[serde(rename = "flory_rehner")]
+    FloryRehner(FloryRehner<Chain>)
+}
+
+/// The implemented functionality of the models.
+impl Model
+{
+    /// The single-chain model, or nothing for the constitutive models.
+    pub fn chain(&self) -> Option<&dyn ForceLaw>
+    {
+        match self
+        {
+            Model::Ideal(model) => Some(model),
+            Model::FJC(model) => Some(model),
+            Model::EFJC(model) => Some(model),
+            Model::SWFJC(model) => Some(model),
+            Model::WLC(model) => Some(model),
+            Model::LennardJonesFJC(model) => Some(model),
+            Model::LogSquaredFJC(model) => Some(model),
+            Model::MorseFJC(model) => Some(model),
+            _ => None
+        }
+    }
+}
+
+/// The thermodynamic ensembles of the single-chain models.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Ensemble
+{
+    /// The isometric ensemble, where the end-to-end length is applied.
+    Isometric,
+    /// The isotensional ensemble, where the force is applied.
+    Isotensional,
+    /// The modified canonical ensemble, where the end-to-end vector is applied through a harmonic potential.
+    ModifiedCanonical
+}
+
+/// The description of a model, naming the model and its parameters, along with the thermodynamic ensemble for the single-chain models.
+#[derive(Deserialize, Serialize)]
+#[serde(try_from = "Unvalidated")]
+pub struct Description
+{
+    /// The model, initialized from its parameters.
+    #[serde(flatten)]
+    pub model: Model,
+
+    /// The thermodynamic ensemble, which is only given for the single-chain models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ensemble: Option<Ensemble>
+}
+
+/// A description of a model whose ensemble has not been validated.
+#[derive(Deserialize)]
+struct Unvalidated
+{
+    #[serde(flatten)]
+    model: Model,
+    ensemble: Option<Ensemble>
+}
+
+impl TryFrom<Unvalidated> for Description
+{
+    type Error = &'static str;
+    fn try_from(description: Unvalidated) -> Result<Self, Self::Error>
+    {
+        match (&description.model, &description.ensemble)
+        {
+            (model, Some(_)) if model.chain().is_none() => Err("The ensemble must only be given for the single-chain models."),
+            (Model::FJC(_), _) | (_, Some(Ensemble::Isometric)) | (_, Some(Ensemble::Isotensional)) | (_, None) => Ok(Description
+            {
+                model: description.model,
+                ensemble: description.ensemble
+            }),
+            (_, Some(Ensemble::ModifiedCanonical)) => Err("The modified canonical ensemble is only implemented for the FJC model.")
+        }
+    }
+}
+
+/// The implemented functionality of the model descriptions.
+impl Description
+{
+    /// Initializes and returns the description of a model from TOML, or an error if the description or any of the parameters are invalid.
+    pub fn from_toml(description: &str) -> Result<Self, toml::de::Error>
+    {
+        toml::from_str(description)
+    }
+    /// Initializes and returns the description of a model from JSON, or an error if the description or any of the parameters are invalid.
+    pub fn from_json(description: &str) -> Result<Self, serde_json::Error>
+    {
+        serde_json::from_str(description)
+    }
+    /// The description of the model as TOML.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error>
+    {
+        toml::to_string(self)
+    }
+    /// The description of the model as JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error>
+    {
+        serde_json::to_string(self)
+    }
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+use super::*;
+use crate::constitutive::
+{
+    distribution::ChainLengthDistribution,
+    hyperelastic::
+    {
+        Method,
+        Quadrature
+    }
+};
+static DESCRIPTIONS: [&str; 12] = [
+    "model = \"ideal\"\nensemble = \"isometric\"\n\n[parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\n",
+    "model = \"fjc\"\nensemble = \"modified_canonical\"\n\n[parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\n",
+    "model = \"efjc\"\nensemble = \"isotensional\"\n\n[parameters]\nnumber_of_links = 8\nlink_length = 0.5\nhinge_mass = 2.0\nlink_stiffness = 1000000.0\n",
+    "model = \"swfjc\"\n\n[parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\nwell_width = 0.1\n",
+    "model = \"wlc\"\nensemble = \"isometric\"\n\n[parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\npersistance_length = 2.0\n",
+    "model = \"morse\"\nensemble = \"isotensional\"\n\n[parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\nlink_stiffness = 1000.0\nlink_energy = 100.0\n",
+    "model = \"eight_chain\"\n\n[parameters]\ntemperature = 300.0\n\n[parameters.chain]\nmodel = \"efjc\"\n\n[parameters.chain.parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\nlink_stiffness = 1000000.0\n",
+    "model = \"nonaffine_microsphere\"\n\n[parameters]\ntemperature = 300.0\nnonaffine_stretch_parameter = 1.5\ntube_geometry_parameter = 0.5\ntube_area_exponent = 0.5\n\n[parameters.chain]\nmodel = \"lennard_jones\"\n\n[parameters.chain.parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\nlink_stiffness = 1000.0\n",
+    "model = \"buche_silberstein\"\n\n[parameters]\nmethod = \"gibbs_legendre\"\nquadrature = \"gauss_legendre\"\nnumber_of_grid_points = 16\nnondimensional_link_stiffness = 100.0\nnumber_of_links = 8\n",
+    "model = \"polydisperse_buche_silberstein_damage\"\n\n[parameters]\nmethod = \"gibbs_legendre\"\nquadrature = \"uniform\"\nnumber_of_grid_points = 16\nnondimensional_critical_extension = 1.1\nnondimensional_link_stiffness = 100.0\nswelling_ratio = 1.0\n\n[parameters.distribution]\nschulz_zimm = [8.0, 1.5]\n",
+    "model = \"transient_network\"\n\n[parameters]\ntemperature = 300.0\nnondimensional_attachment_rate = 1.0\nnondimensional_activation_length = 0.1\nnumber_of_grid_points = 16\n\n[parameters.chain]\nmodel = \"fjc\"\n\n[parameters.chain.parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\n",
+    "model = \"flory_rehner\"\n\n[parameters]\ntemperature = 300.0\nflory_huggins_parameter = 0.5\nnondimensional_solvent_volume = 0.01\n\n[parameters.chain]\nmodel = \"ideal\"\n\n[parameters.chain.parameters]\nnumber_of_links = 8\nlink_length = 1.0\nhinge_mass = 1.0\n"
+];
+mod round_trip
+{
+    use super::*;
+    #[test]
+    fn toml()
+    {
+        DESCRIPTIONS.iter().for_each(|description|{
+            let toml = Description::from_toml(description).unwrap().to_toml().unwrap();
+            assert_eq!(&toml, description);
+            assert_eq!(Description::from_toml(&toml).unwrap().to_toml().unwrap(), toml);
+        });
+    }
+    #[test]
+    fn json()
+    {
+        DESCRIPTIONS.iter().for_each(|description|{
+            let json = Description::from_toml(description).unwrap().to_json().unwrap();
+            assert_eq!(Description::from_json(&json).unwrap().to_json().unwrap(), json);
+            assert_eq!(&Description::from_json(&json).unwrap().to_toml().unwrap(), description);
+        });
+    }
+    #[test]
+    fn single_chain()
+    {
+        let model = MORSEFJC::init(8, 1.0, 1.0, 1e3, 1e2);
+        let json = serde_json::to_string(&model).unwrap();
+        assert_eq!(json, "{\"number_of_links\":8,\"link_length\":1.0,\"hinge_mass\":1.0,\"link_stiffness\":1000.0,\"link_energy\":100.0}");
+        let deserialized: MORSEFJC = serde_json::from_str(&json).unwrap();
+        [0.1, 0.5, 0.9].iter().for_each(|nondimensional_force|
+            assert_eq!(
+                deserialized.thermodynamics.isotensional.nondimensional_end_to_end_length_per_link(nondimensional_force, &300.0),
+                model.thermodynamics.isotensional.nondimensional_end_to_end_length_per_link(nondimensional_force, &300.0)
+            )
+        );
+    }
+    #[test]
+    fn constitutive()
+    {
+        let model = EightChain::init(EFJC::init(8, 1.0, 1.0, 1e6), 300.0);
+        let description = Description::from_toml(DESCRIPTIONS[6]).unwrap();
+        let deserialized = match &description.model
+        {
+            Model::EightChain(deserialized) => deserialized,
+            _ => panic!()
+        };
+        [1.0, 1.5, 2.0].iter().for_each(|stretch|
+            assert_eq!(deserialized.uniaxial_tension(stretch), model.uniaxial_tension(stretch))
+        );
+        let model = PolydisperseBucheSilberstein::init_with_grid(Method::GibbsLegendre, Quadrature::Uniform, 16, 1e2, ChainLengthDistribution::Flory(8.0));
+        let deserialized: PolydisperseBucheSilberstein = serde_json::from_str(&serde_json::to_string(&model).unwrap()).unwrap();
+        assert_eq!(deserialized.distribution, ChainLengthDistribution::Flory(8.0));
+        assert_eq!(deserialized.uniaxial_tension(&1.5), model.uniaxial_tension(&1.5));
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn parameters()
+    {
+        assert!(Description::from_toml(&DESCRIPTIONS[2].replace("link_length = 0.5", "link_length = -0.5")).err().unwrap().to_string().contains("The parameter link_length = -0.5 must be positive and finite."));
+        assert!(Description::from_json("{\"model\":\"fjc\",\"parameters\":{\"number_of_links\":0,\"link_length\":1.0,\"hinge_mass\":1.0}}").is_err());
+        assert!(Description::from_json("{\"model\":\"fjc\",\"parameters\":{\"number_of_links\":8,\"link_length\":1.0}}").is_err());
+        assert!(Description::from_json("{\"model\":\"fjc\",\"parameters\":{\"number_of_links\":8,\"link_length\":1.0,\"hinge_mass\":1.0,\"link_stiffness\":1.0}}").is_err());
+        assert!(Description::from_json("{\"model\":\"nonexistent\",\"parameters\":{}}").is_err());
+        assert!(Description::from_toml(&DESCRIPTIONS[8].replace("gibbs_legendre", "nonexistent")).is_err());
+    }
+    #[test]
+    fn ensemble()
+    {
+        assert!(Description::from_toml(&DESCRIPTIONS[2].replace("isotensional", "modified_canonical")).is_err());
+        assert!(Description::from_toml(&DESCRIPTIONS[6].replace("model = \"eight_chain\"\n", "model = \"eight_chain\"\nensemble = \"isometric\"\n")).is_err());
+        assert!(Description::from_toml(&DESCRIPTIONS[0].replace("isometric", "nonexistent")).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::physics::
+{
+    BOLTZMANN_CONSTANT,
+    single_chain::ForceLaw
+};
+use super::
+{
+    CauchyStress,
+    Hyperelastic,
+    StrainEnergy,
+    gel::FloryRehner,
+    hyperelastic::Tensor,
+    hyperelastic_damage,
+    viscoelastic::TransientNetwork
+};
+
+/// A constitutive model in physical units, where the nondimensional stresses of the model are scaled by the number density of chains and the thermal energy.
+pub struct Dimensional<M>
+{
+    /// The constitutive model.
+    pub model: M,
+
+    /// The number density of chains in units of mol/m^3.
+    pub chain_density: f64,
+
+    /// The temperature in units of K, which should be that of the chains in the model.
+    pub temperature: f64
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(Dimensional<M>, try_init, model: M, chain_density: f64, temperature: f64);
+
+/// The implemented functionality of the constitutive models in physical units.
+impl<M> Dimensional<M>
+{
+    /// Initializes and returns an instance using the given constitutive model, number density of chains, and temperature.
+    pub fn init(model: M, chain_density: f64, temperature: f64) -> Self
+    {
+        Dimensional
+        {
+            model,
+            chain_density,
+            temperature
+        }
+    }
+    /// Initializes and returns an instance, or an error if any of the parameters are invalid.
+    pub fn try_init(model: M, chain_density: f64, temperature: f64) -> Result<Self, Error>
+    {
+        error::positive("chain_density", &chain_density)?;
+        error::positive("temperature", &temperature)?;
+        Ok(Self::init(model, chain_density, temperature))
+    }
+    /// Initializes and returns an instance using the given constitutive model, its nondimensional initial shear modulus, the initial shear modulus, and temperature,
+    /// where the number density of chains is chosen so that the model has the given initial shear modulus.
+    pub fn init_with_nondimensional_shear_modulus(model: M, nondimensional_shear_modulus: &f64, shear_modulus: f64, temperature: f64) -> Self
+    {
+        let chain_density = shear_modulus / nondimensional_shear_modulus / BOLTZMANN_CONSTANT / temperature;
+        Self::init(model, chain_density, temperature)
+    }
+    /// Initializes and returns an instance using the given nondimensional initial shear modulus, or an error if any of the parameters are invalid.
+    pub fn try_init_with_nondimensional_shear_modulus(model: M, nondimensional_shear_modulus: &f64, shear_modulus: f64, temperature: f64) -> Result<Self, Error>
+    {
+        error::positive("shear_modulus", &shear_modulus)?;
+        error::positive("temperature", &temperature)?;
+        let dimensional = Self::init_with_nondimensional_shear_modulus(model, nondimensional_shear_modulus, shear_modulus, temperature);
+        error::positive("chain_density", &dimensional.chain_density)?;
+        Ok(dimensional)
+    }
+    /// The stress that scales the nondimensional stresses of the model, which is the number density of chains times the thermal energy, in units of Pa.
+    pub fn stress_scale(&self) -> f64
+    {
+        self.chain_density * BOLTZMANN_CONSTANT * self.temperature
+    }
+}
+
+/// The implemented functionality of the hyperelastic constitutive models in physical units.
+impl<M: Hyperelastic> Dimensional<M>
+{
+    /// Initializes and returns an instance using the given constitutive model, initial shear modulus, and temperature,
+    /// where the number density of chains is chosen so that the model has the given initial shear modulus.
+    pub fn init_with_shear_modulus(model: M, shear_modulus: f64, temperature: f64) -> Self
+    {
+        let nondimensional_shear_modulus = model.nondimensional_shear_modulus();
+        Self::init_with_nondimensional_shear_modulus(model, &nondimensional_shear_modulus, shear_modulus, temperature)
+    }
+    /// Initializes and returns an instance using the given initial shear modulus, or an error if any of the parameters are invalid.
+    pub fn try_init_with_shear_modulus(model: M, shear_modulus: f64, temperature: f64) -> Result<Self, Error>
+    {
+        let nondimensional_shear_modulus = model.nondimensional_shear_modulus();
+        Self::try_init_with_nondimensional_shear_modulus(model, &nondimensional_shear_modulus, shear_modulus, temperature)
+    }
+    /// The initial shear modulus in units of Pa.
+    pub fn shear_modulus(&self) -> f64
+    {
+        self.stress_scale() * self.model.nondimensional_shear_modulus()
+    }
+    /// The Cauchy stress in units of Pa as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.stress_scale() * self.model.uniaxial_tension(stretch)
+    }
+    /// The Cauchy stress in units of Pa as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.stress_scale() * self.model.equibiaxial_tension(stretch)
+    }
+}
+
+/// The implemented functionality of the strain energy density of the hyperelastic constitutive models in physical units.
+impl<M: StrainEnergy> Dimensional<M>
+{
+    /// The strain energy density in units of J/m^3 as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.stress_scale() * self.model.uniaxial_tension_strain_energy_density(stretch)
+    }
+    /// The strain energy density in units of J/m^3 as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.stress_scale() * self.model.equibiaxial_tension_strain_energy_density(stretch)
+    }
+}
+
+/// The implemented functionality of the constitutive models for general deformations in physical units.
+impl<M: CauchyStress> Dimensional<M>
+{
+    /// The Cauchy stress in units of Pa as a function of the deformation gradient.
+    pub fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        let stress_scale = self.stress_scale();
+        self.model.cauchy_stress(deformation_gradient).map(|row| row.map(|entry| stress_scale * entry))
+    }
+}
+
+/// The implemented functionality of the Flory-Rehner gel constitutive model in physical units.
+impl<T: ForceLaw> Dimensional<FloryRehner<T>>
+{
+    /// The swelling pressure in units of Pa as a function of the swelling ratio.
+    pub fn swelling_pressure(&self, swelling_ratio: &f64) -> f64
+    {
+        self.stress_scale() * self.model.swelling_pressure(swelling_ratio)
+    }
+}
+
+/// The implemented functionality of the Buche-Silberstein hyperelastic damage constitutive model in physical units.
+impl Dimensional<hyperelastic_damage::BucheSilberstein>
+{
+    /// The initial shear modulus in units of Pa.
+    pub fn shear_modulus(&self) -> f64
+    {
+        self.stress_scale() * self.model.nondimensional_shear_modulus()
+    }
+    /// The Cauchy stress in units of Pa and total probability of intact chains as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension<const L: usize>(&self, stretch: &[f64]) -> [[f64; 2]; L]
+    {
+        let mut history = self.model.uniaxial_tension(stretch);
+        scale(self.stress_scale(), &mut history);
+        history
+    }
+    /// The Cauchy stress in units of Pa and total probability of intact chains as a function of stretch and nondimensional time in uniaxial tension,
+    /// or an error if the nondimensional link energy is not positive or the histories are invalid.
+    pub fn uniaxial_tension_rate_dependent(&self, nondimensional_link_energy: &f64, stretch: &[f64], nondimensional_time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        let mut history = self.model.uniaxial_tension_rate_dependent(nondimensional_link_energy, stretch, nondimensional_time)?;
+        scale(self.stress_scale(), &mut history);
+        Ok(history)
+    }
+}
+
+/// The implemented functionality of the polydisperse Buche-Silberstein hyperelastic damage constitutive model in physical units.
+impl Dimensional<hyperelastic_damage::PolydisperseBucheSilberstein>
+{
+    /// The initial shear modulus in units of Pa.
+    pub fn shear_modulus(&self) -> f64
+    {
+        self.stress_scale() * self.model.nondimensional_shear_modulus()
+    }
+    /// The Cauchy stress in units of Pa and total probability of intact chains as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension<const L: usize>(&self, stretch: &[f64]) -> [[f64; 2]; L]
+    {
+        let mut history = self.model.uniaxial_tension(stretch);
+        scale(self.stress_scale(), &mut history);
+        history
+    }
+    /// The Cauchy stress in units of Pa and total probability of intact chains as a function of stretch and nondimensional time in uniaxial tension,
+    /// or an error if the nondimensional link energy is not positive or the histories are invalid.
+    pub fn uniaxial_tension_rate_dependent(&self, nondimensional_link_energy: &f64, stretch: &[f64], nondimensional_time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        let mut history = self.model.uniaxial_tension_rate_dependent(nondimensional_link_energy, stretch, nondimensional_time)?;
+        scale(self.stress_scale(), &mut history);
+        Ok(history)
+    }
+}
+
+/// The implemented functionality of the transient network constitutive model in physical units.
+impl<T: ForceLaw> Dimensional<TransientNetwork<T>>
+{
+    /// The Cauchy stress in units of Pa and fraction of attached chains as a function of stretch and nondimensional time in uniaxial tension.
+    pub fn uniaxial_tension(&self, stretch: &[f64], nondimensional_time: &[f64]) -> Vec<[f64; 2]>
+    {
+        let mut history = self.model.uniaxial_tension(stretch, nondimensional_time);
+        scale(self.stress_scale(), &mut history);
+        history
+    }
+    /// The Cauchy stress in units of Pa and fraction of attached chains as a function of nondimensional time after a step stretch in uniaxial tension.
+    pub fn step_strain_relaxation(&self, stretch: &f64, nondimensional_time: &[f64]) -> Vec<[f64; 2]>
+    {
+        let mut history = self.model.step_strain_relaxation(stretch, nondimensional_time);
+        scale(self.stress_scale(), &mut history);
+        history
+    }
+    /// The Cauchy stress in units of Pa and fraction of attached chains as a function of nondimensional time at a constant stretch rate in uniaxial tension.
+    pub fn constant_rate_loading(&self, nondimensional_stretch_rate: &f64, nondimensional_time: &[f64]) -> Vec<[f64; 2]>
+    {
+        let mut history = self.model.constant_rate_loading(nondimensional_stretch_rate, nondimensional_time);
+        scale(self.stress_scale(), &mut history);
+        history
+    }
+}
+
+/// Scales the nondimensional stress in each pair of a history by the given stress, leaving the accompanying fraction of chains unchanged.
+fn scale(stress_scale: f64, history: &mut [[f64; 2]])
+{
+    history.iter_mut().for_each(|entry| entry[0] *= stress_scale)
+}
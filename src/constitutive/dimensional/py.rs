@@ -0,0 +1,220 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyTuple;
+use crate::physics::BOLTZMANN_CONSTANT;
+
+pub fn register_module(_py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    parent_module.add_class::<Dimensional>()?;
+    Ok(())
+}
+
+/// A constitutive model in physical units, where the nondimensional stresses :math:`\beta\sigma/n` of the model
+/// are scaled by the number density of chains :math:`n` and the thermal energy :math:`kT`, such that
+///
+/// .. math::
+///     \sigma = nkT\left(\frac{\beta\sigma}{n}\right),
+///
+/// where the number density of chains is in units of mol/m^3 and :math:`k` is the gas constant, giving stresses in units of Pa.
+/// The number density of chains may instead be chosen so that the model has a given initial shear modulus :math:`G`.
+///
+/// Args:
+///     model: Any of the constitutive models in :mod:`polymers.constitutive`, such as :class:`polymers.constitutive.hyperelastic.EightChain`.
+///     temperature (float): The temperature :math:`T`.
+///     chain_density (float): The number density of chains :math:`n`.
+///     shear_modulus (float): The initial shear modulus :math:`G`, which requires the model to have a nondimensional initial shear modulus, such as the hyperelastic and hyperelastic damage models.
+///
+/// Raises:
+///     ValueError: If not exactly one of the number density of chains and initial shear modulus is given, the model has no initial shear modulus, or any of the parameters are invalid.
+///
+#[pyclass]
+pub struct Dimensional
+{
+    /// The constitutive model.
+    #[pyo3(get)]
+    pub model: PyObject,
+
+    /// The number density of chains in units of mol/m^3.
+    #[pyo3(get)]
+    pub chain_density: f64,
+
+    /// The temperature in units of K.
+    #[pyo3(get)]
+    pub temperature: f64
+}
+
+impl Dimensional
+{
+    /// The nondimensional initial shear modulus of the model, or an error if the model has none.
+    fn nondimensional_shear_modulus(py: Python<'_>, model: &PyObject) -> PyResult<f64>
+    {
+        let model = model.as_ref(py);
+        if !model.hasattr("nondimensional_shear_modulus")?
+        {
+            return Err(PyValueError::new_err("The model has no initial shear modulus."))
+        }
+        model.call_method0("nondimensional_shear_modulus")?.extract()
+    }
+    /// Calls a method of the model and scales the resulting stress, or the first of the resulting histories.
+    fn scaled(&self, py: Python<'_>, method: &str, args: &PyTuple) -> PyResult<PyObject>
+    {
+        let result = self.model.as_ref(py).call_method1(method, args)?;
+        match result.downcast::<PyTuple>()
+        {
+            Ok(results) =>
+            {
+                let mut results: Vec<PyObject> = results.iter().map(|result| result.into()).collect();
+                results[0] = results[0].as_ref(py).call_method1("__mul__", (self.stress_scale(),))?.into();
+                Ok(PyTuple::new(py, results).into())
+            },
+            Err(_) => Ok(result.call_method1("__mul__", (self.stress_scale(),))?.into())
+        }
+    }
+}
+
+#[pymethods]
+impl Dimensional
+{
+    #[new]
+    #[pyo3(signature = (model, temperature, chain_density = None, shear_modulus = None))]
+    pub fn init(py: Python<'_>, model: PyObject, temperature: f64, chain_density: Option<f64>, shear_modulus: Option<f64>) -> PyResult<Self>
+    {
+        let dimensional = match (chain_density, shear_modulus)
+        {
+            (Some(chain_density), None) => super::Dimensional::try_init(model, chain_density, temperature)?,
+            (None, Some(shear_modulus)) =>
+            {
+                let nondimensional_shear_modulus = Self::nondimensional_shear_modulus(py, &model)?;
+                super::Dimensional::try_init_with_nondimensional_shear_modulus(model, &nondimensional_shear_modulus, shear_modulus, temperature)?
+            },
+            _ => return Err(PyValueError::new_err("Exactly one of the number density of chains and initial shear modulus must be given."))
+        };
+        Ok(Dimensional
+        {
+            model: dimensional.model,
+            chain_density: dimensional.chain_density,
+            temperature: dimensional.temperature
+        })
+    }
+    /// The stress that scales the nondimensional stresses of the model, which is the number density of chains times the thermal energy.
+    ///
+    /// Returns:
+    ///     float: The stress scale :math:`nkT` in units of Pa.
+    ///
+    pub fn stress_scale(&self) -> f64
+    {
+        self.chain_density * BOLTZMANN_CONSTANT * self.temperature
+    }
+    /// The initial shear modulus in units of Pa.
+    ///
+    /// Returns:
+    ///     float: The initial shear modulus :math:`G`.
+    ///
+    /// Raises:
+    ///     ValueError: If the model has no initial shear modulus.
+    ///
+    pub fn shear_modulus(&self, py: Python<'_>) -> PyResult<f64>
+    {
+        Ok(self.stress_scale() * Self::nondimensional_shear_modulus(py, &self.model)?)
+    }
+    /// The Cauchy stress in units of Pa in uniaxial tension, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     numpy.ndarray or tuple: The Cauchy stress :math:`\sigma_{11}`, along with any further histories of the model.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn uniaxial_tension(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "uniaxial_tension", args)
+    }
+    /// The Cauchy stress in units of Pa in equibiaxial tension, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The Cauchy stress :math:`\sigma_{11}=\sigma_{22}`.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn equibiaxial_tension(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "equibiaxial_tension", args)
+    }
+    /// The Cauchy stress in units of Pa as a function of the deformation gradient, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The Cauchy stress :math:`\boldsymbol{\sigma}`.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn cauchy_stress(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "cauchy_stress", args)
+    }
+    /// The Cauchy stress in units of Pa in rate-dependent uniaxial tension, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     tuple: The Cauchy stress :math:`\sigma_{11}`, along with the further histories of the model.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn uniaxial_tension_rate_dependent(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "uniaxial_tension_rate_dependent", args)
+    }
+    /// The Cauchy stress in units of Pa after a step stretch in uniaxial tension, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     tuple: The Cauchy stress :math:`\sigma_{11}(t)`, along with the further histories of the model.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn step_strain_relaxation(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "step_strain_relaxation", args)
+    }
+    /// The Cauchy stress in units of Pa at a constant stretch rate in uniaxial tension, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     tuple: The Cauchy stress :math:`\sigma_{11}(t)`, along with the further histories of the model.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn constant_rate_loading(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "constant_rate_loading", args)
+    }
+    /// The strain energy density in units of J/m^3 in uniaxial tension, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The strain energy density :math:`W`.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn uniaxial_tension_strain_energy_density(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "uniaxial_tension_strain_energy_density", args)
+    }
+    /// The strain energy density in units of J/m^3 in equibiaxial tension, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The strain energy density :math:`W`.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn equibiaxial_tension_strain_energy_density(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "equibiaxial_tension_strain_energy_density", args)
+    }
+    /// The strain energy density in units of J/m^3 as a function of the deformation gradient, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The strain energy density :math:`W`.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn strain_energy_density(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "strain_energy_density", args)
+    }
+    /// The swelling pressure in units of Pa, taking the same arguments as the model.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The swelling pressure :math:`\Pi`.
+    ///
+    #[pyo3(signature = (*args))]
+    pub fn swelling_pressure(&self, py: Python<'_>, args: &PyTuple) -> PyResult<PyObject>
+    {
+        self.scaled(py, "swelling_pressure", args)
+    }
+}
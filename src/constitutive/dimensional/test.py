@@ -0,0 +1,146 @@
+"""Module to test the local module.
+
+"""
+import unittest
+import numpy as np
+from polymers import constitutive, physics
+
+TEMPERATURE = 300.0
+SHEAR_MODULUS = 1e6
+
+
+def hyperelastic_model():
+    """Function to instantiate the hyperelastic model used in the tests.
+
+    """
+    return constitutive.hyperelastic.EightChain(
+        physics.single_chain.fjc.FJC(8, 1.0, 1.0), TEMPERATURE
+    )
+
+
+def hyperelastic_damage_model():
+    """Function to instantiate the hyperelastic damage model used in the tests.
+
+    """
+    return constitutive.hyperelastic_damage.BucheSilberstein(
+        constitutive.hyperelastic_damage.Method.GibbsLegendreGaussian,
+        1.1, 100.0, 8, 1.0
+    )
+
+
+class ShearModulus(unittest.TestCase):
+    """Class for tests of the initial shear modulus.
+
+    """
+    def test_hyperelastic(self):
+        """Function to test the initial shear modulus of a hyperelastic model.
+
+        """
+        model = hyperelastic_model()
+        dimensional = constitutive.Dimensional(
+            model, TEMPERATURE, shear_modulus=SHEAR_MODULUS
+        )
+        self.assertAlmostEqual(
+            dimensional.shear_modulus()/SHEAR_MODULUS, 1.0, delta=1e-12
+        )
+        self.assertAlmostEqual(
+            dimensional.stress_scale()*model.nondimensional_shear_modulus(),
+            SHEAR_MODULUS, delta=1e-12*SHEAR_MODULUS
+        )
+        stretch = np.array([1.5, 2.0])
+        self.assertTrue(
+            np.all(
+                np.abs(
+                    dimensional.uniaxial_tension(stretch) -
+                    dimensional.stress_scale()*model.uniaxial_tension(stretch)
+                ) <= 1e-12*dimensional.stress_scale()
+            )
+        )
+
+    def test_hyperelastic_damage(self):
+        """Function to test the initial shear modulus
+        of a hyperelastic damage model.
+
+        """
+        model = hyperelastic_damage_model()
+        dimensional = constitutive.Dimensional(
+            model, TEMPERATURE, shear_modulus=SHEAR_MODULUS
+        )
+        self.assertAlmostEqual(
+            dimensional.shear_modulus()/SHEAR_MODULUS, 1.0, delta=1e-12
+        )
+        stretch = np.array([1.0, 1.05])
+        cauchy_stress, total_probability = \
+            dimensional.uniaxial_tension(stretch)
+        nondimensional_cauchy_stress, nondimensional_total_probability = \
+            model.uniaxial_tension(stretch)
+        self.assertTrue(
+            np.all(
+                np.abs(
+                    cauchy_stress -
+                    dimensional.stress_scale()*nondimensional_cauchy_stress
+                ) <= 1e-12*dimensional.stress_scale()
+            )
+        )
+        self.assertTrue(
+            np.all(total_probability == nondimensional_total_probability)
+        )
+
+    def test_chain_density(self):
+        """Function to test that the initial shear modulus
+        and number density of chains give the same model.
+
+        """
+        for model in [hyperelastic_model(), hyperelastic_damage_model()]:
+            dimensional = constitutive.Dimensional(
+                model, TEMPERATURE, shear_modulus=SHEAR_MODULUS
+            )
+            self.assertAlmostEqual(
+                constitutive.Dimensional(
+                    model, TEMPERATURE,
+                    chain_density=dimensional.chain_density
+                ).shear_modulus()/SHEAR_MODULUS, 1.0, delta=1e-12
+            )
+
+
+class Errors(unittest.TestCase):
+    """Class for tests of invalid parameters and models.
+
+    """
+    def test_parameters(self):
+        """Function to test that not exactly one of the number density
+        of chains and initial shear modulus raises an error.
+
+        """
+        self.assertRaises(
+            ValueError, constitutive.Dimensional,
+            hyperelastic_model(), TEMPERATURE
+        )
+        self.assertRaises(
+            ValueError, constitutive.Dimensional,
+            hyperelastic_model(), TEMPERATURE,
+            chain_density=100.0, shear_modulus=SHEAR_MODULUS
+        )
+        self.assertRaises(
+            ValueError, constitutive.Dimensional,
+            hyperelastic_damage_model(), TEMPERATURE,
+            shear_modulus=-SHEAR_MODULUS
+        )
+
+    def test_model(self):
+        """Function to test that models without
+        an initial shear modulus raise an error.
+
+        """
+        model = constitutive.viscoelastic.TransientNetwork(
+            physics.single_chain.fjc.FJC(8, 1.0, 1.0), TEMPERATURE, 1.0, 0.1
+        )
+        self.assertRaises(
+            ValueError, constitutive.Dimensional,
+            model, TEMPERATURE, shear_modulus=SHEAR_MODULUS
+        )
+        self.assertRaises(
+            ValueError, constitutive.Dimensional(
+                model, TEMPERATURE, chain_density=100.0
+            ).shear_modulus
+        )
@@ -0,0 +1,122 @@
+#![cfg(test)]
+use super::*;
+use crate::constitutive::hyperelastic::
+{
+    EightChain,
+    ThreeChain
+};
+use crate::physics::single_chain::
+{
+    fjc::FJC,
+    ideal::Ideal
+};
+const TEMPERATURE: f64 = 300.0;
+const CHAIN_DENSITY: f64 = 100.0;
+const STRETCHES: [f64; 4] = [1.1, 1.5, 2.0, 2.5];
+#[test]
+fn stress_scale()
+{
+    let model = Dimensional::init(EightChain::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE), CHAIN_DENSITY, TEMPERATURE);
+    assert_eq!(model.stress_scale(), CHAIN_DENSITY * BOLTZMANN_CONSTANT * TEMPERATURE);
+}
+#[test]
+fn neo_hookean()
+{
+    let model = Dimensional::init(EightChain::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE), CHAIN_DENSITY, TEMPERATURE);
+    let shear_modulus = CHAIN_DENSITY * BOLTZMANN_CONSTANT * TEMPERATURE;
+    assert!((model.shear_modulus() / shear_modulus - 1.0).abs() <= 1e-6);
+    STRETCHES.iter().for_each(|stretch|{
+        let uniaxial_tension = shear_modulus * (stretch.powi(2) - 1.0 / stretch);
+        assert!((model.uniaxial_tension(stretch) / uniaxial_tension - 1.0).abs() <= 1e-12);
+        let equibiaxial_tension = shear_modulus * (stretch.powi(2) - 1.0 / stretch.powi(4));
+        assert!((model.equibiaxial_tension(stretch) / equibiaxial_tension - 1.0).abs() <= 1e-12);
+    });
+}
+#[test]
+fn shear_modulus()
+{
+    let shear_modulus = 1e6;
+    let model = Dimensional::init_with_shear_modulus(ThreeChain::init(FJC::init(8, 1.0, 1.0), TEMPERATURE), shear_modulus, TEMPERATURE);
+    assert!((model.shear_modulus() / shear_modulus - 1.0).abs() <= 1e-12);
+    let stretch = 1.0 + 1e-6;
+    assert!((model.uniaxial_tension(&stretch) / (stretch.powi(2) - 1.0 / stretch) / shear_modulus - 1.0).abs() <= 1e-3);
+    assert!(model.chain_density > 0.0);
+}
+#[test]
+fn cauchy_stress()
+{
+    let chain = FJC::init(8, 1.0, 1.0);
+    let model = Dimensional::init(EightChain::init(chain, TEMPERATURE), CHAIN_DENSITY, TEMPERATURE);
+    let deformation_gradient = [[1.3, 0.2, 0.0], [0.1, 0.9, 0.0], [0.0, 0.0, 1.0]];
+    let nondimensional_cauchy_stress = EightChain::init(FJC::init(8, 1.0, 1.0), TEMPERATURE).cauchy_stress(&deformation_gradient);
+    model.cauchy_stress(&deformation_gradient).iter().flatten().zip(nondimensional_cauchy_stress.iter().flatten()).for_each(|(cauchy_stress, nondimensional_cauchy_stress)|
+        assert!((cauchy_stress - model.stress_scale() * nondimensional_cauchy_stress).abs() <= 1e-12 * model.stress_scale())
+    );
+}
+#[test]
+fn hyperelastic_damage()
+{
+    let model = || hyperelastic_damage::BucheSilberstein::init(hyperelastic_damage::Method::GibbsLegendreGaussian, 1.1, 100.0, 8, 1.0);
+    let dimensional = Dimensional::init(model(), CHAIN_DENSITY, TEMPERATURE);
+    let results: [[f64; 2]; 4] = dimensional.uniaxial_tension(&STRETCHES);
+    let nondimensional_results: [[f64; 2]; 4] = model().uniaxial_tension(&STRETCHES);
+    results.iter().zip(nondimensional_results.iter()).for_each(|([cauchy_stress, probability], [nondimensional_cauchy_stress, nondimensional_probability])|{
+        assert!((cauchy_stress - dimensional.stress_scale() * nondimensional_cauchy_stress).abs() <= 1e-12 * dimensional.stress_scale());
+        assert_eq!(probability, nondimensional_probability);
+    });
+}
+#[test]
+fn hyperelastic_damage_shear_modulus()
+{
+    let shear_modulus = 1e6;
+    let model = hyperelastic_damage::BucheSilberstein::init(hyperelastic_damage::Method::GibbsLegendreGaussian, 1.1, 100.0, 8, 1.0);
+    let nondimensional_shear_modulus = model.nondimensional_shear_modulus();
+    let model = Dimensional::try_init_with_nondimensional_shear_modulus(model, &nondimensional_shear_modulus, shear_modulus, TEMPERATURE).unwrap();
+    assert!((model.shear_modulus() / shear_modulus - 1.0).abs() <= 1e-12);
+    let stretch: f64 = 1.0 + 1e-6;
+    let [[residual_cauchy_stress, _], [cauchy_stress, _]] = model.uniaxial_tension(&[1.0, stretch]);
+    assert!(((cauchy_stress - residual_cauchy_stress) / (stretch.powi(2) - 1.0 / stretch) / shear_modulus - 1.0).abs() <= 1e-3);
+}
+#[test]
+fn hyperelastic_damage_rate_dependent()
+{
+    let model = || hyperelastic_damage::BucheSilberstein::init(hyperelastic_damage::Method::GibbsLegendre, 2.5, 100.0, 8, 1.0);
+    let dimensional = Dimensional::init(model(), CHAIN_DENSITY, TEMPERATURE);
+    let nondimensional_time = [0.0, 1.0, 2.0, 3.0];
+    let results = dimensional.uniaxial_tension_rate_dependent(&20.0, &STRETCHES, &nondimensional_time).unwrap();
+    let nondimensional_results = model().uniaxial_tension_rate_dependent(&20.0, &STRETCHES, &nondimensional_time).unwrap();
+    results.iter().zip(nondimensional_results.iter()).for_each(|([cauchy_stress, probability], [nondimensional_cauchy_stress, nondimensional_probability])|{
+        assert!((cauchy_stress - dimensional.stress_scale() * nondimensional_cauchy_stress).abs() <= 1e-12 * dimensional.stress_scale());
+        assert_eq!(probability, nondimensional_probability);
+    });
+}
+#[test]
+fn transient_network()
+{
+    let model = || TransientNetwork::init_with_grid(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 3.0, 0.0, 16);
+    let dimensional = Dimensional::init(model(), CHAIN_DENSITY, TEMPERATURE);
+    let nondimensional_time = [0.0, 0.5, 1.0];
+    let results = dimensional.step_strain_relaxation(&1.5, &nondimensional_time);
+    let nondimensional_results = model().step_strain_relaxation(&1.5, &nondimensional_time);
+    results.iter().zip(nondimensional_results.iter()).for_each(|([cauchy_stress, attached_fraction], [nondimensional_cauchy_stress, nondimensional_attached_fraction])|{
+        assert!((cauchy_stress - dimensional.stress_scale() * nondimensional_cauchy_stress).abs() <= 1e-12 * dimensional.stress_scale());
+        assert_eq!(attached_fraction, nondimensional_attached_fraction);
+    });
+}
+#[test]
+fn swelling_pressure()
+{
+    let model = || FloryRehner::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 0.4, 100.0);
+    let dimensional = Dimensional::init(model(), CHAIN_DENSITY, TEMPERATURE);
+    assert!((dimensional.swelling_pressure(&2.0) - dimensional.stress_scale() * model().swelling_pressure(&2.0)).abs() <= 1e-12 * dimensional.stress_scale());
+}
+#[test]
+fn try_init()
+{
+    let model = || EightChain::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE);
+    assert!(Dimensional::try_init(model(), CHAIN_DENSITY, TEMPERATURE).is_ok());
+    assert!(Dimensional::try_init(model(), 0.0, TEMPERATURE).is_err());
+    assert!(Dimensional::try_init(model(), CHAIN_DENSITY, -1.0).is_err());
+    assert!(Dimensional::try_init_with_shear_modulus(model(), 1e6, TEMPERATURE).is_ok());
+    assert!(Dimensional::try_init_with_shear_modulus(model(), -1e6, TEMPERATURE).is_err());
+}
@@ -0,0 +1,121 @@
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+use crate::error::
+{
+    self,
+    Error
+};
+
+/// The number fraction below which chains with a given number of links are neglected.
+pub const CUTOFF: f64 = 1e-9;
+
+/// The distributions of the number of links in the chains of a network,
+/// which are truncated to the representable numbers of links and renormalized.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize), serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum ChainLengthDistribution
+{
+    /// Pairs of a number of links and the relative number of chains with that number of links.
+    Discrete(Vec<(u8, f64)>),
+    /// The Flory (most-probable) distribution with the given number-average number of links,
+    /// where the number fraction of chains with N links is proportional to p^(N - 1) with p = 1 - 1/N_n.
+    Flory(f64),
+    /// The Schulz-Zimm distribution with the given number-average number of links and dispersity,
+    /// where the number fraction of chains with N links is proportional to N^(k - 1) exp(-kN/N_n) with k = 1/(Đ - 1).
+    SchulzZimm(f64, f64)
+}
+
+/// The implemented functionality of the chain-length distributions.
+impl ChainLengthDistribution
+{
+    /// Validates the parameters of the distribution.
+    pub fn validate(&self) -> Result<(), Error>
+    {
+        match self
+        {
+            ChainLengthDistribution::Discrete(pairs) => {
+                pairs.iter().try_for_each(|(number_of_links, number_fraction)|{
+                    error::number_of_links(number_of_links)?;
+                    error::nonnegative("number_fraction", number_fraction)
+                })?;
+                error::positive("number_fractions", &pairs.iter().map(|(_, number_fraction)| number_fraction).sum())
+            },
+            ChainLengthDistribution::Flory(number_average_number_of_links) => {
+                if number_average_number_of_links >= &1.0 && number_average_number_of_links.is_finite()
+                {
+                    Ok(())
+                }
+                else
+                {
+                    Err(Error::Parameter("number_average_number_of_links", *number_average_number_of_links, "at least one and finite"))
+                }
+            },
+            ChainLengthDistribution::SchulzZimm(number_average_number_of_links, dispersity) => {
+                error::positive("number_average_number_of_links", number_average_number_of_links)?;
+                if dispersity > &1.0 && dispersity.is_finite()
+                {
+                    Ok(())
+                }
+                else
+                {
+                    Err(Error::Parameter("dispersity", *dispersity, "greater than one and finite"))
+                }
+            }
+        }
+    }
+    /// The numbers of links and the number fractions of chains with each, neglecting number fractions below the cutoff.
+    pub fn number_fractions(&self) -> Vec<(u8, f64)>
+    {
+        let mut relative_numbers = [0.0; 256];
+        match self
+        {
+            ChainLengthDistribution::Discrete(pairs) => pairs.iter().for_each(|(number_of_links, number_fraction)|
+                relative_numbers[*number_of_links as usize] += number_fraction
+            ),
+            ChainLengthDistribution::Flory(number_average_number_of_links) => {
+                let p = 1.0 - 1.0 / number_average_number_of_links;
+                relative_numbers.iter_mut().enumerate().skip(1).for_each(|(number_of_links, relative_number)|
+                    *relative_number = p.powi(number_of_links as i32 - 1)
+                )
+            },
+            ChainLengthDistribution::SchulzZimm(number_average_number_of_links, dispersity) => {
+                let k = 1.0 / (dispersity - 1.0);
+                let logarithm = |number_of_links: f64| (k - 1.0) * number_of_links.ln() - k * number_of_links / number_average_number_of_links;
+                let maximum = (1..256).map(|number_of_links| logarithm(number_of_links as f64)).fold(f64::NEG_INFINITY, f64::max);
+                relative_numbers.iter_mut().enumerate().skip(1).for_each(|(number_of_links, relative_number)|
+                    *relative_number = (logarithm(number_of_links as f64) - maximum).exp()
+                )
+            }
+        }
+        let total: f64 = relative_numbers.iter().sum();
+        let number_fractions: Vec<(u8, f64)> = relative_numbers.iter().enumerate().filter(|(_, relative_number)|
+            *relative_number / total >= CUTOFF
+        ).map(|(number_of_links, relative_number)|
+            (number_of_links as u8, *relative_number)
+        ).collect();
+        let total: f64 = number_fractions.iter().map(|(_, relative_number)| relative_number).sum();
+        number_fractions.into_iter().map(|(number_of_links, relative_number)|
+            (number_of_links, relative_number / total)
+        ).collect()
+    }
+    /// The number-average number of links of the truncated distribution.
+    pub fn number_average_number_of_links(&self) -> f64
+    {
+        self.number_fractions().iter().map(|(number_of_links, number_fraction)|
+            *number_of_links as f64 * number_fraction
+        ).sum()
+    }
+    /// The dispersity, or the ratio of the weight-average to the number-average number of links, of the truncated distribution.
+    pub fn dispersity(&self) -> f64
+    {
+        let number_fractions = self.number_fractions();
+        let (first_moment, second_moment) = number_fractions.iter().fold((0.0, 0.0), |(first_moment, second_moment), (number_of_links, number_fraction)|
+            (first_moment + *number_of_links as f64 * number_fraction, second_moment + (*number_of_links as f64).powi(2) * number_fraction)
+        );
+        second_moment / first_moment.powi(2)
+    }
+}
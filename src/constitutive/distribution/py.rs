@@ -0,0 +1,131 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let distribution = PyModule::new(py, "distribution")?;
+    distribution.add_class::<ChainLengthDistribution>()?;
+    parent_module.add_submodule(distribution)?;
+    Ok(())
+}
+
+/// The distribution of the number of links in the chains of a network,
+/// which is truncated to the representable numbers of links and renormalized.
+/// Numbers of links with number fractions below :math:`10^{-9}` are neglected.
+#[pyclass]
+#[derive(Clone)]
+pub struct ChainLengthDistribution
+{
+    pub distribution: super::ChainLengthDistribution
+}
+
+#[pymethods]
+impl ChainLengthDistribution
+{
+    /// A discrete distribution.
+    ///
+    /// Args:
+    ///     number_of_links (list): The numbers of links.
+    ///     number_fractions (list): The relative numbers of chains with each number of links, which are normalized.
+    ///
+    /// Returns:
+    ///     ChainLengthDistribution: The distribution.
+    ///
+    /// Raises:
+    ///     ValueError: If any of the parameters are invalid.
+    ///
+    #[staticmethod]
+    pub fn discrete(number_of_links: Vec<u8>, number_fractions: Vec<f64>) -> PyResult<Self>
+    {
+        if number_of_links.len() != number_fractions.len()
+        {
+            return Err(PyValueError::new_err("The numbers of links and number fractions must have the same length."))
+        }
+        let distribution = super::ChainLengthDistribution::Discrete(number_of_links.into_iter().zip(number_fractions).collect());
+        distribution.validate()?;
+        Ok(ChainLengthDistribution
+        {
+            distribution
+        })
+    }
+    /// The Flory (most-probable) distribution,
+    ///
+    /// .. math::
+    ///     x_N = (1 - p)p^{N - 1},
+    ///
+    /// where :math:`p = 1 - 1/N_n`.
+    ///
+    /// Args:
+    ///     number_average_number_of_links (float): The number-average number of links :math:`N_n`.
+    ///
+    /// Returns:
+    ///     ChainLengthDistribution: The distribution.
+    ///
+    /// Raises:
+    ///     ValueError: If the number-average number of links is less than one.
+    ///
+    #[staticmethod]
+    pub fn flory(number_average_number_of_links: f64) -> PyResult<Self>
+    {
+        let distribution = super::ChainLengthDistribution::Flory(number_average_number_of_links);
+        distribution.validate()?;
+        Ok(ChainLengthDistribution
+        {
+            distribution
+        })
+    }
+    /// The Schulz-Zimm distribution,
+    ///
+    /// .. math::
+    ///     x_N \propto N^{k - 1}e^{-kN/N_n},
+    ///
+    /// where :math:`k = 1/(Đ - 1)`.
+    ///
+    /// Args:
+    ///     number_average_number_of_links (float): The number-average number of links :math:`N_n`.
+    ///     dispersity (float): The dispersity :math:`Đ`.
+    ///
+    /// Returns:
+    ///     ChainLengthDistribution: The distribution.
+    ///
+    /// Raises:
+    ///     ValueError: If any of the parameters are invalid.
+    ///
+    #[staticmethod]
+    pub fn schulz_zimm(number_average_number_of_links: f64, dispersity: f64) -> PyResult<Self>
+    {
+        let distribution = super::ChainLengthDistribution::SchulzZimm(number_average_number_of_links, dispersity);
+        distribution.validate()?;
+        Ok(ChainLengthDistribution
+        {
+            distribution
+        })
+    }
+    /// The numbers of links and the number fractions of chains with each.
+    ///
+    /// Returns:
+    ///     list: Tuples of the number of links and the number fraction.
+    ///
+    pub fn number_fractions(&self) -> Vec<(u8, f64)>
+    {
+        self.distribution.number_fractions()
+    }
+    /// The number-average number of links of the truncated distribution.
+    ///
+    /// Returns:
+    ///     float: The number-average number of links :math:`N_n`.
+    ///
+    pub fn number_average_number_of_links(&self) -> f64
+    {
+        self.distribution.number_average_number_of_links()
+    }
+    /// The dispersity of the truncated distribution.
+    ///
+    /// Returns:
+    ///     float: The dispersity :math:`Đ`.
+    ///
+    pub fn dispersity(&self) -> f64
+    {
+        self.distribution.dispersity()
+    }
+}
@@ -0,0 +1,124 @@
+#![cfg(test)]
+use super::*;
+use crate::constitutive::
+{
+    hyperelastic,
+    hyperelastic_damage
+};
+mod number_fractions
+{
+    use super::*;
+    #[test]
+    fn discrete()
+    {
+        let number_fractions = ChainLengthDistribution::Discrete(vec![(8, 1.0), (16, 2.0), (8, 1.0)]).number_fractions();
+        assert_eq!(number_fractions, vec![(8, 0.5), (16, 0.5)]);
+    }
+    #[test]
+    fn flory()
+    {
+        let distribution = ChainLengthDistribution::Flory(20.0);
+        let total: f64 = distribution.number_fractions().iter().map(|(_, number_fraction)| number_fraction).sum();
+        assert!((total - 1.0).abs() <= 1e-12);
+        assert!((distribution.number_average_number_of_links() / 20.0 - 1.0).abs() <= 1e-3);
+        assert!((distribution.dispersity() / (2.0 - 1.0 / 20.0) - 1.0).abs() <= 1e-3);
+        assert_eq!(ChainLengthDistribution::Flory(1.0).number_fractions(), vec![(1, 1.0)]);
+    }
+    #[test]
+    fn schulz_zimm()
+    {
+        let distribution = ChainLengthDistribution::SchulzZimm(50.0, 1.5);
+        let total: f64 = distribution.number_fractions().iter().map(|(_, number_fraction)| number_fraction).sum();
+        assert!((total - 1.0).abs() <= 1e-12);
+        assert!((distribution.number_average_number_of_links() / 50.0 - 1.0).abs() <= 1e-2);
+        assert!((distribution.dispersity() / 1.5 - 1.0).abs() <= 1e-2);
+    }
+    #[test]
+    fn cutoff()
+    {
+        ChainLengthDistribution::SchulzZimm(10.0, 1.01).number_fractions().iter().for_each(|(_, number_fraction)|
+            assert!(number_fraction >= &CUTOFF)
+        );
+    }
+}
+mod validate
+{
+    use super::*;
+    #[test]
+    fn discrete()
+    {
+        assert!(ChainLengthDistribution::Discrete(vec![(8, 1.0), (16, 0.0)]).validate().is_ok());
+        assert!(ChainLengthDistribution::Discrete(vec![]).validate().is_err());
+        assert!(ChainLengthDistribution::Discrete(vec![(0, 1.0)]).validate().is_err());
+        assert!(ChainLengthDistribution::Discrete(vec![(8, -1.0), (16, 2.0)]).validate().is_err());
+        assert!(ChainLengthDistribution::Discrete(vec![(8, 0.0)]).validate().is_err());
+    }
+    #[test]
+    fn flory()
+    {
+        assert!(ChainLengthDistribution::Flory(1.0).validate().is_ok());
+        assert!(ChainLengthDistribution::Flory(0.5).validate().is_err());
+        assert!(ChainLengthDistribution::Flory(f64::INFINITY).validate().is_err());
+    }
+    #[test]
+    fn schulz_zimm()
+    {
+        assert!(ChainLengthDistribution::SchulzZimm(10.0, 1.5).validate().is_ok());
+        assert!(ChainLengthDistribution::SchulzZimm(-10.0, 1.5).validate().is_err());
+        assert!(ChainLengthDistribution::SchulzZimm(10.0, 1.0).validate().is_err());
+    }
+}
+mod average
+{
+    use super::*;
+    const NUMBER_OF_GRID_POINTS: usize = 32;
+    const NONDIMENSIONAL_LINK_STIFFNESS: f64 = 100.0;
+    const STRETCHES: [f64; 4] = [1.0, 1.5, 1.25, 2.0];
+    #[test]
+    fn hyperelastic()
+    {
+        let model = |number_of_links|
+            hyperelastic::BucheSilberstein::init_with_grid(hyperelastic::Method::GibbsLegendreGaussian, hyperelastic::Quadrature::GaussLegendre, NUMBER_OF_GRID_POINTS, NONDIMENSIONAL_LINK_STIFFNESS, number_of_links);
+        let (model_8, model_16) = (model(8), model(16));
+        let polydisperse = hyperelastic::PolydisperseBucheSilberstein::init_with_grid(hyperelastic::Method::GibbsLegendreGaussian, hyperelastic::Quadrature::GaussLegendre, NUMBER_OF_GRID_POINTS, NONDIMENSIONAL_LINK_STIFFNESS, ChainLengthDistribution::Discrete(vec![(8, 1.0), (16, 3.0)]));
+        assert_eq!(polydisperse.method(), model_8.method());
+        STRETCHES.iter().for_each(|stretch|{
+            let uniaxial_tension = 0.25 * model_8.uniaxial_tension(stretch) + 0.75 * model_16.uniaxial_tension(stretch);
+            assert!((polydisperse.uniaxial_tension(stretch) - uniaxial_tension).abs() <= 1e-12 * uniaxial_tension.abs().max(1.0));
+            let equibiaxial_tension = 0.25 * model_8.equibiaxial_tension(stretch) + 0.75 * model_16.equibiaxial_tension(stretch);
+            assert!((polydisperse.equibiaxial_tension(stretch) - equibiaxial_tension).abs() <= 1e-12 * equibiaxial_tension.abs().max(1.0));
+        });
+    }
+    #[test]
+    fn hyperelastic_damage()
+    {
+        let model = |number_of_links|
+            hyperelastic_damage::BucheSilberstein::init_with_grid(hyperelastic_damage::Method::GibbsLegendreGaussian, hyperelastic_damage::Quadrature::GaussLegendre, NUMBER_OF_GRID_POINTS, 1.1, NONDIMENSIONAL_LINK_STIFFNESS, number_of_links, 1.0);
+        let [result_8, result_16]: [[[f64; 2]; 4]; 2] = [model(8).uniaxial_tension(&STRETCHES), model(16).uniaxial_tension(&STRETCHES)];
+        let polydisperse = hyperelastic_damage::PolydisperseBucheSilberstein::init_with_grid(hyperelastic_damage::Method::GibbsLegendreGaussian, hyperelastic_damage::Quadrature::GaussLegendre, NUMBER_OF_GRID_POINTS, 1.1, NONDIMENSIONAL_LINK_STIFFNESS, ChainLengthDistribution::Discrete(vec![(8, 1.0), (16, 3.0)]), 1.0);
+        assert_eq!(polydisperse.method(), hyperelastic_damage::Method::GibbsLegendreGaussian);
+        let results: [[f64; 2]; 4] = polydisperse.uniaxial_tension(&STRETCHES);
+        results.iter().zip(result_8.iter().zip(result_16.iter())).for_each(|(result, (result_8_i, result_16_i))|
+            result.iter().zip(result_8_i.iter().zip(result_16_i.iter())).for_each(|(value, (value_8, value_16))|{
+                let average = 0.25 * value_8 + 0.75 * value_16;
+                assert!((value - average).abs() <= 1e-12 * average.abs().max(1.0));
+            })
+        );
+        assert!(polydisperse.try_uniaxial_tension::<4>(&[1.0, 0.5, 1.5, 2.0]).is_err());
+    }
+    #[test]
+    fn monodisperse()
+    {
+        let model = hyperelastic::BucheSilberstein::init_with_grid(hyperelastic::Method::GibbsLegendre, hyperelastic::Quadrature::GaussLegendre, NUMBER_OF_GRID_POINTS, NONDIMENSIONAL_LINK_STIFFNESS, 8);
+        let polydisperse = hyperelastic::PolydisperseBucheSilberstein::init_with_grid(hyperelastic::Method::GibbsLegendre, hyperelastic::Quadrature::GaussLegendre, NUMBER_OF_GRID_POINTS, NONDIMENSIONAL_LINK_STIFFNESS, ChainLengthDistribution::Discrete(vec![(8, 2.0)]));
+        STRETCHES.iter().for_each(|stretch|
+            assert_eq!(polydisperse.uniaxial_tension(stretch), model.uniaxial_tension(stretch))
+        );
+    }
+    #[test]
+    fn try_init()
+    {
+        assert!(hyperelastic::PolydisperseBucheSilberstein::try_init_with_grid(hyperelastic::Method::GibbsLegendre, hyperelastic::Quadrature::GaussLegendre, NUMBER_OF_GRID_POINTS, NONDIMENSIONAL_LINK_STIFFNESS, ChainLengthDistribution::Flory(0.0)).is_err());
+        assert!(hyperelastic_damage::PolydisperseBucheSilberstein::try_init_with_grid(hyperelastic_damage::Method::GibbsLegendre, hyperelastic_damage::Quadrature::GaussLegendre, NUMBER_OF_GRID_POINTS, 1.1, NONDIMENSIONAL_LINK_STIFFNESS, ChainLengthDistribution::SchulzZimm(10.0, 0.5), 1.0).is_err());
+    }
+}
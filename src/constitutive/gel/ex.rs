@@ -0,0 +1,44 @@
+use super::super::hyperelastic::
+{
+    ChainModel,
+    network::ex::
+    {
+        tensor_from_raw,
+        tensor_into_raw
+    }
+};
+#[no_mangle]
+pub extern fn constitutive_gel_flory_rehner_free_swelling_ratio(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::FloryRehner::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, flory_huggins_parameter, nondimensional_solvent_volume).free_swelling_ratio()
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_gel_flory_rehner_swelling_pressure(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64, swelling_ratio: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::FloryRehner::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, flory_huggins_parameter, nondimensional_solvent_volume).swelling_pressure(&swelling_ratio)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_gel_flory_rehner_uniaxial_tension(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::FloryRehner::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, flory_huggins_parameter, nondimensional_solvent_volume).uniaxial_tension(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_gel_flory_rehner_equibiaxial_tension(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::FloryRehner::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, flory_huggins_parameter, nondimensional_solvent_volume).equibiaxial_tension(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub unsafe extern fn constitutive_gel_flory_rehner_cauchy_stress(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64, deformation_gradient: *const f64, cauchy_stress: *mut f64)
+{
+    tensor_into_raw(&ChainModel::try_from(chain_model).map(|chain_model|
+        super::FloryRehner::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, flory_huggins_parameter, nondimensional_solvent_volume).cauchy_stress(&tensor_from_raw(deformation_gradient))
+    ).unwrap_or([[f64::NAN; 3]; 3]), cauchy_stress)
+}
@@ -0,0 +1,327 @@
+"""
+Gel constitutive models for polymer networks swollen by a solvent.
+"""
+module Gel
+
+using DocStringExtensions
+using Polymers_jll
+using ...Polymers: ParameterError, validate_positive
+using ..Hyperelastic: chain_model
+
+"""
+The structure of the Flory-Rehner gel model.
+
+$(FIELDS)
+"""
+struct FLORYREHNER
+    """
+    The single-chain model of each chain in the network.
+    """
+    chain::Any
+    """
+    The temperature ``T`` in units of K.
+    """
+    temperature::Float64
+    """
+    The Flory-Huggins parameter ``\\chi`` of the polymer and solvent.
+    """
+    flory_huggins_parameter::Float64
+    """
+    The nondimensional solvent volume ``\\Omega``.
+    """
+    nondimensional_solvent_volume::Float64
+    """
+    The swelling ratio ``J_0`` of the gel in equilibrium with the solvent in the absence of loads.
+    """
+    free_swelling_ratio::Function
+    """
+    The nondimensional swelling pressure ``\\beta\\Pi/n`` as a function of the swelling ratio ``J``.
+    """
+    swelling_pressure::Function
+    """
+    The nondimensional Cauchy stress ``\\beta\\sigma_{11}/n`` as a function of the applied stretch ``F_{11}/J_0^{1/3}`` from the freely-swollen state in uniaxial tension.
+    """
+    uniaxial_tension::Function
+    """
+    The nondimensional Cauchy stress ``\\beta\\sigma_{11}/n`` as a function of the applied stretch ``F_{11}/J_0^{1/3}`` from the freely-swollen state in equibiaxial tension.
+    """
+    equibiaxial_tension::Function
+    """
+    The nondimensional Cauchy stress ``\\beta\\boldsymbol{\\sigma}/n`` as a function of the deformation gradient ``\\mathbf{F}`` from the dry state.
+    """
+    cauchy_stress::Function
+end
+
+"""
+Initializes and returns an instance of the Flory-Rehner gel model using any of the single-chain models.
+
+$(TYPEDSIGNATURES)
+"""
+function FLORYREHNER(
+    chain::Any,
+    temperature::Float64,
+    flory_huggins_parameter::Float64,
+    nondimensional_solvent_volume::Float64,
+)
+    validate_positive("temperature", temperature)
+    isfinite(flory_huggins_parameter) || throw(
+        ParameterError("flory_huggins_parameter", flory_huggins_parameter, "finite"),
+    )
+    validate_positive("nondimensional_solvent_volume", nondimensional_solvent_volume)
+    parameters = (
+        chain_model(chain)...,
+        temperature,
+        flory_huggins_parameter,
+        nondimensional_solvent_volume,
+    )
+    return FLORYREHNER(
+        chain,
+        temperature,
+        flory_huggins_parameter,
+        nondimensional_solvent_volume,
+        () -> free_swelling_ratio(parameters...),
+        (swelling_ratio) -> swelling_pressure(parameters..., swelling_ratio),
+        (stretch) -> uniaxial_tension(parameters..., stretch),
+        (stretch) -> equibiaxial_tension(parameters..., stretch),
+        (deformation_gradient) -> cauchy_stress(parameters..., deformation_gradient),
+    )
+end
+
+"""
+The swelling ratio ``J_0`` of the Flory-Rehner gel model in equilibrium with the solvent in the absence of loads.
+
+$(TYPEDSIGNATURES)
+"""
+function free_swelling_ratio(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    flory_huggins_parameter::Float64,
+    nondimensional_solvent_volume::Float64,
+)::Float64
+    return ccall(
+        (:constitutive_gel_flory_rehner_free_swelling_ratio, Polymers_jll.libpolymers),
+        Float64,
+        (
+            UInt8,
+            UInt8,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+        ),
+        chain_model,
+        number_of_links,
+        link_length,
+        hinge_mass,
+        model_parameter_1,
+        model_parameter_2,
+        temperature,
+        flory_huggins_parameter,
+        nondimensional_solvent_volume,
+    )
+end
+
+"""
+The nondimensional swelling pressure ``\\beta\\Pi/n`` of the Flory-Rehner gel model as a function of the swelling ratio ``J``.
+
+$(TYPEDSIGNATURES)
+"""
+function swelling_pressure(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    flory_huggins_parameter::Float64,
+    nondimensional_solvent_volume::Float64,
+    swelling_ratio::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        swelling_ratio_i -> ccall(
+            (:constitutive_gel_flory_rehner_swelling_pressure, Polymers_jll.libpolymers),
+            Float64,
+            (
+                UInt8,
+                UInt8,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+            ),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            flory_huggins_parameter,
+            nondimensional_solvent_volume,
+            swelling_ratio_i,
+        ),
+        swelling_ratio,
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta\\sigma_{11}/n`` of the Flory-Rehner gel model as a function of the applied stretch ``F_{11}/J_0^{1/3}`` from the freely-swollen state in uniaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function uniaxial_tension(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    flory_huggins_parameter::Float64,
+    nondimensional_solvent_volume::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (:constitutive_gel_flory_rehner_uniaxial_tension, Polymers_jll.libpolymers),
+            Float64,
+            (
+                UInt8,
+                UInt8,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+            ),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            flory_huggins_parameter,
+            nondimensional_solvent_volume,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta\\sigma_{11}/n`` of the Flory-Rehner gel model as a function of the applied stretch ``F_{11}/J_0^{1/3}`` from the freely-swollen state in equibiaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function equibiaxial_tension(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    flory_huggins_parameter::Float64,
+    nondimensional_solvent_volume::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (:constitutive_gel_flory_rehner_equibiaxial_tension, Polymers_jll.libpolymers),
+            Float64,
+            (
+                UInt8,
+                UInt8,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+            ),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            flory_huggins_parameter,
+            nondimensional_solvent_volume,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta\\boldsymbol{\\sigma}/n`` of the Flory-Rehner gel model as a function of the deformation gradient ``\\mathbf{F}`` from the dry state.
+
+$(TYPEDSIGNATURES)
+"""
+function cauchy_stress(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    flory_huggins_parameter::Float64,
+    nondimensional_solvent_volume::Float64,
+    deformation_gradient::Matrix{Float64},
+)::Matrix{Float64}
+    size(deformation_gradient) == (3, 3) ||
+        throw(DimensionMismatch("the deformation gradient must be three by three"))
+    stress = zeros(9)
+    ccall(
+        (:constitutive_gel_flory_rehner_cauchy_stress, Polymers_jll.libpolymers),
+        Cvoid,
+        (
+            UInt8,
+            UInt8,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Ptr{Float64},
+            Ptr{Float64},
+        ),
+        chain_model,
+        number_of_links,
+        link_length,
+        hinge_mass,
+        model_parameter_1,
+        model_parameter_2,
+        temperature,
+        flory_huggins_parameter,
+        nondimensional_solvent_volume,
+        vec(permutedims(deformation_gradient)),
+        stress,
+    )
+    return permutedims(reshape(stress, 3, 3))
+end
+
+end
@@ -0,0 +1,208 @@
+#[cfg(feature = "extern")]
+mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::math::
+{
+    bracket,
+    inverse_brent
+};
+use crate::physics::single_chain::ForceLaw;
+use super::hyperelastic::
+{
+    Tensor,
+    network::
+    {
+        self,
+        IDENTITY
+    }
+};
+
+/// The relative tolerance of the swelling ratios and stretches solved for.
+static TOLERANCE: f64 = 1e-12;
+
+/// The smallest swelling ratio considered, above which the solvent volume fraction is positive.
+static MINIMUM_SWELLING_RATIO: f64 = 1.0 + 1e-12;
+
+/// The Flory-Rehner gel constitutive model, where an eight-chain network of the given single-chain model,
+/// with the dry state as its reference, is swollen by a solvent according to Flory-Huggins theory.
+/// The stresses are nondimensionalized by the number density of chains in the dry state and the thermal energy.
+pub struct FloryRehner<T: ForceLaw>
+{
+    /// The single-chain model of each chain in the network.
+    pub chain: T,
+
+    /// The temperature in units of K.
+    pub temperature: f64,
+
+    /// The Flory-Huggins parameter of the polymer and solvent.
+    pub flory_huggins_parameter: f64,
+
+    /// The nondimensional solvent volume, which is the volume of a solvent molecule times the number density of chains in the dry state,
+    /// or equivalently the molar volume of the solvent times the molar density of chains in the dry state.
+    pub nondimensional_solvent_volume: f64
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(FloryRehner<T: ForceLaw>, try_init, chain: T, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64);
+
+/// The implemented functionality of the Flory-Rehner gel constitutive model.
+impl<T: ForceLaw> FloryRehner<T>
+{
+    /// Initializes and returns an instance of the model using the given single-chain model.
+    pub fn init(chain: T, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64) -> Self
+    {
+        FloryRehner
+        {
+            chain,
+            temperature,
+            flory_huggins_parameter,
+            nondimensional_solvent_volume
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(chain: T, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64) -> Result<Self, Error>
+    {
+        error::positive("temperature", &temperature)?;
+        if !flory_huggins_parameter.is_finite()
+        {
+            return Err(Error::Parameter("flory_huggins_parameter", flory_huggins_parameter, "finite"))
+        }
+        error::positive("nondimensional_solvent_volume", &nondimensional_solvent_volume)?;
+        Ok(Self::init(chain, temperature, flory_huggins_parameter, nondimensional_solvent_volume))
+    }
+    /// The nondimensional isotropic Cauchy stress from mixing the network and solvent as a function of the swelling ratio,
+    /// which is the derivative of the nondimensional Flory-Huggins free energy of mixing per dry volume with respect to the swelling ratio.
+    pub fn nondimensional_mixing_stress(&self, swelling_ratio: &f64) -> f64
+    {
+        ((1.0 - 1.0 / swelling_ratio).ln() + 1.0 / swelling_ratio + self.flory_huggins_parameter / swelling_ratio.powi(2)) / self.nondimensional_solvent_volume
+    }
+    /// The nondimensional principal Cauchy stresses as a function of the principal stretches from the dry state.
+    fn principal_cauchy_stresses(&self, stretches: &[f64; 3]) -> [f64; 3]
+    {
+        let swelling_ratio = stretches.iter().product::<f64>();
+        let chain_stretch = (stretches.iter().map(|stretch| stretch.powi(2)).sum::<f64>() / 3.0).sqrt();
+        let scale = network::nondimensional_chain_tension(&self.chain, &self.temperature, &chain_stretch) / chain_stretch;
+        let offset = network::nondimensional_chain_tension(&self.chain, &self.temperature, &1.0);
+        let mixing_stress = self.nondimensional_mixing_stress(&swelling_ratio);
+        stretches.map(|stretch| (scale * stretch.powi(2) - offset) / 3.0 / swelling_ratio + mixing_stress)
+    }
+    /// The nondimensional Cauchy stress as a function of the deformation gradient from the dry state,
+    /// where the network free energy includes a term in the logarithm of the swelling ratio that makes the dry state stress-free.
+    pub fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        let (left_cauchy_green, swelling_ratio) = network::left_cauchy_green(deformation_gradient);
+        let left_cauchy_green = left_cauchy_green.map(|row| row.map(|entry| entry * swelling_ratio.powf(2.0 / 3.0)));
+        let chain_stretch = ((left_cauchy_green[0][0] + left_cauchy_green[1][1] + left_cauchy_green[2][2]) / 3.0).sqrt();
+        let mut cauchy_stress = [[0.0; 3]; 3];
+        network::add_scaled(&mut cauchy_stress, &(network::nondimensional_chain_tension(&self.chain, &self.temperature, &chain_stretch) / chain_stretch / 3.0 / swelling_ratio), &left_cauchy_green);
+        network::add_scaled(&mut cauchy_stress, &(self.nondimensional_mixing_stress(&swelling_ratio) - network::nondimensional_chain_tension(&self.chain, &self.temperature, &1.0) / 3.0 / swelling_ratio), &IDENTITY);
+        cauchy_stress
+    }
+    /// The nondimensional swelling pressure as a function of the swelling ratio,
+    /// which is the hydrostatic pressure required to hold the gel at the swelling ratio in equilibrium with the solvent.
+    pub fn swelling_pressure(&self, swelling_ratio: &f64) -> f64
+    {
+        -self.principal_cauchy_stresses(&[swelling_ratio.cbrt(); 3])[0]
+    }
+    /// The nondimensional swelling pressure as a function of the swelling ratio, or an error if the swelling ratio is not greater than one.
+    pub fn try_swelling_pressure(&self, swelling_ratio: &f64) -> Result<f64, Error>
+    {
+        validate("swelling_ratio", swelling_ratio, &1.0, "greater than one and finite")?;
+        Ok(self.swelling_pressure(swelling_ratio))
+    }
+    /// The swelling ratio of the gel in equilibrium with the solvent in the absence of loads.
+    pub fn free_swelling_ratio(&self) -> f64
+    {
+        self.try_free_swelling_ratio().unwrap_or(f64::NAN)
+    }
+    /// The swelling ratio of the gel in equilibrium with the solvent in the absence of loads, or an error if it cannot be found.
+    pub fn try_free_swelling_ratio(&self) -> Result<f64, Error>
+    {
+        let function = |swelling_ratio: &f64| -self.swelling_pressure(swelling_ratio);
+        let (lower, upper) = bracket(&0.0, &function, &MINIMUM_SWELLING_RATIO, &2.0, &f64::INFINITY, &100)?;
+        Ok(inverse_brent(&0.0, &function, &lower, &upper, &TOLERANCE, &100)?)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch from the freely-swollen state in uniaxial tension,
+    /// where the lateral stretches and swelling ratio follow from equilibrium with the solvent and the absence of lateral stress.
+    pub fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.try_uniaxial_tension(stretch).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch from the freely-swollen state in uniaxial tension, or an error if it cannot be found.
+    pub fn try_uniaxial_tension(&self, stretch: &f64) -> Result<f64, Error>
+    {
+        validate("stretch", stretch, &0.0, "positive and finite")?;
+        let free_stretch = self.try_free_swelling_ratio()?.cbrt();
+        let stretches = |lateral_stretch: &f64| [free_stretch * stretch, free_stretch * lateral_stretch, free_stretch * lateral_stretch];
+        let function = |lateral_stretch: &f64| self.principal_cauchy_stresses(&stretches(lateral_stretch))[1];
+        let minimum = (MINIMUM_SWELLING_RATIO / free_stretch.powi(3) / stretch).sqrt();
+        let (lower, upper) = bracket(&0.0, &function, &minimum, &(2.0 * minimum), &f64::INFINITY, &100)?;
+        let lateral_stretch = inverse_brent(&0.0, &function, &lower, &upper, &TOLERANCE, &100)?;
+        let [cauchy_stress_11, cauchy_stress_22, _] = self.principal_cauchy_stresses(&stretches(&lateral_stretch));
+        Ok(cauchy_stress_11 - cauchy_stress_22)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch from the freely-swollen state in equibiaxial tension,
+    /// where the transverse stretch and swelling ratio follow from equilibrium with the solvent and the absence of transverse stress.
+    pub fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.try_equibiaxial_tension(stretch).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch from the freely-swollen state in equibiaxial tension, or an error if it cannot be found.
+    pub fn try_equibiaxial_tension(&self, stretch: &f64) -> Result<f64, Error>
+    {
+        validate("stretch", stretch, &0.0, "positive and finite")?;
+        let free_stretch = self.try_free_swelling_ratio()?.cbrt();
+        let stretches = |transverse_stretch: &f64| [free_stretch * stretch, free_stretch * stretch, free_stretch * transverse_stretch];
+        let function = |transverse_stretch: &f64| self.principal_cauchy_stresses(&stretches(transverse_stretch))[2];
+        let minimum = MINIMUM_SWELLING_RATIO / free_stretch.powi(3) / stretch.powi(2);
+        let (lower, upper) = bracket(&0.0, &function, &minimum, &(2.0 * minimum), &f64::INFINITY, &100)?;
+        let transverse_stretch = inverse_brent(&0.0, &function, &lower, &upper, &TOLERANCE, &100)?;
+        let [cauchy_stress_11, _, cauchy_stress_33] = self.principal_cauchy_stresses(&stretches(&transverse_stretch));
+        Ok(cauchy_stress_11 - cauchy_stress_33)
+    }
+}
+
+/// Validates that an argument is finite and greater than the given minimum.
+fn validate(name: &'static str, value: &f64, minimum: &f64, requirement: &'static str) -> Result<(), Error>
+{
+    if value > minimum && value.is_finite()
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Domain(name, *value, requirement))
+    }
+}
+
+/// The deformation modes of the Flory-Rehner gel constitutive model.
+impl<T: ForceLaw> crate::constitutive::Hyperelastic for FloryRehner<T>
+{
+    fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension(stretch)
+    }
+    fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension(stretch)
+    }
+}
+
+/// The general deformations of the Flory-Rehner gel constitutive model.
+impl<T: ForceLaw> crate::constitutive::CauchyStress for FloryRehner<T>
+{
+    fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        self.cauchy_stress(deformation_gradient)
+    }
+}
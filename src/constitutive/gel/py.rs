@@ -0,0 +1,160 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    PyArrayDyn,
+    PyReadonlyArrayDyn
+};
+use crate::physics::single_chain::ForceLaw;
+use super::super::hyperelastic::network::py::
+{
+    force_law,
+    tensor_batch
+};
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let gel = PyModule::new(py, "gel")?;
+    gel.add_class::<FloryRehner>()?;
+    parent_module.add_submodule(gel)?;
+    Ok(())
+}
+
+/// The Flory-Rehner gel constitutive model,
+///
+/// .. math::
+///     \beta\boldsymbol{\sigma}/n = \frac{1}{3J}\left[\frac{\partial\vartheta}{\partial\lambda_c}\,\frac{\mathbf{B}}{\lambda_c} - \frac{\partial\vartheta}{\partial\lambda_c}\bigg|_{\lambda_c=1}\mathbf{1}\right] + \frac{1}{\Omega}\left[\ln\left(1 - \frac{1}{J}\right) + \frac{1}{J} + \frac{\chi}{J^2}\right]\mathbf{1},
+///
+/// where an eight-chain network with chain stretch :math:`\lambda_c=\sqrt{\mathrm{tr}\,\mathbf{B}/3}` is swollen from the dry state to the swelling ratio :math:`J` by a solvent according to Flory-Huggins theory.
+///
+/// Args:
+///     chain: Any of the single-chain models in :mod:`polymers.physics.single_chain`, such as :class:`polymers.physics.single_chain.fjc.FJC`.
+///     temperature (float): The temperature :math:`T`.
+///     flory_huggins_parameter (float): The Flory-Huggins parameter :math:`\chi`.
+///     nondimensional_solvent_volume (float): The nondimensional solvent volume :math:`\Omega`, which is the molar volume of the solvent times the molar density of chains in the dry state.
+///
+/// Raises:
+///     TypeError: If the chain is not one of the single-chain models.
+///     ValueError: If any of the parameters are invalid.
+///
+#[pyclass]
+pub struct FloryRehner
+{
+    model: super::FloryRehner<Box<dyn ForceLaw + Send + Sync>>,
+
+    /// The single-chain model of each chain in the network.
+    #[pyo3(get)]
+    pub chain: PyObject,
+
+    /// The temperature in units of K.
+    #[pyo3(get)]
+    pub temperature: f64,
+
+    /// The Flory-Huggins parameter of the polymer and solvent.
+    #[pyo3(get)]
+    pub flory_huggins_parameter: f64,
+
+    /// The nondimensional solvent volume.
+    #[pyo3(get)]
+    pub nondimensional_solvent_volume: f64
+}
+
+#[pymethods]
+impl FloryRehner
+{
+    #[new]
+    pub fn init(chain: &PyAny, temperature: f64, flory_huggins_parameter: f64, nondimensional_solvent_volume: f64) -> PyResult<Self>
+    {
+        Ok(FloryRehner
+        {
+            model: super::FloryRehner::try_init(force_law(chain)?, temperature, flory_huggins_parameter, nondimensional_solvent_volume)?,
+            chain: chain.into(),
+            temperature,
+            flory_huggins_parameter,
+            nondimensional_solvent_volume
+        })
+    }
+    /// The swelling ratio of the gel in equilibrium with the solvent in the absence of loads.
+    ///
+    /// Returns:
+    ///     float: The free swelling ratio :math:`J_0`.
+    ///
+    /// Raises:
+    ///     ValueError: If the free swelling ratio cannot be found.
+    ///
+    pub fn free_swelling_ratio(&self) -> PyResult<f64>
+    {
+        Ok(self.model.try_free_swelling_ratio()?)
+    }
+    /// The nondimensional swelling pressure as a function of the swelling ratio.
+    ///
+    /// Args:
+    ///     swelling_ratio (numpy.ndarray): The swelling ratio :math:`J`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional swelling pressure :math:`\beta\Pi/n`.
+    ///
+    /// Raises:
+    ///     ValueError: If any swelling ratio is not greater than one.
+    ///
+    pub fn swelling_pressure<'py>(&self, py: Python<'py>, swelling_ratio: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::validate(&swelling_ratio, |swelling_ratio| super::validate("swelling_ratio", swelling_ratio, &1.0, "greater than one and finite"))?;
+        crate::py::batch(py, swelling_ratio, |swelling_ratio: f64| self.model.swelling_pressure(&swelling_ratio))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch from the freely-swollen state in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}/J_0^{1/3}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta\sigma_{11}/n`.
+    ///
+    /// Raises:
+    ///     ValueError: If any stretch is not positive.
+    ///
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::validate(&stretch, |stretch| super::validate("stretch", stretch, &0.0, "positive and finite"))?;
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension(&stretch))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch from the freely-swollen state in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}/J_0^{1/3}=F_{22}/J_0^{1/3}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta\sigma_{11}/n`.
+    ///
+    /// Raises:
+    ///     ValueError: If any stretch is not positive.
+    ///
+    pub fn equibiaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::validate(&stretch, |stretch| super::validate("stretch", stretch, &0.0, "positive and finite"))?;
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension(&stretch))
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    ///
+    /// Returns:
+    ///     float: The nondimensional initial shear modulus :math:`\beta G/n`.
+    ///
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::Hyperelastic::nondimensional_shear_modulus(&self.model)
+    }
+    /// The nondimensional Cauchy stress as a function of the deformation gradient from the dry state.
+    ///
+    /// Args:
+    ///     deformation_gradient (numpy.ndarray): The deformation gradient :math:`\mathbf{F}`, with trailing dimensions of three by three.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta\boldsymbol{\sigma}/n`.
+    ///
+    /// Raises:
+    ///     ValueError: If the trailing dimensions are not three by three.
+    ///
+    pub fn cauchy_stress<'py>(&self, py: Python<'py>, deformation_gradient: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        tensor_batch(py, deformation_gradient, |deformation_gradient| self.model.cauchy_stress(deformation_gradient))
+    }
+}
@@ -0,0 +1,35 @@
+module Test
+
+using Test
+using Polymers: ParameterError
+using Polymers.Physics.SingleChain.Ideal: IDEAL
+using Polymers.Physics.SingleChain.Fjc: FJC
+using Polymers.Constitutive.Gel: FLORYREHNER
+
+@testset "constitutive::gel::test::swelling" begin
+    model = FLORYREHNER(IDEAL(UInt8(25), 1.0, 1.0), 300.0, 0.5, 10.0)
+    swelling_ratio = model.free_swelling_ratio()
+    mixing_stress =
+        (log(1.0 - 1.0 / swelling_ratio) + 1.0 / swelling_ratio + 0.5 / swelling_ratio^2) /
+        10.0
+    @test abs((swelling_ratio^(2 / 3) - 1.0) / swelling_ratio + mixing_stress) <= 1e-10
+    @test abs(model.swelling_pressure(swelling_ratio)) <= 1e-10
+end
+
+@testset "constitutive::gel::test::loading" begin
+    model = FLORYREHNER(FJC(UInt8(8), 1.0, 1.0), 300.0, 0.4, 10.0)
+    @test abs(model.uniaxial_tension(1.0)) <= 1e-10
+    @test abs(model.equibiaxial_tension(1.0)) <= 1e-10
+    @test all(diff(model.uniaxial_tension([0.8, 1.1, 1.5, 2.0])) .> 0.0)
+    stretch = cbrt(model.free_swelling_ratio())
+    deformation_gradient = stretch * [1.0 0.0 0.0; 0.0 1.0 0.0; 0.0 0.0 1.0]
+    @test all(abs.(model.cauchy_stress(deformation_gradient)) .<= 1e-10)
+end
+
+@testset "constitutive::gel::test::error" begin
+    @test_throws ParameterError FLORYREHNER(FJC(UInt8(8), 1.0, 1.0), 0.0, 0.4, 10.0)
+    @test_throws ParameterError FLORYREHNER(FJC(UInt8(8), 1.0, 1.0), 300.0, NaN, 10.0)
+    @test_throws ParameterError FLORYREHNER(FJC(UInt8(8), 1.0, 1.0), 300.0, 0.4, -1.0)
+end
+
+end
@@ -0,0 +1,89 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::
+{
+    fjc::FJC,
+    ideal::Ideal
+};
+const TEMPERATURE: f64 = 300.0;
+const STRETCHES: [f64; 4] = [0.8, 1.1, 1.5, 2.0];
+mod swelling
+{
+    use super::*;
+    #[test]
+    fn ideal()
+    {
+        let model = FloryRehner::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 0.5, 10.0);
+        let swelling_ratio = model.free_swelling_ratio();
+        let stretch = swelling_ratio.cbrt();
+        let residual = (stretch.powi(2) - 1.0) / swelling_ratio + model.nondimensional_mixing_stress(&swelling_ratio);
+        assert!(residual.abs() <= 1e-10);
+        assert!(swelling_ratio > 1.0);
+    }
+    #[test]
+    fn pressure()
+    {
+        let model = FloryRehner::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.4, 10.0);
+        let swelling_ratio = model.free_swelling_ratio();
+        assert!(model.swelling_pressure(&swelling_ratio).abs() <= 1e-10);
+        assert!(model.swelling_pressure(&(0.9 * swelling_ratio)) > 0.0);
+        assert!(model.swelling_pressure(&(1.1 * swelling_ratio)) < 0.0);
+    }
+    #[test]
+    fn flory_huggins_parameter()
+    {
+        let good = FloryRehner::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.2, 10.0);
+        let poor = FloryRehner::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.8, 10.0);
+        assert!(good.free_swelling_ratio() > poor.free_swelling_ratio());
+    }
+}
+mod loading
+{
+    use super::*;
+    #[test]
+    fn undeformed()
+    {
+        let model = FloryRehner::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.4, 10.0);
+        assert!(model.uniaxial_tension(&1.0).abs() <= 1e-10);
+        assert!(model.equibiaxial_tension(&1.0).abs() <= 1e-10);
+    }
+    #[test]
+    fn monotonic()
+    {
+        let model = FloryRehner::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.4, 10.0);
+        STRETCHES.windows(2).for_each(|stretches|{
+            assert!(model.uniaxial_tension(&stretches[1]) > model.uniaxial_tension(&stretches[0]));
+            assert!(model.equibiaxial_tension(&stretches[1]) > model.equibiaxial_tension(&stretches[0]));
+        });
+    }
+    #[test]
+    fn cauchy_stress()
+    {
+        let model = FloryRehner::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.4, 10.0);
+        let stretch = model.free_swelling_ratio().cbrt();
+        let cauchy_stress = model.cauchy_stress(&IDENTITY.map(|row| row.map(|entry| stretch * entry)));
+        cauchy_stress.iter().flatten().for_each(|entry| assert!(entry.abs() <= 1e-10));
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        assert!(FloryRehner::try_init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.4, 10.0).is_ok());
+        assert!(FloryRehner::try_init(FJC::init(8, 1.0, 1.0), 0.0, 0.4, 10.0).is_err());
+        assert!(FloryRehner::try_init(FJC::init(8, 1.0, 1.0), TEMPERATURE, f64::NAN, 10.0).is_err());
+        assert!(FloryRehner::try_init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.4, -1.0).is_err());
+    }
+    #[test]
+    fn domain()
+    {
+        let model = FloryRehner::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.4, 10.0);
+        assert!(model.try_swelling_pressure(&1.0).is_err());
+        assert!(model.try_uniaxial_tension(&0.0).is_err());
+        assert!(model.try_equibiaxial_tension(&-1.0).is_err());
+        assert!(model.uniaxial_tension(&0.0).is_nan());
+        assert!(model.equibiaxial_tension(&-1.0).is_nan());
+    }
+}
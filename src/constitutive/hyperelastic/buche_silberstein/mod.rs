@@ -4,27 +4,111 @@ pub mod ex;
 #[cfg(feature = "python")]
 pub mod py;
 
+use crate::constitutive::distribution::ChainLengthDistribution;
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::math::gauss_legendre;
 use crate::physics::single_chain::efjc::thermodynamics::isometric::asymptotic::reduced::legendre::
 {
     nondimensional_force,
     nondimensional_relative_helmholtz_free_energy
 };
-use std::
-{
-    array::from_fn,
-    f64::consts::PI
-};
+use std::f64::consts::PI;
 
+/// The default number of grid points in each direction.
 pub const NUMGRID: usize = 256;
 
+/// The methods of the Buche-Silberstein constitutive models.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize), serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Method
+{
+    /// Gibbs-Legendre method for both the Helmholtz free energy and the equilibrium distribution.
+    GibbsLegendre = 2,
+    /// Gibbs-Legendre for the Helmholtz free energy and a Gaussian equilibrium distribution.
+    GibbsLegendreGaussian = 3
+}
+
+/// The conversion of method numbers, as passed across the C ABI, to methods.
+impl TryFrom<u8> for Method
+{
+    type Error = Error;
+    fn try_from(method: u8) -> Result<Self, Self::Error>
+    {
+        match method
+        {
+            2 => Ok(Method::GibbsLegendre),
+            3 => Ok(Method::GibbsLegendreGaussian),
+            _ => Err(Error::Method(method))
+        }
+    }
+}
+
+/// The quadrature rules for the grids of the Buche-Silberstein constitutive models.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize), serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Quadrature
+{
+    /// Uniformly-spaced nodes with equal weights.
+    Uniform = 1,
+    /// Gauss-Legendre nodes and weights.
+    GaussLegendre = 2
+}
+
+/// The nodes and weights of the quadrature rule on the unit interval, excluding its endpoints.
+fn unit_interval(quadrature: &Quadrature, number_of_grid_points: &usize) -> (Vec<f64>, Vec<f64>)
+{
+    match quadrature
+    {
+        Quadrature::Uniform => (0..*number_of_grid_points).map(|i|
+            ((i + 1) as f64 / (*number_of_grid_points as f64 + 1.0), 1.0 / (*number_of_grid_points as f64 + 1.0))
+        ).unzip(),
+        Quadrature::GaussLegendre => {
+            let (nodes, weights) = gauss_legendre(number_of_grid_points);
+            nodes.iter().zip(weights.iter()).map(|(x_i, weight_i)|
+                (0.5 * (x_i + 1.0), 0.5 * weight_i)
+            ).unzip()
+        }
+    }
+}
+
+/// Validates that the number of grid points is enough to resolve the integrals.
+fn validate_number_of_grid_points(number_of_grid_points: &usize) -> Result<(), Error>
+{
+    if number_of_grid_points >= &2
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Parameter("number_of_grid_points", *number_of_grid_points as f64, "at least two"))
+    }
+}
+
 /// The Buche-Silberstein hyperelastic constitutive model.
 pub struct BucheSilberstein
 {
-    element: [[f64; NUMGRID]; NUMGRID],
+    element: Vec<f64>,
     factor: f64,
-    grid: [f64; NUMGRID],
-    method: u8,
+    grid: Vec<f64>,
+    method: Method,
     normalization: f64,
+    weight: Vec<f64>,
+
+    /// The number of grid points in each direction.
+    pub number_of_grid_points: usize,
+
+    /// The quadrature rule of the grid.
+    pub quadrature: Quadrature,
 
     /// The nondimensional stiffness of each link in a chain.
     pub nondimensional_link_stiffness: f64,
@@ -33,56 +117,52 @@ pub struct BucheSilberstein
     pub number_of_links: u8
 }
 
-pub fn init(method: u8, nondimensional_link_stiffness: f64, number_of_links: u8) -> ([[f64; NUMGRID]; NUMGRID], f64, [f64; NUMGRID], f64)
-{
-    let num_grid = NUMGRID as f64 + 1.0;
-    let w: [f64; NUMGRID] = from_fn(|i|
-        (i + 1) as f64 / num_grid
-    );
-    let dw = w[1] - w[0];
-    let grid: [f64; NUMGRID] = from_fn(|i|
-        w[i].atanh()
-    );
-    let mut element = [[0.0_f64; NUMGRID]; NUMGRID];
-    element.iter_mut().zip(grid.iter().zip(w.iter())).for_each(|(element_i, (z_i, w_i))|
-        element_i.iter_mut().zip(grid.iter().zip(w.iter())).for_each(|(element_ij, (r_j, w_j))|
-            *element_ij = nondimensional_force(&nondimensional_link_stiffness, &(z_i.powi(2) + r_j.powi(2)).sqrt())
+#[cfg(feature = "serde")]
+crate::config::parameters!(BucheSilberstein, try_init_with_grid, method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_link_stiffness: f64, number_of_links: u8);
+
+pub fn init(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_link_stiffness: f64, number_of_links: u8) -> (Vec<f64>, f64, Vec<f64>, Vec<f64>, f64)
+{
+    let (w, dw) = unit_interval(&quadrature, &number_of_grid_points);
+    let grid: Vec<f64> = w.iter().map(|w_i| w_i.atanh()).collect();
+    let weight: Vec<f64> = w.iter().zip(dw.iter()).map(|(w_i, dw_i)|
+        dw_i / (1.0 - w_i.powi(2))
+    ).collect();
+    let element = grid.iter().zip(weight.iter()).flat_map(|(z_i, weight_i)|
+        grid.iter().zip(weight.iter()).map(move |(r_j, weight_j)|
+            nondimensional_force(&nondimensional_link_stiffness, &(z_i.powi(2) + r_j.powi(2)).sqrt())
                 * (2.0 * z_i.powi(2) - r_j.powi(2)) * 2.0 * PI * (number_of_links as f64) * r_j
-                / (z_i.powi(2) + r_j.powi(2)).sqrt() / (1.0 - w_i.powi(2)) / (1.0 - w_j.powi(2)) * dw.powi(2)
+                / (z_i.powi(2) + r_j.powi(2)).sqrt() * weight_i * weight_j
         )
-    );
+    ).collect();
     let factor;
     let normalization;
     match method {
-        1 => panic!("Method number 1 not yet implemented."),
-        2 => {
+        Method::GibbsLegendre => {
             factor = 0.0;
-            normalization = grid.iter().zip(w.iter()).map(|(gamma_i, w_i)|
-                gamma_i.powi(2) / (1.0 - w_i.powi(2)) / nondimensional_relative_helmholtz_free_energy(
+            normalization = grid.iter().zip(weight.iter()).map(|(gamma_i, weight_i)|
+                gamma_i.powi(2) * weight_i / nondimensional_relative_helmholtz_free_energy(
                     &number_of_links, &nondimensional_link_stiffness, gamma_i
                 ).exp()
-            ).sum::<f64>() * 4.0 * PI * dw;
+            ).sum::<f64>() * 4.0 * PI;
         }
-        3 => {
+        Method::GibbsLegendreGaussian => {
             factor = 1.5 * number_of_links as f64
                 * nondimensional_link_stiffness * (nondimensional_link_stiffness + 1.0)
                 / (nondimensional_link_stiffness.powi(2) + 6.0 * nondimensional_link_stiffness + 3.0);
-            normalization = grid.iter().zip(w.iter()).map(|(gamma_i, w_i)|
-                gamma_i.powi(2) / (1.0 - w_i.powi(2)) / (factor * gamma_i.powi(2)).exp()
-            ).sum::<f64>() * 4.0 * PI * dw;
+            normalization = grid.iter().zip(weight.iter()).map(|(gamma_i, weight_i)|
+                gamma_i.powi(2) * weight_i / (factor * gamma_i.powi(2)).exp()
+            ).sum::<f64>() * 4.0 * PI;
         }
-        _ => panic!("Invalid method number.")
     }
-    (element, factor, grid, normalization)
+    (element, factor, grid, weight, normalization)
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn uniaxial_tension(element: &[[f64; NUMGRID]], factor: &f64, grid: &[f64], normalization: &f64, method: &u8, nondimensional_link_stiffness: &f64, number_of_links: &u8, stretch: &f64) -> f64
+pub fn uniaxial_tension(element: &[f64], factor: &f64, grid: &[f64], normalization: &f64, method: &Method, nondimensional_link_stiffness: &f64, number_of_links: &u8, stretch: &f64) -> f64
 {
     match method {
-        1 => panic!("Method number 1 not yet implemented."),
-        2 => {
-            element.iter().zip(grid.iter()).flat_map(|(element_i, z_i)|
+        Method::GibbsLegendre => {
+            element.chunks_exact(grid.len()).zip(grid.iter()).flat_map(|(element_i, z_i)|
                 element_i.iter().zip(grid.iter()).map(|(element_ij, r_j)|
                     element_ij / nondimensional_relative_helmholtz_free_energy(
                         number_of_links, nondimensional_link_stiffness, &((*z_i / stretch).powi(2) + stretch * r_j.powi(2)).sqrt()
@@ -90,24 +170,22 @@ pub fn uniaxial_tension(element: &[[f64; NUMGRID]], factor: &f64, grid: &[f64],
                 )
             ).sum::<f64>() / normalization
         }
-        3 => {
-            element.iter().zip(grid.iter()).flat_map(|(element_i, z_i)|
+        Method::GibbsLegendreGaussian => {
+            element.chunks_exact(grid.len()).zip(grid.iter()).flat_map(|(element_i, z_i)|
                 element_i.iter().zip(grid.iter()).map(|(element_ij, r_j)|
                     element_ij / (factor * ((*z_i / stretch).powi(2) + stretch * r_j.powi(2))).exp()
                 )
             ).sum::<f64>() / normalization
         }
-        _ => panic!("Invalid method number.")
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn equibiaxial_tension(element: &[[f64; NUMGRID]], factor: &f64, grid: &[f64], normalization: &f64, method: &u8, nondimensional_link_stiffness: &f64, number_of_links: &u8, stretch: &f64) -> f64
+pub fn equibiaxial_tension(element: &[f64], factor: &f64, grid: &[f64], normalization: &f64, method: &Method, nondimensional_link_stiffness: &f64, number_of_links: &u8, stretch: &f64) -> f64
 {
     match method {
-        1 => panic!("Method number 1 not yet implemented."),
-        2 => {
-            element.iter().zip(grid.iter()).flat_map(|(element_i, z_i)|
+        Method::GibbsLegendre => {
+            element.chunks_exact(grid.len()).zip(grid.iter()).flat_map(|(element_i, z_i)|
                 element_i.iter().zip(grid.iter()).map(|(element_ij, r_j)|
                     -element_ij / nondimensional_relative_helmholtz_free_energy(
                         number_of_links, nondimensional_link_stiffness, &((*z_i * stretch.powi(2)).powi(2) + (*r_j / stretch).powi(2)).sqrt()
@@ -115,27 +193,64 @@ pub fn equibiaxial_tension(element: &[[f64; NUMGRID]], factor: &f64, grid: &[f64
                 )
             ).sum::<f64>() / normalization
         }
-        3 => {
-            element.iter().zip(grid.iter()).flat_map(|(element_i, z_i)|
+        Method::GibbsLegendreGaussian => {
+            element.chunks_exact(grid.len()).zip(grid.iter()).flat_map(|(element_i, z_i)|
                 element_i.iter().zip(grid.iter()).map(|(element_ij, r_j)|
                     -element_ij / (factor * ((*z_i * stretch.powi(2)).powi(2) + (*r_j / stretch).powi(2))).exp()
                 )
             ).sum::<f64>() / normalization
         }
-        _ => panic!("Invalid method number.")
     }
 }
 
+/// The nondimensional strain energy density of the chains, relative to the undeformed network,
+/// given the reference nondimensional end-to-end length per link as a function of the axial and radial components.
+#[allow(clippy::too_many_arguments)]
+fn strain_energy_density(factor: &f64, grid: &[f64], weight: &[f64], normalization: &f64, method: &Method, nondimensional_link_stiffness: &f64, number_of_links: &u8, reference: &dyn Fn(&f64, &f64) -> f64) -> f64
+{
+    let nondimensional_equilibrium_distribution = |gamma_0: f64| match method
+    {
+        Method::GibbsLegendre => (-nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_link_stiffness, &gamma_0)).exp(),
+        Method::GibbsLegendreGaussian => (-factor * gamma_0.powi(2)).exp()
+    };
+    let integral = |reference: &dyn Fn(&f64, &f64) -> f64| grid.iter().zip(weight.iter()).flat_map(|(z_i, weight_i)|
+        grid.iter().zip(weight.iter()).map(move |(r_j, weight_j)|
+            nondimensional_equilibrium_distribution(reference(z_i, r_j))
+                * nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_link_stiffness, &(z_i.powi(2) + r_j.powi(2)).sqrt())
+                * 4.0 * PI * r_j * weight_i * weight_j
+        )
+    ).sum::<f64>() / normalization;
+    integral(reference) - integral(&|z, r| (z.powi(2) + r.powi(2)).sqrt())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn uniaxial_tension_strain_energy_density(factor: &f64, grid: &[f64], weight: &[f64], normalization: &f64, method: &Method, nondimensional_link_stiffness: &f64, number_of_links: &u8, stretch: &f64) -> f64
+{
+    strain_energy_density(factor, grid, weight, normalization, method, nondimensional_link_stiffness, number_of_links, &|z, r| ((z / stretch).powi(2) + stretch * r.powi(2)).sqrt())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn equibiaxial_tension_strain_energy_density(factor: &f64, grid: &[f64], weight: &[f64], normalization: &f64, method: &Method, nondimensional_link_stiffness: &f64, number_of_links: &u8, stretch: &f64) -> f64
+{
+    strain_energy_density(factor, grid, weight, normalization, method, nondimensional_link_stiffness, number_of_links, &|z, r| ((z * stretch.powi(2)).powi(2) + (r / stretch).powi(2)).sqrt())
+}
+
 /// The implemented functionality of the Buche-Silberstein hyperelastic constitutive model.
 impl BucheSilberstein
 {
-    /// Initializes and returns an instance of the model.
-    /// 1. Helmholtz method for both the Helmholtz free energy and the equilibrium distribution.
-    /// 2. Gibbs-Legendre method for both the Helmholtz free energy and the equilibrium distribution.
-    /// 3. Gibbs-Legendre for the Helmholtz free energy and a Gaussian equilibrium distribution.
-    pub fn init(method: u8, nondimensional_link_stiffness: f64, number_of_links: u8) -> Self
+    /// Initializes and returns an instance of the model using the given method and the default uniform grid.
+    pub fn init(method: Method, nondimensional_link_stiffness: f64, number_of_links: u8) -> Self
+    {
+        Self::init_with_grid(method, Quadrature::Uniform, NUMGRID, nondimensional_link_stiffness, number_of_links)
+    }
+    /// Initializes and returns an instance of the model using the given method and grid, and panics if there are fewer than two grid points.
+    pub fn init_with_grid(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_link_stiffness: f64, number_of_links: u8) -> Self
     {
-        let (element, factor, grid, normalization) = init(method, nondimensional_link_stiffness, number_of_links);
+        if let Err(error) = validate_number_of_grid_points(&number_of_grid_points)
+        {
+            panic!("{}", error)
+        }
+        let (element, factor, grid, weight, normalization) = init(method, quadrature, number_of_grid_points, nondimensional_link_stiffness, number_of_links);
         BucheSilberstein
         {
             element,
@@ -143,10 +258,43 @@ impl BucheSilberstein
             grid,
             method,
             normalization,
+            weight,
+            number_of_grid_points,
+            quadrature,
             nondimensional_link_stiffness,
             number_of_links
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(method: Method, nondimensional_link_stiffness: f64, number_of_links: u8) -> Result<Self, Error>
+    {
+        Self::try_init_with_grid(method, Quadrature::Uniform, NUMGRID, nondimensional_link_stiffness, number_of_links)
+    }
+    /// Initializes and returns an instance of the model using the given grid, or an error if any of the parameters are invalid.
+    pub fn try_init_with_grid(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_link_stiffness: f64, number_of_links: u8) -> Result<Self, Error>
+    {
+        validate_number_of_grid_points(&number_of_grid_points)?;
+        error::positive("nondimensional_link_stiffness", &nondimensional_link_stiffness)?;
+        error::number_of_links(&number_of_links)?;
+        Ok(Self::init_with_grid(method, quadrature, number_of_grid_points, nondimensional_link_stiffness, number_of_links))
+    }
+    /// The method used to evaluate the model.
+    pub fn method(&self) -> Method
+    {
+        self.method
+    }
+    /// The nondimensional Cauchy stress in uniaxial tension at the given stretch for each of the given numbers of grid points,
+    /// along with the relative change in the stress from the previous number of grid points.
+    pub fn convergence(method: Method, quadrature: Quadrature, nondimensional_link_stiffness: f64, number_of_links: u8, stretch: &f64, numbers_of_grid_points: &[usize]) -> Vec<(usize, f64, f64)>
+    {
+        let mut previous_stress = f64::NAN;
+        numbers_of_grid_points.iter().map(|number_of_grid_points|{
+            let stress = Self::init_with_grid(method, quadrature, *number_of_grid_points, nondimensional_link_stiffness, number_of_links).uniaxial_tension(stretch);
+            let relative_change = ((stress - previous_stress) / stress).abs();
+            previous_stress = stress;
+            (*number_of_grid_points, stress, relative_change)
+        }).collect()
+    }
     /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
     pub fn uniaxial_tension(&self, stretch: &f64) -> f64
     {
@@ -157,4 +305,160 @@ impl BucheSilberstein
     {
         equibiaxial_tension(&self.element, &self.factor, &self.grid, &self.normalization, &self.method, &self.nondimensional_link_stiffness, &self.number_of_links, stretch)
     }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        uniaxial_tension_strain_energy_density(&self.factor, &self.grid, &self.weight, &self.normalization, &self.method, &self.nondimensional_link_stiffness, &self.number_of_links, stretch)
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        equibiaxial_tension_strain_energy_density(&self.factor, &self.grid, &self.weight, &self.normalization, &self.method, &self.nondimensional_link_stiffness, &self.number_of_links, stretch)
+    }
+}
+
+/// The Buche-Silberstein hyperelastic constitutive model of a network whose chains have a distribution of lengths,
+/// where the stress is averaged over the number fractions of chains with each number of links.
+pub struct PolydisperseBucheSilberstein
+{
+    components: Vec<(f64, BucheSilberstein)>,
+    method: Method,
+
+    /// The number of grid points in each direction.
+    pub number_of_grid_points: usize,
+
+    /// The quadrature rule of the grid.
+    pub quadrature: Quadrature,
+
+    /// The nondimensional stiffness of each link in a chain.
+    pub nondimensional_link_stiffness: f64,
+
+    /// The distribution of the number of links in a chain.
+    pub distribution: ChainLengthDistribution
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(PolydisperseBucheSilberstein, try_init_with_grid, method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution);
+
+/// The implemented functionality of the polydisperse Buche-Silberstein hyperelastic constitutive model.
+impl PolydisperseBucheSilberstein
+{
+    /// Initializes and returns an instance of the model using the given method and the default uniform grid.
+    pub fn init(method: Method, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution) -> Self
+    {
+        Self::init_with_grid(method, Quadrature::Uniform, NUMGRID, nondimensional_link_stiffness, distribution)
+    }
+    /// Initializes and returns an instance of the model using the given method and grid.
+    pub fn init_with_grid(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution) -> Self
+    {
+        PolydisperseBucheSilberstein
+        {
+            components: distribution.number_fractions().into_iter().map(|(number_of_links, number_fraction)|
+                (number_fraction, BucheSilberstein::init_with_grid(method, quadrature, number_of_grid_points, nondimensional_link_stiffness, number_of_links))
+            ).collect(),
+            method,
+            number_of_grid_points,
+            quadrature,
+            nondimensional_link_stiffness,
+            distribution
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(method: Method, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution) -> Result<Self, Error>
+    {
+        Self::try_init_with_grid(method, Quadrature::Uniform, NUMGRID, nondimensional_link_stiffness, distribution)
+    }
+    /// Initializes and returns an instance of the model using the given grid, or an error if any of the parameters are invalid.
+    pub fn try_init_with_grid(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution) -> Result<Self, Error>
+    {
+        validate_number_of_grid_points(&number_of_grid_points)?;
+        error::positive("nondimensional_link_stiffness", &nondimensional_link_stiffness)?;
+        distribution.validate()?;
+        Ok(Self::init_with_grid(method, quadrature, number_of_grid_points, nondimensional_link_stiffness, distribution))
+    }
+    /// The method used to evaluate the model.
+    pub fn method(&self) -> Method
+    {
+        self.method
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.components.iter().map(|(number_fraction, model)|
+            number_fraction * model.uniaxial_tension(stretch)
+        ).sum()
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.components.iter().map(|(number_fraction, model)|
+            number_fraction * model.equibiaxial_tension(stretch)
+        ).sum()
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.components.iter().map(|(number_fraction, model)|
+            number_fraction * model.uniaxial_tension_strain_energy_density(stretch)
+        ).sum()
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.components.iter().map(|(number_fraction, model)|
+            number_fraction * model.equibiaxial_tension_strain_energy_density(stretch)
+        ).sum()
+    }
+}
+
+/// The deformation modes of the Buche-Silberstein hyperelastic constitutive model.
+impl crate::constitutive::Hyperelastic for BucheSilberstein
+{
+    fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension(stretch)
+    }
+    fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension(stretch)
+    }
+}
+
+/// The deformation modes of the polydisperse Buche-Silberstein hyperelastic constitutive model.
+impl crate::constitutive::Hyperelastic for PolydisperseBucheSilberstein
+{
+    fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension(stretch)
+    }
+    fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension(stretch)
+    }
+}
+
+/// The strain energy density of the Buche-Silberstein hyperelastic constitutive model.
+impl crate::constitutive::StrainEnergy for BucheSilberstein
+{
+    fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension_strain_energy_density(stretch)
+    }
+    fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension_strain_energy_density(stretch)
+    }
+}
+
+/// The strain energy density of the polydisperse Buche-Silberstein hyperelastic constitutive model.
+impl crate::constitutive::StrainEnergy for PolydisperseBucheSilberstein
+{
+    fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension_strain_energy_density(stretch)
+    }
+    fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension_strain_energy_density(stretch)
+    }
 }
\ No newline at end of file
@@ -1,35 +1,48 @@
 use pyo3::prelude::*;
 use numpy::
 {
-    IntoPyArray,
     PyArrayDyn,
     PyReadonlyArrayDyn
 };
+use crate::constitutive::distribution::py::ChainLengthDistribution;
 use super::
 {
+    Method,
     NUMGRID,
+    Quadrature,
     init
 };
 
 pub fn register_module(_py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
 {
     parent_module.add_class::<BucheSilberstein>()?;
+    parent_module.add_class::<PolydisperseBucheSilberstein>()?;
+    parent_module.add_class::<Method>()?;
+    parent_module.add_class::<Quadrature>()?;
     Ok(())
 }
 
 /// The Buche-Silberstein hyperelastic constitutive model.
 ///
-/// #. Helmholtz method for both the Helmholtz free energy and the equilibrium distribution.
-/// #. Gibbs-Legendre method for both the Helmholtz free energy and the equilibrium distribution.
-/// #. Gibbs-Legendre for the Helmholtz free energy and a Gaussian equilibrium distribution.
+/// The method is one of the members of :class:`Method`.
+/// The grid uses ``number_of_grid_points`` points in each direction, which default to 256, placed using one of the members of :class:`Quadrature`.
 #[pyclass]
 pub struct BucheSilberstein
 {
-    element: [[f64; NUMGRID]; NUMGRID],
+    element: Vec<f64>,
     factor: f64,
-    grid: [f64; NUMGRID],
-    method: u8,
+    grid: Vec<f64>,
+    method: Method,
     normalization: f64,
+    weight: Vec<f64>,
+
+    /// The number of grid points in each direction.
+    #[pyo3(get)]
+    pub number_of_grid_points: usize,
+
+    /// The quadrature rule of the grid.
+    #[pyo3(get)]
+    pub quadrature: Quadrature,
 
     /// The nondimensional stiffness of each link in a chain.
     #[pyo3(get)]
@@ -44,19 +57,50 @@ pub struct BucheSilberstein
 impl BucheSilberstein
 {
     #[new]
-    pub fn init(method: u8, nondimensional_link_stiffness: f64, number_of_links: u8) -> Self
+    #[pyo3(signature = (method, nondimensional_link_stiffness, number_of_links, quadrature = Quadrature::Uniform, number_of_grid_points = NUMGRID))]
+    pub fn init(method: Method, nondimensional_link_stiffness: f64, number_of_links: u8, quadrature: Quadrature, number_of_grid_points: usize) -> PyResult<Self>
     {
-        let (element, factor, grid, normalization) = init(method, nondimensional_link_stiffness, number_of_links);
-        BucheSilberstein
+        super::validate_number_of_grid_points(&number_of_grid_points)?;
+        crate::error::positive("nondimensional_link_stiffness", &nondimensional_link_stiffness)?;
+        crate::error::number_of_links(&number_of_links)?;
+        let (element, factor, grid, weight, normalization) = init(method, quadrature, number_of_grid_points, nondimensional_link_stiffness, number_of_links);
+        Ok(BucheSilberstein
         {
             element,
             factor,
             grid,
             method,
             normalization,
+            weight,
+            number_of_grid_points,
+            quadrature,
             nondimensional_link_stiffness,
             number_of_links
-        }
+        })
+    }
+    /// The nondimensional Cauchy stress in uniaxial tension at the given stretch for each of the given numbers of grid points.
+    ///
+    /// Args:
+    ///     method (Method): The method.
+    ///     quadrature (Quadrature): The quadrature rule of the grid.
+    ///     nondimensional_link_stiffness (float): The nondimensional stiffness of each link in a chain.
+    ///     number_of_links (int): The number of links in a chain.
+    ///     stretch (float): The applied stretch :math:`F_{11}`.
+    ///     numbers_of_grid_points (list): The numbers of grid points in each direction.
+    ///
+    /// Returns:
+    ///     list: Tuples of the number of grid points, the nondimensional Cauchy stress :math:`\beta\sigma_{11}/n`, and its relative change from the previous number of grid points.
+    ///
+    /// Raises:
+    ///     ValueError: If any of the parameters are invalid.
+    ///
+    #[staticmethod]
+    pub fn convergence(method: Method, quadrature: Quadrature, nondimensional_link_stiffness: f64, number_of_links: u8, stretch: f64, numbers_of_grid_points: Vec<usize>) -> PyResult<Vec<(usize, f64, f64)>>
+    {
+        numbers_of_grid_points.iter().try_for_each(super::validate_number_of_grid_points)?;
+        crate::error::positive("nondimensional_link_stiffness", &nondimensional_link_stiffness)?;
+        crate::error::number_of_links(&number_of_links)?;
+        Ok(super::BucheSilberstein::convergence(method, quadrature, nondimensional_link_stiffness, number_of_links, &stretch, &numbers_of_grid_points))
     }
     /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension,
     ///
@@ -71,14 +115,14 @@ impl BucheSilberstein
     /// Returns:
     ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta\sigma_{11}/n`.
     ///
-    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        stretch.as_array().mapv(|stretch: f64|
+        crate::py::batch(py, stretch, |stretch: f64|
             super::uniaxial_tension(
                 &self.element, &self.factor, &self.grid, &self.normalization, &self.method,
                 &self.nondimensional_link_stiffness, &self.number_of_links, &stretch
             )
-        ).into_pyarray(py)
+        )
     }
     /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension,
     ///
@@ -93,13 +137,176 @@ impl BucheSilberstein
     /// Returns:
     ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta\sigma_{11}/n`.
     ///
-    pub fn equibiaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    pub fn equibiaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        stretch.as_array().mapv(|stretch: f64|
+        crate::py::batch(py, stretch, |stretch: f64|
             super::equibiaxial_tension(
                 &self.element, &self.factor, &self.grid, &self.normalization, &self.method,
                 &self.nondimensional_link_stiffness, &self.number_of_links, &stretch
             )
-        ).into_pyarray(py)
+        )
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    ///
+    /// Returns:
+    ///     float: The nondimensional initial shear modulus :math:`\beta G/n`.
+    ///
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::nondimensional_shear_modulus(|stretch|
+            super::uniaxial_tension(
+                &self.element, &self.factor, &self.grid, &self.normalization, &self.method,
+                &self.nondimensional_link_stiffness, &self.number_of_links, stretch
+            )
+        )
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension,
+    ///
+    /// .. math::
+    ///     \beta W/n = 4\pi\int_0^\infty r\,dr\int_0^\infty dz\,\left[P^\mathrm{eq}(\gamma_0) - P^\mathrm{eq}(\gamma)\right]\vartheta(\gamma),
+    ///
+    /// where :math:`\vartheta` is the nondimensional relative Helmholtz free energy of a chain,
+    /// :math:`\gamma=\sqrt{z^2+r^2}`, and :math:`\gamma_0=\sqrt{\left(z/F_{11}\right)^2+F_{11}r^2}`.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    /// 
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn uniaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64|
+            super::uniaxial_tension_strain_energy_density(
+                &self.factor, &self.grid, &self.weight, &self.normalization, &self.method,
+                &self.nondimensional_link_stiffness, &self.number_of_links, &stretch
+            )
+        )
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension,
+    ///
+    /// .. math::
+    ///     \beta W/n = 4\pi\int_0^\infty r\,dr\int_0^\infty dz\,\left[P^\mathrm{eq}(\gamma_0) - P^\mathrm{eq}(\gamma)\right]\vartheta(\gamma),
+    ///
+    /// where :math:`\gamma=\sqrt{z^2+r^2}` and :math:`\gamma_0=\sqrt{\left(F_{11}^2z\right)^2+\left(r/F_{11}\right)^2}`.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    /// 
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn equibiaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64|
+            super::equibiaxial_tension_strain_energy_density(
+                &self.factor, &self.grid, &self.weight, &self.normalization, &self.method,
+                &self.nondimensional_link_stiffness, &self.number_of_links, &stretch
+            )
+        )
+    }
+}
+
+/// The Buche-Silberstein hyperelastic constitutive model of a network whose chains have a distribution of lengths,
+/// where the stress is averaged over the number fractions of chains with each number of links,
+///
+/// .. math::
+///     \beta\sigma_{11}/n = \sum_N x_N\,\beta\sigma_{11}^{(N)}/n,
+///
+/// and :math:`\beta\sigma_{11}^{(N)}/n` is the nondimensional Cauchy stress of :class:`BucheSilberstein` with :math:`N` links in a chain.
+#[pyclass]
+pub struct PolydisperseBucheSilberstein
+{
+    model: super::PolydisperseBucheSilberstein,
+
+    /// The number of grid points in each direction.
+    #[pyo3(get)]
+    pub number_of_grid_points: usize,
+
+    /// The quadrature rule of the grid.
+    #[pyo3(get)]
+    pub quadrature: Quadrature,
+
+    /// The nondimensional stiffness of each link in a chain.
+    #[pyo3(get)]
+    pub nondimensional_link_stiffness: f64,
+
+    /// The distribution of the number of links in a chain.
+    #[pyo3(get)]
+    pub distribution: ChainLengthDistribution
+}
+
+#[pymethods]
+impl PolydisperseBucheSilberstein
+{
+    #[new]
+    #[pyo3(signature = (method, nondimensional_link_stiffness, distribution, quadrature = Quadrature::Uniform, number_of_grid_points = NUMGRID))]
+    pub fn init(method: Method, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution, quadrature: Quadrature, number_of_grid_points: usize) -> PyResult<Self>
+    {
+        Ok(PolydisperseBucheSilberstein
+        {
+            model: super::PolydisperseBucheSilberstein::try_init_with_grid(method, quadrature, number_of_grid_points, nondimensional_link_stiffness, distribution.distribution.clone())?,
+            number_of_grid_points,
+            quadrature,
+            nondimensional_link_stiffness,
+            distribution
+        })
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    /// 
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta\sigma_{11}/n`.
+    ///
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension(&stretch))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    /// 
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta\sigma_{11}/n`.
+    ///
+    pub fn equibiaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension(&stretch))
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    ///
+    /// Returns:
+    ///     float: The nondimensional initial shear modulus :math:`\beta G/n`.
+    ///
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::Hyperelastic::nondimensional_shear_modulus(&self.model)
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    /// 
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn uniaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension_strain_energy_density(&stretch))
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    /// 
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn equibiaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension_strain_energy_density(&stretch))
     }
 }
\ No newline at end of file
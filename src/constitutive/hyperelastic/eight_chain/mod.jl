@@ -0,0 +1,299 @@
+"""
+The Arruda-Boyce eight-chain hyperelastic constitutive model.
+"""
+module EightChain
+
+using DocStringExtensions
+using Polymers_jll
+using ....Polymers: validate_positive
+using ...Hyperelastic: chain_model
+
+"""
+The structure of the Arruda-Boyce eight-chain model.
+
+$(FIELDS)
+"""
+struct EIGHTCHAIN
+    """
+    The single-chain model of each chain in the network.
+    """
+    chain::Any
+    """
+    The temperature ``T`` in units of K.
+    """
+    temperature::Float64
+    """
+    The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{22})/n`` as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+    """
+    uniaxial_tension::Function
+    """
+    The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{33})/n`` as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+    """
+    equibiaxial_tension::Function
+    """
+    The deviatoric nondimensional Cauchy stress ``\\beta\\boldsymbol{\\sigma}/n`` as a function of the deformation gradient ``\\mathbf{F}``.
+    """
+    cauchy_stress::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+    """
+    uniaxial_tension_strain_energy_density::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+    """
+    equibiaxial_tension_strain_energy_density::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the deformation gradient ``\\mathbf{F}``.
+    """
+    strain_energy_density::Function
+end
+
+"""
+Initializes and returns an instance of the Arruda-Boyce eight-chain model using any of the single-chain models.
+
+$(TYPEDSIGNATURES)
+"""
+function EIGHTCHAIN(chain::Any, temperature::Float64)
+    validate_positive("temperature", temperature)
+    parameters = chain_model(chain)
+    return EIGHTCHAIN(
+        chain,
+        temperature,
+        (stretch) -> uniaxial_tension(parameters..., temperature, stretch),
+        (stretch) -> equibiaxial_tension(parameters..., temperature, stretch),
+        (deformation_gradient) ->
+            cauchy_stress(parameters..., temperature, deformation_gradient),
+        (stretch) ->
+            uniaxial_tension_strain_energy_density(parameters..., temperature, stretch),
+        (stretch) ->
+            equibiaxial_tension_strain_energy_density(parameters..., temperature, stretch),
+        (deformation_gradient) ->
+            strain_energy_density(parameters..., temperature, deformation_gradient),
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{22})/n`` of the Arruda-Boyce eight-chain model as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function uniaxial_tension(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_eight_chain_uniaxial_tension,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Float64),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{33})/n`` of the Arruda-Boyce eight-chain model as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function equibiaxial_tension(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_eight_chain_equibiaxial_tension,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Float64),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The deviatoric nondimensional Cauchy stress ``\\beta\\boldsymbol{\\sigma}/n`` of the Arruda-Boyce eight-chain model as a function of the deformation gradient ``\\mathbf{F}``.
+
+$(TYPEDSIGNATURES)
+"""
+function cauchy_stress(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    deformation_gradient::Matrix{Float64},
+)::Matrix{Float64}
+    size(deformation_gradient) == (3, 3) ||
+        throw(DimensionMismatch("the deformation gradient must be three by three"))
+    stress = zeros(9)
+    ccall(
+        (:constitutive_hyperelastic_eight_chain_cauchy_stress, Polymers_jll.libpolymers),
+        Cvoid,
+        (
+            UInt8,
+            UInt8,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Ptr{Float64},
+            Ptr{Float64},
+        ),
+        chain_model,
+        number_of_links,
+        link_length,
+        hinge_mass,
+        model_parameter_1,
+        model_parameter_2,
+        temperature,
+        vec(permutedims(deformation_gradient)),
+        stress,
+    )
+    return permutedims(reshape(stress, 3, 3))
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the Arruda-Boyce eight-chain model as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function uniaxial_tension_strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_eight_chain_uniaxial_tension_strain_energy_density,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Float64),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the Arruda-Boyce eight-chain model as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function equibiaxial_tension_strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_eight_chain_equibiaxial_tension_strain_energy_density,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Float64),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the Arruda-Boyce eight-chain model as a function of the deformation gradient ``\\mathbf{F}``.
+
+$(TYPEDSIGNATURES)
+"""
+function strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    deformation_gradient::Matrix{Float64},
+)::Float64
+    size(deformation_gradient) == (3, 3) ||
+        throw(DimensionMismatch("the deformation gradient must be three by three"))
+    return ccall(
+        (
+            :constitutive_hyperelastic_eight_chain_strain_energy_density,
+            Polymers_jll.libpolymers,
+        ),
+        Float64,
+        (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Ptr{Float64}),
+        chain_model,
+        number_of_links,
+        link_length,
+        hinge_mass,
+        model_parameter_1,
+        model_parameter_2,
+        temperature,
+        vec(permutedims(deformation_gradient)),
+    )
+end
+
+end
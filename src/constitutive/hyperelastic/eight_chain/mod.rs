@@ -0,0 +1,121 @@
+#[cfg(feature = "extern")]
+pub mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::physics::single_chain::ForceLaw;
+use super::network::
+{
+    self,
+    Tensor
+};
+
+/// The Arruda-Boyce eight-chain hyperelastic constitutive model.
+pub struct EightChain<T: ForceLaw>
+{
+    /// The single-chain model of each chain in the network.
+    pub chain: T,
+
+    /// The temperature in units of K.
+    pub temperature: f64
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(EightChain<T: ForceLaw>, try_init, chain: T, temperature: f64);
+
+/// The implemented functionality of the Arruda-Boyce eight-chain hyperelastic constitutive model.
+impl<T: ForceLaw> EightChain<T>
+{
+    /// Initializes and returns an instance of the model using the given single-chain model.
+    pub fn init(chain: T, temperature: f64) -> Self
+    {
+        EightChain
+        {
+            chain,
+            temperature
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(chain: T, temperature: f64) -> Result<Self, Error>
+    {
+        error::positive("temperature", &temperature)?;
+        Ok(Self::init(chain, temperature))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        network::uniaxial_tension(&|deformation_gradient| self.cauchy_stress(deformation_gradient), stretch)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        network::equibiaxial_tension(&|deformation_gradient| self.cauchy_stress(deformation_gradient), stretch)
+    }
+    /// The deviatoric nondimensional Cauchy stress as a function of the deformation gradient, where the chains stretch with the isochoric part of the deformation gradient.
+    pub fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        let (left_cauchy_green, jacobian) = network::left_cauchy_green(deformation_gradient);
+        let chain_stretch = ((left_cauchy_green[0][0] + left_cauchy_green[1][1] + left_cauchy_green[2][2]) / 3.0).sqrt();
+        let mut kirchhoff_stress = [[0.0; 3]; 3];
+        network::add_scaled(&mut kirchhoff_stress, &(network::nondimensional_chain_tension(&self.chain, &self.temperature, &chain_stretch) / chain_stretch / 3.0), &left_cauchy_green);
+        network::deviatoric(&kirchhoff_stress, &jacobian)
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        network::uniaxial_tension_strain_energy_density(&|deformation_gradient| self.strain_energy_density(deformation_gradient), stretch)
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        network::equibiaxial_tension_strain_energy_density(&|deformation_gradient| self.strain_energy_density(deformation_gradient), stretch)
+    }
+    /// The nondimensional strain energy density as a function of the deformation gradient, which is the relative nondimensional Helmholtz free energy of each chain.
+    pub fn strain_energy_density(&self, deformation_gradient: &Tensor) -> f64
+    {
+        let (left_cauchy_green, _) = network::left_cauchy_green(deformation_gradient);
+        let chain_stretch = ((left_cauchy_green[0][0] + left_cauchy_green[1][1] + left_cauchy_green[2][2]) / 3.0).sqrt();
+        network::nondimensional_chain_helmholtz_free_energy(&self.chain, &self.temperature, &chain_stretch)
+    }
+}
+
+/// The deformation modes of the Arruda-Boyce eight-chain hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::Hyperelastic for EightChain<T>
+{
+    fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension(stretch)
+    }
+    fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension(stretch)
+    }
+}
+
+/// The general deformations of the Arruda-Boyce eight-chain hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::CauchyStress for EightChain<T>
+{
+    fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        self.cauchy_stress(deformation_gradient)
+    }
+}
+
+/// The strain energy density of the Arruda-Boyce eight-chain hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::StrainEnergy for EightChain<T>
+{
+    fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension_strain_energy_density(stretch)
+    }
+    fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension_strain_energy_density(stretch)
+    }
+}
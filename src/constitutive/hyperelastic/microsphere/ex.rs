@@ -0,0 +1,93 @@
+use super::super::
+{
+    ChainModel,
+    network::ex::
+    {
+        tensor_from_raw,
+        tensor_into_raw
+    }
+};
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_affine_microsphere_uniaxial_tension(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::AffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).uniaxial_tension(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_affine_microsphere_equibiaxial_tension(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::AffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).equibiaxial_tension(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub unsafe extern fn constitutive_hyperelastic_affine_microsphere_cauchy_stress(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, deformation_gradient: *const f64, cauchy_stress: *mut f64)
+{
+    tensor_into_raw(&ChainModel::try_from(chain_model).map(|chain_model|
+        super::AffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).cauchy_stress(&tensor_from_raw(deformation_gradient))
+    ).unwrap_or([[f64::NAN; 3]; 3]), cauchy_stress)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_nonaffine_microsphere_uniaxial_tension(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::NonaffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, nonaffine_stretch_parameter, tube_geometry_parameter, tube_area_exponent).uniaxial_tension(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_nonaffine_microsphere_equibiaxial_tension(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::NonaffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, nonaffine_stretch_parameter, tube_geometry_parameter, tube_area_exponent).equibiaxial_tension(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub unsafe extern fn constitutive_hyperelastic_nonaffine_microsphere_cauchy_stress(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64, deformation_gradient: *const f64, cauchy_stress: *mut f64)
+{
+    tensor_into_raw(&ChainModel::try_from(chain_model).map(|chain_model|
+        super::NonaffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, nonaffine_stretch_parameter, tube_geometry_parameter, tube_area_exponent).cauchy_stress(&tensor_from_raw(deformation_gradient))
+    ).unwrap_or([[f64::NAN; 3]; 3]), cauchy_stress)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_affine_microsphere_uniaxial_tension_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::AffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).uniaxial_tension_strain_energy_density(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_affine_microsphere_equibiaxial_tension_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::AffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).equibiaxial_tension_strain_energy_density(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub unsafe extern fn constitutive_hyperelastic_affine_microsphere_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, deformation_gradient: *const f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::AffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).strain_energy_density(&tensor_from_raw(deformation_gradient))
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_nonaffine_microsphere_uniaxial_tension_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::NonaffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, nonaffine_stretch_parameter, tube_geometry_parameter, tube_area_exponent).uniaxial_tension_strain_energy_density(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_nonaffine_microsphere_equibiaxial_tension_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::NonaffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, nonaffine_stretch_parameter, tube_geometry_parameter, tube_area_exponent).equibiaxial_tension_strain_energy_density(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub unsafe extern fn constitutive_hyperelastic_nonaffine_microsphere_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64, deformation_gradient: *const f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::NonaffineMicrosphere::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature, nonaffine_stretch_parameter, tube_geometry_parameter, tube_area_exponent).strain_energy_density(&tensor_from_raw(deformation_gradient))
+    ).unwrap_or(f64::NAN)
+}
@@ -0,0 +1,760 @@
+"""
+The affine and non-affine microsphere hyperelastic constitutive models.
+"""
+module Microsphere
+
+using DocStringExtensions
+using Polymers_jll
+using ....Polymers: validate_nonnegative, validate_positive
+using ...Hyperelastic: chain_model
+
+"""
+The structure of the affine microsphere model.
+
+$(FIELDS)
+"""
+struct AFFINEMICROSPHERE
+    """
+    The single-chain model of each chain in the network.
+    """
+    chain::Any
+    """
+    The temperature ``T`` in units of K.
+    """
+    temperature::Float64
+    """
+    The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{22})/n`` as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+    """
+    uniaxial_tension::Function
+    """
+    The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{33})/n`` as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+    """
+    equibiaxial_tension::Function
+    """
+    The deviatoric nondimensional Cauchy stress ``\\beta\\boldsymbol{\\sigma}/n`` as a function of the deformation gradient ``\\mathbf{F}``.
+    """
+    cauchy_stress::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+    """
+    uniaxial_tension_strain_energy_density::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+    """
+    equibiaxial_tension_strain_energy_density::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the deformation gradient ``\\mathbf{F}``.
+    """
+    strain_energy_density::Function
+end
+
+"""
+Initializes and returns an instance of the affine microsphere model using any of the single-chain models.
+
+$(TYPEDSIGNATURES)
+"""
+function AFFINEMICROSPHERE(chain::Any, temperature::Float64)
+    validate_positive("temperature", temperature)
+    parameters = chain_model(chain)
+    return AFFINEMICROSPHERE(
+        chain,
+        temperature,
+        (stretch) -> affine_uniaxial_tension(parameters..., temperature, stretch),
+        (stretch) -> affine_equibiaxial_tension(parameters..., temperature, stretch),
+        (deformation_gradient) ->
+            affine_cauchy_stress(parameters..., temperature, deformation_gradient),
+        (stretch) -> affine_uniaxial_tension_strain_energy_density(
+            parameters...,
+            temperature,
+            stretch,
+        ),
+        (stretch) -> affine_equibiaxial_tension_strain_energy_density(
+            parameters...,
+            temperature,
+            stretch,
+        ),
+        (deformation_gradient) ->
+            affine_strain_energy_density(parameters..., temperature, deformation_gradient),
+    )
+end
+
+"""
+The structure of the non-affine microsphere model.
+
+$(FIELDS)
+"""
+struct NONAFFINEMICROSPHERE
+    """
+    The single-chain model of each chain in the network.
+    """
+    chain::Any
+    """
+    The temperature ``T`` in units of K.
+    """
+    temperature::Float64
+    """
+    The exponent ``p`` of the p-root average of the microsphere stretches.
+    """
+    nonaffine_stretch_parameter::Float64
+    """
+    The nondimensional tube geometry parameter ``U``, which scales the tube constraint.
+    """
+    tube_geometry_parameter::Float64
+    """
+    The exponent ``q`` of the microsphere area stretches in the tube constraint.
+    """
+    tube_area_exponent::Float64
+    """
+    The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{22})/n`` as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+    """
+    uniaxial_tension::Function
+    """
+    The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{33})/n`` as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+    """
+    equibiaxial_tension::Function
+    """
+    The deviatoric nondimensional Cauchy stress ``\\beta\\boldsymbol{\\sigma}/n`` as a function of the deformation gradient ``\\mathbf{F}``.
+    """
+    cauchy_stress::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+    """
+    uniaxial_tension_strain_energy_density::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+    """
+    equibiaxial_tension_strain_energy_density::Function
+    """
+    The nondimensional strain energy density ``\\beta W/n`` as a function of the deformation gradient ``\\mathbf{F}``.
+    """
+    strain_energy_density::Function
+end
+
+"""
+Initializes and returns an instance of the non-affine microsphere model using any of the single-chain models.
+
+$(TYPEDSIGNATURES)
+"""
+function NONAFFINEMICROSPHERE(
+    chain::Any,
+    temperature::Float64,
+    nonaffine_stretch_parameter::Float64,
+    tube_geometry_parameter::Float64,
+    tube_area_exponent::Float64,
+)
+    validate_positive("temperature", temperature)
+    validate_positive("nonaffine_stretch_parameter", nonaffine_stretch_parameter)
+    validate_nonnegative("tube_geometry_parameter", tube_geometry_parameter)
+    validate_positive("tube_area_exponent", tube_area_exponent)
+    parameters = chain_model(chain)
+    return NONAFFINEMICROSPHERE(
+        chain,
+        temperature,
+        nonaffine_stretch_parameter,
+        tube_geometry_parameter,
+        tube_area_exponent,
+        (stretch) -> nonaffine_uniaxial_tension(
+            parameters...,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            stretch,
+        ),
+        (stretch) -> nonaffine_equibiaxial_tension(
+            parameters...,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            stretch,
+        ),
+        (deformation_gradient) -> nonaffine_cauchy_stress(
+            parameters...,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            deformation_gradient,
+        ),
+        (stretch) -> nonaffine_uniaxial_tension_strain_energy_density(
+            parameters...,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            stretch,
+        ),
+        (stretch) -> nonaffine_equibiaxial_tension_strain_energy_density(
+            parameters...,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            stretch,
+        ),
+        (deformation_gradient) -> nonaffine_strain_energy_density(
+            parameters...,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            deformation_gradient,
+        ),
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{22})/n`` of the affine microsphere model as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function affine_uniaxial_tension(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_affine_microsphere_uniaxial_tension,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Float64),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{33})/n`` of the affine microsphere model as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function affine_equibiaxial_tension(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_affine_microsphere_equibiaxial_tension,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Float64),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The deviatoric nondimensional Cauchy stress ``\\beta\\boldsymbol{\\sigma}/n`` of the affine microsphere model as a function of the deformation gradient ``\\mathbf{F}``.
+
+$(TYPEDSIGNATURES)
+"""
+function affine_cauchy_stress(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    deformation_gradient::Matrix{Float64},
+)::Matrix{Float64}
+    size(deformation_gradient) == (3, 3) ||
+        throw(DimensionMismatch("the deformation gradient must be three by three"))
+    stress = zeros(9)
+    ccall(
+        (
+            :constitutive_hyperelastic_affine_microsphere_cauchy_stress,
+            Polymers_jll.libpolymers,
+        ),
+        Cvoid,
+        (
+            UInt8,
+            UInt8,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Ptr{Float64},
+            Ptr{Float64},
+        ),
+        chain_model,
+        number_of_links,
+        link_length,
+        hinge_mass,
+        model_parameter_1,
+        model_parameter_2,
+        temperature,
+        vec(permutedims(deformation_gradient)),
+        stress,
+    )
+    return permutedims(reshape(stress, 3, 3))
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the affine microsphere model as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function affine_uniaxial_tension_strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_affine_microsphere_uniaxial_tension_strain_energy_density,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Float64),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the affine microsphere model as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function affine_equibiaxial_tension_strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_affine_microsphere_equibiaxial_tension_strain_energy_density,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Float64),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the affine microsphere model as a function of the deformation gradient ``\\mathbf{F}``.
+
+$(TYPEDSIGNATURES)
+"""
+function affine_strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    deformation_gradient::Matrix{Float64},
+)::Float64
+    size(deformation_gradient) == (3, 3) ||
+        throw(DimensionMismatch("the deformation gradient must be three by three"))
+    return ccall(
+        (
+            :constitutive_hyperelastic_affine_microsphere_strain_energy_density,
+            Polymers_jll.libpolymers,
+        ),
+        Float64,
+        (UInt8, UInt8, Float64, Float64, Float64, Float64, Float64, Ptr{Float64}),
+        chain_model,
+        number_of_links,
+        link_length,
+        hinge_mass,
+        model_parameter_1,
+        model_parameter_2,
+        temperature,
+        vec(permutedims(deformation_gradient)),
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{22})/n`` of the non-affine microsphere model as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function nonaffine_uniaxial_tension(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    nonaffine_stretch_parameter::Float64,
+    tube_geometry_parameter::Float64,
+    tube_area_exponent::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_nonaffine_microsphere_uniaxial_tension,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (
+                UInt8,
+                UInt8,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+            ),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional Cauchy stress ``\\beta(\\sigma_{11}-\\sigma_{33})/n`` of the non-affine microsphere model as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function nonaffine_equibiaxial_tension(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    nonaffine_stretch_parameter::Float64,
+    tube_geometry_parameter::Float64,
+    tube_area_exponent::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_nonaffine_microsphere_equibiaxial_tension,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (
+                UInt8,
+                UInt8,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+            ),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The deviatoric nondimensional Cauchy stress ``\\beta\\boldsymbol{\\sigma}/n`` of the non-affine microsphere model as a function of the deformation gradient ``\\mathbf{F}``.
+
+$(TYPEDSIGNATURES)
+"""
+function nonaffine_cauchy_stress(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    nonaffine_stretch_parameter::Float64,
+    tube_geometry_parameter::Float64,
+    tube_area_exponent::Float64,
+    deformation_gradient::Matrix{Float64},
+)::Matrix{Float64}
+    size(deformation_gradient) == (3, 3) ||
+        throw(DimensionMismatch("the deformation gradient must be three by three"))
+    stress = zeros(9)
+    ccall(
+        (
+            :constitutive_hyperelastic_nonaffine_microsphere_cauchy_stress,
+            Polymers_jll.libpolymers,
+        ),
+        Cvoid,
+        (
+            UInt8,
+            UInt8,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Ptr{Float64},
+            Ptr{Float64},
+        ),
+        chain_model,
+        number_of_links,
+        link_length,
+        hinge_mass,
+        model_parameter_1,
+        model_parameter_2,
+        temperature,
+        nonaffine_stretch_parameter,
+        tube_geometry_parameter,
+        tube_area_exponent,
+        vec(permutedims(deformation_gradient)),
+        stress,
+    )
+    return permutedims(reshape(stress, 3, 3))
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the non-affine microsphere model as a function of the applied stretch ``F_{11}`` in uniaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function nonaffine_uniaxial_tension_strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    nonaffine_stretch_parameter::Float64,
+    tube_geometry_parameter::Float64,
+    tube_area_exponent::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_nonaffine_microsphere_uniaxial_tension_strain_energy_density,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (
+                UInt8,
+                UInt8,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+            ),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the non-affine microsphere model as a function of the applied stretch ``F_{11}`` in equibiaxial tension.
+
+$(TYPEDSIGNATURES)
+"""
+function nonaffine_equibiaxial_tension_strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    nonaffine_stretch_parameter::Float64,
+    tube_geometry_parameter::Float64,
+    tube_area_exponent::Float64,
+    stretch::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        stretch_i -> ccall(
+            (
+                :constitutive_hyperelastic_nonaffine_microsphere_equibiaxial_tension_strain_energy_density,
+                Polymers_jll.libpolymers,
+            ),
+            Float64,
+            (
+                UInt8,
+                UInt8,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+                Float64,
+            ),
+            chain_model,
+            number_of_links,
+            link_length,
+            hinge_mass,
+            model_parameter_1,
+            model_parameter_2,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent,
+            stretch_i,
+        ),
+        stretch,
+    )
+end
+
+"""
+The nondimensional strain energy density ``\\beta W/n`` of the non-affine microsphere model as a function of the deformation gradient ``\\mathbf{F}``.
+
+$(TYPEDSIGNATURES)
+"""
+function nonaffine_strain_energy_density(
+    chain_model::UInt8,
+    number_of_links::UInt8,
+    link_length::Float64,
+    hinge_mass::Float64,
+    model_parameter_1::Float64,
+    model_parameter_2::Float64,
+    temperature::Float64,
+    nonaffine_stretch_parameter::Float64,
+    tube_geometry_parameter::Float64,
+    tube_area_exponent::Float64,
+    deformation_gradient::Matrix{Float64},
+)::Float64
+    size(deformation_gradient) == (3, 3) ||
+        throw(DimensionMismatch("the deformation gradient must be three by three"))
+    return ccall(
+        (
+            :constitutive_hyperelastic_nonaffine_microsphere_strain_energy_density,
+            Polymers_jll.libpolymers,
+        ),
+        Float64,
+        (
+            UInt8,
+            UInt8,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Float64,
+            Ptr{Float64},
+        ),
+        chain_model,
+        number_of_links,
+        link_length,
+        hinge_mass,
+        model_parameter_1,
+        model_parameter_2,
+        temperature,
+        nonaffine_stretch_parameter,
+        tube_geometry_parameter,
+        tube_area_exponent,
+        vec(permutedims(deformation_gradient)),
+    )
+end
+
+end
@@ -0,0 +1,267 @@
+#[cfg(feature = "extern")]
+pub mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::physics::single_chain::ForceLaw;
+use super::network::
+{
+    self,
+    SPHERE,
+    Tensor
+};
+
+/// The affine microsphere hyperelastic constitutive model.
+pub struct AffineMicrosphere<T: ForceLaw>
+{
+    /// The single-chain model of each chain in the network.
+    pub chain: T,
+
+    /// The temperature in units of K.
+    pub temperature: f64
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(AffineMicrosphere<T: ForceLaw>, try_init, chain: T, temperature: f64);
+
+/// The implemented functionality of the affine microsphere hyperelastic constitutive model.
+impl<T: ForceLaw> AffineMicrosphere<T>
+{
+    /// Initializes and returns an instance of the model using the given single-chain model.
+    pub fn init(chain: T, temperature: f64) -> Self
+    {
+        AffineMicrosphere
+        {
+            chain,
+            temperature
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(chain: T, temperature: f64) -> Result<Self, Error>
+    {
+        error::positive("temperature", &temperature)?;
+        Ok(Self::init(chain, temperature))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        network::uniaxial_tension(&|deformation_gradient| self.cauchy_stress(deformation_gradient), stretch)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        network::equibiaxial_tension(&|deformation_gradient| self.cauchy_stress(deformation_gradient), stretch)
+    }
+    /// The deviatoric nondimensional Cauchy stress as a function of the deformation gradient, where the chains in each direction of the microsphere stretch affinely with the isochoric part of the deformation gradient.
+    pub fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        let (isochoric_deformation_gradient, jacobian) = network::isochoric(deformation_gradient);
+        let mut kirchhoff_stress = [[0.0; 3]; 3];
+        SPHERE.iter().for_each(|(direction, weight)|{
+            let stretched_direction = network::dot(&isochoric_deformation_gradient, direction);
+            let chain_stretch = network::norm(&stretched_direction);
+            network::add_scaled(&mut kirchhoff_stress, &(weight * network::nondimensional_chain_tension(&self.chain, &self.temperature, &chain_stretch) / chain_stretch), &network::outer(&stretched_direction, &stretched_direction));
+        });
+        network::deviatoric(&kirchhoff_stress, &jacobian)
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        network::uniaxial_tension_strain_energy_density(&|deformation_gradient| self.strain_energy_density(deformation_gradient), stretch)
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        network::equibiaxial_tension_strain_energy_density(&|deformation_gradient| self.strain_energy_density(deformation_gradient), stretch)
+    }
+    /// The nondimensional strain energy density as a function of the deformation gradient, which is the average relative nondimensional Helmholtz free energy of the chains over the microsphere.
+    pub fn strain_energy_density(&self, deformation_gradient: &Tensor) -> f64
+    {
+        let (isochoric_deformation_gradient, _) = network::isochoric(deformation_gradient);
+        SPHERE.iter().map(|(direction, weight)|
+            weight * network::nondimensional_chain_helmholtz_free_energy(&self.chain, &self.temperature, &network::norm(&network::dot(&isochoric_deformation_gradient, direction)))
+        ).sum()
+    }
+}
+
+/// The non-affine microsphere hyperelastic constitutive model of Miehe, Göktepe, and Lulei,
+/// where the chains stretch with the p-root average of the microsphere stretches and are constrained by tubes.
+pub struct NonaffineMicrosphere<T: ForceLaw>
+{
+    /// The single-chain model of each chain in the network.
+    pub chain: T,
+
+    /// The temperature in units of K.
+    pub temperature: f64,
+
+    /// The exponent of the p-root average of the microsphere stretches.
+    pub nonaffine_stretch_parameter: f64,
+
+    /// The nondimensional tube geometry parameter, which scales the tube constraint.
+    pub tube_geometry_parameter: f64,
+
+    /// The exponent of the microsphere area stretches in the tube constraint.
+    pub tube_area_exponent: f64
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(NonaffineMicrosphere<T: ForceLaw>, try_init, chain: T, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64);
+
+/// The implemented functionality of the non-affine microsphere hyperelastic constitutive model.
+impl<T: ForceLaw> NonaffineMicrosphere<T>
+{
+    /// Initializes and returns an instance of the model using the given single-chain model.
+    pub fn init(chain: T, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64) -> Self
+    {
+        NonaffineMicrosphere
+        {
+            chain,
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(chain: T, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64) -> Result<Self, Error>
+    {
+        error::positive("temperature", &temperature)?;
+        error::positive("nonaffine_stretch_parameter", &nonaffine_stretch_parameter)?;
+        error::nonnegative("tube_geometry_parameter", &tube_geometry_parameter)?;
+        error::positive("tube_area_exponent", &tube_area_exponent)?;
+        Ok(Self::init(chain, temperature, nonaffine_stretch_parameter, tube_geometry_parameter, tube_area_exponent))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        network::uniaxial_tension(&|deformation_gradient| self.cauchy_stress(deformation_gradient), stretch)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        network::equibiaxial_tension(&|deformation_gradient| self.cauchy_stress(deformation_gradient), stretch)
+    }
+    /// The deviatoric nondimensional Cauchy stress as a function of the deformation gradient, where the microsphere deforms with the isochoric part of the deformation gradient.
+    pub fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        let (isochoric_deformation_gradient, jacobian) = network::isochoric(deformation_gradient);
+        let isochoric_cofactor = network::inverse_transpose(&isochoric_deformation_gradient);
+        let p = self.nonaffine_stretch_parameter;
+        let q = self.tube_area_exponent;
+        let stretched_directions = SPHERE.map(|(direction, _)| network::dot(&isochoric_deformation_gradient, &direction));
+        let chain_stretch = SPHERE.iter().zip(stretched_directions.iter()).map(|((_, weight), stretched_direction)|
+            weight * network::norm(stretched_direction).powf(p)
+        ).sum::<f64>().powf(1.0 / p);
+        let scale = network::nondimensional_chain_tension(&self.chain, &self.temperature, &chain_stretch) * chain_stretch.powf(1.0 - p);
+        let tube_scale = -self.tube_geometry_parameter * (self.chain.number_of_links() as f64) * q;
+        let mut kirchhoff_stress = [[0.0; 3]; 3];
+        SPHERE.iter().zip(stretched_directions.iter()).for_each(|((direction, weight), stretched_direction)|{
+            network::add_scaled(&mut kirchhoff_stress, &(weight * scale * network::norm(stretched_direction).powf(p - 2.0)), &network::outer(stretched_direction, stretched_direction));
+            let normal = network::dot(&isochoric_cofactor, direction);
+            network::add_scaled(&mut kirchhoff_stress, &(weight * tube_scale * network::norm(&normal).powf(q - 2.0)), &network::outer(&normal, &normal));
+        });
+        network::deviatoric(&kirchhoff_stress, &jacobian)
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        network::uniaxial_tension_strain_energy_density(&|deformation_gradient| self.strain_energy_density(deformation_gradient), stretch)
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        network::equibiaxial_tension_strain_energy_density(&|deformation_gradient| self.strain_energy_density(deformation_gradient), stretch)
+    }
+    /// The nondimensional strain energy density as a function of the deformation gradient, which is the relative nondimensional Helmholtz free energy of a chain at the p-root average stretch along with the energy of the tube constraint.
+    pub fn strain_energy_density(&self, deformation_gradient: &Tensor) -> f64
+    {
+        let (isochoric_deformation_gradient, _) = network::isochoric(deformation_gradient);
+        let isochoric_cofactor = network::inverse_transpose(&isochoric_deformation_gradient);
+        let p = self.nonaffine_stretch_parameter;
+        let q = self.tube_area_exponent;
+        let chain_stretch = SPHERE.iter().map(|(direction, weight)|
+            weight * network::norm(&network::dot(&isochoric_deformation_gradient, direction)).powf(p)
+        ).sum::<f64>().powf(1.0 / p);
+        let area_stretch = SPHERE.iter().map(|(direction, weight)|
+            weight * network::norm(&network::dot(&isochoric_cofactor, direction)).powf(q)
+        ).sum::<f64>();
+        network::nondimensional_chain_helmholtz_free_energy(&self.chain, &self.temperature, &chain_stretch)
+            + self.tube_geometry_parameter * (self.chain.number_of_links() as f64) * (area_stretch - 1.0)
+    }
+}
+
+/// The deformation modes of the affine microsphere hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::Hyperelastic for AffineMicrosphere<T>
+{
+    fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension(stretch)
+    }
+    fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension(stretch)
+    }
+}
+
+/// The general deformations of the affine microsphere hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::CauchyStress for AffineMicrosphere<T>
+{
+    fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        self.cauchy_stress(deformation_gradient)
+    }
+}
+
+/// The deformation modes of the non-affine microsphere hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::Hyperelastic for NonaffineMicrosphere<T>
+{
+    fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension(stretch)
+    }
+    fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension(stretch)
+    }
+}
+
+/// The general deformations of the non-affine microsphere hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::CauchyStress for NonaffineMicrosphere<T>
+{
+    fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        self.cauchy_stress(deformation_gradient)
+    }
+}
+
+/// The strain energy density of the affine microsphere hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::StrainEnergy for AffineMicrosphere<T>
+{
+    fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension_strain_energy_density(stretch)
+    }
+    fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension_strain_energy_density(stretch)
+    }
+}
+
+/// The strain energy density of the non-affine microsphere hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::StrainEnergy for NonaffineMicrosphere<T>
+{
+    fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension_strain_energy_density(stretch)
+    }
+    fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension_strain_energy_density(stretch)
+    }
+}
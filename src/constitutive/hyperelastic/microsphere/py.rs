@@ -0,0 +1,300 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    PyArrayDyn,
+    PyReadonlyArrayDyn
+};
+use crate::physics::single_chain::ForceLaw;
+use super::super::network::py::
+{
+    force_law,
+    scalar_batch,
+    tensor_batch
+};
+
+pub fn register_module(_py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    parent_module.add_class::<AffineMicrosphere>()?;
+    parent_module.add_class::<NonaffineMicrosphere>()?;
+    Ok(())
+}
+
+/// The affine microsphere hyperelastic constitutive model,
+///
+/// .. math::
+///     \beta\boldsymbol{\sigma}/n = \frac{1}{J}\,\mathrm{dev}\left\langle\frac{1}{\lambda}\,\frac{\partial\vartheta}{\partial\lambda}\,\mathbf{t}\otimes\mathbf{t}\right\rangle,
+///
+/// where :math:`\mathbf{t}=\bar{\mathbf{F}}\cdot\mathbf{r}` and :math:`\lambda=|\mathbf{t}|` for each direction :math:`\mathbf{r}` of the 21-point microsphere.
+///
+/// Args:
+///     chain: Any of the single-chain models in :mod:`polymers.physics.single_chain`, such as :class:`polymers.physics.single_chain.fjc.FJC`.
+///     temperature (float): The temperature :math:`T`.
+///
+/// Raises:
+///     TypeError: If the chain is not one of the single-chain models.
+///     ValueError: If any of the parameters are invalid.
+///
+#[pyclass]
+pub struct AffineMicrosphere
+{
+    model: super::AffineMicrosphere<Box<dyn ForceLaw + Send + Sync>>,
+
+    /// The single-chain model of each chain in the network.
+    #[pyo3(get)]
+    pub chain: PyObject,
+
+    /// The temperature in units of K.
+    #[pyo3(get)]
+    pub temperature: f64
+}
+
+#[pymethods]
+impl AffineMicrosphere
+{
+    #[new]
+    pub fn init(chain: &PyAny, temperature: f64) -> PyResult<Self>
+    {
+        Ok(AffineMicrosphere
+        {
+            model: super::AffineMicrosphere::try_init(force_law(chain)?, temperature)?,
+            chain: chain.into(),
+            temperature
+        })
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta(\sigma_{11}-\sigma_{22})/n`.
+    ///
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension(&stretch))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}=F_{22}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta(\sigma_{11}-\sigma_{33})/n`.
+    ///
+    pub fn equibiaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension(&stretch))
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    ///
+    /// Returns:
+    ///     float: The nondimensional initial shear modulus :math:`\beta G/n`.
+    ///
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::Hyperelastic::nondimensional_shear_modulus(&self.model)
+    }
+    /// The deviatoric nondimensional Cauchy stress as a function of the deformation gradient.
+    ///
+    /// Args:
+    ///     deformation_gradient (numpy.ndarray): The deformation gradient :math:`\mathbf{F}`, with trailing dimensions of three by three.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The deviatoric nondimensional Cauchy stress :math:`\beta\boldsymbol{\sigma}/n`.
+    ///
+    /// Raises:
+    ///     ValueError: If the trailing dimensions are not three by three.
+    ///
+    pub fn cauchy_stress<'py>(&self, py: Python<'py>, deformation_gradient: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        tensor_batch(py, deformation_gradient, |deformation_gradient| self.model.cauchy_stress(deformation_gradient))
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn uniaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension_strain_energy_density(&stretch))
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}=F_{22}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn equibiaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension_strain_energy_density(&stretch))
+    }
+    /// The nondimensional strain energy density as a function of the deformation gradient.
+    ///
+    /// Args:
+    ///     deformation_gradient (numpy.ndarray): The deformation gradient :math:`\mathbf{F}`, with trailing dimensions of three by three.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`, with the trailing dimensions removed.
+    ///
+    /// Raises:
+    ///     ValueError: If the trailing dimensions are not three by three.
+    ///
+    pub fn strain_energy_density<'py>(&self, py: Python<'py>, deformation_gradient: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        scalar_batch(py, deformation_gradient, |deformation_gradient| self.model.strain_energy_density(deformation_gradient))
+    }
+}
+
+/// The non-affine microsphere hyperelastic constitutive model of Miehe, Göktepe, and Lulei,
+///
+/// .. math::
+///     \beta\boldsymbol{\sigma}/n = \frac{1}{J}\,\mathrm{dev}\left\langle\frac{\partial\vartheta}{\partial\lambda}\,\lambda^{1-p}|\mathbf{t}|^{p-2}\,\mathbf{t}\otimes\mathbf{t} - UNq|\boldsymbol{\nu}|^{q-2}\,\boldsymbol{\nu}\otimes\boldsymbol{\nu}\right\rangle,
+///
+/// where :math:`\mathbf{t}=\bar{\mathbf{F}}\cdot\mathbf{r}`, :math:`\boldsymbol{\nu}=\bar{\mathbf{F}}^{-T}\cdot\mathbf{r}`, and :math:`\lambda=\langle|\mathbf{t}|^p\rangle^{1/p}` for each direction :math:`\mathbf{r}` of the 21-point microsphere.
+///
+/// Args:
+///     chain: Any of the single-chain models in :mod:`polymers.physics.single_chain`, such as :class:`polymers.physics.single_chain.fjc.FJC`.
+///     temperature (float): The temperature :math:`T`.
+///     nonaffine_stretch_parameter (float): The exponent :math:`p` of the p-root average of the microsphere stretches.
+///     tube_geometry_parameter (float): The nondimensional tube geometry parameter :math:`U`, which scales the tube constraint.
+///     tube_area_exponent (float): The exponent :math:`q` of the microsphere area stretches in the tube constraint.
+///
+/// Raises:
+///     TypeError: If the chain is not one of the single-chain models.
+///     ValueError: If any of the parameters are invalid.
+///
+#[pyclass]
+pub struct NonaffineMicrosphere
+{
+    model: super::NonaffineMicrosphere<Box<dyn ForceLaw + Send + Sync>>,
+
+    /// The single-chain model of each chain in the network.
+    #[pyo3(get)]
+    pub chain: PyObject,
+
+    /// The temperature in units of K.
+    #[pyo3(get)]
+    pub temperature: f64,
+
+    /// The exponent :math:`p` of the p-root average of the microsphere stretches.
+    #[pyo3(get)]
+    pub nonaffine_stretch_parameter: f64,
+
+    /// The nondimensional tube geometry parameter :math:`U`, which scales the tube constraint.
+    #[pyo3(get)]
+    pub tube_geometry_parameter: f64,
+
+    /// The exponent :math:`q` of the microsphere area stretches in the tube constraint.
+    #[pyo3(get)]
+    pub tube_area_exponent: f64
+}
+
+#[pymethods]
+impl NonaffineMicrosphere
+{
+    #[new]
+    pub fn init(chain: &PyAny, temperature: f64, nonaffine_stretch_parameter: f64, tube_geometry_parameter: f64, tube_area_exponent: f64) -> PyResult<Self>
+    {
+        Ok(NonaffineMicrosphere
+        {
+            model: super::NonaffineMicrosphere::try_init(force_law(chain)?, temperature, nonaffine_stretch_parameter, tube_geometry_parameter, tube_area_exponent)?,
+            chain: chain.into(),
+            temperature,
+            nonaffine_stretch_parameter,
+            tube_geometry_parameter,
+            tube_area_exponent
+        })
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta(\sigma_{11}-\sigma_{22})/n`.
+    ///
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension(&stretch))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}=F_{22}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta(\sigma_{11}-\sigma_{33})/n`.
+    ///
+    pub fn equibiaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension(&stretch))
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    ///
+    /// Returns:
+    ///     float: The nondimensional initial shear modulus :math:`\beta G/n`.
+    ///
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::Hyperelastic::nondimensional_shear_modulus(&self.model)
+    }
+    /// The deviatoric nondimensional Cauchy stress as a function of the deformation gradient.
+    ///
+    /// Args:
+    ///     deformation_gradient (numpy.ndarray): The deformation gradient :math:`\mathbf{F}`, with trailing dimensions of three by three.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The deviatoric nondimensional Cauchy stress :math:`\beta\boldsymbol{\sigma}/n`.
+    ///
+    /// Raises:
+    ///     ValueError: If the trailing dimensions are not three by three.
+    ///
+    pub fn cauchy_stress<'py>(&self, py: Python<'py>, deformation_gradient: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        tensor_batch(py, deformation_gradient, |deformation_gradient| self.model.cauchy_stress(deformation_gradient))
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn uniaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension_strain_energy_density(&stretch))
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}=F_{22}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn equibiaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension_strain_energy_density(&stretch))
+    }
+    /// The nondimensional strain energy density as a function of the deformation gradient.
+    ///
+    /// Args:
+    ///     deformation_gradient (numpy.ndarray): The deformation gradient :math:`\mathbf{F}`, with trailing dimensions of three by three.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`, with the trailing dimensions removed.
+    ///
+    /// Raises:
+    ///     ValueError: If the trailing dimensions are not three by three.
+    ///
+    pub fn strain_energy_density<'py>(&self, py: Python<'py>, deformation_gradient: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        scalar_batch(py, deformation_gradient, |deformation_gradient| self.model.strain_energy_density(deformation_gradient))
+    }
+}
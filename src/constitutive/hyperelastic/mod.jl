@@ -0,0 +1,76 @@
+"""
+Hyperelastic constitutive models for polymer networks.
+"""
+module Hyperelastic
+
+using ...Polymers.Physics.SingleChain.Ideal: IDEAL
+using ...Polymers.Physics.SingleChain.Fjc: FJC
+using ...Polymers.Physics.SingleChain.Efjc: EFJC
+using ...Polymers.Physics.SingleChain.Swfjc: SWFJC
+using ...Polymers.Physics.SingleChain.Wlc: WLC
+using ...Polymers.Physics.SingleChain.Ufjc.LennardJones: LENNARDJONESFJC
+using ...Polymers.Physics.SingleChain.Ufjc.LogSquared: LOGSQUAREDFJC
+using ...Polymers.Physics.SingleChain.Ufjc.Morse: MORSEFJC
+
+"""
+The code and parameters of a single-chain model passed to the network constitutive models,
+which are the number of links, link length, hinge mass, and up to two further model parameters.
+"""
+chain_model(chain::IDEAL) =
+    (UInt8(1), chain.number_of_links, chain.link_length, chain.hinge_mass, 0.0, 0.0)
+chain_model(chain::FJC) =
+    (UInt8(2), chain.number_of_links, chain.link_length, chain.hinge_mass, 0.0, 0.0)
+chain_model(chain::EFJC) = (
+    UInt8(3),
+    chain.number_of_links,
+    chain.link_length,
+    chain.hinge_mass,
+    chain.link_stiffness,
+    0.0,
+)
+chain_model(chain::SWFJC) = (
+    UInt8(4),
+    chain.number_of_links,
+    chain.link_length,
+    chain.hinge_mass,
+    chain.well_width,
+    0.0,
+)
+chain_model(chain::WLC) = (
+    UInt8(5),
+    chain.number_of_links,
+    chain.link_length,
+    chain.hinge_mass,
+    chain.persistance_length,
+    0.0,
+)
+chain_model(chain::LENNARDJONESFJC) = (
+    UInt8(6),
+    chain.number_of_links,
+    chain.link_length,
+    chain.hinge_mass,
+    chain.link_stiffness,
+    0.0,
+)
+chain_model(chain::LOGSQUAREDFJC) = (
+    UInt8(7),
+    chain.number_of_links,
+    chain.link_length,
+    chain.hinge_mass,
+    chain.link_stiffness,
+    0.0,
+)
+chain_model(chain::MORSEFJC) = (
+    UInt8(8),
+    chain.number_of_links,
+    chain.link_length,
+    chain.hinge_mass,
+    chain.link_stiffness,
+    chain.link_energy,
+)
+
+include("eight_chain/mod.jl")
+include("three_chain/mod.jl")
+include("microsphere/mod.jl")
+
+end
@@ -1,6 +1,16 @@
 #[cfg(feature = "python")]
 pub mod py;
 
+mod test;
+
 mod buche_silberstein;
+mod eight_chain;
+mod microsphere;
+pub(crate) mod network;
+mod three_chain;
 
-pub use buche_silberstein::BucheSilberstein;
\ No newline at end of file
+pub use buche_silberstein::{BucheSilberstein, Method, PolydisperseBucheSilberstein, Quadrature};
+pub use eight_chain::EightChain;
+pub use microsphere::{AffineMicrosphere, NonaffineMicrosphere};
+pub use network::{ChainModel, Tensor};
+pub use three_chain::ThreeChain;
\ No newline at end of file
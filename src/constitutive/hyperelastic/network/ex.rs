@@ -0,0 +1,28 @@
+use super::Tensor;
+
+/// Reads a tensor from nine entries in row-major order.
+///
+/// # Safety
+///
+/// The pointer must be valid for reading nine entries.
+pub unsafe fn tensor_from_raw(raw_tensor: *const f64) -> Tensor
+{
+    let entries = std::slice::from_raw_parts(raw_tensor, 9);
+    [
+        [entries[0], entries[1], entries[2]],
+        [entries[3], entries[4], entries[5]],
+        [entries[6], entries[7], entries[8]]
+    ]
+}
+
+/// Writes a tensor into nine entries in row-major order.
+///
+/// # Safety
+///
+/// The pointer must be valid for writing nine entries.
+pub unsafe fn tensor_into_raw(tensor: &Tensor, raw_tensor: *mut f64)
+{
+    std::slice::from_raw_parts_mut(raw_tensor, 9).iter_mut().zip(tensor.iter().flatten()).for_each(|(entry, value)|
+        *entry = *value
+    );
+}
@@ -0,0 +1,371 @@
+#[cfg(feature = "extern")]
+pub mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+use crate::error::Error;
+use crate::physics::single_chain::
+{
+    ForceLaw,
+    efjc::EFJC,
+    fjc::FJC,
+    ideal::Ideal,
+    swfjc::SWFJC,
+    ufjc::
+    {
+        lennard_jones::LENNARDJONESFJC,
+        log_squared::LOGSQUAREDFJC,
+        morse::MORSEFJC
+    },
+    wlc::WLC
+};
+use std::f64::consts::FRAC_1_SQRT_2;
+
+/// A second-order tensor in three dimensions.
+pub type Tensor = [[f64; 3]; 3];
+
+/// The identity tensor.
+pub const IDENTITY: Tensor = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// The directions and weights of the 21-point cubature rule of Bažant and Oh on the unit sphere,
+/// where the directions cover half of the sphere and the weights sum to one, which averages even integrands.
+pub const SPHERE: [([f64; 3], f64); 21] =
+[
+    ([1.0, 0.0, 0.0], 0.05304284881863752),
+    ([0.0, 1.0, 0.0], 0.05304284881863752),
+    ([0.0, 0.0, 1.0], 0.05304284881863752),
+    ([FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0], 0.03986029526239847),
+    ([FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0], 0.03986029526239847),
+    ([FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2], 0.03986029526239847),
+    ([FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2], 0.03986029526239847),
+    ([0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2], 0.03986029526239847),
+    ([0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2], 0.03986029526239847),
+    ([0.3879073040668077, 0.3879073040668077, 0.8360955967491052], 0.05014247349747472),
+    ([0.3879073040668077, -0.3879073040668077, 0.8360955967491052], 0.05014247349747472),
+    ([0.3879073040668077, 0.3879073040668077, -0.8360955967491052], 0.05014247349747472),
+    ([0.3879073040668077, -0.3879073040668077, -0.8360955967491052], 0.05014247349747472),
+    ([0.3879073040668077, 0.8360955967491052, 0.3879073040668077], 0.05014247349747472),
+    ([0.3879073040668077, 0.8360955967491052, -0.3879073040668077], 0.05014247349747472),
+    ([-0.3879073040668077, 0.8360955967491052, 0.3879073040668077], 0.05014247349747472),
+    ([-0.3879073040668077, 0.8360955967491052, -0.3879073040668077], 0.05014247349747472),
+    ([0.8360955967491052, 0.3879073040668077, 0.3879073040668077], 0.05014247349747472),
+    ([0.8360955967491052, 0.3879073040668077, -0.3879073040668077], 0.05014247349747472),
+    ([0.8360955967491052, -0.3879073040668077, 0.3879073040668077], 0.05014247349747472),
+    ([0.8360955967491052, -0.3879073040668077, -0.3879073040668077], 0.05014247349747472)
+];
+
+/// The single-chain models available to the network constitutive models through the foreign function interfaces.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ChainModel
+{
+    /// The ideal chain model.
+    Ideal = 1,
+    /// The freely-jointed chain (FJC) model.
+    FJC = 2,
+    /// The extensible freely-jointed chain (EFJC) model, parameterized by the link stiffness.
+    EFJC = 3,
+    /// The square-well freely-jointed chain (SWFJC) model, parameterized by the well width.
+    SWFJC = 4,
+    /// The worm-like chain (WLC) model, parameterized by the persistance length.
+    WLC = 5,
+    /// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model, parameterized by the link stiffness.
+    LennardJonesFJC = 6,
+    /// The log-squared link potential freely-jointed chain (log-squared-FJC) model, parameterized by the link stiffness.
+    LogSquaredFJC = 7,
+    /// The Morse link potential freely-jointed chain (Morse-FJC) model, parameterized by the link stiffness and link energy.
+    MorseFJC = 8
+}
+
+/// The conversion of chain model numbers, as passed across the C ABI, to single-chain models.
+impl TryFrom<u8> for ChainModel
+{
+    type Error = Error;
+    fn try_from(chain_model: u8) -> Result<Self, Self::Error>
+    {
+        match chain_model
+        {
+            1 => Ok(ChainModel::Ideal),
+            2 => Ok(ChainModel::FJC),
+            3 => Ok(ChainModel::EFJC),
+            4 => Ok(ChainModel::SWFJC),
+            5 => Ok(ChainModel::WLC),
+            6 => Ok(ChainModel::LennardJonesFJC),
+            7 => Ok(ChainModel::LogSquaredFJC),
+            8 => Ok(ChainModel::MorseFJC),
+            _ => Err(Error::Parameter("chain_model", chain_model as f64, "one of the chain model numbers"))
+        }
+    }
+}
+
+/// The implemented functionality of the single-chain models available to the network constitutive models.
+impl ChainModel
+{
+    /// Initializes and returns an instance of the single-chain model, where any parameters beyond the number of links, link length, and hinge mass are given in order and the rest are ignored,
+    /// and panics if fewer parameters are given than the single-chain model has.
+    pub fn init(&self, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameters: &[f64]) -> Box<dyn ForceLaw + Send + Sync>
+    {
+        if let Err(error) = self.validate_number_of_model_parameters(model_parameters)
+        {
+            panic!("{}", error)
+        }
+        match self
+        {
+            ChainModel::Ideal => Box::new(Ideal::init(number_of_links, link_length, hinge_mass)),
+            ChainModel::FJC => Box::new(FJC::init(number_of_links, link_length, hinge_mass)),
+            ChainModel::EFJC => Box::new(EFJC::init(number_of_links, link_length, hinge_mass, model_parameters[0])),
+            ChainModel::SWFJC => Box::new(SWFJC::init(number_of_links, link_length, hinge_mass, model_parameters[0])),
+            ChainModel::WLC => Box::new(WLC::init(number_of_links, link_length, hinge_mass, model_parameters[0])),
+            ChainModel::LennardJonesFJC => Box::new(LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, model_parameters[0])),
+            ChainModel::LogSquaredFJC => Box::new(LOGSQUAREDFJC::init(number_of_links, link_length, hinge_mass, model_parameters[0])),
+            ChainModel::MorseFJC => Box::new(MORSEFJC::init(number_of_links, link_length, hinge_mass, model_parameters[0], model_parameters[1]))
+        }
+    }
+    /// Initializes and returns an instance of the single-chain model as in [`ChainModel::init`],
+    /// or an error if fewer parameters are given than the single-chain model has or any of the parameters are invalid.
+    pub fn try_init(&self, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameters: &[f64]) -> Result<Box<dyn ForceLaw + Send + Sync>, Error>
+    {
+        self.validate_number_of_model_parameters(model_parameters)?;
+        let chain: Box<dyn ForceLaw + Send + Sync> = match self
+        {
+            ChainModel::Ideal => Box::new(Ideal::try_init(number_of_links, link_length, hinge_mass)?),
+            ChainModel::FJC => Box::new(FJC::try_init(number_of_links, link_length, hinge_mass)?),
+            ChainModel::EFJC => Box::new(EFJC::try_init(number_of_links, link_length, hinge_mass, model_parameters[0])?),
+            ChainModel::SWFJC => Box::new(SWFJC::try_init(number_of_links, link_length, hinge_mass, model_parameters[0])?),
+            ChainModel::WLC => Box::new(WLC::try_init(number_of_links, link_length, hinge_mass, model_parameters[0])?),
+            ChainModel::LennardJonesFJC => Box::new(LENNARDJONESFJC::try_init(number_of_links, link_length, hinge_mass, model_parameters[0])?),
+            ChainModel::LogSquaredFJC => Box::new(LOGSQUAREDFJC::try_init(number_of_links, link_length, hinge_mass, model_parameters[0])?),
+            ChainModel::MorseFJC => Box::new(MORSEFJC::try_init(number_of_links, link_length, hinge_mass, model_parameters[0], model_parameters[1])?)
+        };
+        Ok(chain)
+    }
+    /// Validates that at least as many parameters are given as the single-chain model has.
+    fn validate_number_of_model_parameters(&self, model_parameters: &[f64]) -> Result<(), Error>
+    {
+        let number_of_model_parameters = self.number_of_model_parameters();
+        if model_parameters.len() < number_of_model_parameters
+        {
+            Err(Error::Parameter("number_of_model_parameters", model_parameters.len() as f64, ["at least zero", "at least one", "at least two"][number_of_model_parameters]))
+        }
+        else
+        {
+            Ok(())
+        }
+    }
+    /// The number of parameters of the single-chain model beyond the number of links, link length, and hinge mass.
+    pub fn number_of_model_parameters(&self) -> usize
+    {
+        match self
+        {
+            ChainModel::Ideal | ChainModel::FJC => 0,
+            ChainModel::EFJC | ChainModel::SWFJC | ChainModel::WLC | ChainModel::LennardJonesFJC | ChainModel::LogSquaredFJC => 1,
+            ChainModel::MorseFJC => 2
+        }
+    }
+}
+
+/// The derivative of the nondimensional Helmholtz free energy of a chain in the network with respect to its stretch,
+/// where the stretch is relative to the root-mean-square end-to-end length of the chain in the absence of force.
+pub fn nondimensional_chain_tension<T: ForceLaw>(chain: &T, temperature: &f64, chain_stretch: &f64) -> f64
+{
+    let nondimensional_reference_end_to_end_length_per_link = chain.nondimensional_reference_end_to_end_length_per_link();
+    (chain.number_of_links() as f64) * nondimensional_reference_end_to_end_length_per_link
+        * chain.nondimensional_force(&(chain_stretch * nondimensional_reference_end_to_end_length_per_link), temperature)
+}
+
+/// The nondimensional Helmholtz free energy of a chain in the network as a function of its stretch,
+/// relative to that of the chain in the undeformed network.
+pub fn nondimensional_chain_helmholtz_free_energy<T: ForceLaw>(chain: &T, temperature: &f64, chain_stretch: &f64) -> f64
+{
+    let nondimensional_reference_end_to_end_length_per_link = chain.nondimensional_reference_end_to_end_length_per_link();
+    (chain.number_of_links() as f64) * (
+        chain.nondimensional_relative_helmholtz_free_energy_per_link(&(chain_stretch * nondimensional_reference_end_to_end_length_per_link), temperature)
+            - chain.nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_reference_end_to_end_length_per_link, temperature)
+    )
+}
+
+/// The determinant of a tensor.
+pub(crate) fn determinant(a: &Tensor) -> f64
+{
+    a[0][0] * (a[1][1] * a[2][2] - a[1][2] * a[2][1])
+        - a[0][1] * (a[1][0] * a[2][2] - a[1][2] * a[2][0])
+        + a[0][2] * (a[1][0] * a[2][1] - a[1][1] * a[2][0])
+}
+
+/// The inverse transpose of a tensor.
+pub(crate) fn inverse_transpose(a: &Tensor) -> Tensor
+{
+    let determinant = determinant(a);
+    [
+        [
+            (a[1][1] * a[2][2] - a[1][2] * a[2][1]) / determinant,
+            (a[1][2] * a[2][0] - a[1][0] * a[2][2]) / determinant,
+            (a[1][0] * a[2][1] - a[1][1] * a[2][0]) / determinant
+        ],
+        [
+            (a[0][2] * a[2][1] - a[0][1] * a[2][2]) / determinant,
+            (a[0][0] * a[2][2] - a[0][2] * a[2][0]) / determinant,
+            (a[0][1] * a[2][0] - a[0][0] * a[2][1]) / determinant
+        ],
+        [
+            (a[0][1] * a[1][2] - a[0][2] * a[1][1]) / determinant,
+            (a[0][2] * a[1][0] - a[0][0] * a[1][2]) / determinant,
+            (a[0][0] * a[1][1] - a[0][1] * a[1][0]) / determinant
+        ]
+    ]
+}
+
+/// The product of a tensor and a vector.
+pub(crate) fn dot(a: &Tensor, b: &[f64; 3]) -> [f64; 3]
+{
+    [
+        a[0][0] * b[0] + a[0][1] * b[1] + a[0][2] * b[2],
+        a[1][0] * b[0] + a[1][1] * b[1] + a[1][2] * b[2],
+        a[2][0] * b[0] + a[2][1] * b[1] + a[2][2] * b[2]
+    ]
+}
+
+/// The Euclidean norm of a vector.
+pub(crate) fn norm(a: &[f64; 3]) -> f64
+{
+    (a[0].powi(2) + a[1].powi(2) + a[2].powi(2)).sqrt()
+}
+
+/// The outer product of two vectors.
+pub(crate) fn outer(a: &[f64; 3], b: &[f64; 3]) -> Tensor
+{
+    [
+        [a[0] * b[0], a[0] * b[1], a[0] * b[2]],
+        [a[1] * b[0], a[1] * b[1], a[1] * b[2]],
+        [a[2] * b[0], a[2] * b[1], a[2] * b[2]]
+    ]
+}
+
+/// Adds a scaled tensor to a tensor in place.
+pub(crate) fn add_scaled(a: &mut Tensor, scale: &f64, b: &Tensor)
+{
+    a.iter_mut().zip(b.iter()).for_each(|(a_i, b_i)|
+        a_i.iter_mut().zip(b_i.iter()).for_each(|(a_ij, b_ij)|
+            *a_ij += scale * b_ij
+        )
+    );
+}
+
+/// The isochoric part of the deformation gradient along with its determinant.
+pub fn isochoric(deformation_gradient: &Tensor) -> (Tensor, f64)
+{
+    let jacobian = determinant(deformation_gradient);
+    let scale = jacobian.powf(-1.0/3.0);
+    (deformation_gradient.map(|row| row.map(|entry| scale * entry)), jacobian)
+}
+
+/// The isochoric left Cauchy-Green deformation tensor along with the determinant of the deformation gradient.
+pub fn left_cauchy_green(deformation_gradient: &Tensor) -> (Tensor, f64)
+{
+    let (isochoric_deformation_gradient, jacobian) = isochoric(deformation_gradient);
+    let mut left_cauchy_green = [[0.0; 3]; 3];
+    left_cauchy_green.iter_mut().enumerate().for_each(|(i, left_cauchy_green_i)|
+        left_cauchy_green_i.iter_mut().enumerate().for_each(|(j, left_cauchy_green_ij)|
+            *left_cauchy_green_ij = (0..3).map(|k|
+                isochoric_deformation_gradient[i][k] * isochoric_deformation_gradient[j][k]
+            ).sum()
+        )
+    );
+    (left_cauchy_green, jacobian)
+}
+
+/// The deviatoric Cauchy stress corresponding to the Kirchhoff stress of the isochoric deformation.
+pub fn deviatoric(kirchhoff_stress: &Tensor, jacobian: &f64) -> Tensor
+{
+    let pressure = (kirchhoff_stress[0][0] + kirchhoff_stress[1][1] + kirchhoff_stress[2][2]) / 3.0;
+    let mut cauchy_stress = [[0.0; 3]; 3];
+    add_scaled(&mut cauchy_stress, &(1.0 / jacobian), kirchhoff_stress);
+    add_scaled(&mut cauchy_stress, &(-pressure / jacobian), &IDENTITY);
+    cauchy_stress
+}
+
+/// The eigenvalues and eigenvectors, as the columns of a tensor, of a symmetric tensor using the cyclic Jacobi method.
+pub fn symmetric_eigen(a: &Tensor) -> ([f64; 3], Tensor)
+{
+    let mut a = *a;
+    let mut eigenvectors = IDENTITY;
+    for _ in 0..50
+    {
+        let off_diagonal = a[0][1].powi(2) + a[0][2].powi(2) + a[1][2].powi(2);
+        if off_diagonal <= f64::EPSILON.powi(2) * (a[0][0].powi(2) + a[1][1].powi(2) + a[2][2].powi(2))
+        {
+            break
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)]
+        {
+            if a[p][q] == 0.0
+            {
+                continue
+            }
+            let theta = 0.5 * (a[q][q] - a[p][p]) / a[p][q];
+            let tangent = theta.signum() / (theta.abs() + (theta.powi(2) + 1.0).sqrt());
+            let cosine = 1.0 / (tangent.powi(2) + 1.0).sqrt();
+            let sine = tangent * cosine;
+            for row in a.iter_mut()
+            {
+                let a_kp = row[p];
+                let a_kq = row[q];
+                row[p] = cosine * a_kp - sine * a_kq;
+                row[q] = sine * a_kp + cosine * a_kq;
+            }
+            let (a_p, a_q) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| cosine * a_p[k] - sine * a_q[k]);
+            a[q] = std::array::from_fn(|k| sine * a_p[k] + cosine * a_q[k]);
+            for row in eigenvectors.iter_mut()
+            {
+                let v_p = row[p];
+                let v_q = row[q];
+                row[p] = cosine * v_p - sine * v_q;
+                row[q] = sine * v_p + cosine * v_q;
+            }
+        }
+    }
+    ([a[0][0], a[1][1], a[2][2]], eigenvectors)
+}
+
+/// The deformation gradient in uniaxial tension.
+fn uniaxial(stretch: &f64) -> Tensor
+{
+    let lateral_stretch = 1.0 / stretch.sqrt();
+    [[*stretch, 0.0, 0.0], [0.0, lateral_stretch, 0.0], [0.0, 0.0, lateral_stretch]]
+}
+
+/// The deformation gradient in equibiaxial tension.
+fn equibiaxial(stretch: &f64) -> Tensor
+{
+    [[*stretch, 0.0, 0.0], [0.0, *stretch, 0.0], [0.0, 0.0, 1.0 / stretch.powi(2)]]
+}
+
+/// The nondimensional Cauchy stress in uniaxial tension given the deviatoric nondimensional Cauchy stress as a function of the deformation gradient.
+pub fn uniaxial_tension(cauchy_stress: &dyn Fn(&Tensor) -> Tensor, stretch: &f64) -> f64
+{
+    let stress = cauchy_stress(&uniaxial(stretch));
+    stress[0][0] - stress[1][1]
+}
+
+/// The nondimensional Cauchy stress in equibiaxial tension given the deviatoric nondimensional Cauchy stress as a function of the deformation gradient.
+pub fn equibiaxial_tension(cauchy_stress: &dyn Fn(&Tensor) -> Tensor, stretch: &f64) -> f64
+{
+    let stress = cauchy_stress(&equibiaxial(stretch));
+    stress[0][0] - stress[2][2]
+}
+
+/// The nondimensional strain energy density in uniaxial tension given the nondimensional strain energy density as a function of the deformation gradient.
+pub fn uniaxial_tension_strain_energy_density(strain_energy_density: &dyn Fn(&Tensor) -> f64, stretch: &f64) -> f64
+{
+    strain_energy_density(&uniaxial(stretch))
+}
+
+/// The nondimensional strain energy density in equibiaxial tension given the nondimensional strain energy density as a function of the deformation gradient.
+pub fn equibiaxial_tension_strain_energy_density(strain_energy_density: &dyn Fn(&Tensor) -> f64, stretch: &f64) -> f64
+{
+    strain_energy_density(&equibiaxial(stretch))
+}
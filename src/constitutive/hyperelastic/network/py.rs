@@ -0,0 +1,106 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::
+{
+    PyTypeError,
+    PyValueError
+};
+use numpy::
+{
+    IntoPyArray,
+    PyArrayDyn,
+    PyReadonlyArrayDyn,
+    ndarray::ArrayD
+};
+use crate::physics::single_chain::
+{
+    ForceLaw,
+    efjc::py::EFJC,
+    fjc::py::FJC,
+    ideal::py::Ideal,
+    swfjc::py::SWFJC,
+    ufjc::
+    {
+        lennard_jones::py::LENNARDJONESFJC,
+        log_squared::py::LOGSQUAREDFJC,
+        morse::py::MORSEFJC
+    },
+    wlc::py::WLC
+};
+use super::
+{
+    ChainModel,
+    Tensor
+};
+
+/// Returns the force law of any of the single-chain models, or a type error if the object is not one of them.
+pub fn force_law(chain: &PyAny) -> PyResult<Box<dyn ForceLaw + Send + Sync>>
+{
+    if let Ok(chain) = chain.extract::<PyRef<Ideal>>()
+    {
+        Ok(ChainModel::Ideal.init(chain.number_of_links, chain.link_length, chain.hinge_mass, &[0.0, 0.0]))
+    }
+    else if let Ok(chain) = chain.extract::<PyRef<FJC>>()
+    {
+        Ok(ChainModel::FJC.init(chain.number_of_links, chain.link_length, chain.hinge_mass, &[0.0, 0.0]))
+    }
+    else if let Ok(chain) = chain.extract::<PyRef<EFJC>>()
+    {
+        Ok(ChainModel::EFJC.init(chain.number_of_links, chain.link_length, chain.hinge_mass, &[chain.link_stiffness, 0.0]))
+    }
+    else if let Ok(chain) = chain.extract::<PyRef<SWFJC>>()
+    {
+        Ok(ChainModel::SWFJC.init(chain.number_of_links, chain.link_length, chain.hinge_mass, &[chain.well_width, 0.0]))
+    }
+    else if let Ok(chain) = chain.extract::<PyRef<WLC>>()
+    {
+        Ok(ChainModel::WLC.init(chain.number_of_links, chain.link_length, chain.hinge_mass, &[chain.persistance_length, 0.0]))
+    }
+    else if let Ok(chain) = chain.extract::<PyRef<LENNARDJONESFJC>>()
+    {
+        Ok(ChainModel::LennardJonesFJC.init(chain.number_of_links, chain.link_length, chain.hinge_mass, &[chain.link_stiffness, 0.0]))
+    }
+    else if let Ok(chain) = chain.extract::<PyRef<LOGSQUAREDFJC>>()
+    {
+        Ok(ChainModel::LogSquaredFJC.init(chain.number_of_links, chain.link_length, chain.hinge_mass, &[chain.link_stiffness, 0.0]))
+    }
+    else if let Ok(chain) = chain.extract::<PyRef<MORSEFJC>>()
+    {
+        Ok(ChainModel::MorseFJC.init(chain.number_of_links, chain.link_length, chain.hinge_mass, &[chain.link_stiffness, chain.link_energy]))
+    }
+    else
+    {
+        Err(PyTypeError::new_err("The chain must be one of the single-chain models."))
+    }
+}
+
+/// Returns the entries of the tensors in an array whose last two dimensions are three by three, or a value error if they are not.
+fn tensors(arguments: &PyReadonlyArrayDyn<f64>) -> PyResult<Vec<Tensor>>
+{
+    let arguments = arguments.as_array();
+    if arguments.ndim() < 2 || arguments.shape()[arguments.ndim() - 2..] != [3, 3]
+    {
+        return Err(PyValueError::new_err("The deformation gradient must have trailing dimensions of three by three."))
+    }
+    Ok(arguments.iter().copied().collect::<Vec<f64>>().chunks_exact(9).map(|entries|
+        [
+            [entries[0], entries[1], entries[2]],
+            [entries[3], entries[4], entries[5]],
+            [entries[6], entries[7], entries[8]]
+        ]
+    ).collect())
+}
+
+/// Evaluates a function over every tensor in an array whose last two dimensions are three by three, preserving the shape of the array.
+pub fn tensor_batch<'py>(py: Python<'py>, arguments: PyReadonlyArrayDyn<f64>, function: impl Fn(&Tensor) -> Tensor) -> PyResult<&'py PyArrayDyn<f64>>
+{
+    let values = tensors(&arguments)?.iter().flat_map(|tensor| function(tensor).into_iter().flatten()).collect();
+    Ok(ArrayD::from_shape_vec(arguments.as_array().raw_dim(), values).unwrap().into_pyarray(py))
+}
+
+/// Evaluates a scalar function over every tensor in an array whose last two dimensions are three by three, removing those dimensions from the shape of the array.
+pub fn scalar_batch<'py>(py: Python<'py>, arguments: PyReadonlyArrayDyn<f64>, function: impl Fn(&Tensor) -> f64) -> PyResult<&'py PyArrayDyn<f64>>
+{
+    let values = tensors(&arguments)?.iter().map(function).collect();
+    let arguments = arguments.as_array();
+    Ok(ArrayD::from_shape_vec(&arguments.shape()[..arguments.ndim() - 2], values).unwrap().into_pyarray(py))
+}
@@ -4,6 +4,9 @@ pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
 {
     let hyperelastic = PyModule::new(py, "hyperelastic")?;
     super::buche_silberstein::py::register_module(py, hyperelastic)?;
+    super::eight_chain::py::register_module(py, hyperelastic)?;
+    super::microsphere::py::register_module(py, hyperelastic)?;
+    super::three_chain::py::register_module(py, hyperelastic)?;
     parent_module.add_submodule(hyperelastic)?;
     Ok(())
 }
\ No newline at end of file
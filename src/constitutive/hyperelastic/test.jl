@@ -0,0 +1,81 @@
+module Test
+
+using Test
+using Polymers.Physics.SingleChain.Ideal: IDEAL
+using Polymers.Physics.SingleChain.Fjc: FJC
+using Polymers.Constitutive.Hyperelastic.EightChain: EIGHTCHAIN
+using Polymers.Constitutive.Hyperelastic.ThreeChain: THREECHAIN
+using Polymers.Constitutive.Hyperelastic.Microsphere:
+    AFFINEMICROSPHERE, NONAFFINEMICROSPHERE
+
+const stretches = [1.1, 1.5, 2.0, 2.5]
+
+@testset "constitutive::hyperelastic::test::gaussian" begin
+    chain = IDEAL(UInt8(25), 1.0, 1.0)
+    for model in [
+        EIGHTCHAIN(chain, 300.0),
+        THREECHAIN(chain, 300.0),
+        AFFINEMICROSPHERE(chain, 300.0),
+        NONAFFINEMICROSPHERE(chain, 300.0, 2.0, 0.0, 1.0),
+    ]
+        @test all(
+            isapprox.(
+                model.uniaxial_tension(stretches),
+                stretches .^ 2 .- 1.0 ./ stretches,
+                rtol = 1e-12,
+            ),
+        )
+        @test all(
+            isapprox.(
+                model.equibiaxial_tension(stretches),
+                stretches .^ 2 .- 1.0 ./ stretches .^ 4,
+                rtol = 1e-12,
+            ),
+        )
+    end
+end
+
+@testset "constitutive::hyperelastic::test::strain_energy_density" begin
+    chain = IDEAL(UInt8(25), 1.0, 1.0)
+    for model in [
+        EIGHTCHAIN(chain, 300.0),
+        THREECHAIN(chain, 300.0),
+        AFFINEMICROSPHERE(chain, 300.0),
+        NONAFFINEMICROSPHERE(chain, 300.0, 2.0, 0.0, 1.0),
+    ]
+        @test all(
+            isapprox.(
+                model.uniaxial_tension_strain_energy_density(stretches),
+                (stretches .^ 2 .+ 2.0 ./ stretches .- 3.0) ./ 2.0,
+                rtol = 1e-10,
+            ),
+        )
+        @test all(
+            isapprox.(
+                model.equibiaxial_tension_strain_energy_density(stretches),
+                (2.0 .* stretches .^ 2 .+ 1.0 ./ stretches .^ 4 .- 3.0) ./ 2.0,
+                rtol = 1e-10,
+            ),
+        )
+    end
+end
+
+@testset "constitutive::hyperelastic::test::cauchy_stress" begin
+    model = EIGHTCHAIN(FJC(UInt8(8), 1.0, 1.0), 300.0)
+    deformation_gradient = [1.2 0.1 0.0; 0.0 0.9 0.2; 0.1 0.0 1.0]
+    stress = model.cauchy_stress(deformation_gradient)
+    @test isapprox(stress, permutedims(stress), rtol = 1e-12)
+    @test abs(stress[1, 1] + stress[2, 2] + stress[3, 3]) <=
+          1e-12 * maximum(abs.(stress))
+    stretch = 1.5
+    uniaxial = model.cauchy_stress(
+        [stretch 0.0 0.0; 0.0 stretch^-0.5 0.0; 0.0 0.0 stretch^-0.5],
+    )
+    @test isapprox(
+        uniaxial[1, 1] - uniaxial[2, 2],
+        model.uniaxial_tension(stretch),
+        rtol = 1e-12,
+    )
+end
+
+end
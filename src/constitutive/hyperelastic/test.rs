@@ -0,0 +1,438 @@
+#![cfg(test)]
+use super::*;
+use crate::error::Error;
+use crate::math::inverse_langevin;
+use crate::physics::single_chain::
+{
+    ForceLaw,
+    fjc::FJC,
+    ideal::Ideal,
+    wlc::WLC
+};
+use rand::Rng;
+const TEMPERATURE: f64 = 300.0;
+const STRETCHES: [f64; 4] = [1.1, 1.5, 2.0, 2.5];
+fn deformation_gradient() -> Tensor
+{
+    let mut rng = rand::thread_rng();
+    let mut deformation_gradient = [[0.0; 3]; 3];
+    deformation_gradient.iter_mut().enumerate().for_each(|(i, row)|
+        row.iter_mut().enumerate().for_each(|(j, entry)|
+            *entry = (i == j) as u8 as f64 + 0.3 * (rng.gen::<f64>() - 0.5)
+        )
+    );
+    deformation_gradient
+}
+fn rotation() -> Tensor
+{
+    let mut rng = rand::thread_rng();
+    let angle = std::f64::consts::PI * rng.gen::<f64>();
+    let axis = [rng.gen::<f64>(), rng.gen::<f64>(), rng.gen::<f64>()];
+    let norm = (axis[0].powi(2) + axis[1].powi(2) + axis[2].powi(2)).sqrt();
+    let [x, y, z] = axis.map(|component| component / norm);
+    let (sine, cosine) = angle.sin_cos();
+    [
+        [cosine + x * x * (1.0 - cosine), x * y * (1.0 - cosine) - z * sine, x * z * (1.0 - cosine) + y * sine],
+        [y * x * (1.0 - cosine) + z * sine, cosine + y * y * (1.0 - cosine), y * z * (1.0 - cosine) - x * sine],
+        [z * x * (1.0 - cosine) - y * sine, z * y * (1.0 - cosine) + x * sine, cosine + z * z * (1.0 - cosine)]
+    ]
+}
+fn product(a: &Tensor, b: &Tensor) -> Tensor
+{
+    let mut c = [[0.0; 3]; 3];
+    c.iter_mut().enumerate().for_each(|(i, c_i)|
+        c_i.iter_mut().enumerate().for_each(|(j, c_ij)|
+            *c_ij = (0..3).map(|k| a[i][k] * b[k][j]).sum()
+        )
+    );
+    c
+}
+fn transpose(a: &Tensor) -> Tensor
+{
+    let mut b = [[0.0; 3]; 3];
+    b.iter_mut().enumerate().for_each(|(i, b_i)|
+        b_i.iter_mut().enumerate().for_each(|(j, b_ij)|
+            *b_ij = a[j][i]
+        )
+    );
+    b
+}
+fn assert_tensors(a: &Tensor, b: &Tensor, tolerance: f64)
+{
+    let scale = a.iter().flatten().map(|entry| entry.abs()).fold(1.0, f64::max);
+    a.iter().flatten().zip(b.iter().flatten()).for_each(|(a_ij, b_ij)|
+        assert!((a_ij - b_ij).abs() <= tolerance * scale, "{:?} != {:?}", a, b)
+    );
+}
+mod gaussian
+{
+    use super::*;
+    fn check(uniaxial_tension: &dyn Fn(&f64) -> f64, equibiaxial_tension: &dyn Fn(&f64) -> f64)
+    {
+        STRETCHES.iter().for_each(|stretch|{
+            let neo_hookean = stretch.powi(2) - 1.0 / stretch;
+            assert!((uniaxial_tension(stretch) / neo_hookean - 1.0).abs() <= 1e-12);
+            let neo_hookean = stretch.powi(2) - 1.0 / stretch.powi(4);
+            assert!((equibiaxial_tension(stretch) / neo_hookean - 1.0).abs() <= 1e-12);
+        });
+    }
+    #[test]
+    fn eight_chain()
+    {
+        let model = EightChain::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE);
+        check(&|stretch| model.uniaxial_tension(stretch), &|stretch| model.equibiaxial_tension(stretch));
+    }
+    #[test]
+    fn three_chain()
+    {
+        let model = ThreeChain::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE);
+        check(&|stretch| model.uniaxial_tension(stretch), &|stretch| model.equibiaxial_tension(stretch));
+    }
+    #[test]
+    fn affine_microsphere()
+    {
+        let model = AffineMicrosphere::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE);
+        check(&|stretch| model.uniaxial_tension(stretch), &|stretch| model.equibiaxial_tension(stretch));
+    }
+    #[test]
+    fn nonaffine_microsphere()
+    {
+        let model = NonaffineMicrosphere::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 2.0, 0.0, 1.0);
+        check(&|stretch| model.uniaxial_tension(stretch), &|stretch| model.equibiaxial_tension(stretch));
+    }
+}
+mod closed_form
+{
+    use super::*;
+    #[test]
+    fn eight_chain()
+    {
+        let number_of_links = 8;
+        let model = EightChain::init(FJC::init(number_of_links, 1.0, 1.0), TEMPERATURE);
+        let reference = 1.0 / (number_of_links as f64).sqrt();
+        STRETCHES.iter().for_each(|stretch|{
+            let chain_stretch = ((stretch.powi(2) + 2.0 / stretch) / 3.0).sqrt();
+            let uniaxial_tension = (number_of_links as f64) * reference * inverse_langevin(&(chain_stretch * reference)) / chain_stretch / 3.0 * (stretch.powi(2) - 1.0 / stretch);
+            assert!((model.uniaxial_tension(stretch) / uniaxial_tension - 1.0).abs() <= 1e-10);
+        });
+    }
+    #[test]
+    fn three_chain()
+    {
+        let number_of_links = 8;
+        let model = ThreeChain::init(FJC::init(number_of_links, 1.0, 1.0), TEMPERATURE);
+        let reference = 1.0 / (number_of_links as f64).sqrt();
+        let tension = |stretch: f64| (number_of_links as f64) * reference * stretch * inverse_langevin(&(stretch * reference)) / 3.0;
+        STRETCHES.iter().for_each(|stretch|{
+            let uniaxial_tension = tension(*stretch) - tension(1.0 / stretch.sqrt());
+            assert!((model.uniaxial_tension(stretch) / uniaxial_tension - 1.0).abs() <= 1e-10);
+            let equibiaxial_tension = tension(*stretch) - tension(1.0 / stretch.powi(2));
+            assert!((model.equibiaxial_tension(stretch) / equibiaxial_tension - 1.0).abs() <= 1e-10);
+        });
+    }
+    #[test]
+    fn wlc_reference()
+    {
+        let wlc = WLC::init(25, 1.0, 1.0, 1e4);
+        assert!((wlc.nondimensional_reference_end_to_end_length_per_link() - 1.0).abs() <= 1e-3);
+        let wlc = WLC::init(25, 1.0, 1.0, 1e-3);
+        assert!((wlc.nondimensional_reference_end_to_end_length_per_link() / (2.0 * 1e-3 / 25.0_f64).sqrt() - 1.0).abs() <= 1e-3);
+    }
+}
+mod general
+{
+    use super::*;
+    fn check(cauchy_stress: &dyn Fn(&Tensor) -> Tensor, tolerance: f64)
+    {
+        (0..8).for_each(|_|{
+            let deformation_gradient = deformation_gradient();
+            let stress = cauchy_stress(&deformation_gradient);
+            assert_tensors(&stress, &transpose(&stress), 1e-12);
+            assert!((stress[0][0] + stress[1][1] + stress[2][2]).abs() <= 1e-12 * stress.iter().flatten().map(|entry| entry.abs()).fold(1.0, f64::max));
+            let rotation = rotation();
+            let rotated_stress = cauchy_stress(&product(&rotation, &deformation_gradient));
+            assert_tensors(&rotated_stress, &product(&product(&rotation, &stress), &transpose(&rotation)), tolerance);
+            let dilated_stress = cauchy_stress(&deformation_gradient.map(|row| row.map(|entry| 1.1 * entry)));
+            assert_tensors(&dilated_stress, &stress.map(|row| row.map(|entry| entry / 1.1_f64.powi(3))), 1e-12);
+        });
+    }
+    #[test]
+    fn eight_chain()
+    {
+        let model = EightChain::init(FJC::init(8, 1.0, 1.0), TEMPERATURE);
+        check(&|deformation_gradient| model.cauchy_stress(deformation_gradient), 1e-12);
+    }
+    #[test]
+    fn three_chain()
+    {
+        let model = ThreeChain::init(FJC::init(8, 1.0, 1.0), TEMPERATURE);
+        check(&|deformation_gradient| model.cauchy_stress(deformation_gradient), 1e-10);
+    }
+    #[test]
+    fn affine_microsphere()
+    {
+        let model = AffineMicrosphere::init(FJC::init(8, 1.0, 1.0), TEMPERATURE);
+        check(&|deformation_gradient| model.cauchy_stress(deformation_gradient), 1e-2);
+    }
+    #[test]
+    fn nonaffine_microsphere()
+    {
+        let model = NonaffineMicrosphere::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 1.5, 0.5, 0.5);
+        check(&|deformation_gradient| model.cauchy_stress(deformation_gradient), 1e-2);
+    }
+    #[test]
+    fn chain_model()
+    {
+        let deformation_gradient = deformation_gradient();
+        let boxed = EightChain::init(ChainModel::FJC.init(8, 1.0, 1.0, &[0.0, 0.0]), TEMPERATURE);
+        let model = EightChain::init(FJC::init(8, 1.0, 1.0), TEMPERATURE);
+        assert_eq!(boxed.cauchy_stress(&deformation_gradient), model.cauchy_stress(&deformation_gradient));
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        assert!(EightChain::try_init(FJC::init(8, 1.0, 1.0), TEMPERATURE).is_ok());
+        assert!(ThreeChain::try_init(FJC::init(8, 1.0, 1.0), 0.0).is_err());
+        assert!(AffineMicrosphere::try_init(FJC::init(8, 1.0, 1.0), -1.0).is_err());
+        assert!(NonaffineMicrosphere::try_init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 1.5, 0.0, 0.5).is_ok());
+        assert!(NonaffineMicrosphere::try_init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 1.5, -0.1, 0.5).is_err());
+        assert!(NonaffineMicrosphere::try_init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.0, 0.5, 0.5).is_err());
+    }
+    #[test]
+    fn method()
+    {
+        assert_eq!(Method::try_from(2).unwrap(), Method::GibbsLegendre);
+        assert_eq!(Method::try_from(3).unwrap(), Method::GibbsLegendreGaussian);
+        assert!(matches!(Method::try_from(0), Err(Error::Method(0))));
+        assert!(matches!(Method::try_from(1), Err(Error::Method(1))));
+        assert!(matches!(Method::try_from(4), Err(Error::Method(4))));
+    }
+    #[test]
+    #[should_panic(expected = "number_of_grid_points")]
+    fn number_of_grid_points()
+    {
+        BucheSilberstein::init_with_grid(Method::GibbsLegendre, Quadrature::GaussLegendre, 1, 1e2, 8);
+    }
+    #[test]
+    fn chain_model()
+    {
+        [
+            ChainModel::Ideal,
+            ChainModel::FJC,
+            ChainModel::EFJC,
+            ChainModel::SWFJC,
+            ChainModel::WLC,
+            ChainModel::LennardJonesFJC,
+            ChainModel::LogSquaredFJC,
+            ChainModel::MorseFJC
+        ].iter().for_each(|chain_model|
+            assert_eq!(ChainModel::try_from(*chain_model as u8).unwrap(), *chain_model)
+        );
+        assert!(matches!(ChainModel::try_from(0), Err(Error::Parameter(..))));
+        assert!(matches!(ChainModel::try_from(9), Err(Error::Parameter(..))));
+    }
+    #[test]
+    fn model_parameters()
+    {
+        assert!(ChainModel::FJC.try_init(8, 1.0, 1.0, &[]).is_ok());
+        assert!(ChainModel::EFJC.try_init(8, 1.0, 1.0, &[1e6, 0.0]).is_ok());
+        assert_eq!(ChainModel::EFJC.try_init(8, 1.0, 1.0, &[]).err(), Some(Error::Parameter("number_of_model_parameters", 0.0, "at least one")));
+        assert_eq!(ChainModel::MorseFJC.try_init(8, 1.0, 1.0, &[1e6]).err(), Some(Error::Parameter("number_of_model_parameters", 1.0, "at least two")));
+        assert!(ChainModel::WLC.try_init(8, 1.0, 1.0, &[-1.0]).is_err());
+    }
+    #[test]
+    #[should_panic(expected = "number_of_model_parameters")]
+    fn number_of_model_parameters()
+    {
+        ChainModel::MorseFJC.init(8, 1.0, 1.0, &[1e6]);
+    }
+    #[cfg(feature = "extern")]
+    #[test]
+    fn extern_chain_model()
+    {
+        let model = EightChain::init(FJC::init(8, 1.0, 1.0), TEMPERATURE);
+        let deformation_gradient = deformation_gradient();
+        let uniaxial_tension = |chain_model: u8|
+            eight_chain::ex::constitutive_hyperelastic_eight_chain_uniaxial_tension(chain_model, 8, 1.0, 1.0, 0.0, 0.0, TEMPERATURE, 1.1);
+        let cauchy_stress = |chain_model: u8|{
+            let mut cauchy_stress = [[0.0; 3]; 3];
+            unsafe {
+                eight_chain::ex::constitutive_hyperelastic_eight_chain_cauchy_stress(chain_model, 8, 1.0, 1.0, 0.0, 0.0, TEMPERATURE, deformation_gradient.as_ptr() as *const f64, cauchy_stress.as_mut_ptr() as *mut f64)
+            };
+            cauchy_stress
+        };
+        assert_eq!(uniaxial_tension(ChainModel::FJC as u8), model.uniaxial_tension(&1.1));
+        assert_eq!(cauchy_stress(ChainModel::FJC as u8), model.cauchy_stress(&deformation_gradient));
+        [0, 9, u8::MAX].iter().for_each(|chain_model|{
+            assert!(uniaxial_tension(*chain_model).is_nan());
+            assert!(cauchy_stress(*chain_model).iter().flatten().all(|entry| entry.is_nan()));
+        });
+    }
+    #[cfg(feature = "extern")]
+    #[test]
+    fn extern_method()
+    {
+        let model = BucheSilberstein::init_with_grid(Method::GibbsLegendre, Quadrature::GaussLegendre, 16, 1e2, 8);
+        let (element, factor, grid, weight, normalization) = buche_silberstein::init(Method::GibbsLegendre, Quadrature::GaussLegendre, 16, 1e2, 8);
+        let uniaxial_tension = |method: u8| unsafe {
+            buche_silberstein::ex::constitutive_hyperelastic_buche_silberstein_uniaxial_tension(element.as_ptr(), factor, grid.as_ptr(), grid.len(), normalization, method, 1e2, 8, 1.1)
+        };
+        let strain_energy_density = |method: u8| unsafe {
+            buche_silberstein::ex::constitutive_hyperelastic_buche_silberstein_uniaxial_tension_strain_energy_density(factor, grid.as_ptr(), weight.as_ptr(), grid.len(), normalization, method, 1e2, 8, 1.1)
+        };
+        assert_eq!(uniaxial_tension(2), model.uniaxial_tension(&1.1));
+        assert_eq!(strain_energy_density(2), model.uniaxial_tension_strain_energy_density(&1.1));
+        [0, 1, 4, u8::MAX].iter().for_each(|method|{
+            assert!(uniaxial_tension(*method).is_nan());
+            assert!(strain_energy_density(*method).is_nan());
+        });
+    }
+}
+mod strain_energy
+{
+    use super::*;
+    use crate::constitutive::StrainEnergy;
+    fn gaussian(uniaxial_tension: &dyn Fn(&f64) -> f64, equibiaxial_tension: &dyn Fn(&f64) -> f64)
+    {
+        STRETCHES.iter().for_each(|stretch|{
+            let neo_hookean = (stretch.powi(2) + 2.0 / stretch - 3.0) / 2.0;
+            assert!((uniaxial_tension(stretch) / neo_hookean - 1.0).abs() <= 1e-10);
+            let neo_hookean = (2.0 * stretch.powi(2) + 1.0 / stretch.powi(4) - 3.0) / 2.0;
+            assert!((equibiaxial_tension(stretch) / neo_hookean - 1.0).abs() <= 1e-10);
+        });
+    }
+    fn derivative(model: &dyn StrainEnergy, stretches: &[f64], tolerance: f64)
+    {
+        let step = 1e-5;
+        stretches.iter().for_each(|stretch|{
+            let derivative = (model.uniaxial_tension_strain_energy_density(&(stretch + step)) - model.uniaxial_tension_strain_energy_density(&(stretch - step))) / (2.0 * step);
+            assert!((stretch * derivative / model.uniaxial_tension(stretch) - 1.0).abs() <= tolerance);
+            let derivative = (model.equibiaxial_tension_strain_energy_density(&(stretch + step)) - model.equibiaxial_tension_strain_energy_density(&(stretch - step))) / (2.0 * step);
+            assert!((stretch * derivative / 2.0 / model.equibiaxial_tension(stretch) - 1.0).abs() <= tolerance);
+        });
+    }
+    fn general(strain_energy_density: &dyn Fn(&Tensor) -> f64, cauchy_stress: &dyn Fn(&Tensor) -> Tensor)
+    {
+        let step = 1e-6;
+        (0..4).for_each(|_|{
+            let deformation_gradient = deformation_gradient();
+            let mut first_piola_kirchhoff_stress = [[0.0; 3]; 3];
+            first_piola_kirchhoff_stress.iter_mut().enumerate().for_each(|(i, row)|
+                row.iter_mut().enumerate().for_each(|(j, entry)|{
+                    let mut forward = deformation_gradient;
+                    forward[i][j] += step;
+                    let mut backward = deformation_gradient;
+                    backward[i][j] -= step;
+                    *entry = (strain_energy_density(&forward) - strain_energy_density(&backward)) / (2.0 * step);
+                })
+            );
+            let jacobian = deformation_gradient[0][0] * (deformation_gradient[1][1] * deformation_gradient[2][2] - deformation_gradient[1][2] * deformation_gradient[2][1])
+                - deformation_gradient[0][1] * (deformation_gradient[1][0] * deformation_gradient[2][2] - deformation_gradient[1][2] * deformation_gradient[2][0])
+                + deformation_gradient[0][2] * (deformation_gradient[1][0] * deformation_gradient[2][1] - deformation_gradient[1][1] * deformation_gradient[2][0]);
+            let mut stress = product(&first_piola_kirchhoff_stress, &transpose(&deformation_gradient)).map(|row| row.map(|entry| entry / jacobian));
+            let pressure = (stress[0][0] + stress[1][1] + stress[2][2]) / 3.0;
+            (0..3).for_each(|i| stress[i][i] -= pressure);
+            assert_tensors(&stress, &cauchy_stress(&deformation_gradient), 1e-6);
+        });
+    }
+    #[test]
+    fn eight_chain()
+    {
+        let model = EightChain::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE);
+        gaussian(&|stretch| model.uniaxial_tension_strain_energy_density(stretch), &|stretch| model.equibiaxial_tension_strain_energy_density(stretch));
+        let model = EightChain::init(FJC::init(8, 1.0, 1.0), TEMPERATURE);
+        derivative(&model, &STRETCHES, 1e-6);
+        general(&|deformation_gradient| model.strain_energy_density(deformation_gradient), &|deformation_gradient| model.cauchy_stress(deformation_gradient));
+    }
+    #[test]
+    fn three_chain()
+    {
+        let model = ThreeChain::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE);
+        gaussian(&|stretch| model.uniaxial_tension_strain_energy_density(stretch), &|stretch| model.equibiaxial_tension_strain_energy_density(stretch));
+        let model = ThreeChain::init(FJC::init(8, 1.0, 1.0), TEMPERATURE);
+        derivative(&model, &STRETCHES, 1e-6);
+        general(&|deformation_gradient| model.strain_energy_density(deformation_gradient), &|deformation_gradient| model.cauchy_stress(deformation_gradient));
+    }
+    #[test]
+    fn affine_microsphere()
+    {
+        let model = AffineMicrosphere::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE);
+        gaussian(&|stretch| model.uniaxial_tension_strain_energy_density(stretch), &|stretch| model.equibiaxial_tension_strain_energy_density(stretch));
+        let model = AffineMicrosphere::init(FJC::init(8, 1.0, 1.0), TEMPERATURE);
+        derivative(&model, &STRETCHES, 1e-6);
+        general(&|deformation_gradient| model.strain_energy_density(deformation_gradient), &|deformation_gradient| model.cauchy_stress(deformation_gradient));
+    }
+    #[test]
+    fn nonaffine_microsphere()
+    {
+        let model = NonaffineMicrosphere::init(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 2.0, 0.0, 1.0);
+        gaussian(&|stretch| model.uniaxial_tension_strain_energy_density(stretch), &|stretch| model.equibiaxial_tension_strain_energy_density(stretch));
+        let model = NonaffineMicrosphere::init(FJC::init(8, 1.0, 1.0), TEMPERATURE, 1.5, 0.5, 0.5);
+        derivative(&model, &STRETCHES, 1e-6);
+        general(&|deformation_gradient| model.strain_energy_density(deformation_gradient), &|deformation_gradient| model.cauchy_stress(deformation_gradient));
+    }
+    #[test]
+    fn buche_silberstein()
+    {
+        let model = BucheSilberstein::init_with_grid(Method::GibbsLegendre, Quadrature::GaussLegendre, 64, 1e2, 8);
+        derivative(&model, &[1.05, 1.1, 1.2], 1e-6);
+        let model = BucheSilberstein::init_with_grid(Method::GibbsLegendreGaussian, Quadrature::GaussLegendre, 64, 1e2, 8);
+        derivative(&model, &[1.05, 1.1, 1.2], 1e-6);
+    }
+    #[test]
+    fn chain_model()
+    {
+        let step = 1e-6;
+        [
+            (ChainModel::Ideal, [0.0, 0.0]),
+            (ChainModel::FJC, [0.0, 0.0]),
+            (ChainModel::EFJC, [1e6, 0.0]),
+            (ChainModel::SWFJC, [1.5, 0.0]),
+            (ChainModel::WLC, [5.0, 0.0]),
+            (ChainModel::LennardJonesFJC, [1e6, 0.0]),
+            (ChainModel::LogSquaredFJC, [1e6, 0.0]),
+            (ChainModel::MorseFJC, [1e6, 5e4])
+        ].iter().for_each(|(chain_model, model_parameters)|{
+            let chain = chain_model.init(8, 1.0, 1.0, model_parameters);
+            [0.2, 0.5, 0.8].iter().for_each(|nondimensional_end_to_end_length_per_link: &f64|{
+                let derivative = (chain.nondimensional_relative_helmholtz_free_energy_per_link(&(nondimensional_end_to_end_length_per_link + step), &TEMPERATURE) - chain.nondimensional_relative_helmholtz_free_energy_per_link(&(nondimensional_end_to_end_length_per_link - step), &TEMPERATURE)) / (2.0 * step);
+                assert!((derivative / chain.nondimensional_force(nondimensional_end_to_end_length_per_link, &TEMPERATURE) - 1.0).abs() <= 1e-6, "{:?}", chain_model);
+            });
+        });
+    }
+}
+mod quadrature
+{
+    use super::*;
+    const METHODS: [Method; 2] = [Method::GibbsLegendre, Method::GibbsLegendreGaussian];
+    #[test]
+    fn agreement()
+    {
+        METHODS.iter().for_each(|method|{
+            let reference = BucheSilberstein::init_with_grid(*method, Quadrature::GaussLegendre, 128, 1e2, 8);
+            let residuals: Vec<[f64; 2]> = [32, 64, 128, 256].iter().map(|number_of_grid_points|{
+                let model = BucheSilberstein::init_with_grid(*method, Quadrature::Uniform, *number_of_grid_points, 1e2, 8);
+                [
+                    (model.uniaxial_tension(&1.2) / reference.uniaxial_tension(&1.2) - 1.0).abs(),
+                    (model.equibiaxial_tension(&1.2) / reference.equibiaxial_tension(&1.2) - 1.0).abs()
+                ]
+            }).collect();
+            residuals.windows(2).for_each(|residual| assert!(residual[1][0] < 0.6 * residual[0][0] && residual[1][1] < 0.6 * residual[0][1], "{:?}", method));
+            assert!(residuals.last().unwrap().iter().all(|residual| residual <= &2e-2), "{:?}", method);
+        });
+    }
+    #[test]
+    fn convergence()
+    {
+        METHODS.iter().for_each(|method|
+            [Quadrature::Uniform, Quadrature::GaussLegendre].iter().for_each(|quadrature|{
+                let relative_changes: Vec<f64> = BucheSilberstein::convergence(*method, *quadrature, 1e2, 8, &1.2, &[16, 32, 64, 128]).iter().skip(1).map(|(_, _, relative_change)| *relative_change).collect();
+                relative_changes.windows(2).for_each(|relative_change| assert!(relative_change[1] < relative_change[0], "{:?} {:?}", method, quadrature));
+            })
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use super::super::
+{
+    ChainModel,
+    network::ex::
+    {
+        tensor_from_raw,
+        tensor_into_raw
+    }
+};
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_three_chain_uniaxial_tension(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::ThreeChain::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).uniaxial_tension(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_three_chain_equibiaxial_tension(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::ThreeChain::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).equibiaxial_tension(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub unsafe extern fn constitutive_hyperelastic_three_chain_cauchy_stress(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, deformation_gradient: *const f64, cauchy_stress: *mut f64)
+{
+    tensor_into_raw(&ChainModel::try_from(chain_model).map(|chain_model|
+        super::ThreeChain::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).cauchy_stress(&tensor_from_raw(deformation_gradient))
+    ).unwrap_or([[f64::NAN; 3]; 3]), cauchy_stress)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_three_chain_uniaxial_tension_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::ThreeChain::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).uniaxial_tension_strain_energy_density(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn constitutive_hyperelastic_three_chain_equibiaxial_tension_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, stretch: f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::ThreeChain::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).equibiaxial_tension_strain_energy_density(&stretch)
+    ).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub unsafe extern fn constitutive_hyperelastic_three_chain_strain_energy_density(chain_model: u8, number_of_links: u8, link_length: f64, hinge_mass: f64, model_parameter_1: f64, model_parameter_2: f64, temperature: f64, deformation_gradient: *const f64) -> f64
+{
+    ChainModel::try_from(chain_model).map(|chain_model|
+        super::ThreeChain::init(chain_model.init(number_of_links, link_length, hinge_mass, &[model_parameter_1, model_parameter_2]), temperature).strain_energy_density(&tensor_from_raw(deformation_gradient))
+    ).unwrap_or(f64::NAN)
+}
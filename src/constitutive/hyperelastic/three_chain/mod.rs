@@ -0,0 +1,127 @@
+#[cfg(feature = "extern")]
+pub mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::physics::single_chain::ForceLaw;
+use super::network::
+{
+    self,
+    Tensor
+};
+
+/// The three-chain hyperelastic constitutive model.
+pub struct ThreeChain<T: ForceLaw>
+{
+    /// The single-chain model of each chain in the network.
+    pub chain: T,
+
+    /// The temperature in units of K.
+    pub temperature: f64
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(ThreeChain<T: ForceLaw>, try_init, chain: T, temperature: f64);
+
+/// The implemented functionality of the three-chain hyperelastic constitutive model.
+impl<T: ForceLaw> ThreeChain<T>
+{
+    /// Initializes and returns an instance of the model using the given single-chain model.
+    pub fn init(chain: T, temperature: f64) -> Self
+    {
+        ThreeChain
+        {
+            chain,
+            temperature
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(chain: T, temperature: f64) -> Result<Self, Error>
+    {
+        error::positive("temperature", &temperature)?;
+        Ok(Self::init(chain, temperature))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        network::uniaxial_tension(&|deformation_gradient| self.cauchy_stress(deformation_gradient), stretch)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        network::equibiaxial_tension(&|deformation_gradient| self.cauchy_stress(deformation_gradient), stretch)
+    }
+    /// The deviatoric nondimensional Cauchy stress as a function of the deformation gradient, where the chains lie along the principal directions of the isochoric part of the deformation gradient.
+    pub fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        let (left_cauchy_green, jacobian) = network::left_cauchy_green(deformation_gradient);
+        let (eigenvalues, eigenvectors) = network::symmetric_eigen(&left_cauchy_green);
+        let mut kirchhoff_stress = [[0.0; 3]; 3];
+        eigenvalues.iter().enumerate().for_each(|(i, eigenvalue)|{
+            let chain_stretch = eigenvalue.sqrt();
+            let direction = [eigenvectors[0][i], eigenvectors[1][i], eigenvectors[2][i]];
+            network::add_scaled(&mut kirchhoff_stress, &(network::nondimensional_chain_tension(&self.chain, &self.temperature, &chain_stretch) * chain_stretch / 3.0), &network::outer(&direction, &direction));
+        });
+        network::deviatoric(&kirchhoff_stress, &jacobian)
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        network::uniaxial_tension_strain_energy_density(&|deformation_gradient| self.strain_energy_density(deformation_gradient), stretch)
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    pub fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        network::equibiaxial_tension_strain_energy_density(&|deformation_gradient| self.strain_energy_density(deformation_gradient), stretch)
+    }
+    /// The nondimensional strain energy density as a function of the deformation gradient, which is the average relative nondimensional Helmholtz free energy of the three chains.
+    pub fn strain_energy_density(&self, deformation_gradient: &Tensor) -> f64
+    {
+        let (left_cauchy_green, _) = network::left_cauchy_green(deformation_gradient);
+        let (eigenvalues, _) = network::symmetric_eigen(&left_cauchy_green);
+        eigenvalues.iter().map(|eigenvalue|
+            network::nondimensional_chain_helmholtz_free_energy(&self.chain, &self.temperature, &eigenvalue.sqrt())
+        ).sum::<f64>() / 3.0
+    }
+}
+
+/// The deformation modes of the three-chain hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::Hyperelastic for ThreeChain<T>
+{
+    fn uniaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension(stretch)
+    }
+    fn equibiaxial_tension(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension(stretch)
+    }
+}
+
+/// The general deformations of the three-chain hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::CauchyStress for ThreeChain<T>
+{
+    fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor
+    {
+        self.cauchy_stress(deformation_gradient)
+    }
+}
+
+/// The strain energy density of the three-chain hyperelastic constitutive model.
+impl<T: ForceLaw> crate::constitutive::StrainEnergy for ThreeChain<T>
+{
+    fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.uniaxial_tension_strain_energy_density(stretch)
+    }
+    fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64
+    {
+        self.equibiaxial_tension_strain_energy_density(stretch)
+    }
+}
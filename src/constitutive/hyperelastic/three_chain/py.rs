@@ -0,0 +1,150 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    PyArrayDyn,
+    PyReadonlyArrayDyn
+};
+use crate::physics::single_chain::ForceLaw;
+use super::super::network::py::
+{
+    force_law,
+    scalar_batch,
+    tensor_batch
+};
+
+pub fn register_module(_py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    parent_module.add_class::<ThreeChain>()?;
+    Ok(())
+}
+
+/// The three-chain hyperelastic constitutive model,
+///
+/// .. math::
+///     \beta\boldsymbol{\sigma}/n = \frac{1}{3J}\,\mathrm{dev}\sum_{i=1}^3\lambda_i\,\frac{\partial\vartheta}{\partial\lambda_i}\,\mathbf{n}_i\otimes\mathbf{n}_i,
+///
+/// where :math:`\lambda_i^2` and :math:`\mathbf{n}_i` are the eigenvalues and eigenvectors of :math:`\bar{\mathbf{B}}` and :math:`\vartheta` is the nondimensional Helmholtz free energy of the chain.
+///
+/// Args:
+///     chain: Any of the single-chain models in :mod:`polymers.physics.single_chain`, such as :class:`polymers.physics.single_chain.fjc.FJC`.
+///     temperature (float): The temperature :math:`T`.
+///
+/// Raises:
+///     TypeError: If the chain is not one of the single-chain models.
+///     ValueError: If any of the parameters are invalid.
+///
+#[pyclass]
+pub struct ThreeChain
+{
+    model: super::ThreeChain<Box<dyn ForceLaw + Send + Sync>>,
+
+    /// The single-chain model of each chain in the network.
+    #[pyo3(get)]
+    pub chain: PyObject,
+
+    /// The temperature in units of K.
+    #[pyo3(get)]
+    pub temperature: f64
+}
+
+#[pymethods]
+impl ThreeChain
+{
+    #[new]
+    pub fn init(chain: &PyAny, temperature: f64) -> PyResult<Self>
+    {
+        Ok(ThreeChain
+        {
+            model: super::ThreeChain::try_init(force_law(chain)?, temperature)?,
+            chain: chain.into(),
+            temperature
+        })
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta(\sigma_{11}-\sigma_{22})/n`.
+    ///
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension(&stretch))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}=F_{22}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional Cauchy stress :math:`\beta(\sigma_{11}-\sigma_{33})/n`.
+    ///
+    pub fn equibiaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension(&stretch))
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    ///
+    /// Returns:
+    ///     float: The nondimensional initial shear modulus :math:`\beta G/n`.
+    ///
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::Hyperelastic::nondimensional_shear_modulus(&self.model)
+    }
+    /// The deviatoric nondimensional Cauchy stress as a function of the deformation gradient.
+    ///
+    /// Args:
+    ///     deformation_gradient (numpy.ndarray): The deformation gradient :math:`\mathbf{F}`, with trailing dimensions of three by three.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The deviatoric nondimensional Cauchy stress :math:`\beta\boldsymbol{\sigma}/n`.
+    ///
+    /// Raises:
+    ///     ValueError: If the trailing dimensions are not three by three.
+    ///
+    pub fn cauchy_stress<'py>(&self, py: Python<'py>, deformation_gradient: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        tensor_batch(py, deformation_gradient, |deformation_gradient| self.model.cauchy_stress(deformation_gradient))
+    }
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn uniaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.uniaxial_tension_strain_energy_density(&stretch))
+    }
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch :math:`F_{11}=F_{22}`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`.
+    ///
+    pub fn equibiaxial_tension_strain_energy_density<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::batch(py, stretch, |stretch: f64| self.model.equibiaxial_tension_strain_energy_density(&stretch))
+    }
+    /// The nondimensional strain energy density as a function of the deformation gradient.
+    ///
+    /// Args:
+    ///     deformation_gradient (numpy.ndarray): The deformation gradient :math:`\mathbf{F}`, with trailing dimensions of three by three.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional strain energy density :math:`\beta W/n`, with the trailing dimensions removed.
+    ///
+    /// Raises:
+    ///     ValueError: If the trailing dimensions are not three by three.
+    ///
+    pub fn strain_energy_density<'py>(&self, py: Python<'py>, deformation_gradient: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        scalar_batch(py, deformation_gradient, |deformation_gradient| self.model.strain_energy_density(deformation_gradient))
+    }
+}
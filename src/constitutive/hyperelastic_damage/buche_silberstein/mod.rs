@@ -4,27 +4,57 @@
 #[cfg(feature = "python")]
 pub mod py;
 
+use crate::constitutive::distribution::ChainLengthDistribution;
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::math::gauss_legendre;
 use crate::physics::single_chain::efjc::thermodynamics::isometric::asymptotic::reduced::legendre::
 {
     nondimensional_force,
     nondimensional_relative_helmholtz_free_energy
 };
+use crate::physics::
+{
+    BOLTZMANN_CONSTANT,
+    PLANCK_CONSTANT
+};
+use crate::physics::single_chain::ufjc::morse::
+{
+    rupture::isotensional::nondimensional_breaking_rate,
+    thermodynamics::isometric::asymptotic::reduced::legendre::nondimensional_force as morse_nondimensional_force
+};
+pub use crate::constitutive::hyperelastic::
+{
+    Method,
+    Quadrature
+};
 use std::
 {
     array::from_fn,
     f64::consts::PI
 };
 
-const NUMGRID: usize = 256;
+/// The default number of grid points in each direction.
+pub const NUMGRID: usize = 256;
 
 /// The Buche-Silberstein hyperelastic damage constitutive model.
 pub struct BucheSilberstein
 {
-    element: [[f64; NUMGRID]; NUMGRID],
+    element: Vec<f64>,
     factor: f64,
-    grid: [f64; NUMGRID],
-    method: u8,
+    grid: Vec<f64>,
+    method: Method,
     normalization: f64,
+    weight: Vec<f64>,
+
+    /// The number of grid points in each direction.
+    pub number_of_grid_points: usize,
+
+    /// The quadrature rule of the grid.
+    pub quadrature: Quadrature,
 
     /// The nondimensional critical extension which irreversibly breaks chains.
     pub nondimensional_critical_extension: f64,
@@ -39,56 +69,89 @@ pub struct BucheSilberstein
     pub swelling_ratio: f64
 }
 
-pub fn init(method: u8, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64) -> ([[f64; NUMGRID]; NUMGRID], f64, [f64; NUMGRID], f64)
+#[cfg(feature = "serde")]
+crate::config::parameters!(BucheSilberstein, try_init_with_grid, method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64);
+
+pub fn init(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64) -> (Vec<f64>, f64, Vec<f64>, Vec<f64>, f64)
 {
-    let grid: [f64; NUMGRID] = from_fn(|i|
-        nondimensional_critical_extension * (i + 1) as f64 / NUMGRID as f64
-    );
-    let dz = grid[1] - grid[0];
-    let mut element = [[0.0_f64; NUMGRID]; NUMGRID];
-    element.iter_mut().zip(grid.iter()).for_each(|(element_i, z_i)|
-        element_i.iter_mut().zip(grid.iter()).for_each(|(element_ij, r_j)|
-            *element_ij = nondimensional_force(&nondimensional_link_stiffness, &(z_i.powi(2) + r_j.powi(2)).sqrt())
+    let (grid, weight): (Vec<f64>, Vec<f64>) = match quadrature
+    {
+        Quadrature::Uniform => (0..number_of_grid_points).map(|i|
+            (nondimensional_critical_extension * (i + 1) as f64 / number_of_grid_points as f64, nondimensional_critical_extension / number_of_grid_points as f64)
+        ).unzip(),
+        Quadrature::GaussLegendre => {
+            let (nodes, weights) = gauss_legendre(&number_of_grid_points);
+            nodes.iter().zip(weights.iter()).map(|(x_i, weight_i)|
+                (0.5 * nondimensional_critical_extension * (x_i + 1.0), 0.5 * nondimensional_critical_extension * weight_i)
+            ).unzip()
+        }
+    };
+    let element = grid.iter().zip(weight.iter()).flat_map(|(z_i, weight_i)|
+        grid.iter().zip(weight.iter()).map(move |(r_j, weight_j)|
+            nondimensional_force(&nondimensional_link_stiffness, &(z_i.powi(2) + r_j.powi(2)).sqrt())
                 * (2.0 * z_i.powi(2) - r_j.powi(2)) * 2.0 * PI * (number_of_links as f64) * r_j
-                / (z_i.powi(2) + r_j.powi(2)).sqrt() * dz.powi(2) / swelling_ratio.powi(3)
+                / (z_i.powi(2) + r_j.powi(2)).sqrt() * weight_i * weight_j / swelling_ratio.powi(3)
         )
-    );
+    ).collect();
     let factor;
     let normalization;
     match method {
-        1 => panic!("Method number 1 not yet implemented."),
-        2 => {
+        Method::GibbsLegendre => {
             factor = 0.0;
-            normalization = grid.iter().map(|gamma_i|
-                gamma_i.powi(2) / nondimensional_relative_helmholtz_free_energy(
+            normalization = grid.iter().zip(weight.iter()).map(|(gamma_i, weight_i)|
+                gamma_i.powi(2) * weight_i / nondimensional_relative_helmholtz_free_energy(
                     &number_of_links, &nondimensional_link_stiffness, gamma_i
                 ).exp()
-            ).sum::<f64>() * 4.0 * PI * dz / swelling_ratio;
+            ).sum::<f64>() * 4.0 * PI / swelling_ratio;
         }
-        3 => {
+        Method::GibbsLegendreGaussian => {
             factor = 1.5 * number_of_links as f64
                 * nondimensional_link_stiffness * (nondimensional_link_stiffness + 1.0)
                 / (nondimensional_link_stiffness.powi(2) + 6.0 * nondimensional_link_stiffness + 3.0);
-            normalization = grid.iter().map(|gamma_i|
-                gamma_i.powi(2) / (factor * gamma_i.powi(2)).exp()
-            ).sum::<f64>() * 4.0 * PI * dz / swelling_ratio;
+            normalization = grid.iter().zip(weight.iter()).map(|(gamma_i, weight_i)|
+                gamma_i.powi(2) * weight_i / (factor * gamma_i.powi(2)).exp()
+            ).sum::<f64>() * 4.0 * PI / swelling_ratio;
         }
-        _ => panic!("Invalid method number.")
     }
-    (element, factor, grid, normalization)
+    (element, factor, grid, weight, normalization)
+}
+
+/// Validates that the number of grid points is enough to resolve the integrals.
+fn validate_number_of_grid_points(number_of_grid_points: &usize) -> Result<(), Error>
+{
+    if number_of_grid_points >= &2
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Parameter("number_of_grid_points", *number_of_grid_points as f64, "at least two"))
+    }
+}
+
+/// Validates that the stretch is at least one, since the model is only defined in tension.
+fn validate_stretch(stretch: &f64) -> Result<(), Error>
+{
+    if stretch >= &1.0
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Domain("stretch", *stretch, "at least one"))
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn uniaxial_tension(element: &[[f64; NUMGRID]], factor: &f64, grid: &[f64], normalization: &f64, method: &u8, nondimensional_critical_extension: &f64, nondimensional_link_stiffness: &f64, number_of_links: &u8, swelling_ratio: &f64, stretch: &f64, maximum_previous_stretch: &f64) -> [f64; 2]
+pub fn uniaxial_tension(element: &[f64], factor: &f64, grid: &[f64], weight: &[f64], normalization: &f64, method: &Method, nondimensional_critical_extension: &f64, nondimensional_link_stiffness: &f64, number_of_links: &u8, swelling_ratio: &f64, stretch: &f64, maximum_previous_stretch: &f64) -> Result<[f64; 2], Error>
 {
-    assert!(stretch >= &1.0);
+    validate_stretch(stretch)?;
     let cauchy_stress: f64;
     let total_probability: f64;
     let j_1_3 = swelling_ratio.powf(1.0/3.0);
     match method {
-        1 => panic!("Method number 1 not yet implemented."),
-        2 => {
-            cauchy_stress = element.iter().zip(grid.iter()).flat_map(|(element_i, z_i)|
+        Method::GibbsLegendre => {
+            cauchy_stress = element.chunks_exact(grid.len()).zip(grid.iter()).flat_map(|(element_i, z_i)|
                 element_i.iter().zip(grid.iter()).map(|(element_ij, r_j)|
                     element_ij / nondimensional_relative_helmholtz_free_energy(
                         number_of_links, nondimensional_link_stiffness, &((*z_i / stretch).powi(2) + stretch * r_j.powi(2)).sqrt()
@@ -98,19 +161,19 @@ pub fn uniaxial_tension(element: &[[f64; NUMGRID]], factor: &f64, grid: &[f64],
                     ) as u8 as f64)
                 )
             ).sum::<f64>() / normalization;
-            total_probability = grid.iter().flat_map(|z_i|
-                grid.iter().map(|r_j|
-                    r_j / nondimensional_relative_helmholtz_free_energy(
+            total_probability = grid.iter().zip(weight.iter()).flat_map(|(z_i, weight_i)|
+                grid.iter().zip(weight.iter()).map(move |(r_j, weight_j)|
+                    r_j * weight_i * weight_j / nondimensional_relative_helmholtz_free_energy(
                         number_of_links, nondimensional_link_stiffness, &((*z_i / stretch).powi(2) + stretch * r_j.powi(2)).sqrt()
                     ).exp() * ((
                         ((*z_i * maximum_previous_stretch / stretch).powi(2) + stretch / maximum_previous_stretch * r_j.powi(2)).sqrt()
                         <= *nondimensional_critical_extension
                     ) as u8 as f64)
                 )
-            ).sum::<f64>() / normalization * 4.0 * PI * ((grid[1] - grid[0]) / swelling_ratio).powi(2);
+            ).sum::<f64>() / normalization * 4.0 * PI / swelling_ratio.powi(2);
         }
-        3 => {
-            cauchy_stress = element.iter().zip(grid.iter()).flat_map(|(element_i, z_i)|
+        Method::GibbsLegendreGaussian => {
+            cauchy_stress = element.chunks_exact(grid.len()).zip(grid.iter()).flat_map(|(element_i, z_i)|
                 element_i.iter().zip(grid.iter()).map(|(element_ij, r_j)|
                     element_ij / (factor * ((*z_i / stretch).powi(2) + stretch * r_j.powi(2)) / j_1_3.powi(2)).exp() * ((
                         ((*z_i * maximum_previous_stretch / stretch).powi(2) + stretch / maximum_previous_stretch * r_j.powi(2)).sqrt()
@@ -118,30 +181,124 @@ pub fn uniaxial_tension(element: &[[f64; NUMGRID]], factor: &f64, grid: &[f64],
                     ) as u8 as f64)
                 )
             ).sum::<f64>() / normalization;
-            total_probability = grid.iter().flat_map(|z_i|
-                grid.iter().map(|r_j|
-                    r_j / (factor * ((*z_i / stretch).powi(2) + stretch * r_j.powi(2)) / j_1_3.powi(2)).exp() * ((
+            total_probability = grid.iter().zip(weight.iter()).flat_map(|(z_i, weight_i)|
+                grid.iter().zip(weight.iter()).map(move |(r_j, weight_j)|
+                    r_j * weight_i * weight_j / (factor * ((*z_i / stretch).powi(2) + stretch * r_j.powi(2)) / j_1_3.powi(2)).exp() * ((
                         ((*z_i * maximum_previous_stretch / stretch).powi(2) + stretch / maximum_previous_stretch * r_j.powi(2)).sqrt()
                         <= *nondimensional_critical_extension
                     ) as u8 as f64)
                 )
-            ).sum::<f64>() / normalization * 4.0 * PI * ((grid[1] - grid[0]) / swelling_ratio).powi(2);
+            ).sum::<f64>() / normalization * 4.0 * PI / swelling_ratio.powi(2);
         }
-        _ => panic!("Invalid method number.")
     }
-    [cauchy_stress, total_probability]
+    Ok([cauchy_stress, total_probability])
+}
+
+/// Validates that the stretch and nondimensional time histories are the same length, that every stretch is at least one, and that time does not decrease.
+fn validate_histories(stretch: &[f64], nondimensional_time: &[f64]) -> Result<(), Error>
+{
+    if nondimensional_time.len() != stretch.len()
+    {
+        return Err(Error::Domain("nondimensional_time", nondimensional_time.len() as f64, "as long as the stretch"))
+    }
+    stretch.iter().try_for_each(validate_stretch)?;
+    nondimensional_time.windows(2).try_for_each(|time|
+        if time[1] >= time[0]
+        {
+            Ok(())
+        }
+        else
+        {
+            Err(Error::Domain("nondimensional_time", time[1], "nondecreasing"))
+        }
+    )
+}
+
+/// Nondimensionalizes the link energy in units of J/mol by the thermal energy and the time in units of s by the Planck constant over the thermal energy,
+/// or an error if the link energy or temperature is not positive.
+fn nondimensionalize(link_energy: &f64, temperature: &f64, time: &[f64]) -> Result<(f64, Vec<f64>), Error>
+{
+    error::positive("link_energy", link_energy)?;
+    error::positive("temperature", temperature)?;
+    let thermal_energy = BOLTZMANN_CONSTANT * temperature;
+    Ok((link_energy / thermal_energy, time.iter().map(|time_k| time_k * thermal_energy / PLANCK_CONSTANT).collect()))
+}
+
+/// The nondimensional Cauchy stress and total probability of intact chains as functions of stretch and nondimensional time in uniaxial tension,
+/// or an error if the nondimensional link energy is not positive or the histories are invalid.
+///
+/// The force on each chain and its rate of breaking are those of a Morse-FJC chain with the given nondimensional link energy,
+/// and chains stretched beyond the maximum extension of the Morse links are broken.
+#[allow(clippy::too_many_arguments)]
+pub fn uniaxial_tension_rate_dependent(factor: &f64, grid: &[f64], weight: &[f64], normalization: &f64, method: &Method, nondimensional_critical_extension: &f64, nondimensional_link_energy: &f64, nondimensional_link_stiffness: &f64, number_of_links: &u8, swelling_ratio: &f64, stretch: &[f64], nondimensional_time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+{
+    error::positive("nondimensional_link_energy", nondimensional_link_energy)?;
+    validate_histories(stretch, nondimensional_time)?;
+    let j_1_3 = swelling_ratio.powf(1.0/3.0);
+    let equilibrium_distribution: Vec<Vec<f64>> = grid.iter().map(|z_i|
+        grid.iter().map(|r_j|{
+            let gamma_0 = (z_i.powi(2) + r_j.powi(2)).sqrt();
+            let probability = match method {
+                Method::GibbsLegendre => 1.0 / nondimensional_relative_helmholtz_free_energy(
+                    number_of_links, nondimensional_link_stiffness, &gamma_0
+                ).exp(),
+                Method::GibbsLegendreGaussian => 1.0 / (factor * gamma_0.powi(2) / j_1_3.powi(2)).exp()
+            };
+            probability * ((gamma_0 <= *nondimensional_critical_extension) as u8 as f64)
+        }).collect()
+    ).collect();
+    let mut breaking_rate = vec![vec![0.0_f64; grid.len()]; grid.len()];
+    let mut survival_probability = vec![vec![1.0_f64; grid.len()]; grid.len()];
+    let mut previous_time = nondimensional_time.first().copied().unwrap_or(0.0);
+    Ok(stretch.iter().zip(nondimensional_time.iter()).map(|(stretch_k, time_k)|{
+        let time_step = time_k - previous_time;
+        previous_time = *time_k;
+        let mut cauchy_stress = 0.0;
+        let mut total_probability = 0.0;
+        grid.iter().enumerate().for_each(|(i, z_i)|
+            grid.iter().enumerate().for_each(|(j, r_j)|{
+                let z = z_i * stretch_k;
+                let r = r_j / stretch_k.sqrt();
+                let gamma = (z.powi(2) + r.powi(2)).sqrt();
+                let eta = morse_nondimensional_force(nondimensional_link_stiffness, nondimensional_link_energy, &gamma);
+                if !eta.is_finite()
+                {
+                    survival_probability[i][j] = 0.0;
+                    return
+                }
+                let rate = nondimensional_breaking_rate(
+                    number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, &eta
+                );
+                survival_probability[i][j] *= (-0.5 * (breaking_rate[i][j] + rate) * time_step).exp();
+                breaking_rate[i][j] = rate;
+                let density = r_j * weight[i] * weight[j] * equilibrium_distribution[i][j] * survival_probability[i][j];
+                cauchy_stress += density * eta * (2.0 * z.powi(2) - r.powi(2)) / gamma;
+                total_probability += density;
+            })
+        );
+        [
+            cauchy_stress * 2.0 * PI * (*number_of_links as f64) / swelling_ratio.powi(3) / normalization,
+            total_probability * 4.0 * PI / swelling_ratio.powi(2) / normalization
+        ]
+    }).collect())
 }
 
 /// The implemented functionality of the Buche-Silberstein hyperelastic damage constitutive model.
 impl BucheSilberstein
 {
-    /// Initializes and returns an instance of the model.
-    /// 1. Helmholtz method for both the Helmholtz free energy and the equilibrium distribution.
-    /// 2. Gibbs-Legendre method for both the Helmholtz free energy and the equilibrium distribution.
-    /// 3. Gibbs-Legendre for the Helmholtz free energy and a Gaussian equilibrium distribution.
-    pub fn init(method: u8, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64) -> Self
+    /// Initializes and returns an instance of the model using the given method and the default uniform grid.
+    pub fn init(method: Method, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64) -> Self
     {
-        let (element, factor, grid, normalization) = init(method, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio);
+        Self::init_with_grid(method, Quadrature::Uniform, NUMGRID, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio)
+    }
+    /// Initializes and returns an instance of the model using the given method and grid, and panics if there are fewer than two grid points.
+    pub fn init_with_grid(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64) -> Self
+    {
+        if let Err(error) = validate_number_of_grid_points(&number_of_grid_points)
+        {
+            panic!("{}", error)
+        }
+        let (element, factor, grid, weight, normalization) = init(method, quadrature, number_of_grid_points, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio);
         BucheSilberstein
         {
             element,
@@ -149,13 +306,50 @@ impl BucheSilberstein
             grid,
             method,
             normalization,
+            weight,
+            number_of_grid_points,
+            quadrature,
             nondimensional_critical_extension,
             nondimensional_link_stiffness,
             number_of_links,
             swelling_ratio
         }
     }
-    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(method: Method, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64) -> Result<Self, Error>
+    {
+        Self::try_init_with_grid(method, Quadrature::Uniform, NUMGRID, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio)
+    }
+    /// Initializes and returns an instance of the model using the given grid, or an error if any of the parameters are invalid.
+    pub fn try_init_with_grid(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64) -> Result<Self, Error>
+    {
+        validate_number_of_grid_points(&number_of_grid_points)?;
+        error::positive("nondimensional_critical_extension", &nondimensional_critical_extension)?;
+        error::positive("nondimensional_link_stiffness", &nondimensional_link_stiffness)?;
+        error::number_of_links(&number_of_links)?;
+        error::positive("swelling_ratio", &swelling_ratio)?;
+        Ok(Self::init_with_grid(method, quadrature, number_of_grid_points, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio))
+    }
+    /// The method used to evaluate the model.
+    pub fn method(&self) -> Method
+    {
+        self.method
+    }
+    /// The nondimensional Cauchy stress in monotonic uniaxial tension at the given stretch for each of the given numbers of grid points,
+    /// along with the relative change in the stress from the previous number of grid points.
+    #[allow(clippy::too_many_arguments)]
+    pub fn convergence(method: Method, quadrature: Quadrature, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64, stretch: &f64, numbers_of_grid_points: &[usize]) -> Vec<(usize, f64, f64)>
+    {
+        let mut previous_stress = f64::NAN;
+        numbers_of_grid_points.iter().map(|number_of_grid_points|{
+            let model = Self::init_with_grid(method, quadrature, *number_of_grid_points, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio);
+            let [stress, _] = uniaxial_tension(&model.element, &model.factor, &model.grid, &model.weight, &model.normalization, &model.method, &model.nondimensional_critical_extension, &model.nondimensional_link_stiffness, &model.number_of_links, &model.swelling_ratio, stretch, stretch).unwrap_or([f64::NAN; 2]);
+            let relative_change = ((stress - previous_stress) / stress).abs();
+            previous_stress = stress;
+            (*number_of_grid_points, stress, relative_change)
+        }).collect()
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension, which is not a number wherever the stretch is less than one.
     pub fn uniaxial_tension<const L: usize>(&self, stretch: &[f64]) -> [[f64; 2]; L]
     {
         let mut maximum_previous_stretch = 1.0;
@@ -166,7 +360,167 @@ impl BucheSilberstein
             } else {
                 maximum_previous_stretch
             };
-            uniaxial_tension(&self.element, &self.factor, &self.grid, &self.normalization, &self.method, &self.nondimensional_critical_extension, &self.nondimensional_link_stiffness, &self.number_of_links, &self.swelling_ratio, &stretch[i], &maximum_previous_stretch)
+            uniaxial_tension(&self.element, &self.factor, &self.grid, &self.weight, &self.normalization, &self.method, &self.nondimensional_critical_extension, &self.nondimensional_link_stiffness, &self.number_of_links, &self.swelling_ratio, &stretch[i], &maximum_previous_stretch).unwrap_or([f64::NAN; 2])
         })
     }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::nondimensional_shear_modulus(|stretch| self.uniaxial_tension::<1>(&[*stretch])[0][0])
+    }
+    /// The nondimensional Cauchy stress and total probability of intact chains as functions of stretch and nondimensional time in uniaxial tension,
+    /// where chains break at the force-dependent rate of Morse links with the given nondimensional link energy
+    /// rather than at the nondimensional critical extension, which here only bounds the reference configuration,
+    /// or an error if the nondimensional link energy is not positive or the histories are invalid.
+    pub fn uniaxial_tension_rate_dependent(&self, nondimensional_link_energy: &f64, stretch: &[f64], nondimensional_time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        uniaxial_tension_rate_dependent(&self.factor, &self.grid, &self.weight, &self.normalization, &self.method, &self.nondimensional_critical_extension, nondimensional_link_energy, &self.nondimensional_link_stiffness, &self.number_of_links, &self.swelling_ratio, stretch, nondimensional_time)
+    }
+    /// The nondimensional Cauchy stress and total probability of intact chains as functions of stretch and time in units of s in uniaxial tension,
+    /// given the link energy in units of J/mol and the temperature in units of K,
+    /// or an error if the link energy or temperature is not positive or the histories are invalid.
+    pub fn uniaxial_tension_rate_dependent_at_temperature(&self, link_energy: &f64, temperature: &f64, stretch: &[f64], time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        let (nondimensional_link_energy, nondimensional_time) = nondimensionalize(link_energy, temperature, time)?;
+        self.uniaxial_tension_rate_dependent(&nondimensional_link_energy, stretch, &nondimensional_time)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension, or an error if the stretch history is invalid.
+    pub fn try_uniaxial_tension<const L: usize>(&self, stretch: &[f64]) -> Result<[[f64; 2]; L], Error>
+    {
+        if stretch.len() < L
+        {
+            return Err(Error::Domain("stretch", stretch.len() as f64, "at least as long as the output"))
+        }
+        stretch.iter().try_for_each(validate_stretch)?;
+        Ok(self.uniaxial_tension(stretch))
+    }
+}
+
+/// Accumulates the nondimensional Cauchy stress and total probability of intact chains of each component, weighted by its number fraction.
+fn average(total: &mut [[f64; 2]], number_fraction: &f64, results: &[[f64; 2]])
+{
+    total.iter_mut().zip(results.iter()).for_each(|(total_i, result_i)|{
+        total_i[0] += number_fraction * result_i[0];
+        total_i[1] += number_fraction * result_i[1];
+    });
+}
+
+/// The Buche-Silberstein hyperelastic damage constitutive model of a network whose chains have a distribution of lengths,
+/// where the stress and total probability of intact chains are averaged over the number fractions of chains with each number of links.
+pub struct PolydisperseBucheSilberstein
+{
+    components: Vec<(f64, BucheSilberstein)>,
+    method: Method,
+
+    /// The number of grid points in each direction.
+    pub number_of_grid_points: usize,
+
+    /// The quadrature rule of the grid.
+    pub quadrature: Quadrature,
+
+    /// The nondimensional critical extension which irreversibly breaks chains.
+    pub nondimensional_critical_extension: f64,
+
+    /// The nondimensional stiffness of each link in a chain.
+    pub nondimensional_link_stiffness: f64,
+
+    /// The distribution of the number of links in a chain.
+    pub distribution: ChainLengthDistribution,
+
+    /// The volumetric swelling ratio.
+    pub swelling_ratio: f64
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(PolydisperseBucheSilberstein, try_init_with_grid, method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution, swelling_ratio: f64);
+
+/// The implemented functionality of the polydisperse Buche-Silberstein hyperelastic damage constitutive model.
+impl PolydisperseBucheSilberstein
+{
+    /// Initializes and returns an instance of the model using the given method and the default uniform grid.
+    pub fn init(method: Method, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution, swelling_ratio: f64) -> Self
+    {
+        Self::init_with_grid(method, Quadrature::Uniform, NUMGRID, nondimensional_critical_extension, nondimensional_link_stiffness, distribution, swelling_ratio)
+    }
+    /// Initializes and returns an instance of the model using the given method and grid.
+    pub fn init_with_grid(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution, swelling_ratio: f64) -> Self
+    {
+        PolydisperseBucheSilberstein
+        {
+            components: distribution.number_fractions().into_iter().map(|(number_of_links, number_fraction)|
+                (number_fraction, BucheSilberstein::init_with_grid(method, quadrature, number_of_grid_points, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio))
+            ).collect(),
+            method,
+            number_of_grid_points,
+            quadrature,
+            nondimensional_critical_extension,
+            nondimensional_link_stiffness,
+            distribution,
+            swelling_ratio
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(method: Method, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution, swelling_ratio: f64) -> Result<Self, Error>
+    {
+        Self::try_init_with_grid(method, Quadrature::Uniform, NUMGRID, nondimensional_critical_extension, nondimensional_link_stiffness, distribution, swelling_ratio)
+    }
+    /// Initializes and returns an instance of the model using the given grid, or an error if any of the parameters are invalid.
+    pub fn try_init_with_grid(method: Method, quadrature: Quadrature, number_of_grid_points: usize, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution, swelling_ratio: f64) -> Result<Self, Error>
+    {
+        validate_number_of_grid_points(&number_of_grid_points)?;
+        error::positive("nondimensional_critical_extension", &nondimensional_critical_extension)?;
+        error::positive("nondimensional_link_stiffness", &nondimensional_link_stiffness)?;
+        distribution.validate()?;
+        error::positive("swelling_ratio", &swelling_ratio)?;
+        Ok(Self::init_with_grid(method, quadrature, number_of_grid_points, nondimensional_critical_extension, nondimensional_link_stiffness, distribution, swelling_ratio))
+    }
+    /// The method used to evaluate the model.
+    pub fn method(&self) -> Method
+    {
+        self.method
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    pub fn uniaxial_tension<const L: usize>(&self, stretch: &[f64]) -> [[f64; 2]; L]
+    {
+        let mut total = [[0.0; 2]; L];
+        self.components.iter().for_each(|(number_fraction, model)|
+            average(&mut total, number_fraction, &model.uniaxial_tension::<L>(stretch))
+        );
+        total
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::nondimensional_shear_modulus(|stretch| self.uniaxial_tension::<1>(&[*stretch])[0][0])
+    }
+    /// The nondimensional Cauchy stress and total probability of intact chains as functions of stretch and nondimensional time in uniaxial tension,
+    /// where chains break at the force-dependent rate of Morse links with the given nondimensional link energy,
+    /// or an error if the nondimensional link energy is not positive or the histories are invalid.
+    pub fn uniaxial_tension_rate_dependent(&self, nondimensional_link_energy: &f64, stretch: &[f64], nondimensional_time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        let mut total = vec![[0.0; 2]; stretch.len()];
+        self.components.iter().try_for_each(|(number_fraction, model)|{
+            average(&mut total, number_fraction, &model.uniaxial_tension_rate_dependent(nondimensional_link_energy, stretch, nondimensional_time)?);
+            Ok::<(), Error>(())
+        })?;
+        Ok(total)
+    }
+    /// The nondimensional Cauchy stress and total probability of intact chains as functions of stretch and time in units of s in uniaxial tension,
+    /// given the link energy in units of J/mol and the temperature in units of K,
+    /// or an error if the link energy or temperature is not positive or the histories are invalid.
+    pub fn uniaxial_tension_rate_dependent_at_temperature(&self, link_energy: &f64, temperature: &f64, stretch: &[f64], time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        let (nondimensional_link_energy, nondimensional_time) = nondimensionalize(link_energy, temperature, time)?;
+        self.uniaxial_tension_rate_dependent(&nondimensional_link_energy, stretch, &nondimensional_time)
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension, or an error if the stretch history is invalid.
+    pub fn try_uniaxial_tension<const L: usize>(&self, stretch: &[f64]) -> Result<[[f64; 2]; L], Error>
+    {
+        let mut total = [[0.0; 2]; L];
+        self.components.iter().try_for_each(|(number_fraction, model)|{
+            average(&mut total, number_fraction, &model.try_uniaxial_tension::<L>(stretch)?);
+            Ok::<(), Error>(())
+        })?;
+        Ok(total)
+    }
 }
\ No newline at end of file
@@ -5,31 +5,53 @@ use numpy::
     PyArrayDyn,
     PyReadonlyArrayDyn
 };
+use crate::constitutive::distribution::py::ChainLengthDistribution;
 use super::
 {
+    Method,
     NUMGRID,
+    Quadrature,
     init
 };
 
 pub fn register_module(_py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
 {
     parent_module.add_class::<BucheSilberstein>()?;
+    parent_module.add_class::<PolydisperseBucheSilberstein>()?;
+    parent_module.add_class::<Method>()?;
+    parent_module.add_class::<Quadrature>()?;
     Ok(())
 }
 
+/// Splits a history of the nondimensional Cauchy stress and total probability of intact chains into two arrays.
+fn histories<'py>(py: Python<'py>, results: Vec<[f64; 2]>) -> (&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)
+{
+    let results_1 = results.iter().map(|result| result[0]).collect();
+    let results_2 = results.iter().map(|result| result[1]).collect();
+    (PyArray::from_vec(py, results_1).to_dyn(), PyArray::from_vec(py, results_2).to_dyn())
+}
+
 /// The Buche-Silberstein hyperelastic damage constitutive model.
 ///
-/// #. Helmholtz method for both the Helmholtz free energy and the equilibrium distribution.
-/// #. Gibbs-Legendre method for both the Helmholtz free energy and the equilibrium distribution.
-/// #. Gibbs-Legendre for the Helmholtz free energy and a Gaussian equilibrium distribution.
+/// The method is one of the members of :class:`Method`.
+/// The grid uses ``number_of_grid_points`` points in each direction, which default to 256, placed using one of the members of :class:`Quadrature`.
 #[pyclass]
 pub struct BucheSilberstein
 {
-    element: [[f64; NUMGRID]; NUMGRID],
+    element: Vec<f64>,
     factor: f64,
-    grid: [f64; NUMGRID],
-    method: u8,
+    grid: Vec<f64>,
+    method: Method,
     normalization: f64,
+    weight: Vec<f64>,
+
+    /// The number of grid points in each direction.
+    #[pyo3(get)]
+    pub number_of_grid_points: usize,
+
+    /// The quadrature rule of the grid.
+    #[pyo3(get)]
+    pub quadrature: Quadrature,
 
     /// The nondimensional critical extension which irreversibly breaks chains.
     #[pyo3(get)]
@@ -52,21 +74,60 @@ pub struct BucheSilberstein
 impl BucheSilberstein
 {
     #[new]
-    pub fn init(method: u8, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64) -> Self
+    #[pyo3(signature = (method, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio, quadrature = Quadrature::Uniform, number_of_grid_points = NUMGRID))]
+    pub fn init(method: Method, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64, quadrature: Quadrature, number_of_grid_points: usize) -> PyResult<Self>
     {
-        let (element, factor, grid, normalization) = init(method, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio);
-        BucheSilberstein
+        super::validate_number_of_grid_points(&number_of_grid_points)?;
+        crate::error::positive("nondimensional_critical_extension", &nondimensional_critical_extension)?;
+        crate::error::positive("nondimensional_link_stiffness", &nondimensional_link_stiffness)?;
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("swelling_ratio", &swelling_ratio)?;
+        let (element, factor, grid, weight, normalization) = init(method, quadrature, number_of_grid_points, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio);
+        Ok(BucheSilberstein
         {
             element,
             factor,
             grid,
             method,
             normalization,
+            weight,
+            number_of_grid_points,
+            quadrature,
             nondimensional_critical_extension,
             nondimensional_link_stiffness,
             number_of_links,
             swelling_ratio
-        }
+        })
+    }
+    /// The nondimensional Cauchy stress in monotonic uniaxial tension at the given stretch for each of the given numbers of grid points.
+    ///
+    /// Args:
+    ///     method (Method): The method.
+    ///     quadrature (Quadrature): The quadrature rule of the grid.
+    ///     nondimensional_critical_extension (float): The nondimensional critical extension which irreversibly breaks chains.
+    ///     nondimensional_link_stiffness (float): The nondimensional stiffness of each link in a chain.
+    ///     number_of_links (int): The number of links in a chain.
+    ///     swelling_ratio (float): The volumetric swelling ratio.
+    ///     stretch (float): The applied stretch :math:`F_{11}`.
+    ///     numbers_of_grid_points (list): The numbers of grid points in each direction.
+    ///
+    /// Returns:
+    ///     list: Tuples of the number of grid points, the nondimensional Cauchy stress :math:`\beta\sigma_{11}/n`, and its relative change from the previous number of grid points.
+    ///
+    /// Raises:
+    ///     ValueError: If any of the parameters are invalid.
+    ///
+    #[staticmethod]
+    #[allow(clippy::too_many_arguments)]
+    pub fn convergence(method: Method, quadrature: Quadrature, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, number_of_links: u8, swelling_ratio: f64, stretch: f64, numbers_of_grid_points: Vec<usize>) -> PyResult<Vec<(usize, f64, f64)>>
+    {
+        numbers_of_grid_points.iter().try_for_each(super::validate_number_of_grid_points)?;
+        crate::error::positive("nondimensional_critical_extension", &nondimensional_critical_extension)?;
+        crate::error::positive("nondimensional_link_stiffness", &nondimensional_link_stiffness)?;
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("swelling_ratio", &swelling_ratio)?;
+        super::validate_stretch(&stretch)?;
+        Ok(super::BucheSilberstein::convergence(method, quadrature, nondimensional_critical_extension, nondimensional_link_stiffness, number_of_links, swelling_ratio, &stretch, &numbers_of_grid_points))
     }
     /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension,
     ///
@@ -93,7 +154,10 @@ impl BucheSilberstein
     ///       - (*numpy.ndarray*) -
     ///         The total probability of intact chains :math:`P^\mathrm{tot}(t)`.
     ///
-    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretches: PyReadonlyArrayDyn<f64>) -> (&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)
+    /// Raises:
+    ///     ValueError: If any stretch is less than one.
+    ///
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretches: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
     {
         let mut maximum_previous_stretch = 1.0;
         let results = stretches.as_array().iter().map(|stretch|{
@@ -104,14 +168,250 @@ impl BucheSilberstein
                 maximum_previous_stretch
             };
             super::uniaxial_tension(
-                &self.element, &self.factor, &self.grid, &self.normalization, &self.method,
+                &self.element, &self.factor, &self.grid, &self.weight, &self.normalization, &self.method,
                 &self.nondimensional_critical_extension, &self.nondimensional_link_stiffness,
                 &self.number_of_links, &self.swelling_ratio,
                 stretch, &maximum_previous_stretch
             )
-        }).collect::<Vec<[f64; 2]>>();
+        }).collect::<Result<Vec<[f64; 2]>, _>>()?;
         let results_1 = results.iter().map(|result| result[0]).collect();
         let results_2 = results.iter().map(|result| result[1]).collect();
-        (PyArray::from_vec(py, results_1).to_dyn(), PyArray::from_vec(py, results_2).to_dyn())
+        Ok((PyArray::from_vec(py, results_1).to_dyn(), PyArray::from_vec(py, results_2).to_dyn()))
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    ///
+    /// Returns:
+    ///     float: The nondimensional initial shear modulus :math:`\beta G/n`.
+    ///
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        crate::constitutive::nondimensional_shear_modulus(|stretch|
+            super::uniaxial_tension(
+                &self.element, &self.factor, &self.grid, &self.weight, &self.normalization, &self.method,
+                &self.nondimensional_critical_extension, &self.nondimensional_link_stiffness,
+                &self.number_of_links, &self.swelling_ratio,
+                stretch, stretch
+            ).map_or(f64::NAN, |[cauchy_stress, _]| cauchy_stress)
+        )
+    }
+    /// The nondimensional Cauchy stress as a function of stretch and nondimensional time in uniaxial tension,
+    /// where chains break at the force-dependent rate of Morse links with the given nondimensional link energy,
+    /// rather than at the nondimensional critical extension, which here only bounds the reference configuration.
+    /// The force on each chain is that of the asymptotic reduced Legendre Morse-FJC model, and chains stretched beyond the maximum extension of the Morse links are broken.
+    ///
+    /// Args:
+    ///     nondimensional_link_energy (float): The nondimensional energy of each link in a chain.
+    ///     stretch (numpy.ndarray): The applied stretch history :math:`F_{11}(t)`.
+    ///     nondimensional_time (numpy.ndarray): The nondimensional time :math:`k_BTt/h`.
+    ///
+    /// Returns:
+    ///     tuple:
+    ///       - (*numpy.ndarray*) -
+    ///         The nondimensional Cauchy stress :math:`\beta\sigma_{11}(t)/n`.
+    ///       - (*numpy.ndarray*) -
+    ///         The total probability of intact chains :math:`P^\mathrm{tot}(t)`.
+    ///
+    /// Raises:
+    ///     ValueError: If the nondimensional link energy is not positive, the histories differ in length, any stretch is less than one, or the time decreases.
+    ///
+    pub fn uniaxial_tension_rate_dependent<'py>(&self, py: Python<'py>, nondimensional_link_energy: f64, stretch: PyReadonlyArrayDyn<f64>, nondimensional_time: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
+    {
+        let stretch: Vec<f64> = stretch.as_array().iter().copied().collect();
+        let nondimensional_time: Vec<f64> = nondimensional_time.as_array().iter().copied().collect();
+        Ok(histories(py, super::uniaxial_tension_rate_dependent(
+            &self.factor, &self.grid, &self.weight, &self.normalization, &self.method,
+            &self.nondimensional_critical_extension, &nondimensional_link_energy, &self.nondimensional_link_stiffness,
+            &self.number_of_links, &self.swelling_ratio,
+            &stretch, &nondimensional_time
+        )?))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch and time in uniaxial tension,
+    /// where chains break at the force-dependent rate of Morse links with the given link energy at the given temperature.
+    ///
+    /// Args:
+    ///     link_energy (float): The energy of each link in a chain in units of J/mol.
+    ///     temperature (float): The temperature :math:`T` in units of K.
+    ///     stretch (numpy.ndarray): The applied stretch history :math:`F_{11}(t)`.
+    ///     time (numpy.ndarray): The time :math:`t` in units of s.
+    ///
+    /// Returns:
+    ///     tuple:
+    ///       - (*numpy.ndarray*) -
+    ///         The nondimensional Cauchy stress :math:`\beta\sigma_{11}(t)/n`.
+    ///       - (*numpy.ndarray*) -
+    ///         The total probability of intact chains :math:`P^\mathrm{tot}(t)`.
+    ///
+    /// Raises:
+    ///     ValueError: If the link energy or temperature is not positive, the histories differ in length, any stretch is less than one, or the time decreases.
+    ///
+    pub fn uniaxial_tension_rate_dependent_at_temperature<'py>(&self, py: Python<'py>, link_energy: f64, temperature: f64, stretch: PyReadonlyArrayDyn<f64>, time: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
+    {
+        let stretch: Vec<f64> = stretch.as_array().iter().copied().collect();
+        let time: Vec<f64> = time.as_array().iter().copied().collect();
+        let (nondimensional_link_energy, nondimensional_time) = super::nondimensionalize(&link_energy, &temperature, &time)?;
+        Ok(histories(py, super::uniaxial_tension_rate_dependent(
+            &self.factor, &self.grid, &self.weight, &self.normalization, &self.method,
+            &self.nondimensional_critical_extension, &nondimensional_link_energy, &self.nondimensional_link_stiffness,
+            &self.number_of_links, &self.swelling_ratio,
+            &stretch, &nondimensional_time
+        )?))
     }
 }
+
+/// The Buche-Silberstein hyperelastic damage constitutive model of a network whose chains have a distribution of lengths,
+/// where the stress and total probability of intact chains are averaged over the number fractions of chains with each number of links,
+///
+/// .. math::
+///     \beta\sigma_{11}(t)/n = \sum_N x_N\,\beta\sigma_{11}^{(N)}(t)/n,\qquad P^\mathrm{tot}(t) = \sum_N x_N\,P^\mathrm{tot}_{(N)}(t),
+///
+/// and the terms in each sum are those of :class:`BucheSilberstein` with :math:`N` links in a chain.
+#[pyclass]
+pub struct PolydisperseBucheSilberstein
+{
+    model: super::PolydisperseBucheSilberstein,
+
+    /// The number of grid points in each direction.
+    #[pyo3(get)]
+    pub number_of_grid_points: usize,
+
+    /// The quadrature rule of the grid.
+    #[pyo3(get)]
+    pub quadrature: Quadrature,
+
+    /// The nondimensional critical extension which irreversibly breaks chains.
+    #[pyo3(get)]
+    pub nondimensional_critical_extension: f64,
+
+    /// The nondimensional stiffness of each link in a chain.
+    #[pyo3(get)]
+    pub nondimensional_link_stiffness: f64,
+
+    /// The distribution of the number of links in a chain.
+    #[pyo3(get)]
+    pub distribution: ChainLengthDistribution,
+
+    /// The volumetric swelling ratio.
+    #[pyo3(get)]
+    pub swelling_ratio: f64
+}
+
+#[pymethods]
+impl PolydisperseBucheSilberstein
+{
+    #[new]
+    #[pyo3(signature = (method, nondimensional_critical_extension, nondimensional_link_stiffness, distribution, swelling_ratio, quadrature = Quadrature::Uniform, number_of_grid_points = NUMGRID))]
+    pub fn init(method: Method, nondimensional_critical_extension: f64, nondimensional_link_stiffness: f64, distribution: ChainLengthDistribution, swelling_ratio: f64, quadrature: Quadrature, number_of_grid_points: usize) -> PyResult<Self>
+    {
+        Ok(PolydisperseBucheSilberstein
+        {
+            model: super::PolydisperseBucheSilberstein::try_init_with_grid(method, quadrature, number_of_grid_points, nondimensional_critical_extension, nondimensional_link_stiffness, distribution.distribution.clone(), swelling_ratio)?,
+            number_of_grid_points,
+            quadrature,
+            nondimensional_critical_extension,
+            nondimensional_link_stiffness,
+            distribution,
+            swelling_ratio
+        })
+    }
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch history :math:`F_{11}(t)`.
+    /// 
+    /// Returns:
+    ///     tuple:
+    ///       - (*numpy.ndarray*) -
+    ///         The nondimensional Cauchy stress :math:`\beta\sigma_{11}(t)/n`.
+    ///       - (*numpy.ndarray*) -
+    ///         The total probability of intact chains :math:`P^\mathrm{tot}(t)`.
+    ///
+    /// Raises:
+    ///     ValueError: If any stretch is less than one.
+    ///
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretches: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
+    {
+        crate::py::validate(&stretches, super::validate_stretch)?;
+        let stretches = stretches.as_array();
+        let mut results_1 = vec![0.0; stretches.len()];
+        let mut results_2 = vec![0.0; stretches.len()];
+        self.model.components.iter().for_each(|(number_fraction, model)|{
+            let mut maximum_previous_stretch = 1.0;
+            stretches.iter().zip(results_1.iter_mut().zip(results_2.iter_mut())).for_each(|(stretch, (result_1, result_2))|{
+                maximum_previous_stretch =
+                if maximum_previous_stretch < *stretch {
+                    *stretch
+                } else {
+                    maximum_previous_stretch
+                };
+                let [cauchy_stress, total_probability] = super::uniaxial_tension(
+                    &model.element, &model.factor, &model.grid, &model.weight, &model.normalization, &model.method,
+                    &model.nondimensional_critical_extension, &model.nondimensional_link_stiffness,
+                    &model.number_of_links, &model.swelling_ratio,
+                    stretch, &maximum_previous_stretch
+                );
+                *result_1 += number_fraction * cauchy_stress;
+                *result_2 += number_fraction * total_probability;
+            })
+        });
+        Ok((PyArray::from_vec(py, results_1).to_dyn(), PyArray::from_vec(py, results_2).to_dyn()))
+    }
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    ///
+    /// Returns:
+    ///     float: The nondimensional initial shear modulus :math:`\beta G/n`.
+    ///
+    pub fn nondimensional_shear_modulus(&self) -> f64
+    {
+        self.model.nondimensional_shear_modulus()
+    }
+    /// The nondimensional Cauchy stress as a function of stretch and nondimensional time in uniaxial tension,
+    /// where chains break at the force-dependent rate of Morse links with the given nondimensional link energy.
+    /// The force on each chain is that of the asymptotic reduced Legendre Morse-FJC model, and chains stretched beyond the maximum extension of the Morse links are broken.
+    ///
+    /// Args:
+    ///     nondimensional_link_energy (float): The nondimensional energy of each link in a chain.
+    ///     stretch (numpy.ndarray): The applied stretch history :math:`F_{11}(t)`.
+    ///     nondimensional_time (numpy.ndarray): The nondimensional time :math:`k_BTt/h`.
+    ///
+    /// Returns:
+    ///     tuple:
+    ///       - (*numpy.ndarray*) -
+    ///         The nondimensional Cauchy stress :math:`\beta\sigma_{11}(t)/n`.
+    ///       - (*numpy.ndarray*) -
+    ///         The total probability of intact chains :math:`P^\mathrm{tot}(t)`.
+    ///
+    /// Raises:
+    ///     ValueError: If the nondimensional link energy is not positive, the histories differ in length, any stretch is less than one, or the time decreases.
+    ///
+    pub fn uniaxial_tension_rate_dependent<'py>(&self, py: Python<'py>, nondimensional_link_energy: f64, stretch: PyReadonlyArrayDyn<f64>, nondimensional_time: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
+    {
+        let stretch: Vec<f64> = stretch.as_array().iter().copied().collect();
+        let nondimensional_time: Vec<f64> = nondimensional_time.as_array().iter().copied().collect();
+        Ok(histories(py, self.model.uniaxial_tension_rate_dependent(&nondimensional_link_energy, &stretch, &nondimensional_time)?))
+    }
+    /// The nondimensional Cauchy stress as a function of stretch and time in uniaxial tension,
+    /// where chains break at the force-dependent rate of Morse links with the given link energy at the given temperature.
+    ///
+    /// Args:
+    ///     link_energy (float): The energy of each link in a chain in units of J/mol.
+    ///     temperature (float): The temperature :math:`T` in units of K.
+    ///     stretch (numpy.ndarray): The applied stretch history :math:`F_{11}(t)`.
+    ///     time (numpy.ndarray): The time :math:`t` in units of s.
+    ///
+    /// Returns:
+    ///     tuple:
+    ///       - (*numpy.ndarray*) -
+    ///         The nondimensional Cauchy stress :math:`\beta\sigma_{11}(t)/n`.
+    ///       - (*numpy.ndarray*) -
+    ///         The total probability of intact chains :math:`P^\mathrm{tot}(t)`.
+    ///
+    /// Raises:
+    ///     ValueError: If the link energy or temperature is not positive, the histories differ in length, any stretch is less than one, or the time decreases.
+    ///
+    pub fn uniaxial_tension_rate_dependent_at_temperature<'py>(&self, py: Python<'py>, link_energy: f64, temperature: f64, stretch: PyReadonlyArrayDyn<f64>, time: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
+    {
+        let stretch: Vec<f64> = stretch.as_array().iter().copied().collect();
+        let time: Vec<f64> = time.as_array().iter().copied().collect();
+        Ok(histories(py, self.model.uniaxial_tension_rate_dependent_at_temperature(&link_energy, &temperature, &stretch, &time)?))
+    }
+}
\ No newline at end of file
@@ -1,6 +1,8 @@
 #[cfg(feature = "python")]
 pub mod py;
 
+mod test;
+
 mod buche_silberstein;
 
-pub use buche_silberstein::BucheSilberstein;
\ No newline at end of file
+pub use buche_silberstein::{BucheSilberstein, Method, PolydisperseBucheSilberstein, Quadrature};
\ No newline at end of file
@@ -0,0 +1,82 @@
+"""Module to test the local module.
+
+"""
+import unittest
+import numpy as np
+from polymers import constitutive
+
+hyperelastic_damage = constitutive.hyperelastic_damage
+
+
+def model():
+    """Function to instantiate the model used in the tests.
+
+    """
+    return hyperelastic_damage.BucheSilberstein(
+        hyperelastic_damage.Method.GibbsLegendre, 2.5, 100.0, 8, 1.0,
+        hyperelastic_damage.Quadrature.GaussLegendre, 64
+    )
+
+
+class RateDependent(unittest.TestCase):
+    """Class for tests of rate-dependent uniaxial tension.
+
+    """
+    def test_intact(self):
+        """Function to test that chains with very strong links remain intact.
+
+        """
+        stretch = np.linspace(1.0, 1.5, 6)
+        nondimensional_time = np.linspace(0.0, 5.0, 6)
+        cauchy_stress, total_probability = \
+            model().uniaxial_tension_rate_dependent(
+                1e4, stretch, nondimensional_time
+            )
+        intact_cauchy_stress, intact_total_probability = \
+            model().uniaxial_tension(stretch)
+        self.assertTrue(
+            np.all(np.abs(cauchy_stress - intact_cauchy_stress) <= 1e-8)
+        )
+        self.assertTrue(
+            np.all(
+                np.abs(total_probability - intact_total_probability) <= 1e-12
+            )
+        )
+
+    def test_faster_loading(self):
+        """Function to test that faster loading retains more stress.
+
+        """
+        stretch = np.linspace(1.0, 1.5, 51)
+        final_cauchy_stress = [
+            model().uniaxial_tension_rate_dependent(
+                20.0, stretch, np.linspace(0.0, final_time, 51)
+            )[0][-1] for final_time in [1e6, 1e4, 1e2]
+        ]
+        self.assertTrue(np.all(np.diff(final_cauchy_stress) > 0))
+
+    def test_errors(self):
+        """Function to test that invalid histories raise errors.
+
+        """
+        self.assertRaises(
+            ValueError, model().uniaxial_tension_rate_dependent,
+            20.0, np.array([1.0, 1.1]), np.array([0.0])
+        )
+        self.assertRaises(
+            ValueError, model().uniaxial_tension_rate_dependent,
+            20.0, np.array([1.0, 1.1]), np.array([1.0, 0.0])
+        )
+
+
+class Errors(unittest.TestCase):
+    """Class for tests of invalid stretches.
+
+    """
+    def test_stretch(self):
+        """Function to test that stretches less than one raise an error.
+
+        """
+        self.assertRaises(
+            ValueError, model().uniaxial_tension, np.array([1.0, 0.9])
+        )
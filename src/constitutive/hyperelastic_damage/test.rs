@@ -0,0 +1,96 @@
+#![cfg(test)]
+use super::*;
+use crate::constitutive::distribution::ChainLengthDistribution;
+use crate::error::Error;
+const STRETCHES: [f64; 6] = [1.0, 1.1, 1.2, 1.3, 1.4, 1.5];
+fn model() -> BucheSilberstein
+{
+    BucheSilberstein::init_with_grid(Method::GibbsLegendre, Quadrature::GaussLegendre, 64, 2.5, 100.0, 8, 1.0)
+}
+fn times(final_time: f64, number_of_steps: usize) -> Vec<f64>
+{
+    (0..=number_of_steps).map(|k| final_time * (k as f64) / (number_of_steps as f64)).collect()
+}
+mod rate_dependent
+{
+    use super::*;
+    #[test]
+    fn intact()
+    {
+        let model = model();
+        let intact: [[f64; 2]; 6] = model.uniaxial_tension(&STRETCHES);
+        let results = model.uniaxial_tension_rate_dependent(&1e8, &STRETCHES, &times(5.0, 5)).unwrap();
+        assert_eq!(results.len(), STRETCHES.len());
+        results.iter().zip(intact.iter()).for_each(|([cauchy_stress, total_probability], [intact_cauchy_stress, intact_total_probability])|{
+            assert!((cauchy_stress - intact_cauchy_stress).abs() <= 1e-5 * intact_cauchy_stress.abs() + 1e-12);
+            assert!((total_probability - intact_total_probability).abs() <= 1e-12);
+        });
+    }
+    #[test]
+    fn faster_loading()
+    {
+        let model = model();
+        let stretch: Vec<f64> = times(0.5, 50).iter().map(|time| 1.0 + time).collect();
+        let [intact_cauchy_stress, _] = model.uniaxial_tension::<51>(&stretch)[50];
+        let final_states: Vec<[f64; 2]> = [1e6, 1e4, 1e2].iter().map(|final_time|
+            *model.uniaxial_tension_rate_dependent(&20.0, &stretch, &times(*final_time, 50)).unwrap().last().unwrap()
+        ).collect();
+        final_states.windows(2).for_each(|states|{
+            assert!(states[1][0] > states[0][0]);
+            assert!(states[1][1] > states[0][1]);
+        });
+        assert!(final_states[2][0] < intact_cauchy_stress);
+    }
+    #[test]
+    fn polydisperse()
+    {
+        let distribution = ChainLengthDistribution::Discrete(vec![(8, 1.0)]);
+        let polydisperse = PolydisperseBucheSilberstein::init_with_grid(Method::GibbsLegendre, Quadrature::GaussLegendre, 64, 2.5, 100.0, distribution, 1.0);
+        let time = times(1e2, 5);
+        let results = polydisperse.uniaxial_tension_rate_dependent(&20.0, &STRETCHES, &time).unwrap();
+        assert_eq!(results, model().uniaxial_tension_rate_dependent(&20.0, &STRETCHES, &time).unwrap());
+    }
+    #[test]
+    fn temperature()
+    {
+        let model = model();
+        let temperature = 300.0;
+        let thermal_energy = crate::physics::BOLTZMANN_CONSTANT * temperature;
+        let nondimensional_time = times(1e2, 5);
+        let time: Vec<f64> = nondimensional_time.iter().map(|time_k| time_k * crate::physics::PLANCK_CONSTANT / thermal_energy).collect();
+        let results = model.uniaxial_tension_rate_dependent_at_temperature(&(20.0 * thermal_energy), &temperature, &STRETCHES, &time).unwrap();
+        let nondimensional_results = model.uniaxial_tension_rate_dependent(&20.0, &STRETCHES, &nondimensional_time).unwrap();
+        results.iter().zip(nondimensional_results.iter()).for_each(|([cauchy_stress, total_probability], [nondimensional_cauchy_stress, nondimensional_total_probability])|{
+            assert!((cauchy_stress - nondimensional_cauchy_stress).abs() <= 1e-10 * nondimensional_cauchy_stress.abs().max(1.0));
+            assert!((total_probability - nondimensional_total_probability).abs() <= 1e-10);
+        });
+        assert!(matches!(model.uniaxial_tension_rate_dependent_at_temperature(&(20.0 * thermal_energy), &0.0, &STRETCHES, &time), Err(Error::Parameter(..))));
+    }
+    #[test]
+    fn errors()
+    {
+        let model = model();
+        let time = times(1.0, 5);
+        assert!(matches!(model.uniaxial_tension_rate_dependent(&0.0, &STRETCHES, &time), Err(Error::Parameter(..))));
+        assert!(matches!(model.uniaxial_tension_rate_dependent(&20.0, &STRETCHES, &time[1..]), Err(Error::Domain(..))));
+        assert!(matches!(model.uniaxial_tension_rate_dependent(&20.0, &[1.0, 0.9], &[0.0, 1.0]), Err(Error::Domain(..))));
+        assert!(matches!(model.uniaxial_tension_rate_dependent(&20.0, &[1.0, 1.1], &[1.0, 0.0]), Err(Error::Domain(..))));
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    #[should_panic(expected = "number_of_grid_points")]
+    fn number_of_grid_points()
+    {
+        BucheSilberstein::init_with_grid(Method::GibbsLegendre, Quadrature::GaussLegendre, 0, 2.5, 100.0, 8, 1.0);
+    }
+    #[test]
+    fn stretch()
+    {
+        let [result] = model().uniaxial_tension::<1>(&[0.9]);
+        assert!(result.iter().all(|value| value.is_nan()));
+        assert!(matches!(model().try_uniaxial_tension::<1>(&[0.9]), Err(Error::Domain(..))));
+    }
+}
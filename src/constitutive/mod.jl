@@ -0,0 +1,9 @@
+"""
+Constitutive models for polymer networks.
+"""
+module Constitutive
+
+include("hyperelastic/mod.jl")
+include("gel/mod.jl")
+
+end
@@ -1,8 +1,61 @@
 #[cfg(feature = "python")]
 pub mod py;
 
+/// Constitutive models in physical units.
+pub mod dimensional;
+
+/// Distributions of the chain lengths in polymer networks.
+pub mod distribution;
+
+/// Gel constitutive models.
+pub mod gel;
+
 /// Hyperelastic constitutive models.
 pub mod hyperelastic;
 
 /// Hyperelastic damage constitutive models.
-pub mod hyperelastic_damage;
\ No newline at end of file
+pub mod hyperelastic_damage;
+
+/// Viscoelastic constitutive models.
+pub mod viscoelastic;
+
+use hyperelastic::Tensor;
+
+/// The homogeneous deformation modes shared by the constitutive models,
+/// where the stresses are nondimensionalized by the number density of chains and the thermal energy.
+pub trait Hyperelastic
+{
+    /// The nondimensional Cauchy stress as a function of stretch in uniaxial tension.
+    fn uniaxial_tension(&self, stretch: &f64) -> f64;
+    /// The nondimensional Cauchy stress as a function of stretch in equibiaxial tension.
+    fn equibiaxial_tension(&self, stretch: &f64) -> f64;
+    /// The nondimensional initial shear modulus, from the nondimensional Cauchy stress in uniaxial tension as the stretch approaches one.
+    fn nondimensional_shear_modulus(&self) -> f64
+    {
+        nondimensional_shear_modulus(|stretch| self.uniaxial_tension(stretch))
+    }
+}
+
+/// The nondimensional initial shear modulus, from the given nondimensional Cauchy stress in uniaxial tension as the stretch approaches one,
+/// relative to any residual stress at a stretch of one.
+pub(crate) fn nondimensional_shear_modulus(uniaxial_tension: impl Fn(&f64) -> f64) -> f64
+{
+    let stretch = 1.0 + 1e-4;
+    (uniaxial_tension(&stretch) - uniaxial_tension(&1.0)) / (stretch.powi(2) - 1.0 / stretch)
+}
+
+/// The response of the constitutive models to general deformations.
+pub trait CauchyStress
+{
+    /// The nondimensional Cauchy stress as a function of the deformation gradient.
+    fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Tensor;
+}
+/// The strain energy density of the hyperelastic constitutive models in the homogeneous deformation modes,
+/// where the strain energy density is nondimensionalized by the number density of chains and the thermal energy.
+pub trait StrainEnergy: Hyperelastic
+{
+    /// The nondimensional strain energy density as a function of stretch in uniaxial tension.
+    fn uniaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64;
+    /// The nondimensional strain energy density as a function of stretch in equibiaxial tension.
+    fn equibiaxial_tension_strain_energy_density(&self, stretch: &f64) -> f64;
+}
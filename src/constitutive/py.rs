@@ -3,8 +3,12 @@ use pyo3::prelude::*;
 pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
 {
     let constitutive = PyModule::new(py, "constitutive")?;
+    super::dimensional::py::register_module(py, constitutive)?;
+    super::distribution::py::register_module(py, constitutive)?;
+    super::gel::py::register_module(py, constitutive)?;
     super::hyperelastic::py::register_module(py, constitutive)?;
     super::hyperelastic_damage::py::register_module(py, constitutive)?;
+    super::viscoelastic::py::register_module(py, constitutive)?;
     parent_module.add_submodule(constitutive)?;
     Ok(())
 }
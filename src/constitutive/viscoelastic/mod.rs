@@ -0,0 +1,8 @@
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+mod transient_network;
+
+pub use transient_network::TransientNetwork;
\ No newline at end of file
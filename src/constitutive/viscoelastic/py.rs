@@ -0,0 +1,9 @@
+use pyo3::prelude::*;
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let viscoelastic = PyModule::new(py, "viscoelastic")?;
+    super::transient_network::py::register_module(py, viscoelastic)?;
+    parent_module.add_submodule(viscoelastic)?;
+    Ok(())
+}
\ No newline at end of file
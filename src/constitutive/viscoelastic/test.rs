@@ -0,0 +1,187 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::
+{
+    ForceLaw,
+    fjc::FJC,
+    ideal::Ideal
+};
+const TEMPERATURE: f64 = 300.0;
+const NUMBER_OF_GRID_POINTS: usize = 32;
+fn times(final_time: f64, number_of_steps: usize) -> Vec<f64>
+{
+    (0..=number_of_steps).map(|k| final_time * (k as f64) / (number_of_steps as f64)).collect()
+}
+fn neo_hookean(stretch: f64) -> f64
+{
+    stretch.powi(2) - 1.0 / stretch
+}
+mod gaussian
+{
+    use super::*;
+    #[test]
+    fn steady()
+    {
+        let model = TransientNetwork::init_with_grid(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 3.0, 0.0, NUMBER_OF_GRID_POINTS);
+        let [[cauchy_stress, attached_fraction]] = model.step_strain_relaxation(&1.0, &[0.0])[..] else { panic!() };
+        assert!(cauchy_stress.abs() <= 1e-10);
+        assert!((attached_fraction - 0.75).abs() <= 1e-12);
+    }
+    #[test]
+    fn step_strain_relaxation()
+    {
+        let model = TransientNetwork::init_with_grid(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 3.0, 0.0, NUMBER_OF_GRID_POINTS);
+        let time = times(3.0, 30);
+        [0.8, 1.5, 2.0].iter().for_each(|stretch|
+            model.step_strain_relaxation(stretch, &time).iter().zip(time.iter()).for_each(|([cauchy_stress, attached_fraction], time)|{
+                assert!((cauchy_stress / (0.75 * (-time).exp() * neo_hookean(*stretch)) - 1.0).abs() <= 1e-8);
+                assert!((attached_fraction - 0.75).abs() <= 1e-1);
+            })
+        );
+    }
+    #[test]
+    fn long_step_strain_relaxation()
+    {
+        let model = TransientNetwork::init_with_grid(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 3.0, 0.0, NUMBER_OF_GRID_POINTS);
+        let time = times(20.0, 20_000);
+        model.step_strain_relaxation(&1.5, &time).iter().zip(time.iter()).step_by(2_000).for_each(|([cauchy_stress, attached_fraction], time)|{
+            assert!((cauchy_stress - 0.75 * (-time).exp() * neo_hookean(1.5)).abs() <= 1e-8);
+            assert!((attached_fraction - 0.75).abs() <= 1e-3);
+        });
+    }
+    #[test]
+    fn constant_rate_loading()
+    {
+        let nondimensional_attachment_rate = 3.0;
+        let nondimensional_stretch_rate = 0.5;
+        let model = TransientNetwork::init_with_grid(Ideal::init(25, 1.0, 1.0), TEMPERATURE, nondimensional_attachment_rate, 0.0, NUMBER_OF_GRID_POINTS);
+        let final_time = 2.0;
+        let [cauchy_stress, _] = *model.constant_rate_loading(&nondimensional_stretch_rate, &times(final_time, 200)).last().unwrap();
+        let stretch = |time: f64| 1.0 + nondimensional_stretch_rate * time;
+        let number_of_points = 10000;
+        let maxwell = 0.75 * (-final_time).exp() * neo_hookean(stretch(final_time)) + times(final_time, number_of_points).iter().map(|time|
+            nondimensional_attachment_rate * 0.25 * (time - final_time).exp() * neo_hookean(stretch(final_time) / stretch(*time))
+        ).sum::<f64>() * final_time / (number_of_points as f64);
+        assert!((cauchy_stress / maxwell - 1.0).abs() <= 1e-2);
+    }
+    #[test]
+    fn instantaneous()
+    {
+        let model = TransientNetwork::init_with_grid(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 3.0, 0.0, NUMBER_OF_GRID_POINTS);
+        let [cauchy_stress, _] = *model.constant_rate_loading(&1e4, &times(1e-4, 10)).last().unwrap();
+        assert!((cauchy_stress / (0.75 * neo_hookean(2.0)) - 1.0).abs() <= 1e-3);
+    }
+}
+mod force_dependent
+{
+    use super::*;
+    #[test]
+    fn loading()
+    {
+        let model = TransientNetwork::init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, 0.5, NUMBER_OF_GRID_POINTS);
+        let history = model.constant_rate_loading(&1.0, &times(1.0, 50));
+        assert!(history[0][0].abs() <= 1e-6 && history[1][0] > 0.0);
+        let linear = TransientNetwork::init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, 0.0, NUMBER_OF_GRID_POINTS);
+        let linear_history = linear.constant_rate_loading(&1.0, &times(1.0, 50));
+        assert!(history.last().unwrap()[0] < linear_history.last().unwrap()[0]);
+        assert!(history.last().unwrap()[1] / history[0][1] < linear_history.last().unwrap()[1] / linear_history[0][1]);
+    }
+    #[test]
+    fn relaxation()
+    {
+        let model = TransientNetwork::init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, 0.5, NUMBER_OF_GRID_POINTS);
+        let history = model.step_strain_relaxation(&1.5, &times(10.0, 200));
+        history.windows(2).for_each(|window| assert!(window[1][0] < window[0][0]));
+        assert!(history.last().unwrap()[0].abs() <= 1e-3 * history[0][0]);
+        let linear = TransientNetwork::init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, 0.0, NUMBER_OF_GRID_POINTS);
+        let linear_history = linear.step_strain_relaxation(&1.5, &times(10.0, 200));
+        assert!(history[1][0] / history[0][0] < linear_history[1][0] / linear_history[0][0]);
+    }
+    #[test]
+    fn equilibrium_distribution()
+    {
+        struct Gaussian(Ideal);
+        impl ForceLaw for Gaussian
+        {
+            fn number_of_links(&self) -> u8
+            {
+                self.0.number_of_links()
+            }
+            fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+            {
+                self.0.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature)
+            }
+        }
+        let ideal = Ideal::init(25, 1.0, 1.0);
+        let gaussian = Gaussian(Ideal::init(25, 1.0, 1.0));
+        [0.1, 0.2, 0.4].iter().for_each(|nondimensional_end_to_end_length_per_link|{
+            let ratio = ideal.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link, &TEMPERATURE) / ideal.nondimensional_equilibrium_distribution(&0.0, &TEMPERATURE);
+            assert!((gaussian.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link, &TEMPERATURE) / ratio - 1.0).abs() <= 1e-10);
+        });
+    }
+}
+mod cohorts
+{
+    use super::*;
+    #[test]
+    fn loading()
+    {
+        let model = TransientNetwork::init_with_grid(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 3.0, 0.5, 8);
+        let stretch = |time: &f64| 1.0 + 0.1 * time;
+        let time = times(2.0, 5_000);
+        let (history, number_of_cohorts) = model.evolve(&time.iter().map(stretch).collect::<Vec<f64>>(), &time);
+        assert!(number_of_cohorts <= 1 + (1.2_f64.ln() / 1.001_f64.ln()).ceil() as usize);
+        let coarse_time = times(2.0, 150);
+        let (coarse_history, coarse_number_of_cohorts) = model.evolve(&coarse_time.iter().map(stretch).collect::<Vec<f64>>(), &coarse_time);
+        assert_eq!(coarse_number_of_cohorts, coarse_time.len());
+        assert!((history.last().unwrap()[0] / coarse_history.last().unwrap()[0] - 1.0).abs() <= 2e-2);
+    }
+    #[test]
+    fn cycling()
+    {
+        let model = TransientNetwork::init_with_grid(Ideal::init(25, 1.0, 1.0), TEMPERATURE, 3.0, 0.5, 8);
+        let time = times(30.0, 5_000);
+        let stretch: Vec<f64> = time.iter().map(|time| 1.25 - 0.25 * time.cos()).collect();
+        let (_, number_of_cohorts) = model.evolve(&stretch, &time);
+        assert!(number_of_cohorts <= 1 + (1.5_f64.ln() / 1.001_f64.ln()).ceil() as usize);
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        assert!(TransientNetwork::try_init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, 0.5, NUMBER_OF_GRID_POINTS).is_ok());
+        assert!(TransientNetwork::try_init_with_grid(FJC::init(8, 1.0, 1.0), 0.0, 3.0, 0.5, NUMBER_OF_GRID_POINTS).is_err());
+        assert!(TransientNetwork::try_init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 0.0, 0.5, NUMBER_OF_GRID_POINTS).is_err());
+        assert!(TransientNetwork::try_init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, -0.5, NUMBER_OF_GRID_POINTS).is_err());
+        assert!(TransientNetwork::try_init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, 0.5, 1).is_err());
+    }
+    #[test]
+    fn histories()
+    {
+        let model = TransientNetwork::init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, 0.5, NUMBER_OF_GRID_POINTS);
+        assert!(model.try_uniaxial_tension(&[1.0, 1.1], &[0.0, 0.1]).is_ok());
+        assert!(model.try_uniaxial_tension(&[1.0, 1.1], &[0.0]).is_err());
+        assert!(model.try_uniaxial_tension(&[1.0, 1.1], &[0.1, 0.0]).is_err());
+        assert!(model.try_uniaxial_tension(&[1.0, 0.0], &[0.0, 0.1]).is_err());
+        assert!(model.try_step_strain_relaxation(&-1.0, &[0.0, 0.1]).is_err());
+        assert!(model.try_constant_rate_loading(&-20.0, &[0.0, 0.1]).is_err());
+        assert!(model.try_constant_rate_loading(&1.0, &[0.0, f64::INFINITY]).is_err());
+    }
+    #[test]
+    fn not_a_number()
+    {
+        let model = TransientNetwork::init_with_grid(FJC::init(8, 1.0, 1.0), TEMPERATURE, 3.0, 0.5, NUMBER_OF_GRID_POINTS);
+        [
+            model.uniaxial_tension(&[1.0, 1.1], &[0.1, 0.0]),
+            model.uniaxial_tension(&[1.0, 0.0], &[0.0, 0.1]),
+            model.step_strain_relaxation(&-1.0, &[0.0, 0.1]),
+            model.constant_rate_loading(&-20.0, &[0.0, 0.1])
+        ].iter().for_each(|history|{
+            assert_eq!(history.len(), 2);
+            assert!(history.iter().flatten().all(|value| value.is_nan()));
+        });
+    }
+}
@@ -0,0 +1,296 @@
+#[cfg(feature = "python")]
+pub mod py;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::math::gauss_legendre;
+use crate::physics::single_chain::ForceLaw;
+
+/// The default number of grid points in each direction.
+pub const NUMGRID: usize = 64;
+
+/// The extent of the grid of reference end-to-end vectors in each direction, relative to the reference end-to-end length per link.
+static GRID_EXTENT: f64 = 5.0;
+
+/// The relative difference in stretch within which chains attaching to the network join an existing cohort.
+static COHORT_TOLERANCE: f64 = 1e-3;
+
+/// The chains that attached to the network at the same stretch, which have since deformed affinely with the network.
+struct Cohort
+{
+    stretch: f64,
+    density: Vec<f64>,
+    detachment_rate: Vec<f64>
+}
+
+/// The transient network constitutive model, where chains detach from the network at a force-dependent rate
+/// and reattach in their equilibrium distribution at a constant rate, following Tanaka and Edwards.
+/// Time is nondimensionalized by the rate at which chains detach in the absence of force,
+/// and the stresses are nondimensionalized by the number density of chains, both attached and detached, and the thermal energy.
+pub struct TransientNetwork<T: ForceLaw>
+{
+    configuration: Vec<[f64; 2]>,
+    equilibrium: Vec<f64>,
+    reference_detachment_rate: Vec<f64>,
+    steady: Vec<f64>,
+
+    /// The single-chain model of each chain in the network.
+    pub chain: T,
+
+    /// The temperature in units of K.
+    pub temperature: f64,
+
+    /// The rate at which detached chains reattach to the network, relative to the rate at which chains detach in the absence of force.
+    pub nondimensional_attachment_rate: f64,
+
+    /// The length over which the force on a chain activates its detachment, relative to the link length.
+    pub nondimensional_activation_length: f64,
+
+    /// The number of grid points in each direction.
+    pub number_of_grid_points: usize
+}
+
+#[cfg(feature = "serde")]
+crate::config::parameters!(TransientNetwork<T: ForceLaw>, try_init_with_grid, chain: T, temperature: f64, nondimensional_attachment_rate: f64, nondimensional_activation_length: f64, number_of_grid_points: usize);
+
+/// The implemented functionality of the transient network constitutive model.
+impl<T: ForceLaw> TransientNetwork<T>
+{
+    /// Initializes and returns an instance of the model using the given single-chain model and the default grid.
+    pub fn init(chain: T, temperature: f64, nondimensional_attachment_rate: f64, nondimensional_activation_length: f64) -> Self
+    {
+        Self::init_with_grid(chain, temperature, nondimensional_attachment_rate, nondimensional_activation_length, NUMGRID)
+    }
+    /// Initializes and returns an instance of the model using the given single-chain model and number of grid points in each direction.
+    pub fn init_with_grid(chain: T, temperature: f64, nondimensional_attachment_rate: f64, nondimensional_activation_length: f64, number_of_grid_points: usize) -> Self
+    {
+        let extent = GRID_EXTENT * chain.nondimensional_reference_end_to_end_length_per_link();
+        let (nodes, weights) = gauss_legendre(&number_of_grid_points);
+        let grid: Vec<[f64; 2]> = nodes.iter().zip(weights.iter()).map(|(node, weight)|
+            [0.5 * extent * (1.0 + node), 0.5 * extent * weight]
+        ).collect();
+        let mut configuration = Vec::new();
+        let mut equilibrium = Vec::new();
+        grid.iter().for_each(|[z, weight_z]|
+            grid.iter().for_each(|[r, weight_r]|{
+                let density = r * weight_z * weight_r * chain.nondimensional_equilibrium_distribution(&(z.powi(2) + r.powi(2)).sqrt(), &temperature);
+                if density.is_finite() && density > 0.0
+                {
+                    configuration.push([*z, *r]);
+                    equilibrium.push(density);
+                }
+            })
+        );
+        let normalization = equilibrium.iter().sum::<f64>();
+        equilibrium.iter_mut().for_each(|density| *density /= normalization);
+        let mut model = TransientNetwork
+        {
+            configuration,
+            equilibrium,
+            reference_detachment_rate: Vec::new(),
+            steady: Vec::new(),
+            chain,
+            temperature,
+            nondimensional_attachment_rate,
+            nondimensional_activation_length,
+            number_of_grid_points
+        };
+        model.reference_detachment_rate = model.configuration.iter().map(|[z, r]|
+            model.nondimensional_detachment_rate(&(z.powi(2) + r.powi(2)).sqrt())
+        ).collect();
+        let detached_fraction = 1.0 / (1.0 + nondimensional_attachment_rate * model.equilibrium.iter().zip(model.reference_detachment_rate.iter()).map(|(density, rate)|
+            density / rate
+        ).sum::<f64>());
+        model.steady = model.equilibrium.iter().zip(model.reference_detachment_rate.iter()).map(|(density, rate)|
+            nondimensional_attachment_rate * detached_fraction * density / rate
+        ).collect();
+        model
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(chain: T, temperature: f64, nondimensional_attachment_rate: f64, nondimensional_activation_length: f64) -> Result<Self, Error>
+    {
+        Self::try_init_with_grid(chain, temperature, nondimensional_attachment_rate, nondimensional_activation_length, NUMGRID)
+    }
+    /// Initializes and returns an instance of the model using the given number of grid points, or an error if any of the parameters are invalid.
+    pub fn try_init_with_grid(chain: T, temperature: f64, nondimensional_attachment_rate: f64, nondimensional_activation_length: f64, number_of_grid_points: usize) -> Result<Self, Error>
+    {
+        error::positive("temperature", &temperature)?;
+        error::positive("nondimensional_attachment_rate", &nondimensional_attachment_rate)?;
+        error::nonnegative("nondimensional_activation_length", &nondimensional_activation_length)?;
+        if number_of_grid_points < 2
+        {
+            return Err(Error::Parameter("number_of_grid_points", number_of_grid_points as f64, "at least two"))
+        }
+        Ok(Self::init_with_grid(chain, temperature, nondimensional_attachment_rate, nondimensional_activation_length, number_of_grid_points))
+    }
+    /// The rate at which chains detach from the network as a function of the nondimensional end-to-end length per link,
+    /// relative to the rate in the absence of force, which increases exponentially with the force on the chain.
+    pub fn nondimensional_detachment_rate(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        (self.nondimensional_activation_length * self.chain.nondimensional_force(nondimensional_end_to_end_length_per_link, &self.temperature)).exp()
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of stretch and nondimensional time in uniaxial tension,
+    /// where the network starts at steady state in the undeformed configuration and the stretch history, and hence its rate, is arbitrary,
+    /// which are not a number if the histories are invalid.
+    pub fn uniaxial_tension(&self, stretch: &[f64], nondimensional_time: &[f64]) -> Vec<[f64; 2]>
+    {
+        self.try_uniaxial_tension(stretch, nondimensional_time).unwrap_or_else(|_| vec![[f64::NAN; 2]; stretch.len()])
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains over valid stretch and nondimensional time histories, and the largest number of cohorts held at once,
+    /// where chains attaching within a relative tolerance of the stretch of an existing cohort join it, and each cohort is discarded once it holds a negligible fraction of the attached chains,
+    /// so that the number of cohorts is bounded by the range of stretch visited rather than growing with the number of steps.
+    pub(super) fn evolve(&self, stretch: &[f64], nondimensional_time: &[f64]) -> (Vec<[f64; 2]>, usize)
+    {
+        let mut cohorts = vec![Cohort
+        {
+            stretch: 1.0,
+            density: self.steady.clone(),
+            detachment_rate: self.reference_detachment_rate.clone()
+        }];
+        let mut maximum_number_of_cohorts = cohorts.len();
+        let mut previous_time = nondimensional_time.first().copied().unwrap_or(0.0);
+        let history = stretch.iter().zip(nondimensional_time.iter()).map(|(stretch_k, time_k)|{
+            let time_step = time_k - previous_time;
+            previous_time = *time_k;
+            let mut cauchy_stress = 0.0;
+            let mut attached_fraction = 0.0;
+            cohorts.iter_mut().for_each(|cohort|{
+                let relative_stretch = stretch_k / cohort.stretch;
+                self.configuration.iter().zip(cohort.density.iter_mut().zip(cohort.detachment_rate.iter_mut())).for_each(|([z_0, r_0], (density, detachment_rate))|{
+                    if *density == 0.0
+                    {
+                        return
+                    }
+                    let z = z_0 * relative_stretch;
+                    let r = r_0 / relative_stretch.sqrt();
+                    let gamma = (z.powi(2) + r.powi(2)).sqrt();
+                    let eta = self.chain.nondimensional_force(&gamma, &self.temperature);
+                    if !eta.is_finite()
+                    {
+                        *density = 0.0;
+                        return
+                    }
+                    let rate = (self.nondimensional_activation_length * eta).exp();
+                    if time_step > 0.0
+                    {
+                        *density *= (-0.5 * (*detachment_rate + rate) * time_step).exp();
+                    }
+                    *detachment_rate = rate;
+                    if density.is_finite() && *density > 0.0
+                    {
+                        cauchy_stress += *density * eta * (2.0 * z.powi(2) - r.powi(2)) / gamma;
+                        attached_fraction += *density;
+                    }
+                    else
+                    {
+                        *density = 0.0;
+                    }
+                })
+            });
+            cohorts.retain(|cohort| cohort.density.iter().sum::<f64>() > f64::EPSILON * attached_fraction);
+            let attached = (1.0 - attached_fraction) * -(-self.nondimensional_attachment_rate * time_step).exp_m1();
+            if attached > 0.0
+            {
+                match cohorts.iter_mut().rev().find(|cohort| (stretch_k / cohort.stretch - 1.0).abs() <= COHORT_TOLERANCE)
+                {
+                    Some(cohort) => cohort.density.iter_mut().zip(self.equilibrium.iter()).for_each(|(density, equilibrium)|
+                        *density += attached * equilibrium
+                    ),
+                    None => cohorts.push(Cohort
+                    {
+                        stretch: *stretch_k,
+                        density: self.equilibrium.iter().map(|density| attached * density).collect(),
+                        detachment_rate: self.reference_detachment_rate.clone()
+                    })
+                }
+                attached_fraction += attached;
+            }
+            maximum_number_of_cohorts = maximum_number_of_cohorts.max(cohorts.len());
+            [0.5 * (self.chain.number_of_links() as f64) * cauchy_stress, attached_fraction]
+        }).collect();
+        (history, maximum_number_of_cohorts)
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of nondimensional time
+    /// after the given stretch is applied in uniaxial tension at the first time and then held,
+    /// which are not a number if the arguments are invalid.
+    pub fn step_strain_relaxation(&self, stretch: &f64, nondimensional_time: &[f64]) -> Vec<[f64; 2]>
+    {
+        self.try_step_strain_relaxation(stretch, nondimensional_time).unwrap_or_else(|_| vec![[f64::NAN; 2]; nondimensional_time.len()])
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of nondimensional time
+    /// when the stretch increases from one at the first time at the given constant nondimensional rate in uniaxial tension,
+    /// which are not a number if the arguments are invalid.
+    pub fn constant_rate_loading(&self, nondimensional_stretch_rate: &f64, nondimensional_time: &[f64]) -> Vec<[f64; 2]>
+    {
+        self.try_constant_rate_loading(nondimensional_stretch_rate, nondimensional_time).unwrap_or_else(|_| vec![[f64::NAN; 2]; nondimensional_time.len()])
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of stretch and nondimensional time in uniaxial tension, or an error if the histories are invalid.
+    pub fn try_uniaxial_tension(&self, stretch: &[f64], nondimensional_time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        if nondimensional_time.len() != stretch.len()
+        {
+            return Err(Error::Domain("nondimensional_time", nondimensional_time.len() as f64, "as long as the stretch"))
+        }
+        stretch.iter().try_for_each(validate_stretch)?;
+        validate_nondimensional_time(nondimensional_time)?;
+        Ok(self.evolve(stretch, nondimensional_time).0)
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of nondimensional time after a step stretch in uniaxial tension, or an error if the arguments are invalid.
+    pub fn try_step_strain_relaxation(&self, stretch: &f64, nondimensional_time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        validate_stretch(stretch)?;
+        self.try_uniaxial_tension(&vec![*stretch; nondimensional_time.len()], nondimensional_time)
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of nondimensional time at a constant stretch rate in uniaxial tension, or an error if the arguments are invalid.
+    pub fn try_constant_rate_loading(&self, nondimensional_stretch_rate: &f64, nondimensional_time: &[f64]) -> Result<Vec<[f64; 2]>, Error>
+    {
+        if !nondimensional_stretch_rate.is_finite()
+        {
+            return Err(Error::Domain("nondimensional_stretch_rate", *nondimensional_stretch_rate, "finite"))
+        }
+        let initial_time = nondimensional_time.first().copied().unwrap_or(0.0);
+        let stretch: Vec<f64> = nondimensional_time.iter().map(|time| 1.0 + nondimensional_stretch_rate * (time - initial_time)).collect();
+        self.try_uniaxial_tension(&stretch, nondimensional_time)
+    }
+}
+
+/// Validates that the stretch is positive and finite.
+fn validate_stretch(stretch: &f64) -> Result<(), Error>
+{
+    if stretch > &0.0 && stretch.is_finite()
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Domain("stretch", *stretch, "positive and finite"))
+    }
+}
+
+/// Validates that the nondimensional time is finite and nondecreasing.
+fn validate_nondimensional_time(nondimensional_time: &[f64]) -> Result<(), Error>
+{
+    nondimensional_time.iter().try_for_each(|time|
+        if time.is_finite()
+        {
+            Ok(())
+        }
+        else
+        {
+            Err(Error::Domain("nondimensional_time", *time, "finite"))
+        }
+    )?;
+    nondimensional_time.windows(2).try_for_each(|time|
+        if time[1] >= time[0]
+        {
+            Ok(())
+        }
+        else
+        {
+            Err(Error::Domain("nondimensional_time", time[1], "nondecreasing"))
+        }
+    )
+}
\ No newline at end of file
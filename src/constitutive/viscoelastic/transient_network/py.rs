@@ -0,0 +1,160 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    PyArray,
+    PyArrayDyn,
+    PyReadonlyArrayDyn
+};
+use crate::physics::single_chain::ForceLaw;
+use crate::constitutive::hyperelastic::network::py::force_law;
+use super::NUMGRID;
+
+pub fn register_module(_py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    parent_module.add_class::<TransientNetwork>()?;
+    Ok(())
+}
+
+/// Splits a history of the nondimensional Cauchy stress and fraction of attached chains into two arrays.
+fn histories<'py>(py: Python<'py>, results: Vec<[f64; 2]>) -> (&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)
+{
+    let results_1 = results.iter().map(|result| result[0]).collect();
+    let results_2 = results.iter().map(|result| result[1]).collect();
+    (PyArray::from_vec(py, results_1).to_dyn(), PyArray::from_vec(py, results_2).to_dyn())
+}
+
+/// The transient network constitutive model of Tanaka and Edwards, where attached chains deform affinely with the network,
+/// detach at the force-dependent rate
+///
+/// .. math::
+///     k_d(\gamma) = k_d^0\exp\left[\eta(\gamma)\,\Delta x/\ell_b\right],
+///
+/// and detached chains reattach at the rate :math:`k_a` in their equilibrium distribution :math:`\mathscr{P}_\mathrm{eq}(\gamma)`,
+/// so that the distribution of attached chains evolves as
+///
+/// .. math::
+///     \frac{D\mathscr{P}_a}{Dt} = -k_d(\gamma)\,\mathscr{P}_a + k_a\left(1 - \int\mathscr{P}_a\,d^3\boldsymbol{\gamma}\right)\mathscr{P}_\mathrm{eq},
+///
+/// starting from steady state in the undeformed configuration. Time is nondimensionalized by :math:`k_d^0`.
+///
+/// Args:
+///     chain: Any of the single-chain models in :mod:`polymers.physics.single_chain`, such as :class:`polymers.physics.single_chain.fjc.FJC`.
+///     temperature (float): The temperature :math:`T`.
+///     nondimensional_attachment_rate (float): The nondimensional attachment rate :math:`k_a/k_d^0`.
+///     nondimensional_activation_length (float): The nondimensional activation length :math:`\Delta x/\ell_b`.
+///     number_of_grid_points (int): The number of grid points in each direction.
+///
+/// Raises:
+///     TypeError: If the chain is not one of the single-chain models.
+///     ValueError: If any of the parameters are invalid.
+///
+#[pyclass]
+pub struct TransientNetwork
+{
+    model: super::TransientNetwork<Box<dyn ForceLaw + Send + Sync>>,
+
+    /// The single-chain model of each chain in the network.
+    #[pyo3(get)]
+    pub chain: PyObject,
+
+    /// The temperature in units of K.
+    #[pyo3(get)]
+    pub temperature: f64,
+
+    /// The nondimensional attachment rate.
+    #[pyo3(get)]
+    pub nondimensional_attachment_rate: f64,
+
+    /// The nondimensional activation length.
+    #[pyo3(get)]
+    pub nondimensional_activation_length: f64,
+
+    /// The number of grid points in each direction.
+    #[pyo3(get)]
+    pub number_of_grid_points: usize
+}
+
+#[pymethods]
+impl TransientNetwork
+{
+    #[new]
+    #[pyo3(signature = (chain, temperature, nondimensional_attachment_rate, nondimensional_activation_length, number_of_grid_points = NUMGRID))]
+    pub fn init(chain: &PyAny, temperature: f64, nondimensional_attachment_rate: f64, nondimensional_activation_length: f64, number_of_grid_points: usize) -> PyResult<Self>
+    {
+        Ok(TransientNetwork
+        {
+            model: super::TransientNetwork::try_init_with_grid(force_law(chain)?, temperature, nondimensional_attachment_rate, nondimensional_activation_length, number_of_grid_points)?,
+            chain: chain.into(),
+            temperature,
+            nondimensional_attachment_rate,
+            nondimensional_activation_length,
+            number_of_grid_points
+        })
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of stretch and nondimensional time in uniaxial tension.
+    ///
+    /// Args:
+    ///     stretch (numpy.ndarray): The applied stretch history :math:`F_{11}(t)`.
+    ///     nondimensional_time (numpy.ndarray): The nondimensional time :math:`k_d^0t`.
+    ///
+    /// Returns:
+    ///     tuple:
+    ///       - (*numpy.ndarray*) -
+    ///         The nondimensional Cauchy stress :math:`\beta\sigma_{11}(t)/n`.
+    ///       - (*numpy.ndarray*) -
+    ///         The fraction of attached chains :math:`\int\mathscr{P}_a\,d^3\boldsymbol{\gamma}`.
+    ///
+    /// Raises:
+    ///     ValueError: If the histories differ in length, any stretch is not positive, or the time decreases.
+    ///
+    pub fn uniaxial_tension<'py>(&self, py: Python<'py>, stretch: PyReadonlyArrayDyn<f64>, nondimensional_time: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
+    {
+        let stretch: Vec<f64> = stretch.as_array().iter().copied().collect();
+        let nondimensional_time: Vec<f64> = nondimensional_time.as_array().iter().copied().collect();
+        Ok(histories(py, self.model.try_uniaxial_tension(&stretch, &nondimensional_time)?))
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of nondimensional time
+    /// after a step stretch in uniaxial tension is applied at the first time and then held.
+    ///
+    /// Args:
+    ///     stretch (float): The applied stretch :math:`F_{11}`.
+    ///     nondimensional_time (numpy.ndarray): The nondimensional time :math:`k_d^0t`.
+    ///
+    /// Returns:
+    ///     tuple:
+    ///       - (*numpy.ndarray*) -
+    ///         The nondimensional Cauchy stress :math:`\beta\sigma_{11}(t)/n`.
+    ///       - (*numpy.ndarray*) -
+    ///         The fraction of attached chains :math:`\int\mathscr{P}_a\,d^3\boldsymbol{\gamma}`.
+    ///
+    /// Raises:
+    ///     ValueError: If the stretch is not positive or the time decreases.
+    ///
+    pub fn step_strain_relaxation<'py>(&self, py: Python<'py>, stretch: f64, nondimensional_time: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
+    {
+        let nondimensional_time: Vec<f64> = nondimensional_time.as_array().iter().copied().collect();
+        Ok(histories(py, self.model.try_step_strain_relaxation(&stretch, &nondimensional_time)?))
+    }
+    /// The nondimensional Cauchy stress and fraction of attached chains as a function of nondimensional time
+    /// when the stretch increases from one at the first time at a constant rate in uniaxial tension.
+    ///
+    /// Args:
+    ///     nondimensional_stretch_rate (float): The nondimensional stretch rate :math:`\dot{F}_{11}/k_d^0`.
+    ///     nondimensional_time (numpy.ndarray): The nondimensional time :math:`k_d^0t`.
+    ///
+    /// Returns:
+    ///     tuple:
+    ///       - (*numpy.ndarray*) -
+    ///         The nondimensional Cauchy stress :math:`\beta\sigma_{11}(t)/n`.
+    ///       - (*numpy.ndarray*) -
+    ///         The fraction of attached chains :math:`\int\mathscr{P}_a\,d^3\boldsymbol{\gamma}`.
+    ///
+    /// Raises:
+    ///     ValueError: If the stretch would not remain positive or the time decreases.
+    ///
+    pub fn constant_rate_loading<'py>(&self, py: Python<'py>, nondimensional_stretch_rate: f64, nondimensional_time: PyReadonlyArrayDyn<f64>) -> PyResult<(&'py PyArrayDyn<f64>, &'py PyArrayDyn<f64>)>
+    {
+        let nondimensional_time: Vec<f64> = nondimensional_time.as_array().iter().copied().collect();
+        Ok(histories(py, self.model.try_constant_rate_loading(&nondimensional_stretch_rate, &nondimensional_time)?))
+    }
+}
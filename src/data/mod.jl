@@ -0,0 +1,182 @@
+"""
+Bundled experimental and Monte Carlo datasets.
+"""
+module Data
+
+using DocStringExtensions
+using ..Polymers: PROJECT_ROOT, ParameterError
+
+"""
+The numbers of links of the Monte Carlo datasets of the freely-rotating chain (FRC) model.
+"""
+const FRC_NUMBERS_OF_LINKS = UInt8.(8:2:32)
+
+"""
+The bond angles in degrees of the Monte Carlo datasets of the freely-rotating chain (FRC) model.
+"""
+const FRC_BOND_ANGLES = UInt8.(15:5:90)
+
+"""
+The number of links of the Monte Carlo datasets of the extensible freely-rotating chain (EFRC) model.
+"""
+const EFRC_NUMBER_OF_LINKS = UInt8(8)
+
+"""
+The bond angle in degrees of the Monte Carlo datasets of the extensible freely-rotating chain (EFRC) model.
+"""
+const EFRC_BOND_ANGLE = UInt8(45)
+
+"""
+The nondimensional link stiffnesses of the Monte Carlo datasets of the extensible freely-rotating chain (EFRC) model.
+"""
+const EFRC_NONDIMENSIONAL_LINK_STIFFNESSES = UInt8.(7:50)
+
+"""
+The DOI, figure, and description of the columns of each of the experimental datasets.
+"""
+const EXPERIMENTS = [
+    (
+        "10.1126/science.1248494",
+        "fig4c1",
+        "The stretch and the nominal stress in units of MPa in cyclic uniaxial tension.",
+    ),
+    (
+        "10.1126/science.1248494",
+        "fig4c2",
+        "The stretch and the photon count times 1e-6 from mechanoluminescence in cyclic uniaxial tension.",
+    ),
+]
+
+"""
+A Monte Carlo dataset of the nondimensional equilibrium radial distribution of the freely-rotating chain (FRC) model or its extensible counterpart (EFRC),
+histogrammed with 1e3 bins from 1e12 samples and normalized to unity over the nondimensional end-to-end length per link.
+
+$(FIELDS)
+"""
+struct MonteCarloDataset
+    """
+    The number of links in the chain.
+    """
+    number_of_links::UInt8
+    """
+    The bond angle in degrees.
+    """
+    bond_angle::UInt8
+    """
+    The nondimensional link stiffness, or nothing for inextensible links.
+    """
+    nondimensional_link_stiffness::Union{UInt8,Nothing}
+    """
+    The nondimensional end-to-end lengths per link ``\\gamma`` at the centers of the bins.
+    """
+    nondimensional_end_to_end_length_per_link::Vector{Float64}
+    """
+    The nondimensional equilibrium radial distribution ``\\mathscr{g}_\\mathrm{eq}`` in each bin.
+    """
+    nondimensional_equilibrium_radial_distribution::Vector{Float64}
+end
+
+"""
+An experimental dataset digitized from a figure of a publication.
+
+$(FIELDS)
+"""
+struct ExperimentalDataset
+    """
+    The DOI of the publication.
+    """
+    doi::String
+    """
+    The figure of the publication.
+    """
+    figure::String
+    """
+    The description of the columns of the dataset.
+    """
+    description::String
+    """
+    The first column of the dataset.
+    """
+    x::Vector{Float64}
+    """
+    The second column of the dataset.
+    """
+    y::Vector{Float64}
+end
+
+"""
+Parses the two whitespace-separated columns of a dataset.
+"""
+function columns(path::String)
+    lines = filter(line -> !isempty(strip(line)), readlines(path))
+    entries = [parse.(Float64, split(line)) for line in lines]
+    return first.(entries), last.(entries)
+end
+
+"""
+Loads the Monte Carlo dataset of the freely-rotating chain (FRC) model with the given number of links and bond angle in degrees.
+
+$(TYPEDSIGNATURES)
+"""
+function frc(number_of_links::UInt8, bond_angle::UInt8)::MonteCarloDataset
+    number_of_links in FRC_NUMBERS_OF_LINKS || throw(
+        ParameterError(
+            "number_of_links",
+            number_of_links,
+            "one of the numbers of links of the datasets",
+        ),
+    )
+    bond_angle in FRC_BOND_ANGLES || throw(
+        ParameterError("bond_angle", bond_angle, "one of the bond angles of the datasets"),
+    )
+    gamma, g_eq = columns(
+        string(
+            PROJECT_ROOT,
+            "data/frc/$(number_of_links)-links/$(bond_angle)-degrees-1e3-bins-1e12-samples.csv",
+        ),
+    )
+    return MonteCarloDataset(number_of_links, bond_angle, nothing, gamma, g_eq)
+end
+
+"""
+Loads the Monte Carlo dataset of the extensible freely-rotating chain (EFRC) model with the given nondimensional link stiffness.
+
+$(TYPEDSIGNATURES)
+"""
+function efrc(nondimensional_link_stiffness::UInt8)::MonteCarloDataset
+    nondimensional_link_stiffness in EFRC_NONDIMENSIONAL_LINK_STIFFNESSES || throw(
+        ParameterError(
+            "nondimensional_link_stiffness",
+            nondimensional_link_stiffness,
+            "one of the nondimensional link stiffnesses of the datasets",
+        ),
+    )
+    gamma, g_eq = columns(
+        string(
+            PROJECT_ROOT,
+            "data/efrc/$(EFRC_NUMBER_OF_LINKS)-links/$(EFRC_BOND_ANGLE)-degrees/",
+            "$(nondimensional_link_stiffness)-kappa-1e3-bins-1e12-samples.csv",
+        ),
+    )
+    return MonteCarloDataset(
+        EFRC_NUMBER_OF_LINKS,
+        EFRC_BOND_ANGLE,
+        nondimensional_link_stiffness,
+        gamma,
+        g_eq,
+    )
+end
+
+"""
+Loads the experimental dataset from the given figure of the publication with the given DOI, or nothing if there is no such dataset.
+
+$(TYPEDSIGNATURES)
+"""
+function experiment(doi::String, figure::String)::Union{ExperimentalDataset,Nothing}
+    index = findfirst(entry -> entry[1] == doi && entry[2] == figure, EXPERIMENTS)
+    isnothing(index) && return nothing
+    x, y = columns(string(PROJECT_ROOT, "data/doi/$(doi)/$(figure).csv"))
+    return ExperimentalDataset(doi, figure, EXPERIMENTS[index][3], x, y)
+end
+
+end
@@ -0,0 +1,196 @@
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+use crate::error::Error;
+
+/// The numbers of links of the Monte Carlo datasets of the freely-rotating chain (FRC) model.
+pub const FRC_NUMBERS_OF_LINKS: [u8; 13] = [8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32];
+
+/// The bond angles in degrees of the Monte Carlo datasets of the freely-rotating chain (FRC) model.
+pub const FRC_BOND_ANGLES: [u8; 16] = [15, 20, 25, 30, 35, 40, 45, 50, 55, 60, 65, 70, 75, 80, 85, 90];
+
+/// The number of links of the Monte Carlo datasets of the extensible freely-rotating chain (EFRC) model.
+pub const EFRC_NUMBER_OF_LINKS: u8 = 8;
+
+/// The bond angle in degrees of the Monte Carlo datasets of the extensible freely-rotating chain (EFRC) model.
+pub const EFRC_BOND_ANGLE: u8 = 45;
+
+/// The nondimensional link stiffnesses of the Monte Carlo datasets of the extensible freely-rotating chain (EFRC) model.
+pub const EFRC_NONDIMENSIONAL_LINK_STIFFNESSES: [u8; 44] = [7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50];
+
+/// The DOI, figure, and description of the columns of each of the experimental datasets.
+pub const EXPERIMENTS: [(&str, &str, &str); 2] = [
+    ("10.1126/science.1248494", "fig4c1", "The stretch and the nominal stress in units of MPa in cyclic uniaxial tension."),
+    ("10.1126/science.1248494", "fig4c2", "The stretch and the photon count times 1e-6 from mechanoluminescence in cyclic uniaxial tension.")
+];
+
+macro_rules! frc_bond_angles
+{
+    ($number_of_links: literal) => {
+        [
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/15-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/20-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/25-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/30-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/35-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/40-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/45-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/50-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/55-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/60-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/65-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/70-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/75-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/80-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/85-degrees-1e3-bins-1e12-samples.csv")),
+            include_str!(concat!("../../data/frc/", $number_of_links, "-links/90-degrees-1e3-bins-1e12-samples.csv"))
+        ]
+    }
+}
+
+static FRC: [[&str; 16]; 13] = [
+    frc_bond_angles!(8),
+    frc_bond_angles!(10),
+    frc_bond_angles!(12),
+    frc_bond_angles!(14),
+    frc_bond_angles!(16),
+    frc_bond_angles!(18),
+    frc_bond_angles!(20),
+    frc_bond_angles!(22),
+    frc_bond_angles!(24),
+    frc_bond_angles!(26),
+    frc_bond_angles!(28),
+    frc_bond_angles!(30),
+    frc_bond_angles!(32)
+];
+
+macro_rules! efrc_nondimensional_link_stiffnesses
+{
+    ($($nondimensional_link_stiffness: literal),*) => {
+        [$(
+            include_str!(concat!("../../data/efrc/8-links/45-degrees/", $nondimensional_link_stiffness, "-kappa-1e3-bins-1e12-samples.csv"))
+        ),*]
+    }
+}
+
+static EFRC: [&str; 44] = efrc_nondimensional_link_stiffnesses!(7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50);
+
+static EXPERIMENTAL: [&str; 2] = [
+    include_str!("../../data/doi/10.1126/science.1248494/fig4c1.csv"),
+    include_str!("../../data/doi/10.1126/science.1248494/fig4c2.csv")
+];
+
+/// A Monte Carlo dataset of the nondimensional equilibrium radial distribution of the freely-rotating chain (FRC) model or its extensible counterpart (EFRC),
+/// histogrammed with 1e3 bins from 1e12 samples and normalized to unity over the nondimensional end-to-end length per link.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonteCarloDataset
+{
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The bond angle in degrees.
+    pub bond_angle: u8,
+
+    /// The nondimensional link stiffness, or nothing for inextensible links.
+    pub nondimensional_link_stiffness: Option<u8>,
+
+    /// The nondimensional end-to-end lengths per link at the centers of the bins.
+    pub nondimensional_end_to_end_length_per_link: Vec<f64>,
+
+    /// The nondimensional equilibrium radial distribution in each bin.
+    pub nondimensional_equilibrium_radial_distribution: Vec<f64>
+}
+
+/// An experimental dataset digitized from a figure of a publication.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExperimentalDataset
+{
+    /// The DOI of the publication.
+    pub doi: &'static str,
+
+    /// The figure of the publication.
+    pub figure: &'static str,
+
+    /// The description of the columns of the dataset.
+    pub description: &'static str,
+
+    /// The first column of the dataset.
+    pub x: Vec<f64>,
+
+    /// The second column of the dataset.
+    pub y: Vec<f64>
+}
+
+/// Parses the two whitespace-separated columns of the bundled dataset with the given file name, and panics if the dataset is malformed.
+fn columns(contents: &str, file: &str) -> (Vec<f64>, Vec<f64>)
+{
+    contents.lines().filter(|line| !line.trim().is_empty()).map(|line|{
+        let mut entries = line.split_whitespace().map(|entry|
+            entry.parse::<f64>().unwrap_or_else(|_| panic!("The entry {} of the dataset {} is not a number.", entry, file))
+        );
+        match (entries.next(), entries.next())
+        {
+            (Some(x), Some(y)) => (x, y),
+            _ => panic!("The line {} of the dataset {} does not have two columns.", line, file)
+        }
+    }).unzip()
+}
+
+/// Loads the Monte Carlo dataset of the freely-rotating chain (FRC) model with the given number of links and bond angle in degrees,
+/// or an error if there is no such dataset.
+pub fn frc(number_of_links: &u8, bond_angle: &u8) -> Result<MonteCarloDataset, Error>
+{
+    let i = FRC_NUMBERS_OF_LINKS.iter().position(|number_of_links_i| number_of_links_i == number_of_links).ok_or(
+        Error::Parameter("number_of_links", *number_of_links as f64, "one of the numbers of links of the datasets")
+    )?;
+    let j = FRC_BOND_ANGLES.iter().position(|bond_angle_j| bond_angle_j == bond_angle).ok_or(
+        Error::Parameter("bond_angle", *bond_angle as f64, "one of the bond angles of the datasets")
+    )?;
+    let (nondimensional_end_to_end_length_per_link, nondimensional_equilibrium_radial_distribution) = columns(FRC[i][j], &format!("data/frc/{}-links/{}-degrees-1e3-bins-1e12-samples.csv", number_of_links, bond_angle));
+    Ok(MonteCarloDataset
+    {
+        number_of_links: *number_of_links,
+        bond_angle: *bond_angle,
+        nondimensional_link_stiffness: None,
+        nondimensional_end_to_end_length_per_link,
+        nondimensional_equilibrium_radial_distribution
+    })
+}
+
+/// Loads the Monte Carlo dataset of the extensible freely-rotating chain (EFRC) model with the given nondimensional link stiffness,
+/// or an error if there is no such dataset.
+pub fn efrc(nondimensional_link_stiffness: &u8) -> Result<MonteCarloDataset, Error>
+{
+    let k = EFRC_NONDIMENSIONAL_LINK_STIFFNESSES.iter().position(|nondimensional_link_stiffness_k| nondimensional_link_stiffness_k == nondimensional_link_stiffness).ok_or(
+        Error::Parameter("nondimensional_link_stiffness", *nondimensional_link_stiffness as f64, "one of the nondimensional link stiffnesses of the datasets")
+    )?;
+    let (nondimensional_end_to_end_length_per_link, nondimensional_equilibrium_radial_distribution) = columns(EFRC[k], &format!("data/efrc/{}-links/{}-degrees/{}-kappa-1e3-bins-1e12-samples.csv", EFRC_NUMBER_OF_LINKS, EFRC_BOND_ANGLE, nondimensional_link_stiffness));
+    Ok(MonteCarloDataset
+    {
+        number_of_links: EFRC_NUMBER_OF_LINKS,
+        bond_angle: EFRC_BOND_ANGLE,
+        nondimensional_link_stiffness: Some(*nondimensional_link_stiffness),
+        nondimensional_end_to_end_length_per_link,
+        nondimensional_equilibrium_radial_distribution
+    })
+}
+
+/// Loads the experimental dataset from the given figure of the publication with the given DOI, or nothing if there is no such dataset.
+pub fn experiment(doi: &str, figure: &str) -> Option<ExperimentalDataset>
+{
+    EXPERIMENTS.iter().zip(EXPERIMENTAL.iter()).find(|((doi_i, figure_i, _), _)|
+        doi_i == &doi && figure_i == &figure
+    ).map(|((doi, figure, description), contents)|{
+        let (x, y) = columns(contents, &format!("data/doi/{}/{}.csv", doi, figure));
+        ExperimentalDataset
+        {
+            doi,
+            figure,
+            description,
+            x,
+            y
+        }
+    })
+}
@@ -0,0 +1,184 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    IntoPyArray,
+    PyArray1
+};
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let data = PyModule::new(py, "data")?;
+    data.add("FRC_NUMBERS_OF_LINKS", super::FRC_NUMBERS_OF_LINKS.to_vec())?;
+    data.add("FRC_BOND_ANGLES", super::FRC_BOND_ANGLES.to_vec())?;
+    data.add("EFRC_NUMBER_OF_LINKS", super::EFRC_NUMBER_OF_LINKS)?;
+    data.add("EFRC_BOND_ANGLE", super::EFRC_BOND_ANGLE)?;
+    data.add("EFRC_NONDIMENSIONAL_LINK_STIFFNESSES", super::EFRC_NONDIMENSIONAL_LINK_STIFFNESSES.to_vec())?;
+    data.add("EXPERIMENTS", super::EXPERIMENTS.to_vec())?;
+    data.add_class::<MonteCarloDataset>()?;
+    data.add_class::<ExperimentalDataset>()?;
+    data.add_function(wrap_pyfunction!(frc, data)?)?;
+    data.add_function(wrap_pyfunction!(efrc, data)?)?;
+    data.add_function(wrap_pyfunction!(experiment, data)?)?;
+    parent_module.add_submodule(data)?;
+    Ok(())
+}
+
+/// A Monte Carlo dataset of the nondimensional equilibrium radial distribution of the freely-rotating chain (FRC) model or its extensible counterpart (EFRC),
+/// histogrammed with 1e3 bins from 1e12 samples and normalized to unity over the nondimensional end-to-end length per link.
+#[pyclass]
+pub struct MonteCarloDataset
+{
+    /// The number of links in the chain.
+    #[pyo3(get)]
+    pub number_of_links: u8,
+
+    /// The bond angle in degrees.
+    #[pyo3(get)]
+    pub bond_angle: u8,
+
+    /// The nondimensional link stiffness, or None for inextensible links.
+    #[pyo3(get)]
+    pub nondimensional_link_stiffness: Option<u8>,
+
+    nondimensional_end_to_end_length_per_link: Vec<f64>,
+
+    nondimensional_equilibrium_radial_distribution: Vec<f64>
+}
+
+#[pymethods]
+impl MonteCarloDataset
+{
+    /// The nondimensional end-to-end lengths per link :math:`\gamma` at the centers of the bins.
+    #[getter]
+    pub fn nondimensional_end_to_end_length_per_link<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64>
+    {
+        self.nondimensional_end_to_end_length_per_link.clone().into_pyarray(py)
+    }
+
+    /// The nondimensional equilibrium radial distribution :math:`\mathscr{g}_\mathrm{eq}` in each bin.
+    #[getter]
+    pub fn nondimensional_equilibrium_radial_distribution<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64>
+    {
+        self.nondimensional_equilibrium_radial_distribution.clone().into_pyarray(py)
+    }
+}
+
+impl From<super::MonteCarloDataset> for MonteCarloDataset
+{
+    fn from(dataset: super::MonteCarloDataset) -> Self
+    {
+        MonteCarloDataset
+        {
+            number_of_links: dataset.number_of_links,
+            bond_angle: dataset.bond_angle,
+            nondimensional_link_stiffness: dataset.nondimensional_link_stiffness,
+            nondimensional_end_to_end_length_per_link: dataset.nondimensional_end_to_end_length_per_link,
+            nondimensional_equilibrium_radial_distribution: dataset.nondimensional_equilibrium_radial_distribution
+        }
+    }
+}
+
+/// An experimental dataset digitized from a figure of a publication.
+#[pyclass]
+pub struct ExperimentalDataset
+{
+    /// The DOI of the publication.
+    #[pyo3(get)]
+    pub doi: String,
+
+    /// The figure of the publication.
+    #[pyo3(get)]
+    pub figure: String,
+
+    /// The description of the columns of the dataset.
+    #[pyo3(get)]
+    pub description: String,
+
+    x: Vec<f64>,
+
+    y: Vec<f64>
+}
+
+#[pymethods]
+impl ExperimentalDataset
+{
+    /// The first column of the dataset.
+    #[getter]
+    pub fn x<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64>
+    {
+        self.x.clone().into_pyarray(py)
+    }
+
+    /// The second column of the dataset.
+    #[getter]
+    pub fn y<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64>
+    {
+        self.y.clone().into_pyarray(py)
+    }
+}
+
+impl From<super::ExperimentalDataset> for ExperimentalDataset
+{
+    fn from(dataset: super::ExperimentalDataset) -> Self
+    {
+        ExperimentalDataset
+        {
+            doi: dataset.doi.to_string(),
+            figure: dataset.figure.to_string(),
+            description: dataset.description.to_string(),
+            x: dataset.x,
+            y: dataset.y
+        }
+    }
+}
+
+/// Loads the Monte Carlo dataset of the freely-rotating chain (FRC) model with the given number of links and bond angle in degrees.
+///
+/// Args:
+///     number_of_links (int): The number of links :math:`N_b`, one of ``FRC_NUMBERS_OF_LINKS``.
+///     bond_angle (int): The bond angle in degrees, one of ``FRC_BOND_ANGLES``.
+///
+/// Returns:
+///     MonteCarloDataset: The nondimensional equilibrium radial distribution.
+///
+/// Raises:
+///     ValueError: If there is no such dataset.
+///
+#[pyfunction]
+pub fn frc(number_of_links: u8, bond_angle: u8) -> PyResult<MonteCarloDataset>
+{
+    Ok(super::frc(&number_of_links, &bond_angle)?.into())
+}
+
+/// Loads the Monte Carlo dataset of the extensible freely-rotating chain (EFRC) model with the given nondimensional link stiffness,
+/// where the number of links is ``EFRC_NUMBER_OF_LINKS`` and the bond angle is ``EFRC_BOND_ANGLE``.
+///
+/// Args:
+///     nondimensional_link_stiffness (int): The nondimensional link stiffness :math:`\kappa`, one of ``EFRC_NONDIMENSIONAL_LINK_STIFFNESSES``.
+///
+/// Returns:
+///     MonteCarloDataset: The nondimensional equilibrium radial distribution.
+///
+/// Raises:
+///     ValueError: If there is no such dataset.
+///
+#[pyfunction]
+pub fn efrc(nondimensional_link_stiffness: u8) -> PyResult<MonteCarloDataset>
+{
+    Ok(super::efrc(&nondimensional_link_stiffness)?.into())
+}
+
+/// Loads the experimental dataset from the given figure of the publication with the given DOI.
+///
+/// Args:
+///     doi (str): The DOI of the publication, as listed in ``EXPERIMENTS``.
+///     figure (str): The figure of the publication, as listed in ``EXPERIMENTS``.
+///
+/// Returns:
+///     ExperimentalDataset: The dataset, or None if there is no such dataset.
+///
+#[pyfunction]
+pub fn experiment(doi: &str, figure: &str) -> Option<ExperimentalDataset>
+{
+    super::experiment(doi, figure).map(ExperimentalDataset::from)
+}
@@ -0,0 +1,55 @@
+module Test
+
+using Test
+using Polymers: ParameterError
+using Polymers.Data:
+    FRC_NUMBERS_OF_LINKS,
+    FRC_BOND_ANGLES,
+    EFRC_NONDIMENSIONAL_LINK_STIFFNESSES,
+    EXPERIMENTS,
+    frc,
+    efrc,
+    experiment
+
+normalization(dataset) =
+    sum(dataset.nondimensional_equilibrium_radial_distribution) * (
+        dataset.nondimensional_end_to_end_length_per_link[2] -
+        dataset.nondimensional_end_to_end_length_per_link[1]
+    )
+
+@testset "data::test::frc::all" begin
+    for number_of_links in FRC_NUMBERS_OF_LINKS
+        for bond_angle in FRC_BOND_ANGLES
+            dataset = frc(number_of_links, bond_angle)
+            @test length(dataset.nondimensional_end_to_end_length_per_link) == 1000
+            @test isnothing(dataset.nondimensional_link_stiffness)
+            @test abs(normalization(dataset) - 1.0) <= 1e-2
+        end
+    end
+end
+
+@testset "data::test::efrc::all" begin
+    for nondimensional_link_stiffness in EFRC_NONDIMENSIONAL_LINK_STIFFNESSES
+        dataset = efrc(nondimensional_link_stiffness)
+        @test dataset.nondimensional_link_stiffness == nondimensional_link_stiffness
+        @test abs(normalization(dataset) - 1.0) <= 1e-2
+    end
+end
+
+@testset "data::test::experiment::all" begin
+    for (doi, figure, description) in EXPERIMENTS
+        dataset = experiment(doi, figure)
+        @test dataset.description == description
+        @test length(dataset.x) == length(dataset.y)
+    end
+    @test length(experiment("10.1126/science.1248494", "fig4c1").x) == 169
+end
+
+@testset "data::test::error" begin
+    @test_throws ParameterError frc(UInt8(9), UInt8(45))
+    @test_throws ParameterError frc(UInt8(8), UInt8(46))
+    @test_throws ParameterError efrc(UInt8(6))
+    @test isnothing(experiment("10.1126/science.1248494", "fig4c3"))
+end
+
+end
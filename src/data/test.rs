@@ -0,0 +1,91 @@
+#![cfg(test)]
+use super::*;
+fn normalization(dataset: &MonteCarloDataset) -> f64
+{
+    let gamma = &dataset.nondimensional_end_to_end_length_per_link;
+    let bin_width = gamma[1] - gamma[0];
+    dataset.nondimensional_equilibrium_radial_distribution.iter().sum::<f64>() * bin_width
+}
+mod frc
+{
+    use super::*;
+    #[test]
+    fn all()
+    {
+        FRC_NUMBERS_OF_LINKS.iter().for_each(|number_of_links|
+            FRC_BOND_ANGLES.iter().for_each(|bond_angle|{
+                let dataset = frc(number_of_links, bond_angle).unwrap();
+                assert_eq!(dataset.number_of_links, *number_of_links);
+                assert_eq!(dataset.bond_angle, *bond_angle);
+                assert_eq!(dataset.nondimensional_link_stiffness, None);
+                assert_eq!(dataset.nondimensional_end_to_end_length_per_link.len(), 1000);
+                assert_eq!(dataset.nondimensional_equilibrium_radial_distribution.len(), 1000);
+                assert!((normalization(&dataset) - 1.0).abs() <= 1e-2);
+            })
+        )
+    }
+    #[test]
+    fn maximum()
+    {
+        let dataset = frc(&8, &90).unwrap();
+        let maximum = (45.0_f64).to_radians().cos();
+        assert!(dataset.nondimensional_end_to_end_length_per_link.iter().all(|gamma| gamma <= &maximum));
+    }
+}
+mod efrc
+{
+    use super::*;
+    #[test]
+    fn all()
+    {
+        EFRC_NONDIMENSIONAL_LINK_STIFFNESSES.iter().for_each(|nondimensional_link_stiffness|{
+            let dataset = efrc(nondimensional_link_stiffness).unwrap();
+            assert_eq!(dataset.number_of_links, EFRC_NUMBER_OF_LINKS);
+            assert_eq!(dataset.bond_angle, EFRC_BOND_ANGLE);
+            assert_eq!(dataset.nondimensional_link_stiffness, Some(*nondimensional_link_stiffness));
+            assert_eq!(dataset.nondimensional_end_to_end_length_per_link.len(), 1000);
+            assert!((normalization(&dataset) - 1.0).abs() <= 1e-2);
+        })
+    }
+}
+mod experiment
+{
+    use super::*;
+    #[test]
+    fn all()
+    {
+        EXPERIMENTS.iter().for_each(|(doi, figure, description)|{
+            let dataset = experiment(doi, figure).unwrap();
+            assert_eq!(&dataset.doi, doi);
+            assert_eq!(&dataset.figure, figure);
+            assert_eq!(&dataset.description, description);
+            assert_eq!(dataset.x.len(), dataset.y.len());
+            assert!(dataset.x.iter().all(|stretch| stretch >= &1.0));
+        })
+    }
+    #[test]
+    fn length()
+    {
+        assert_eq!(experiment("10.1126/science.1248494", "fig4c1").unwrap().x.len(), 169);
+        assert_eq!(experiment("10.1126/science.1248494", "fig4c2").unwrap().x.len(), 171);
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn arguments()
+    {
+        assert_eq!(frc(&9, &45), Err(Error::Parameter("number_of_links", 9.0, "one of the numbers of links of the datasets")));
+        assert_eq!(frc(&8, &46), Err(Error::Parameter("bond_angle", 46.0, "one of the bond angles of the datasets")));
+        assert!(efrc(&6).is_err());
+        assert_eq!(experiment("10.1126/science.1248494", "fig4c3"), None);
+        assert_eq!(experiment("10.0000/none", "fig4c1"), None);
+    }
+    #[test]
+    #[should_panic(expected = "data/none.csv")]
+    fn malformed()
+    {
+        columns("1.0 2.0\n3.0\n", "data/none.csv");
+    }
+}
@@ -0,0 +1,102 @@
+mod test;
+
+use crate::math::MathError;
+
+/// The errors that can arise when initializing or evaluating a model.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error
+{
+    /// A model parameter, given by its name and value, does not satisfy the stated requirement.
+    Parameter(&'static str, f64, &'static str),
+    /// A function argument, given by its name and value, is outside the domain stated by the requirement.
+    Domain(&'static str, f64, &'static str),
+    /// The method number is not one of the implemented methods.
+    Method(u8),
+    /// An error arising in one of the mathematical methods.
+    Math(MathError)
+}
+
+impl std::fmt::Display for Error
+{
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        match self
+        {
+            Error::Parameter(name, value, requirement) => write!(formatter, "The parameter {} = {} must be {}.", name, value, requirement),
+            Error::Domain(name, value, requirement) => write!(formatter, "The argument {} = {} must be {}.", name, value, requirement),
+            Error::Method(method) => write!(formatter, "The method number {} is not implemented.", method),
+            Error::Math(error) => error.fmt(formatter)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<MathError> for Error
+{
+    fn from(error: MathError) -> Self
+    {
+        Error::Math(error)
+    }
+}
+
+#[cfg(feature = "python")]
+impl From<Error> for pyo3::PyErr
+{
+    fn from(error: Error) -> Self
+    {
+        pyo3::exceptions::PyValueError::new_err(error.to_string())
+    }
+}
+
+/// Validates that the number of links is nonzero.
+pub fn number_of_links(number_of_links: &u8) -> Result<(), Error>
+{
+    if number_of_links == &0
+    {
+        Err(Error::Parameter("number_of_links", 0.0, "positive"))
+    }
+    else
+    {
+        Ok(())
+    }
+}
+
+/// Validates that a parameter is positive and finite.
+pub fn positive(name: &'static str, value: &f64) -> Result<(), Error>
+{
+    if value > &0.0 && value.is_finite()
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Parameter(name, *value, "positive and finite"))
+    }
+}
+
+/// Validates that a parameter is nonnegative and finite.
+pub fn nonnegative(name: &'static str, value: &f64) -> Result<(), Error>
+{
+    if value >= &0.0 && value.is_finite()
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Parameter(name, *value, "nonnegative and finite"))
+    }
+}
+
+/// Validates that an end-to-end length of an inextensible chain is less than the contour length in magnitude.
+pub fn inextensible(name: &'static str, value: &f64, contour_length: &f64) -> Result<(), Error>
+{
+    if &value.abs() < contour_length
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Domain(name, *value, "less than the contour length in magnitude"))
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(test)]
+use super::*;
+#[test]
+fn parameters()
+{
+    assert_eq!(number_of_links(&1), Ok(()));
+    assert_eq!(number_of_links(&0), Err(Error::Parameter("number_of_links", 0.0, "positive")));
+    assert_eq!(positive("link_length", &1.0), Ok(()));
+    assert!(positive("link_length", &0.0).is_err());
+    assert!(positive("link_length", &-1.0).is_err());
+    assert!(positive("link_length", &f64::NAN).is_err());
+    assert!(positive("link_length", &f64::INFINITY).is_err());
+    assert_eq!(nonnegative("tube_geometry_parameter", &0.0), Ok(()));
+    assert!(nonnegative("tube_geometry_parameter", &-1.0).is_err());
+    assert!(nonnegative("tube_geometry_parameter", &f64::NAN).is_err());
+}
+#[test]
+fn domain()
+{
+    assert_eq!(inextensible("nondimensional_end_to_end_length_per_link", &0.99, &1.0), Ok(()));
+    assert_eq!(inextensible("nondimensional_end_to_end_length_per_link", &-0.99, &1.0), Ok(()));
+    assert!(inextensible("nondimensional_end_to_end_length_per_link", &1.0, &1.0).is_err());
+    assert!(inextensible("nondimensional_end_to_end_length_per_link", &f64::NAN, &1.0).is_err());
+    assert_eq!(inextensible("end_to_end_length", &7.0, &8.0), Ok(()));
+    assert_eq!(inextensible("end_to_end_length", &8.0, &8.0), Err(Error::Domain("end_to_end_length", 8.0, "less than the contour length in magnitude")));
+}
+#[test]
+fn display()
+{
+    assert_eq!(Error::Parameter("link_length", -1.0, "positive and finite").to_string(), "The parameter link_length = -1 must be positive and finite.");
+    assert_eq!(Error::Method(7).to_string(), "The method number 7 is not implemented.");
+    assert_eq!(Error::from(MathError::Pole("gamma", 0.0)).to_string(), MathError::Pole("gamma", 0.0).to_string());
+}
@@ -0,0 +1,405 @@
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+use crate::constitutive::
+{
+    Hyperelastic,
+    hyperelastic::
+    {
+        AffineMicrosphere,
+        ChainModel,
+        EightChain,
+        NonaffineMicrosphere,
+        ThreeChain
+    }
+};
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::math::MathError;
+use crate::physics::
+{
+    BOLTZMANN_CONSTANT,
+    single_chain::ForceLaw
+};
+
+/// The maximum number of iterations of the Levenberg-Marquardt method.
+pub const MAXIMUM_ITERATIONS: u32 = 500;
+
+/// The relative decrease in the sum of squared residuals, or the relative size of the step, below which the Levenberg-Marquardt method has converged.
+pub const TOLERANCE: f64 = 1e-12;
+
+/// The quantile of the standard normal distribution giving the asymptotic 95% confidence intervals of the parameters.
+pub const CONFIDENCE_QUANTILE: f64 = 1.959963984540054;
+
+/// The hinge mass given to the single-chain models while fitting, which does not affect the forces or stresses.
+const HINGE_MASS: f64 = 1.0;
+
+/// The damping above which no step decreases the sum of squared residuals, such that the parameters are at a minimum.
+const MAXIMUM_DAMPING: f64 = 1e16;
+
+/// The result of fitting parameters to data with nonlinear least squares.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeastSquares
+{
+    /// The fitted parameters.
+    pub parameters: Vec<f64>,
+
+    /// The standard errors of the fitted parameters, from the covariance estimated by the Jacobian of the residuals.
+    pub standard_errors: Vec<f64>,
+
+    /// The asymptotic 95% confidence intervals of the fitted parameters.
+    pub confidence_intervals: Vec<[f64; 2]>,
+
+    /// The residuals, or the model minus the data, at the fitted parameters.
+    pub residuals: Vec<f64>,
+
+    /// The sum of the squared residuals at the fitted parameters.
+    pub sum_of_squared_residuals: f64,
+
+    /// The number of iterations taken to converge.
+    pub iterations: u32
+}
+
+/// The network constitutive models available for fitting to stress-stretch data.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Network
+{
+    /// The eight-chain model.
+    EightChain = 1,
+    /// The three-chain model.
+    ThreeChain = 2,
+    /// The affine microsphere model.
+    AffineMicrosphere = 3,
+    /// The nonaffine microsphere model, parameterized by the nonaffine stretch parameter, tube geometry parameter, and tube area exponent.
+    NonaffineMicrosphere = 4
+}
+
+/// The implemented functionality of the network constitutive models available for fitting.
+impl Network
+{
+    /// The number of parameters of the network constitutive model beyond the single-chain model and temperature.
+    pub fn number_of_network_parameters(&self) -> usize
+    {
+        match self
+        {
+            Network::EightChain | Network::ThreeChain | Network::AffineMicrosphere => 0,
+            Network::NonaffineMicrosphere => 3
+        }
+    }
+    /// Initializes and returns an instance of the network constitutive model, where any parameters beyond the single-chain model and temperature are given in order,
+    /// or an error if any of the parameters are invalid.
+    pub fn try_init(&self, chain: Box<dyn ForceLaw + Send + Sync>, temperature: f64, network_parameters: &[f64]) -> Result<Box<dyn Hyperelastic>, Error>
+    {
+        Ok(match self
+        {
+            Network::EightChain => Box::new(EightChain::try_init(chain, temperature)?),
+            Network::ThreeChain => Box::new(ThreeChain::try_init(chain, temperature)?),
+            Network::AffineMicrosphere => Box::new(AffineMicrosphere::try_init(chain, temperature)?),
+            Network::NonaffineMicrosphere => Box::new(NonaffineMicrosphere::try_init(chain, temperature, network_parameters[0], network_parameters[1], network_parameters[2])?)
+        })
+    }
+}
+
+/// The homogeneous deformation modes of stress-stretch data.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Loading
+{
+    /// Uniaxial tension, where the stress is the Cauchy stress as a function of the stretch.
+    UniaxialTension = 1,
+    /// Equibiaxial tension, where the stress is the Cauchy stress as a function of the stretch.
+    EquibiaxialTension = 2
+}
+
+/// Validates that the initial parameters are within the lower and upper bounds.
+fn validate_bounds(initial: &[f64], lower: &[f64], upper: &[f64]) -> Result<(), Error>
+{
+    if lower.len() != initial.len()
+    {
+        return Err(Error::Domain("lower", lower.len() as f64, "of the same length as the initial parameters"))
+    }
+    if upper.len() != initial.len()
+    {
+        return Err(Error::Domain("upper", upper.len() as f64, "of the same length as the initial parameters"))
+    }
+    initial.iter().zip(lower.iter().zip(upper.iter())).try_for_each(|(initial_i, (lower_i, upper_i))|
+        if lower_i <= initial_i && initial_i <= upper_i
+        {
+            Ok(())
+        }
+        else
+        {
+            Err(Error::Domain("initial", *initial_i, "within the lower and upper bounds"))
+        }
+    )
+}
+
+/// Validates that the data are pairs of the same length.
+fn validate_data(name: &'static str, x: &[f64], y: &[f64]) -> Result<(), Error>
+{
+    if x.len() == y.len()
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::Domain(name, y.len() as f64, "of the same length as the applied data"))
+    }
+}
+
+/// The inverse of a symmetric positive semidefinite matrix using Gauss-Jordan elimination with partial pivoting after scaling its diagonal to unity,
+/// or nothing if the matrix is singular.
+fn inverse(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>>
+{
+    let size = matrix.len();
+    let scale: Vec<f64> = (0..size).map(|i| matrix[i][i].sqrt()).collect();
+    if scale.iter().any(|scale_i| !(scale_i > &0.0 && scale_i.is_finite()))
+    {
+        return None
+    }
+    let mut augmented: Vec<Vec<f64>> = matrix.iter().enumerate().map(|(i, row)|
+        row.iter().enumerate().map(|(j, entry)| entry / scale[i] / scale[j]).chain((0..size).map(|j| (i == j) as u8 as f64)).collect()
+    ).collect();
+    for column in 0..size
+    {
+        let pivot = (column..size).max_by(|&a, &b| augmented[a][column].abs().total_cmp(&augmented[b][column].abs()))?;
+        if augmented[pivot][column].abs() <= f64::EPSILON * size as f64 || !augmented[pivot][column].is_finite()
+        {
+            return None
+        }
+        augmented.swap(column, pivot);
+        let pivot_entry = augmented[column][column];
+        augmented[column].iter_mut().for_each(|entry| *entry /= pivot_entry);
+        let pivot_row = augmented[column].clone();
+        augmented.iter_mut().enumerate().filter(|(row, _)| row != &column).for_each(|(_, row)|{
+            let factor = row[column];
+            row.iter_mut().zip(pivot_row.iter()).for_each(|(entry, pivot_row_entry)| *entry -= factor * pivot_row_entry)
+        });
+    }
+    Some(augmented.into_iter().enumerate().map(|(i, row)|
+        row[size..].iter().enumerate().map(|(j, entry)| entry / scale[i] / scale[j]).collect()
+    ).collect())
+}
+
+/// The sum of squared residuals, which is infinite if any of the residuals are not finite.
+fn sum_of_squares(residuals: &[f64]) -> f64
+{
+    let sum_of_squares: f64 = residuals.iter().map(|residual| residual.powi(2)).sum();
+    if sum_of_squares.is_finite()
+    {
+        sum_of_squares
+    }
+    else
+    {
+        f64::INFINITY
+    }
+}
+
+/// The Jacobian of the residuals with respect to the parameters using forward differences, taken backward at the upper bounds.
+fn jacobian(residuals: &dyn Fn(&[f64]) -> Vec<f64>, parameters: &[f64], current: &[f64], upper: &[f64]) -> Vec<Vec<f64>>
+{
+    let mut jacobian = vec![vec![0.0; parameters.len()]; current.len()];
+    parameters.iter().zip(upper.iter()).enumerate().for_each(|(j, (parameter, upper_j))|{
+        let mut step = f64::EPSILON.sqrt() * parameter.abs().max(f64::EPSILON.sqrt());
+        if parameter + step > *upper_j
+        {
+            step = -step
+        }
+        let mut perturbed = parameters.to_vec();
+        perturbed[j] += step;
+        residuals(&perturbed).iter().zip(current.iter()).zip(jacobian.iter_mut()).for_each(|((perturbed_i, current_i), jacobian_i)|
+            jacobian_i[j] = (perturbed_i - current_i) / step
+        );
+    });
+    jacobian
+}
+
+/// The approximate Hessian and gradient of half the sum of squared residuals from the Jacobian.
+fn normal_equations(jacobian: &[Vec<f64>], residuals: &[f64]) -> (Vec<Vec<f64>>, Vec<f64>)
+{
+    let number_of_parameters = jacobian.first().map_or(0, |row| row.len());
+    let hessian = (0..number_of_parameters).map(|j|
+        (0..number_of_parameters).map(|k|
+            jacobian.iter().map(|row| row[j] * row[k]).sum()
+        ).collect()
+    ).collect();
+    let gradient = (0..number_of_parameters).map(|j|
+        jacobian.iter().zip(residuals.iter()).map(|(row, residual)| row[j] * residual).sum()
+    ).collect();
+    (hessian, gradient)
+}
+
+/// Minimizes the sum of squared residuals over parameters within lower and upper bounds using the Levenberg-Marquardt method,
+/// where parameters held at a bound by the gradient are fixed for the step, steps are projected onto the bounds,
+/// and any parameters that give residuals which are not finite are rejected.
+pub fn levenberg_marquardt(residuals: &dyn Fn(&[f64]) -> Vec<f64>, initial: &[f64], lower: &[f64], upper: &[f64]) -> Result<LeastSquares, Error>
+{
+    validate_bounds(initial, lower, upper)?;
+    let mut parameters = initial.to_vec();
+    let mut current = residuals(&parameters);
+    if current.len() < parameters.len()
+    {
+        return Err(Error::Domain("number_of_residuals", current.len() as f64, "at least the number of parameters"))
+    }
+    let mut sum_of_squared_residuals = sum_of_squares(&current);
+    if !sum_of_squared_residuals.is_finite()
+    {
+        return Err(Error::Domain("sum_of_squared_residuals", sum_of_squared_residuals, "finite for the initial parameters"))
+    }
+    let mut damping = 1e-3;
+    for iteration in 1..=MAXIMUM_ITERATIONS
+    {
+        let (hessian, gradient) = normal_equations(&jacobian(residuals, &parameters, &current, upper), &current);
+        let free: Vec<usize> = (0..parameters.len()).filter(|&j|
+            !((parameters[j] <= lower[j] && gradient[j] >= 0.0) || (parameters[j] >= upper[j] && gradient[j] <= 0.0))
+        ).collect();
+        let mut converged = true;
+        while damping < MAXIMUM_DAMPING && sum_of_squared_residuals > 0.0 && !free.is_empty()
+        {
+            let damped: Vec<Vec<f64>> = free.iter().map(|&j|
+                free.iter().map(|&k| hessian[j][k] + (j == k) as u8 as f64 * damping * hessian[j][j].max(f64::EPSILON)).collect()
+            ).collect();
+            if let Some(inverse) = inverse(&damped)
+            {
+                let mut trial = parameters.clone();
+                free.iter().zip(inverse.iter()).for_each(|(&j, inverse_j)|
+                    trial[j] = (parameters[j] - inverse_j.iter().zip(free.iter()).map(|(inverse_jk, &k)| inverse_jk * gradient[k]).sum::<f64>()).clamp(lower[j], upper[j])
+                );
+                let trial_residuals = residuals(&trial);
+                let trial_sum_of_squared_residuals = sum_of_squares(&trial_residuals);
+                if trial_sum_of_squared_residuals < sum_of_squared_residuals
+                {
+                    let step: f64 = trial.iter().zip(parameters.iter()).map(|(trial_j, parameter)| (trial_j - parameter).powi(2)).sum::<f64>().sqrt();
+                    let size: f64 = parameters.iter().map(|parameter| parameter.powi(2)).sum::<f64>().sqrt();
+                    converged = sum_of_squared_residuals - trial_sum_of_squared_residuals <= TOLERANCE * sum_of_squared_residuals || step <= TOLERANCE * (size + TOLERANCE);
+                    parameters = trial;
+                    current = trial_residuals;
+                    sum_of_squared_residuals = trial_sum_of_squared_residuals;
+                    damping = (damping / 3.0).max(1e-12);
+                    break
+                }
+            }
+            damping *= 4.0;
+        }
+        if converged
+        {
+            return Ok(statistics(residuals, parameters, current, sum_of_squared_residuals, upper, iteration))
+        }
+    }
+    Err(MathError::Convergence("levenberg_marquardt", MAXIMUM_ITERATIONS).into())
+}
+
+/// The standard errors and confidence intervals of the fitted parameters, from the covariance estimated by the Jacobian of the residuals.
+fn statistics(residuals: &dyn Fn(&[f64]) -> Vec<f64>, parameters: Vec<f64>, current: Vec<f64>, sum_of_squared_residuals: f64, upper: &[f64], iterations: u32) -> LeastSquares
+{
+    let degrees_of_freedom = current.len() - parameters.len();
+    let (hessian, _) = normal_equations(&jacobian(residuals, &parameters, &current, upper), &current);
+    let standard_errors: Vec<f64> = match inverse(&hessian)
+    {
+        Some(covariance) if degrees_of_freedom > 0 => covariance.iter().enumerate().map(|(j, row)|
+            (row[j] * sum_of_squared_residuals / degrees_of_freedom as f64).sqrt()
+        ).collect(),
+        _ => vec![f64::NAN; parameters.len()]
+    };
+    let confidence_intervals = parameters.iter().zip(standard_errors.iter()).map(|(parameter, standard_error)|
+        [parameter - CONFIDENCE_QUANTILE * standard_error, parameter + CONFIDENCE_QUANTILE * standard_error]
+    ).collect();
+    LeastSquares
+    {
+        parameters,
+        standard_errors,
+        confidence_intervals,
+        residuals: current,
+        sum_of_squared_residuals,
+        iterations
+    }
+}
+
+/// Fits for each of the numbers of links, returning the number of links and fit with the smallest sum of squared residuals,
+/// or the first error if none of the fits succeed.
+fn best_number_of_links(numbers_of_links: &[u8], fit: impl Fn(u8) -> Result<LeastSquares, Error>) -> Result<(u8, LeastSquares), Error>
+{
+    if numbers_of_links.is_empty()
+    {
+        return Err(Error::Domain("numbers_of_links", 0.0, "nonempty"))
+    }
+    numbers_of_links.iter().try_for_each(error::number_of_links)?;
+    let mut best: Option<(u8, LeastSquares)> = None;
+    let mut first_error = None;
+    numbers_of_links.iter().for_each(|number_of_links| match fit(*number_of_links)
+    {
+        Ok(least_squares) => if best.as_ref().is_none_or(|(_, best)| least_squares.sum_of_squared_residuals < best.sum_of_squared_residuals)
+        {
+            best = Some((*number_of_links, least_squares))
+        },
+        Err(error) => if first_error.is_none()
+        {
+            first_error = Some(error)
+        }
+    });
+    best.ok_or_else(|| first_error.unwrap())
+}
+
+/// Fits the link length and any further parameters of a single-chain model to force-extension data at a given temperature,
+/// where the number of links is chosen from the given numbers of links as the one with the smallest sum of squared residuals.
+/// The parameters are the link length followed by the model parameters, as in [`ChainModel::init`], and the residuals are the force of the model minus the data.
+/// The data must remain within the range of extensions of the model.
+#[allow(clippy::too_many_arguments)]
+pub fn single_chain(chain_model: &ChainModel, temperature: &f64, end_to_end_length: &[f64], force: &[f64], numbers_of_links: &[u8], initial: &[f64], lower: &[f64], upper: &[f64]) -> Result<(u8, LeastSquares), Error>
+{
+    error::positive("temperature", temperature)?;
+    validate_data("force", end_to_end_length, force)?;
+    if initial.len() != 1 + chain_model.number_of_model_parameters()
+    {
+        return Err(Error::Domain("initial", initial.len() as f64, "one more than the number of model parameters in length"))
+    }
+    best_number_of_links(numbers_of_links, |number_of_links| levenberg_marquardt(&|parameters: &[f64]|{
+        let link_length = parameters[0];
+        let chain = chain_model.init(number_of_links, link_length, HINGE_MASS, &parameters[1..]);
+        end_to_end_length.iter().zip(force.iter()).map(|(end_to_end_length_i, force_i)|
+            BOLTZMANN_CONSTANT * temperature / link_length * chain.nondimensional_force(&(end_to_end_length_i / (number_of_links as f64) / link_length), temperature) - force_i
+        ).collect()
+    }, initial, lower, upper))
+}
+
+/// Fits the stress scale, which is the number density of chains times the thermal energy, and any further parameters of a network constitutive model
+/// to stress-stretch data in any of the homogeneous deformation modes at a given temperature and link length,
+/// where the number of links is chosen from the given numbers of links as the one with the smallest sum of squared residuals.
+/// The parameters are the stress scale, followed by the model parameters of the single-chain model as in [`ChainModel::init`], followed by the network parameters as in [`Network::try_init`],
+/// and the residuals are the stress of the model minus the data, in the same units as the stress scale.
+#[allow(clippy::too_many_arguments)]
+pub fn hyperelastic(network: &Network, chain_model: &ChainModel, temperature: &f64, link_length: &f64, data: &[(Loading, &[f64], &[f64])], numbers_of_links: &[u8], initial: &[f64], lower: &[f64], upper: &[f64]) -> Result<(u8, LeastSquares), Error>
+{
+    error::positive("temperature", temperature)?;
+    error::positive("link_length", link_length)?;
+    data.iter().try_for_each(|(_, stretch, stress)| validate_data("stress", stretch, stress))?;
+    let number_of_model_parameters = chain_model.number_of_model_parameters();
+    if initial.len() != 1 + number_of_model_parameters + network.number_of_network_parameters()
+    {
+        return Err(Error::Domain("initial", initial.len() as f64, "one more than the number of model and network parameters in length"))
+    }
+    best_number_of_links(numbers_of_links, |number_of_links| levenberg_marquardt(&|parameters: &[f64]|{
+        let chain = chain_model.init(number_of_links, *link_length, HINGE_MASS, &parameters[1..1 + number_of_model_parameters]);
+        match network.try_init(chain, *temperature, &parameters[1 + number_of_model_parameters..])
+        {
+            Ok(model) => data.iter().flat_map(|(loading, stretch, stress)| stretch.iter().zip(stress.iter()).map(|(stretch_i, stress_i)|
+                parameters[0] * match loading
+                {
+                    Loading::UniaxialTension => model.uniaxial_tension(stretch_i),
+                    Loading::EquibiaxialTension => model.equibiaxial_tension(stretch_i)
+                } - stress_i
+            ).collect::<Vec<f64>>()).collect(),
+            Err(_) => vec![f64::NAN; data.iter().map(|(_, stretch, _)| stretch.len()).sum()]
+        }
+    }, initial, lower, upper))
+}
@@ -0,0 +1,136 @@
+use pyo3::prelude::*;
+use numpy::PyReadonlyArrayDyn;
+use crate::constitutive::hyperelastic::ChainModel;
+use super::
+{
+    Loading,
+    Network
+};
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let fit = PyModule::new(py, "fit")?;
+    fit.add_class::<ChainModel>()?;
+    fit.add_class::<Network>()?;
+    fit.add_class::<Loading>()?;
+    fit.add_class::<LeastSquares>()?;
+    fit.add_function(wrap_pyfunction!(single_chain, fit)?)?;
+    fit.add_function(wrap_pyfunction!(hyperelastic, fit)?)?;
+    parent_module.add_submodule(fit)?;
+    Ok(())
+}
+
+/// The result of fitting parameters to data with nonlinear least squares using the Levenberg-Marquardt method,
+/// where the confidence intervals are asymptotic, or :math:`\theta\pm 1.96\,\mathrm{SE}(\theta)`.
+#[pyclass]
+pub struct LeastSquares
+{
+    /// The number of links with the smallest sum of squared residuals.
+    #[pyo3(get)]
+    pub number_of_links: u8,
+
+    /// The fitted parameters.
+    #[pyo3(get)]
+    pub parameters: Vec<f64>,
+
+    /// The standard errors of the fitted parameters.
+    #[pyo3(get)]
+    pub standard_errors: Vec<f64>,
+
+    /// The 95% confidence intervals of the fitted parameters.
+    #[pyo3(get)]
+    pub confidence_intervals: Vec<[f64; 2]>,
+
+    /// The residuals, or the model minus the data, at the fitted parameters.
+    #[pyo3(get)]
+    pub residuals: Vec<f64>,
+
+    /// The sum of the squared residuals at the fitted parameters.
+    #[pyo3(get)]
+    pub sum_of_squared_residuals: f64,
+
+    /// The number of iterations taken to converge.
+    #[pyo3(get)]
+    pub iterations: u32
+}
+
+impl From<(u8, super::LeastSquares)> for LeastSquares
+{
+    fn from((number_of_links, least_squares): (u8, super::LeastSquares)) -> Self
+    {
+        LeastSquares
+        {
+            number_of_links,
+            parameters: least_squares.parameters,
+            standard_errors: least_squares.standard_errors,
+            confidence_intervals: least_squares.confidence_intervals,
+            residuals: least_squares.residuals,
+            sum_of_squared_residuals: least_squares.sum_of_squared_residuals,
+            iterations: least_squares.iterations
+        }
+    }
+}
+
+/// Fits the link length and any further parameters of a single-chain model to force-extension data at a given temperature,
+/// where the number of links is chosen from the given numbers of links as the one with the smallest sum of squared residuals.
+/// The residuals are the force of the model minus the data, and the data must remain within the range of extensions of the model.
+///
+/// Args:
+///     chain_model (ChainModel): The single-chain model.
+///     temperature (float): The temperature :math:`T`.
+///     end_to_end_length (numpy.ndarray): The end-to-end lengths :math:`\xi`.
+///     force (numpy.ndarray): The forces :math:`f`.
+///     numbers_of_links (list): The numbers of links :math:`N_b` to consider.
+///     initial (list): The initial link length :math:`\ell_b`, followed by any initial parameters of the model, such as the link stiffness.
+///     lower (list): The lower bounds of the parameters.
+///     upper (list): The upper bounds of the parameters.
+///
+/// Returns:
+///     LeastSquares: The fitted parameters, along with the number of links, confidence intervals, and residuals.
+///
+/// Raises:
+///     ValueError: If any of the arguments are invalid, or the fit does not converge.
+///
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn single_chain(chain_model: ChainModel, temperature: f64, end_to_end_length: PyReadonlyArrayDyn<f64>, force: PyReadonlyArrayDyn<f64>, numbers_of_links: Vec<u8>, initial: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>) -> PyResult<LeastSquares>
+{
+    let end_to_end_length: Vec<f64> = end_to_end_length.as_array().iter().copied().collect();
+    let force: Vec<f64> = force.as_array().iter().copied().collect();
+    Ok(super::single_chain(&chain_model, &temperature, &end_to_end_length, &force, &numbers_of_links, &initial, &lower, &upper)?.into())
+}
+
+/// Fits the stress scale :math:`nkT` and any further parameters of a network constitutive model to stress-stretch data
+/// in any of the homogeneous deformation modes at a given temperature and link length,
+/// where the number of links is chosen from the given numbers of links as the one with the smallest sum of squared residuals.
+/// The residuals are the Cauchy stress of the model minus the data, in the same units as the stress scale.
+///
+/// Args:
+///     network (Network): The network constitutive model.
+///     chain_model (ChainModel): The single-chain model.
+///     temperature (float): The temperature :math:`T`.
+///     link_length (float): The link length :math:`\ell_b`.
+///     data (list): Tuples of the deformation mode (Loading), the stretches, and the Cauchy stresses.
+///     numbers_of_links (list): The numbers of links :math:`N_b` to consider.
+///     initial (list): The initial stress scale, followed by any initial parameters of the single-chain model, followed by any initial parameters of the network model.
+///     lower (list): The lower bounds of the parameters.
+///     upper (list): The upper bounds of the parameters.
+///
+/// Returns:
+///     LeastSquares: The fitted parameters, along with the number of links, confidence intervals, and residuals.
+///
+/// Raises:
+///     ValueError: If any of the arguments are invalid, or the fit does not converge.
+///
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn hyperelastic(network: Network, chain_model: ChainModel, temperature: f64, link_length: f64, data: Vec<(Loading, PyReadonlyArrayDyn<f64>, PyReadonlyArrayDyn<f64>)>, numbers_of_links: Vec<u8>, initial: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>) -> PyResult<LeastSquares>
+{
+    let data: Vec<(Loading, Vec<f64>, Vec<f64>)> = data.iter().map(|(loading, stretch, stress)|
+        (*loading, stretch.as_array().iter().copied().collect(), stress.as_array().iter().copied().collect())
+    ).collect();
+    let data: Vec<(Loading, &[f64], &[f64])> = data.iter().map(|(loading, stretch, stress)|
+        (*loading, &stretch[..], &stress[..])
+    ).collect();
+    Ok(super::hyperelastic(&network, &chain_model, &temperature, &link_length, &data, &numbers_of_links, &initial, &lower, &upper)?.into())
+}
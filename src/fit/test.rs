@@ -0,0 +1,145 @@
+#![cfg(test)]
+use super::*;
+use crate::constitutive::hyperelastic::ChainModel;
+const TEMPERATURE: f64 = 300.0;
+mod levenberg_marquardt
+{
+    use super::*;
+    fn exponential(parameters: &[f64], x: &f64) -> f64
+    {
+        parameters[0] * (-parameters[1] * x).exp()
+    }
+    #[test]
+    fn exact()
+    {
+        let x: Vec<f64> = (0..20).map(|i| 0.1 * i as f64).collect();
+        let y: Vec<f64> = x.iter().map(|x_i| exponential(&[2.0, 1.5], x_i)).collect();
+        let fit = levenberg_marquardt(&|parameters: &[f64]| x.iter().zip(y.iter()).map(|(x_i, y_i)| exponential(parameters, x_i) - y_i).collect(), &[1.0, 0.5], &[0.0, 0.0], &[10.0, 10.0]).unwrap();
+        assert!((fit.parameters[0] / 2.0 - 1.0).abs() <= 1e-8);
+        assert!((fit.parameters[1] / 1.5 - 1.0).abs() <= 1e-8);
+        assert!(fit.sum_of_squared_residuals <= 1e-16);
+    }
+    #[test]
+    fn noisy()
+    {
+        let x: Vec<f64> = (0..50).map(|i| 0.05 * i as f64).collect();
+        let y: Vec<f64> = x.iter().enumerate().map(|(i, x_i)| exponential(&[2.0, 1.5], x_i) + 0.01 * (7.0 * i as f64).sin()).collect();
+        let fit = levenberg_marquardt(&|parameters: &[f64]| x.iter().zip(y.iter()).map(|(x_i, y_i)| exponential(parameters, x_i) - y_i).collect(), &[1.0, 0.5], &[0.0, 0.0], &[10.0, 10.0]).unwrap();
+        assert_eq!(fit.residuals.len(), x.len());
+        assert!((fit.sum_of_squared_residuals - fit.residuals.iter().map(|residual| residual.powi(2)).sum::<f64>()).abs() <= 1e-14);
+        [2.0, 1.5].iter().zip(fit.parameters.iter().zip(fit.standard_errors.iter().zip(fit.confidence_intervals.iter()))).for_each(|(truth, (parameter, (standard_error, [lower, upper])))|{
+            assert!(standard_error > &0.0 && standard_error < &0.1);
+            assert!((upper - lower - 2.0 * CONFIDENCE_QUANTILE * standard_error).abs() <= 1e-12);
+            assert!(lower < parameter && parameter < upper);
+            assert!(lower < truth && truth < upper);
+        });
+    }
+    #[test]
+    fn bounds()
+    {
+        let x: Vec<f64> = (0..20).map(|i| 0.1 * i as f64).collect();
+        let y: Vec<f64> = x.iter().map(|x_i| exponential(&[2.0, 1.5], x_i)).collect();
+        let fit = levenberg_marquardt(&|parameters: &[f64]| x.iter().zip(y.iter()).map(|(x_i, y_i)| exponential(parameters, x_i) - y_i).collect(), &[1.0, 0.5], &[0.0, 0.0], &[1.8, 10.0]).unwrap();
+        assert_eq!(fit.parameters[0], 1.8);
+        assert!(fit.parameters[1] < 1.5);
+    }
+}
+mod single_chain
+{
+    use super::*;
+    fn data(chain_model: &ChainModel, number_of_links: u8, link_length: f64, model_parameters: &[f64; 2]) -> (Vec<f64>, Vec<f64>)
+    {
+        let chain = chain_model.init(number_of_links, link_length, 1.0, model_parameters);
+        let end_to_end_length: Vec<f64> = (1..=16).map(|i| 0.05 * i as f64 * number_of_links as f64 * link_length).collect();
+        let force = end_to_end_length.iter().map(|end_to_end_length_i|
+            BOLTZMANN_CONSTANT * TEMPERATURE / link_length * chain.nondimensional_force(&(end_to_end_length_i / number_of_links as f64 / link_length), &TEMPERATURE)
+        ).collect();
+        (end_to_end_length, force)
+    }
+    #[test]
+    fn fjc()
+    {
+        let (end_to_end_length, force) = data(&ChainModel::FJC, 8, 0.5, &[0.0, 0.0]);
+        let numbers_of_links: Vec<u8> = (4..=12).collect();
+        let (number_of_links, fit) = single_chain(&ChainModel::FJC, &TEMPERATURE, &end_to_end_length, &force, &numbers_of_links, &[0.6], &[0.1], &[2.0]).unwrap();
+        assert_eq!(number_of_links, 8);
+        assert!((fit.parameters[0] / 0.5 - 1.0).abs() <= 1e-6);
+    }
+    #[test]
+    fn efjc()
+    {
+        let (end_to_end_length, force) = data(&ChainModel::EFJC, 8, 0.5, &[5e5, 0.0]);
+        let (number_of_links, fit) = single_chain(&ChainModel::EFJC, &TEMPERATURE, &end_to_end_length, &force, &[8], &[0.6, 2e5], &[0.1, 1e4], &[2.0, 1e7]).unwrap();
+        assert_eq!(number_of_links, 8);
+        assert!((fit.parameters[0] / 0.5 - 1.0).abs() <= 1e-6);
+        assert!((fit.parameters[1] / 5e5 - 1.0).abs() <= 1e-4);
+    }
+}
+mod hyperelastic
+{
+    use super::*;
+    #[test]
+    fn eight_chain()
+    {
+        let stretch: Vec<f64> = (1..=12).map(|i| 1.0 + 0.2 * i as f64).collect();
+        let model = Network::EightChain.try_init(ChainModel::FJC.init(12, 1.0, 1.0, &[0.0, 0.0]), TEMPERATURE, &[]).unwrap();
+        let uniaxial_tension: Vec<f64> = stretch.iter().map(|stretch_i| 0.3 * model.uniaxial_tension(stretch_i)).collect();
+        let equibiaxial_tension: Vec<f64> = stretch.iter().map(|stretch_i| 0.3 * model.equibiaxial_tension(stretch_i)).collect();
+        let data = [(Loading::UniaxialTension, &stretch[..], &uniaxial_tension[..]), (Loading::EquibiaxialTension, &stretch[..], &equibiaxial_tension[..])];
+        let numbers_of_links: Vec<u8> = (6..=24).collect();
+        let (number_of_links, fit) = hyperelastic(&Network::EightChain, &ChainModel::FJC, &TEMPERATURE, &1.0, &data, &numbers_of_links, &[1.0], &[1e-3], &[1e3]).unwrap();
+        assert_eq!(number_of_links, 12);
+        assert!((fit.parameters[0] / 0.3 - 1.0).abs() <= 1e-8);
+        assert_eq!(fit.residuals.len(), 2 * stretch.len());
+    }
+    #[test]
+    fn nonaffine_microsphere()
+    {
+        let stretch: Vec<f64> = (1..=12).map(|i| 1.0 + 0.2 * i as f64).collect();
+        let model = Network::NonaffineMicrosphere.try_init(ChainModel::FJC.init(12, 1.0, 1.0, &[0.0, 0.0]), TEMPERATURE, &[1.5, 0.5, 0.5]).unwrap();
+        let uniaxial_tension: Vec<f64> = stretch.iter().map(|stretch_i| 0.3 * model.uniaxial_tension(stretch_i)).collect();
+        let equibiaxial_tension: Vec<f64> = stretch.iter().map(|stretch_i| 0.3 * model.equibiaxial_tension(stretch_i)).collect();
+        let data = [(Loading::UniaxialTension, &stretch[..], &uniaxial_tension[..]), (Loading::EquibiaxialTension, &stretch[..], &equibiaxial_tension[..])];
+        let (_, fit) = hyperelastic(&Network::NonaffineMicrosphere, &ChainModel::FJC, &TEMPERATURE, &1.0, &data, &[12], &[0.2, 1.2, 0.3, 0.5], &[1e-3, 1.0, 0.0, 0.5], &[1e3, 3.0, 2.0, 0.5]).unwrap();
+        assert!((fit.parameters[0] / 0.3 - 1.0).abs() <= 1e-4);
+        assert!((fit.parameters[1] / 1.5 - 1.0).abs() <= 1e-4);
+        assert!((fit.parameters[2] / 0.5 - 1.0).abs() <= 1e-4);
+        assert_eq!(fit.parameters[3], 0.5);
+    }
+    #[test]
+    fn experiment()
+    {
+        let (stretch, stress): (Vec<f64>, Vec<f64>) = include_str!("../../data/doi/10.1126/science.1248494/fig4c1.csv").lines().map(|line|{
+            let mut entries = line.split_whitespace().map(|entry| entry.parse::<f64>().unwrap());
+            (entries.next().unwrap(), entries.next().unwrap())
+        }).unzip();
+        let data = [(Loading::UniaxialTension, &stretch[..], &stress[..])];
+        let numbers_of_links: Vec<u8> = (4..=32).collect();
+        let (number_of_links, fit) = hyperelastic(&Network::EightChain, &ChainModel::FJC, &TEMPERATURE, &1.0, &data, &numbers_of_links, &[0.5], &[1e-3], &[1e2]).unwrap();
+        let model = Network::EightChain.try_init(ChainModel::FJC.init(number_of_links, 1.0, 1.0, &[0.0, 0.0]), TEMPERATURE, &[]).unwrap();
+        let initial: f64 = stretch.iter().zip(stress.iter()).map(|(stretch_i, stress_i)| (0.5 * model.uniaxial_tension(stretch_i) - stress_i).powi(2)).sum();
+        assert_eq!(fit.residuals.len(), stretch.len());
+        assert!(fit.sum_of_squared_residuals < initial);
+        assert!(fit.confidence_intervals[0][0] < fit.parameters[0] && fit.parameters[0] < fit.confidence_intervals[0][1]);
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn arguments()
+    {
+        let residuals = |parameters: &[f64]| vec![parameters[0] - 1.0, parameters[0] + 1.0];
+        assert!(levenberg_marquardt(&residuals, &[0.5], &[0.0], &[1.0]).is_ok());
+        assert_eq!(levenberg_marquardt(&residuals, &[2.0], &[0.0], &[1.0]), Err(Error::Domain("initial", 2.0, "within the lower and upper bounds")));
+        assert!(levenberg_marquardt(&residuals, &[0.5], &[0.0, 0.0], &[1.0]).is_err());
+        assert!(levenberg_marquardt(&|_: &[f64]| vec![f64::NAN; 2], &[0.5], &[0.0], &[1.0]).is_err());
+        assert!(levenberg_marquardt(&|parameters: &[f64]| vec![parameters[0]], &[0.5, 0.5], &[0.0, 0.0], &[1.0, 1.0]).is_err());
+        assert!(single_chain(&ChainModel::FJC, &TEMPERATURE, &[1.0, 2.0], &[1.0], &[8], &[0.5], &[0.1], &[1.0]).is_err());
+        assert!(single_chain(&ChainModel::EFJC, &TEMPERATURE, &[1.0, 2.0], &[1.0, 2.0], &[8], &[0.5], &[0.1], &[1.0]).is_err());
+        assert!(single_chain(&ChainModel::FJC, &TEMPERATURE, &[1.0, 2.0], &[1.0, 2.0], &[], &[0.5], &[0.1], &[1.0]).is_err());
+        assert!(single_chain(&ChainModel::FJC, &TEMPERATURE, &[1.0, 2.0], &[1.0, 2.0], &[0], &[0.5], &[0.1], &[1.0]).is_err());
+        assert!(hyperelastic(&Network::NonaffineMicrosphere, &ChainModel::FJC, &TEMPERATURE, &1.0, &[(Loading::UniaxialTension, &[1.5], &[1.0])], &[8], &[1.0], &[0.1], &[10.0]).is_err());
+        assert!(hyperelastic(&Network::EightChain, &ChainModel::FJC, &-1.0, &1.0, &[(Loading::UniaxialTension, &[1.5], &[1.0])], &[8], &[1.0], &[0.1], &[10.0]).is_err());
+    }
+}
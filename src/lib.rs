@@ -6,6 +6,20 @@ pub mod py;
 /// Constitutive models.
 pub mod constitutive;
 
+/// Serialization and construction of models from TOML and JSON descriptions.
+#[cfg(feature = "serde")]
+pub mod config;
+
+/// Bundled experimental and Monte Carlo datasets.
+#[cfg(feature = "data")]
+pub mod data;
+
+/// Errors arising from invalid parameters and arguments.
+pub mod error;
+
+/// Fitting model parameters to experimental data.
+pub mod fit;
+
 /// Mathematical methods.
 pub mod math;
 
@@ -0,0 +1,15 @@
+#[no_mangle]
+pub extern fn math_inverse_langevin(y: f64) -> f64
+{
+    super::inverse_langevin(&y)
+}
+#[no_mangle]
+pub extern fn math_inverse_langevin_derivative(y: f64) -> f64
+{
+    super::inverse_langevin_derivative(&y)
+}
+#[no_mangle]
+pub extern fn math_inverse_langevin_second_derivative(y: f64) -> f64
+{
+    super::inverse_langevin_second_derivative(&y)
+}
@@ -0,0 +1,80 @@
+"""
+Mathematical methods.
+"""
+module Math
+
+using DocStringExtensions
+using Polymers_jll
+
+"""
+The inverse Langevin function,
+
+```math
+x = \\mathcal{L}^{-1}(y),\\qquad y = \\mathcal{L}(x) = \\coth(x) - 1/x,
+```
+
+which behaves as ``\\mathcal{L}^{-1}(y)\\sim 1/(1 - y)`` as ``y\\to 1``.
+
+$(TYPEDSIGNATURES)
+"""
+function inverse_langevin(
+    y::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        y_i -> ccall(
+            (:math_inverse_langevin, Polymers_jll.libpolymers),
+            Float64,
+            (Float64,),
+            y_i,
+        ),
+        y,
+    )
+end
+
+"""
+The first derivative of the inverse Langevin function,
+
+```math
+\\frac{d\\mathcal{L}^{-1}}{dy} = \\frac{1}{\\mathcal{L}'(x)}.
+```
+
+$(TYPEDSIGNATURES)
+"""
+function inverse_langevin_derivative(
+    y::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        y_i -> ccall(
+            (:math_inverse_langevin_derivative, Polymers_jll.libpolymers),
+            Float64,
+            (Float64,),
+            y_i,
+        ),
+        y,
+    )
+end
+
+"""
+The second derivative of the inverse Langevin function,
+
+```math
+\\frac{d^2\\mathcal{L}^{-1}}{dy^2} = -\\frac{\\mathcal{L}''(x)}{\\mathcal{L}'(x)^3}.
+```
+
+$(TYPEDSIGNATURES)
+"""
+function inverse_langevin_second_derivative(
+    y::Union{Float64,Vector,Matrix,Array},
+)::Union{Float64,Vector,Matrix,Array}
+    return broadcast(
+        y_i -> ccall(
+            (:math_inverse_langevin_second_derivative, Polymers_jll.libpolymers),
+            Float64,
+            (Float64,),
+            y_i,
+        ),
+        y,
+    )
+end
+
+end
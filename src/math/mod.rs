@@ -1,3 +1,22 @@
+#[cfg(feature = "extern")]
+pub mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+use std::
+{
+    f64::consts::PI,
+    sync::atomic::
+    {
+        AtomicUsize,
+        Ordering
+    },
+    thread
+};
+
 pub fn inverse_newton_raphson_powered(y: &f64, f: &dyn Fn(&f64) -> f64, fp: &dyn Fn(&f64) -> f64, guess: &f64, &rel_tol: &f64, max_iters: &u8, power: i32) -> f64
 {
     let mut x = *guess;
@@ -32,215 +51,1677 @@ pub fn inverse_newton_raphson(y: &f64, f: &dyn Fn(&f64) -> f64, fp: &dyn Fn(&f64
     x
 }
 
-pub fn inverse_langevin(y: &f64) -> f64
+pub fn inverse_newton_raphson_bracketed(y: &f64, f: &dyn Fn(&f64) -> f64, fp: &dyn Fn(&f64) -> f64, lower: &f64, upper: &f64, rel_tol: &f64, max_iters: &u32) -> Result<f64, MathError>
 {
-    if y <= &1e-3
+    let residual_lower = f(lower) - y;
+    let residual_upper = f(upper) - y;
+    if residual_lower == 0.0
+    {
+        return Ok(*lower)
+    }
+    if residual_upper == 0.0
+    {
+        return Ok(*upper)
+    }
+    if residual_lower.is_nan() || residual_upper.is_nan() || residual_lower.signum() == residual_upper.signum()
+    {
+        return Err(MathError::Bracket("inverse_newton_raphson_bracketed", *lower, *upper))
+    }
+    let (mut x_low, mut x_high) = if residual_lower < 0.0
     {
-        3.0*y
+        (*lower, *upper)
     }
     else
     {
-        inverse_newton_raphson(y, &|x: &f64| 1.0/x.tanh() - 1.0/x, &|x: &f64| 1.0/x.powi(2) - 1.0/x.sinh().powi(2), &((2.14234*y.powi(3) - 4.22785*y.powi(2) + 3.0*y)/(1.0 - y)/(0.71716*y.powi(3) - 0.41103*y.powi(2) - 0.39165*y + 1.0)), &1e-6, &100)
+        (*upper, *lower)
+    };
+    let mut x = 0.5*(lower + upper);
+    let mut dx_old = (upper - lower).abs();
+    let mut dx = dx_old;
+    let mut residual = f(&x) - y;
+    let mut derivative = fp(&x);
+    for _ in 0..*max_iters
+    {
+        if residual.is_nan()
+        {
+            return Err(MathError::Domain("inverse_newton_raphson_bracketed", x))
+        }
+        if ((x - x_high)*derivative - residual)*((x - x_low)*derivative - residual) > 0.0 || (2.0*residual).abs() > (dx_old*derivative).abs() || derivative.is_nan()
+        {
+            dx_old = dx;
+            dx = 0.5*(x_high - x_low);
+            x = x_low + dx;
+        }
+        else
+        {
+            dx_old = dx;
+            dx = residual/derivative;
+            x -= dx;
+        }
+        if dx.abs() <= rel_tol*x.abs() || residual == 0.0
+        {
+            return Ok(x)
+        }
+        residual = f(&x) - y;
+        derivative = fp(&x);
+        if residual < 0.0
+        {
+            x_low = x;
+        }
+        else
+        {
+            x_high = x;
+        }
     }
+    Err(MathError::Convergence("inverse_newton_raphson_bracketed", *max_iters))
 }
 
-pub fn integrate_1d(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64, num_points: &u128) -> f64
+pub fn inverse_brent(y: &f64, f: &dyn Fn(&f64) -> f64, lower: &f64, upper: &f64, rel_tol: &f64, max_iters: &u32) -> Result<f64, MathError>
 {
-    let dx = (x_max - x_min)/(*num_points as f64);
-    (0..*num_points).collect::<Vec::<u128>>().iter().map(|index| f(&(x_min + (0.5 + *index as f64)*dx))).sum::<f64>()*dx
+    let mut a = *lower;
+    let mut b = *upper;
+    let mut residual_a = f(&a) - y;
+    let mut residual_b = f(&b) - y;
+    if residual_a == 0.0
+    {
+        return Ok(a)
+    }
+    if residual_b == 0.0
+    {
+        return Ok(b)
+    }
+    if residual_a.is_nan() || residual_b.is_nan() || residual_a.signum() == residual_b.signum()
+    {
+        return Err(MathError::Bracket("inverse_brent", a, b))
+    }
+    let mut c = b;
+    let mut residual_c = residual_b;
+    let mut d = b - a;
+    let mut e = d;
+    for _ in 0..*max_iters
+    {
+        if residual_b.signum() == residual_c.signum()
+        {
+            c = a;
+            residual_c = residual_a;
+            d = b - a;
+            e = d;
+        }
+        if residual_c.abs() < residual_b.abs()
+        {
+            a = b;
+            b = c;
+            c = a;
+            residual_a = residual_b;
+            residual_b = residual_c;
+            residual_c = residual_a;
+        }
+        let tolerance = 2.0*f64::EPSILON*b.abs() + 0.5*rel_tol*b.abs() + f64::MIN_POSITIVE;
+        let m = 0.5*(c - b);
+        if m.abs() <= tolerance || residual_b == 0.0
+        {
+            return Ok(b)
+        }
+        if e.abs() >= tolerance && residual_a.abs() > residual_b.abs()
+        {
+            let s = residual_b/residual_a;
+            let (mut p, mut q) = if a == c
+            {
+                (2.0*m*s, 1.0 - s)
+            }
+            else
+            {
+                let q = residual_a/residual_c;
+                let r = residual_b/residual_c;
+                (s*(2.0*m*q*(q - r) - (b - a)*(r - 1.0)), (q - 1.0)*(r - 1.0)*(s - 1.0))
+            };
+            if p > 0.0
+            {
+                q = -q;
+            }
+            else
+            {
+                p = -p;
+            }
+            if 2.0*p < (3.0*m*q - (tolerance*q).abs()).min((e*q).abs())
+            {
+                e = d;
+                d = p/q;
+            }
+            else
+            {
+                d = m;
+                e = m;
+            }
+        }
+        else
+        {
+            d = m;
+            e = m;
+        }
+        a = b;
+        residual_a = residual_b;
+        b += if d.abs() > tolerance
+        {
+            d
+        }
+        else
+        {
+            tolerance.copysign(m)
+        };
+        residual_b = f(&b) - y;
+        if residual_b.is_nan()
+        {
+            return Err(MathError::Domain("inverse_brent", b))
+        }
+    }
+    Err(MathError::Convergence("inverse_brent", *max_iters))
 }
 
-pub fn integrate_1d_grid(f: &dyn Fn(&f64) -> f64, grid: &[f64], dx: &f64) -> f64
+pub fn bracket(y: &f64, f: &dyn Fn(&f64) -> f64, lower: &f64, upper: &f64, maximum: &f64, max_iters: &u32) -> Result<(f64, f64), MathError>
 {
-    grid.iter().map(f).sum::<f64>()*dx
+    let mut a = *lower;
+    let mut b = *upper;
+    for _ in 0..*max_iters
+    {
+        let residual = f(&b) - y;
+        if residual.is_nan()
+        {
+            b = 0.5*(a + b);
+        }
+        else if residual >= 0.0
+        {
+            return Ok((a, b))
+        }
+        else
+        {
+            a = b;
+            b = if maximum.is_finite()
+            {
+                let step = 0.5*(b + maximum);
+                if step > b
+                {
+                    step
+                }
+                else
+                {
+                    *maximum
+                }
+            }
+            else
+            {
+                2.0*b
+            };
+        }
+    }
+    Err(MathError::Bracket("bracket", *lower, b))
 }
 
-pub fn integrate_2d(f: &dyn Fn(&f64, &f64) -> f64, x_min: &f64, x_max: &f64, y_min: &f64, y_max: &f64, num_points: u128) -> f64
+pub fn langevin(x: &f64) -> f64
 {
-    let dx = (x_max - x_min)/(num_points as f64);
-    let dy = (y_max - y_min)/(num_points as f64);
-    let grid_x = (0..num_points).map(|index| x_min + (0.5 + index as f64)*dx).collect::<Vec<f64>>();
-    let grid_y = (0..num_points).map(|index| y_min + (0.5 + index as f64)*dy).collect::<Vec<f64>>();
-    grid_x.iter().flat_map(|x| grid_y.iter().map(|y| f(x, y))).sum::<f64>()*dx*dy
+    langevin_and_derivatives(&x.abs())[0].copysign(*x)
 }
 
-pub fn integrate_2d_symmetric(f: &dyn Fn(&f64, &f64) -> f64, x_min: &f64, x_max: &f64, num_points: u128) -> f64
+pub fn inverse_langevin(y: &f64) -> f64
 {
-    let dx = (x_max - x_min)/(num_points as f64);
-    let grid = (0..num_points).map(|index| x_min + (0.5 + index as f64)*dx).collect::<Vec<f64>>();
-    grid.iter().flat_map(|x| grid.iter().map(|y| f(x, y))).sum::<f64>()*dx.powi(2)
+    if y < &0.0
+    {
+        return -inverse_langevin(&-y)
+    }
+    if y >= &1.0
+    {
+        return if y == &1.0
+        {
+            f64::INFINITY
+        }
+        else
+        {
+            f64::NAN
+        }
+    }
+    if y < &1e-2
+    {
+        let y2 = y*y;
+        return y*(3.0 + y2*(9.0/5.0 + y2*(297.0/175.0 + y2*(1539.0/875.0 + y2*126117.0/67375.0))))
+    }
+    let one_minus_y = 1.0 - y;
+    if one_minus_y < 4e-2
+    {
+        return 1.0/one_minus_y
+    }
+    let mut x = (2.14234*y.powi(3) - 4.22785*y.powi(2) + 3.0*y)/one_minus_y/(0.71716*y.powi(3) - 0.41103*y.powi(2) - 0.39165*y + 1.0);
+    for _ in 0..2
+    {
+        let [langevin, langevin_complement, first_derivative, second_derivative] = langevin_and_derivatives(&x);
+        let residual = if y <= &0.5
+        {
+            langevin - y
+        }
+        else
+        {
+            one_minus_y - langevin_complement
+        };
+        x -= residual/first_derivative/(1.0 - 0.5*residual*second_derivative/first_derivative.powi(2));
+    }
+    x
 }
 
-pub fn integrate_2d_grid(f: &dyn Fn(&f64, &f64) -> f64, grid_x: &[f64], grid_y: &[f64], da: &f64) -> f64
+pub fn inverse_langevin_derivative(y: &f64) -> f64
 {
-    grid_x.iter().flat_map(|x| grid_y.iter().map(|y| f(x, y))).sum::<f64>()*da
+    let x = inverse_langevin(&y.abs());
+    if x > 25.0
+    {
+        x*x
+    }
+    else
+    {
+        1.0/langevin_and_derivatives(&x)[2]
+    }
 }
 
-pub fn integrate_2d_symmetric_grid(f: &dyn Fn(&f64, &f64) -> f64, grid: &[f64], da: &f64) -> f64
+pub fn inverse_langevin_second_derivative(y: &f64) -> f64
 {
-    grid.iter().flat_map(|x| grid.iter().map(|y| f(x, y))).sum::<f64>()*da
+    let x = inverse_langevin(&y.abs());
+    let second_derivative = if x > 25.0
+    {
+        2.0*x.powi(3)
+    }
+    else
+    {
+        let [_, _, first_derivative, second_derivative] = langevin_and_derivatives(&x);
+        -second_derivative/first_derivative.powi(3)
+    };
+    second_derivative.copysign(*y)
 }
 
-pub fn lambert_w(x: &f64) -> f64
+static LANGEVIN_SERIES: [f64; 20] = [0.3333333333333333, -0.022222222222222223, 0.0021164021164021165, -0.00021164021164021165, 2.1377799155576935e-05, -2.1644042808063972e-06, 2.1925947851873778e-07, -2.2214608789979678e-08, 2.2507846516808994e-09, -2.2805151204592183e-10, 2.3106432599002624e-11, -2.3411706819824882e-12, 2.3721017400233653e-13, -2.4034415333307705e-14, 2.4351954029183367e-15, -2.4673688045172075e-16, 2.499967277122081e-17, -2.532996435740635e-18, 2.566461970282629e-19, -2.6003696460137274e-20];
+
+fn langevin_and_derivatives(x: &f64) -> [f64; 4]
 {
-    let amount_of_iterations = ((x.log10()/3.0).ceil() as u8).max(4_u8);
-    let mut w = 0.75*(x + 1.0).ln();
-    for _ in 0..amount_of_iterations
+    if x < &1.0
+    {
+        let x2 = x*x;
+        let langevin = x*LANGEVIN_SERIES.iter().rev().fold(0.0, |sum, coefficient| sum*x2 + coefficient);
+        let first_derivative = LANGEVIN_SERIES.iter().enumerate().rev().fold(0.0, |sum, (n, coefficient)| sum*x2 + (2*n + 1) as f64*coefficient);
+        let second_derivative = x*LANGEVIN_SERIES.iter().enumerate().skip(1).rev().fold(0.0, |sum, (n, coefficient)| sum*x2 + ((2*n + 1)*2*n) as f64*coefficient);
+        [langevin, 1.0 - langevin, first_derivative, second_derivative]
+    }
+    else
     {
-        w -= (w*w.exp() - x)/(w.exp()*(w + 1.0) - (w + 2.0)*(w*w.exp() - x)/(2.0 * w + 2.0));
+        let exponential = (-2.0*x).exp();
+        let coth_minus_one = 2.0*exponential/(1.0 - exponential);
+        let csch_squared = 4.0*exponential/(1.0 - exponential).powi(2);
+        let langevin_complement = 1.0/x - coth_minus_one;
+        [1.0 - langevin_complement, langevin_complement, 1.0/x.powi(2) - csch_squared, 2.0*(1.0 + coth_minus_one)*csch_squared - 2.0/x.powi(3)]
     }
-    w
 }
 
-pub fn bessel_i(nu: &u8, x: &f64) -> f64
+static BATCH_THREADS: AtomicUsize = AtomicUsize::new(0);
+static BATCH_MINIMUM_PER_THREAD: usize = 256;
+
+pub fn set_batch_threads(number_of_threads: &usize)
 {
-    if nu == &0
+    BATCH_THREADS.store(*number_of_threads, Ordering::Relaxed);
+}
+
+pub fn batch_threads() -> usize
+{
+    match BATCH_THREADS.load(Ordering::Relaxed)
     {
-        bessel_i0(x)
+        0 => thread::available_parallelism().map_or(1, |number_of_threads| number_of_threads.get()),
+        number_of_threads => number_of_threads
     }
-    else if nu == &1
+}
+
+fn batch_number_of_threads(length: usize) -> usize
+{
+    batch_threads().min(length/BATCH_MINIMUM_PER_THREAD).max(1)
+}
+
+pub fn batch(f: &(dyn Fn(&f64) -> f64 + Sync), x: &[f64]) -> Vec<f64>
+{
+    let mut y = vec![0.0; x.len()];
+    batch_chunks(f, x, &mut y);
+    y
+}
+
+pub fn batch_into(f: &(dyn Fn(&f64) -> f64 + Sync), x: &[f64], y: &mut [f64]) -> Result<(), MathError>
+{
+    if x.len() != y.len()
+    {
+        return Err(MathError::Length("batch_into", x.len(), y.len()))
+    }
+    batch_chunks(f, x, y);
+    Ok(())
+}
+
+fn batch_chunks(f: &(dyn Fn(&f64) -> f64 + Sync), x: &[f64], y: &mut [f64])
+{
+    let number_of_threads = batch_number_of_threads(x.len());
+    if number_of_threads == 1
     {
-        bessel_i1(x)
+        y.iter_mut().zip(x.iter()).for_each(|(y_i, x_i)| *y_i = f(x_i));
     }
     else
     {
-        -1.0
+        let chunk_size = x.len().div_ceil(number_of_threads);
+        thread::scope(|scope|
+            x.chunks(chunk_size).zip(y.chunks_mut(chunk_size)).for_each(|(x_chunk, y_chunk)|
+            {
+                scope.spawn(move ||
+                    y_chunk.iter_mut().zip(x_chunk.iter()).for_each(|(y_i, x_i)| *y_i = f(x_i))
+                );
+            })
+        );
     }
 }
 
-fn bessel_i0(x: &f64) -> f64
+pub fn try_batch<E: Send>(f: &(dyn Fn(&f64) -> Result<f64, E> + Sync), x: &[f64]) -> Result<Vec<f64>, E>
 {
-    if x < &7.75
-    {
-        let coefficients = [
-            1.0,
-            2.499_999_999_999_999e-1,
-            2.777_777_777_777_822e-2,
-            1.736_111_111_110_237e-3,
-            6.944_444_444_533_525e-5,
-            1.929_012_345_132_199e-6,
-            3.936_759_911_025_107e-8,
-            6.151_186_727_044_392e-10,
-            7.594_070_020_589_734e-12,
-            7.593_897_933_698_363e-14,
-            6.277_677_736_362_926e-16,
-            4.347_097_041_532_722e-18,
-            2.634_177_426_901_091e-20,
-            1.139_430_377_448_228e-22,
-            9.079_269_200_856_248e-25
-        ];
-        let t = 0.25*x.powi(2);
-        1.0 + t*coefficients.iter().enumerate().map(|(i, c)| c*t.powi(i.try_into().unwrap())).sum::<f64>()
-    }
-    else if x < &500.0
+    let number_of_threads = batch_number_of_threads(x.len());
+    if number_of_threads == 1
     {
-        let coefficients = [
-             3.989_422_804_014_25e-1,
-             4.986_778_506_049_619e-2,
-             2.805_062_339_283_126e-2,
-             2.922_112_251_660_478e-2,
-             4.442_072_994_936_595e-2,
-             1.309_705_746_058_567e-1,
-            -3.350_522_802_317_27,
-             2.330_257_115_835_147e2,
-            -1.133_663_506_971_723e4,
-             4.240_576_743_178_673e5,
-            -1.231_570_285_956_987e7,
-             2.802_319_381_552_675e8,
-            -5.018_839_997_137_779e9,
-             7.080_292_430_151_091e10,
-            -7.842_610_821_248_111e11,
-             6.768_257_378_540_965e12,
-            -4.490_348_496_961_38e13,
-             2.241_552_399_669_589e14,
-            -8.134_264_678_656_593e14,
-             2.023_910_973_916_877e15,
-            -3.086_757_152_953_708e15,
-             2.175_875_438_638_19e15
-        ];
-        x.exp()/x.sqrt()*coefficients.iter().enumerate().map(|(i, c)| c/x.powi(i.try_into().unwrap())).sum::<f64>()
+        x.iter().map(f).collect()
     }
     else
     {
-        let coefficients = [
-            3.989_422_804_014_329e-1,
-            4.986_778_504_914_345e-2,
-            2.805_063_089_165_061e-2,
-            2.921_790_968_539_151e-2,
-            4.533_712_087_625_794e-2
-        ];
-        let expf = (0.5*x).exp();
-        (expf/x.sqrt()*coefficients.iter().enumerate().map(|(i, c)| c/x.powi(i.try_into().unwrap())).sum::<f64>())*expf
+        let chunk_size = x.len().div_ceil(number_of_threads);
+        thread::scope(|scope|
+        {
+            let handles: Vec<_> = x.chunks(chunk_size).map(|x_chunk|
+                scope.spawn(move || x_chunk.iter().map(f).collect::<Result<Vec<f64>, E>>())
+            ).collect();
+            handles.into_iter().map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))).collect::<Result<Vec<Vec<f64>>, E>>()
+        }).map(|y| y.concat())
     }
 }
 
-fn bessel_i1(x: &f64) -> f64
+static TABLE_DEGREE: usize = 16;
+static TABLE_MAXIMUM_REFINEMENTS: u32 = 16;
+
+#[derive(Clone, Debug)]
+pub struct Table
 {
-    if x < &7.75
+    lower: f64,
+    upper: f64,
+    width: f64,
+    error: f64,
+    value: Vec<[f64; TABLE_DEGREE]>,
+    derivative: Vec<[f64; TABLE_DEGREE]>,
+    second_derivative: Vec<[f64; TABLE_DEGREE]>
+}
+
+impl Table
+{
+    pub fn init(f: &(dyn Fn(&f64) -> f64 + Sync), lower: &f64, upper: &f64, abs_tol: &f64, rel_tol: &f64) -> Result<Self, MathError>
     {
-        let coefficients = [
-            8.333_333_333_333_333e-2,
-            6.944_444_444_444_341e-3,
-            3.472_222_222_225_921e-4,
-            1.157_407_407_354_987e-5,
-            2.755_731_926_254_79e-7,
-            4.920_949_692_800_671e-9,
-            6.834_657_311_305_621e-11,
-            7.593_969_849_687_574e-13,
-            6.904_822_652_741_917e-15,
-            5.220_157_095_351_373e-17,
-            3.410_720_494_727_771e-19,
-            1.625_212_890_947_171e-21,
-            1.332_898_928_162_29e-23
-        ];
-        let t = 0.25*x.powi(2);
-        let more_coefficients = [
-            1.0,
-            0.5,
-            coefficients.iter().enumerate().map(|(i, c)| c*t.powi(i.try_into().unwrap())).sum::<f64>()
-        ];
-        0.5*x*more_coefficients.iter().enumerate().map(|(i, c)| c*t.powi(i.try_into().unwrap())).sum::<f64>()
+        if lower >= upper || !lower.is_finite() || !upper.is_finite()
+        {
+            return Err(MathError::Bracket("Table::init", *lower, *upper))
+        }
+        let nodes: Vec<f64> = (0..TABLE_DEGREE).map(|k| -(PI*(k as f64 + 0.5)/(TABLE_DEGREE as f64)).cos()).collect();
+        let mut number_of_segments = 1;
+        for _ in 0..=TABLE_MAXIMUM_REFINEMENTS
+        {
+            let width = (upper - lower)/(number_of_segments as f64);
+            let x: Vec<f64> = (0..number_of_segments).flat_map(|segment|
+            {
+                let center = lower + width*(segment as f64 + 0.5);
+                nodes.iter().map(move |node| center + 0.5*width*node)
+            }).collect();
+            let y = batch(f, &x);
+            if let Some(index) = y.iter().position(|y_i| !y_i.is_finite())
+            {
+                return Err(MathError::Domain("Table::init", x[index]))
+            }
+            let value: Vec<[f64; TABLE_DEGREE]> = y.chunks(TABLE_DEGREE).map(chebyshev_coefficients).collect();
+            let converged = value.iter().zip(y.chunks(TABLE_DEGREE)).all(|(coefficients, y_segment)|
+            {
+                let scale = y_segment.iter().fold(0.0_f64, |scale, y_i| scale.max(y_i.abs()));
+                chebyshev_truncation_error(coefficients) <= abs_tol.max(rel_tol*scale)
+            });
+            if converged
+            {
+                let derivative: Vec<[f64; TABLE_DEGREE]> = value.iter().map(|coefficients| chebyshev_derivative(coefficients, &width)).collect();
+                let second_derivative = derivative.iter().map(|coefficients| chebyshev_derivative(coefficients, &width)).collect();
+                return Ok(Self
+                {
+                    lower: *lower,
+                    upper: *upper,
+                    width,
+                    error: value.iter().map(chebyshev_truncation_error).fold(0.0, f64::max),
+                    value,
+                    derivative,
+                    second_derivative
+                })
+            }
+            number_of_segments *= 2;
+        }
+        Err(MathError::Convergence("Table::init", TABLE_MAXIMUM_REFINEMENTS))
     }
-    else if x < &500.0
+    pub fn lower(&self) -> f64
     {
-        let coefficients = [
-             3.989_422_804_014_406e-1,
-            -1.496_033_551_613_111e-1,
-            -4.675_104_253_598_537e-2,
-            -4.090_895_951_581_637e-2,
-            -5.719_036_414_430_205e-2,
-            -1.528_189_554_374_492e-1,
-             3.458_284_470_977_172e0,
-            -2.426_181_371_595_021e2,
-             1.178_785_865_993_44e4,
-            -4.404_655_582_443_487e5,
-             1.277_677_779_341_446e7,
-            -2.903_390_398_236_656e8,
-             5.192_386_898_222_206e9,
-            -7.313_784_438_967_834e10,
-             8.087_824_484_994_859e11,
-            -6.967_602_516_005_787e12,
-             4.614_040_809_616_582e13,
-            -2.298_849_639_457_172e14,
-             8.325_554_073_334_618e14,
-            -2.067_285_045_778_906e15,
-             3.146_401_654_361_325e15,
-            -2.213_318_202_179_221e15
-        ];
-        x.exp()/x.sqrt()*coefficients.iter().enumerate().map(|(i, c)| c/x.powi(i.try_into().unwrap())).sum::<f64>()
+        self.lower
+    }
+    pub fn upper(&self) -> f64
+    {
+        self.upper
+    }
+    pub fn error(&self) -> f64
+    {
+        self.error
+    }
+    pub fn number_of_segments(&self) -> usize
+    {
+        self.value.len()
+    }
+    pub fn value(&self, x: &f64) -> f64
+    {
+        self.evaluate(&self.value, x)
+    }
+    pub fn derivative(&self, x: &f64) -> f64
+    {
+        self.evaluate(&self.derivative, x)
+    }
+    pub fn second_derivative(&self, x: &f64) -> f64
+    {
+        self.evaluate(&self.second_derivative, x)
+    }
+    fn evaluate(&self, coefficients: &[[f64; TABLE_DEGREE]], x: &f64) -> f64
+    {
+        if x < &self.lower || x > &self.upper || x.is_nan()
+        {
+            return f64::NAN
+        }
+        let position = (x - self.lower)/self.width;
+        let segment = (position as usize).min(coefficients.len() - 1);
+        chebyshev_clenshaw(&coefficients[segment], &(2.0*(position - segment as f64) - 1.0))
+    }
+}
+
+fn chebyshev_coefficients(y: &[f64]) -> [f64; TABLE_DEGREE]
+{
+    let mut coefficients = [0.0; TABLE_DEGREE];
+    coefficients.iter_mut().enumerate().for_each(|(j, coefficient)|
+    {
+        *coefficient = 2.0/(TABLE_DEGREE as f64)*y.iter().enumerate().map(|(k, y_k)|
+            y_k*(PI*(j as f64)*(k as f64 + 0.5)/(TABLE_DEGREE as f64)).cos()
+        ).sum::<f64>()*if j % 2 == 0 {1.0} else {-1.0};
+    });
+    coefficients[0] *= 0.5;
+    coefficients
+}
+
+fn chebyshev_derivative(coefficients: &[f64; TABLE_DEGREE], width: &f64) -> [f64; TABLE_DEGREE]
+{
+    let mut derivative = [0.0; TABLE_DEGREE + 1];
+    for j in (1..TABLE_DEGREE).rev()
+    {
+        derivative[j - 1] = derivative[j + 1] + 2.0*(j as f64)*coefficients[j];
+    }
+    derivative[0] *= 0.5;
+    let mut scaled = [0.0; TABLE_DEGREE];
+    scaled.iter_mut().zip(derivative.iter()).for_each(|(scaled_j, derivative_j)| *scaled_j = 2.0*derivative_j/width);
+    scaled
+}
+
+fn chebyshev_truncation_error(coefficients: &[f64; TABLE_DEGREE]) -> f64
+{
+    coefficients[TABLE_DEGREE - 2].abs() + coefficients[TABLE_DEGREE - 1].abs()
+}
+
+fn chebyshev_clenshaw(coefficients: &[f64; TABLE_DEGREE], t: &f64) -> f64
+{
+    let mut b_1 = 0.0;
+    let mut b_2 = 0.0;
+    for coefficient in coefficients[1..].iter().rev()
+    {
+        let b_0 = coefficient + 2.0*t*b_1 - b_2;
+        b_2 = b_1;
+        b_1 = b_0;
+    }
+    coefficients[0] + t*b_1 - b_2
+}
+
+pub fn integrate_1d(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64, num_points: &u128) -> f64
+{
+    let dx = (x_max - x_min)/(*num_points as f64);
+    (0..*num_points).collect::<Vec::<u128>>().iter().map(|index| f(&(x_min + (0.5 + *index as f64)*dx))).sum::<f64>()*dx
+}
+
+pub fn integrate_1d_grid(f: &dyn Fn(&f64) -> f64, grid: &[f64], dx: &f64) -> f64
+{
+    grid.iter().map(f).sum::<f64>()*dx
+}
+
+pub fn integrate_1d_gauss_kronrod(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64, abs_tol: &f64, rel_tol: &f64) -> (f64, f64)
+{
+    let (integral, error, _) = gauss_kronrod_adaptive(f, x_min, x_max, abs_tol, rel_tol);
+    (integral, error)
+}
+
+pub fn try_integrate_1d_gauss_kronrod(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64, abs_tol: &f64, rel_tol: &f64) -> Result<(f64, f64), MathError>
+{
+    match gauss_kronrod_adaptive(f, x_min, x_max, abs_tol, rel_tol)
+    {
+        (integral, error, Ok(())) => Ok((integral, error)),
+        (_, _, Err(error)) => Err(error)
+    }
+}
+
+fn gauss_kronrod_adaptive(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64, abs_tol: &f64, rel_tol: &f64) -> (f64, f64, Result<(), MathError>)
+{
+    let (mut integral, mut error, mut integral_abs) = gauss_kronrod_15(f, x_min, x_max);
+    let mut intervals = vec![(*x_min, *x_max, integral, error, integral_abs)];
+    let mut roundoff_count = 0;
+    while error > abs_tol.max(rel_tol*integral.abs()).max(50.0*f64::EPSILON*integral_abs) && error.is_finite() && intervals.len() < 500 && roundoff_count < 10
+    {
+        let index = (0..intervals.len()).fold(0, |index, other| if intervals[other].3 > intervals[index].3 {other} else {index});
+        let (x_left, x_right, integral_interval, error_interval, _) = intervals.swap_remove(index);
+        let x_middle = 0.5*(x_left + x_right);
+        let (integral_left, error_left, integral_abs_left) = gauss_kronrod_15(f, &x_left, &x_middle);
+        let (integral_right, error_right, integral_abs_right) = gauss_kronrod_15(f, &x_middle, &x_right);
+        if error_left + error_right >= 0.99*error_interval && (integral_left + integral_right - integral_interval).abs() <= 1e-5*(integral_left + integral_right).abs()
+        {
+            roundoff_count += 1;
+        }
+        intervals.push((x_left, x_middle, integral_left, error_left, integral_abs_left));
+        intervals.push((x_middle, x_right, integral_right, error_right, integral_abs_right));
+        integral = intervals.iter().map(|interval| interval.2).sum::<f64>();
+        error = intervals.iter().map(|interval| interval.3).sum::<f64>();
+        integral_abs = intervals.iter().map(|interval| interval.4).sum::<f64>();
+    }
+    if integral.is_finite() && error.is_finite() && (error <= abs_tol.max(rel_tol*integral.abs()).max(50.0*f64::EPSILON*integral_abs) || roundoff_count >= 10)
+    {
+        (integral, error, Ok(()))
+    }
+    else
+    {
+        (integral, error, Err(MathError::Convergence("integrate_1d_gauss_kronrod", intervals.len() as u32)))
+    }
+}
+
+fn gauss_kronrod_15(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64) -> (f64, f64, f64)
+{
+    let nodes = [0.9914553711208126, 0.9491079123427585, 0.8648644233597691, 0.7415311855993945, 0.5860872354676911, 0.4058451513773972, 0.20778495500789848];
+    let weights_kronrod = [0.022935322010529224, 0.06309209262997856, 0.10479001032225019, 0.14065325971552592, 0.1690047266392679, 0.19035057806478542, 0.20443294007529889];
+    let weights_gauss = [0.1294849661688697, 0.27970539148927664, 0.3818300505051189, 0.4179591836734694];
+    let weight_kronrod_center = 0.20948214108472782;
+    let x_center = 0.5*(x_min + x_max);
+    let half_width = 0.5*(x_max - x_min);
+    let f_center = f(&x_center);
+    let f_pairs: Vec<(f64, f64)> = nodes.iter().map(|node| (f(&(x_center - half_width*node)), f(&(x_center + half_width*node)))).collect();
+    let integral_kronrod = weight_kronrod_center*f_center + weights_kronrod.iter().zip(f_pairs.iter()).map(|(weight, (f_left, f_right))| weight*(f_left + f_right)).sum::<f64>();
+    let integral_gauss = weights_gauss[3]*f_center + f_pairs.iter().skip(1).step_by(2).zip(weights_gauss.iter()).map(|((f_left, f_right), weight)| weight*(f_left + f_right)).sum::<f64>();
+    let mean = 0.5*integral_kronrod;
+    let integral_abs = weight_kronrod_center*f_center.abs() + weights_kronrod.iter().zip(f_pairs.iter()).map(|(weight, (f_left, f_right))| weight*(f_left.abs() + f_right.abs())).sum::<f64>();
+    let integral_deviation = weight_kronrod_center*(f_center - mean).abs() + weights_kronrod.iter().zip(f_pairs.iter()).map(|(weight, (f_left, f_right))| weight*((f_left - mean).abs() + (f_right - mean).abs())).sum::<f64>();
+    let mut error = ((integral_kronrod - integral_gauss)*half_width).abs();
+    if integral_deviation != 0.0 && error != 0.0
+    {
+        error = integral_deviation*half_width*(200.0*error/(integral_deviation*half_width)).powf(1.5).min(1.0);
+    }
+    (integral_kronrod*half_width, error, integral_abs*half_width)
+}
+
+pub fn integrate_1d_tanh_sinh(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64, abs_tol: &f64, rel_tol: &f64) -> (f64, f64)
+{
+    let (integral, error, _) = tanh_sinh_refined(f, x_min, x_max, abs_tol, rel_tol);
+    (integral, error)
+}
+
+pub fn try_integrate_1d_tanh_sinh(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64, abs_tol: &f64, rel_tol: &f64) -> Result<(f64, f64), MathError>
+{
+    match tanh_sinh_refined(f, x_min, x_max, abs_tol, rel_tol)
+    {
+        (integral, error, Ok(())) => Ok((integral, error)),
+        (_, _, Err(error)) => Err(error)
+    }
+}
+
+fn tanh_sinh_refined(f: &dyn Fn(&f64) -> f64, x_min: &f64, x_max: &f64, abs_tol: &f64, rel_tol: &f64) -> (f64, f64, Result<(), MathError>)
+{
+    let half_width = 0.5*(x_max - x_min);
+    let abscissa_max = 4.0;
+    let term = |abscissa: &f64|
+    {
+        let argument = std::f64::consts::FRAC_PI_2*abscissa.sinh();
+        let complement = 2.0/((2.0*argument).exp() + 1.0);
+        let weight = std::f64::consts::FRAC_PI_2*abscissa.cosh()*complement*(2.0 - complement);
+        let distance = half_width*complement;
+        let mut sum = 0.0;
+        if x_min + distance > *x_min
+        {
+            sum += f(&(x_min + distance));
+        }
+        if x_max - distance < *x_max
+        {
+            sum += f(&(x_max - distance));
+        }
+        weight*sum
+    };
+    let mut step = 1.0;
+    let mut sum = std::f64::consts::FRAC_PI_2*f(&(0.5*(x_min + x_max))) + (1..=(abscissa_max/step) as usize).map(|index| term(&(index as f64*step))).sum::<f64>();
+    let mut integral = half_width*step*sum;
+    let mut error = f64::INFINITY;
+    for level in 1..=12
+    {
+        step *= 0.5;
+        sum += (0..(abscissa_max/step) as usize/2).map(|index| term(&((2*index + 1) as f64*step))).sum::<f64>();
+        let integral_refined = half_width*step*sum;
+        error = (integral_refined - integral).abs();
+        integral = integral_refined;
+        if !integral.is_finite()
+        {
+            break
+        }
+        if level >= 3 && error <= abs_tol.max(rel_tol*integral.abs())
+        {
+            return (integral, error, Ok(()))
+        }
+    }
+    (integral, error, Err(MathError::Convergence("integrate_1d_tanh_sinh", 12)))
+}
+
+pub fn gauss_legendre(number_of_nodes: &usize) -> (Vec<f64>, Vec<f64>)
+{
+    let n = *number_of_nodes;
+    let mut nodes = vec![0.0; n];
+    let mut weights = vec![0.0; n];
+    for index in 0..n.div_ceil(2)
+    {
+        let mut x = (PI*(index as f64 + 0.75)/(n as f64 + 0.5)).cos();
+        for _ in 0..100
+        {
+            let (p, dp) = legendre_and_derivative(&n, &x);
+            let dx = p/dp;
+            x -= dx;
+            if dx.abs() <= 4.0*f64::EPSILON
+            {
+                break
+            }
+        }
+        let (_, dp) = legendre_and_derivative(&n, &x);
+        nodes[index] = -x;
+        nodes[n - 1 - index] = x;
+        weights[index] = 2.0/((1.0 - x*x)*dp*dp);
+        weights[n - 1 - index] = weights[index];
+    }
+    (nodes, weights)
+}
+
+fn legendre_and_derivative(n: &usize, x: &f64) -> (f64, f64)
+{
+    let mut p_previous = 1.0;
+    let mut p = *x;
+    for k in 2..=*n
+    {
+        let p_next = ((2*k - 1) as f64*x*p - (k - 1) as f64*p_previous)/(k as f64);
+        p_previous = p;
+        p = p_next;
+    }
+    (p, (*n as f64)*(x*p - p_previous)/(x*x - 1.0))
+}
+
+pub fn integrate_2d(f: &dyn Fn(&f64, &f64) -> f64, x_min: &f64, x_max: &f64, y_min: &f64, y_max: &f64, num_points: u128) -> f64
+{
+    let dx = (x_max - x_min)/(num_points as f64);
+    let dy = (y_max - y_min)/(num_points as f64);
+    let grid_x = (0..num_points).map(|index| x_min + (0.5 + index as f64)*dx).collect::<Vec<f64>>();
+    let grid_y = (0..num_points).map(|index| y_min + (0.5 + index as f64)*dy).collect::<Vec<f64>>();
+    grid_x.iter().flat_map(|x| grid_y.iter().map(|y| f(x, y))).sum::<f64>()*dx*dy
+}
+
+pub fn integrate_2d_symmetric(f: &dyn Fn(&f64, &f64) -> f64, x_min: &f64, x_max: &f64, num_points: u128) -> f64
+{
+    let dx = (x_max - x_min)/(num_points as f64);
+    let grid = (0..num_points).map(|index| x_min + (0.5 + index as f64)*dx).collect::<Vec<f64>>();
+    grid.iter().flat_map(|x| grid.iter().map(|y| f(x, y))).sum::<f64>()*dx.powi(2)
+}
+
+pub fn integrate_2d_grid(f: &dyn Fn(&f64, &f64) -> f64, grid_x: &[f64], grid_y: &[f64], da: &f64) -> f64
+{
+    grid_x.iter().flat_map(|x| grid_y.iter().map(|y| f(x, y))).sum::<f64>()*da
+}
+
+pub fn integrate_2d_symmetric_grid(f: &dyn Fn(&f64, &f64) -> f64, grid: &[f64], da: &f64) -> f64
+{
+    grid.iter().flat_map(|x| grid.iter().map(|y| f(x, y))).sum::<f64>()*da
+}
+
+pub fn lambert_w(x: &f64) -> f64
+{
+    lambert_w_0(x).unwrap_or(f64::NAN)
+}
+
+pub fn lambert_w_0(x: &f64) -> Result<f64, MathError>
+{
+    if x.is_nan() || x == &f64::NEG_INFINITY
+    {
+        return Err(MathError::Domain("lambert_w_0", *x))
+    }
+    if x == &f64::INFINITY
+    {
+        return Ok(f64::INFINITY)
+    }
+    if x.abs() < 1e-8
+    {
+        return Ok(x - x*x)
+    }
+    let p = match lambert_w_branch_point_distance(x)
+    {
+        Some(p) => p,
+        None => return Err(MathError::Domain("lambert_w_0", *x))
+    };
+    if p < 1e-2
+    {
+        return Ok(lambert_w_branch_point_series(&p))
+    }
+    let w = if x < &-0.25
+    {
+        lambert_w_branch_point_series(&p)
+    }
+    else if x < &3.0
+    {
+        let l = x.ln_1p();
+        l*(1.0 - l.ln_1p()/(2.0 + l))
+    }
+    else
+    {
+        let l_1 = x.ln();
+        let l_2 = l_1.ln();
+        l_1 - l_2 + l_2/l_1
+    };
+    lambert_w_fritsch(x, &w, "lambert_w_0")
+}
+
+pub fn lambert_w_m1(x: &f64) -> Result<f64, MathError>
+{
+    if x.is_nan() || x > &0.0
+    {
+        return Err(MathError::Domain("lambert_w_m1", *x))
+    }
+    if x == &0.0
+    {
+        return Ok(f64::NEG_INFINITY)
+    }
+    let p = match lambert_w_branch_point_distance(x)
+    {
+        Some(p) => p,
+        None => return Err(MathError::Domain("lambert_w_m1", *x))
+    };
+    if p < 1e-2
+    {
+        return Ok(lambert_w_branch_point_series(&-p))
+    }
+    let w = if x < &-0.25
+    {
+        lambert_w_branch_point_series(&-p)
+    }
+    else
+    {
+        let l_1 = (-x).ln();
+        let l_2 = (-l_1).ln();
+        l_1 - l_2 + l_2/l_1
+    };
+    lambert_w_fritsch(x, &w, "lambert_w_m1")
+}
+
+fn lambert_w_branch_point_distance(x: &f64) -> Option<f64>
+{
+    let inverse_e_high = 0.36787944117144233;
+    let inverse_e_low = -1.2428753672788363e-17;
+    let q = (x + inverse_e_high) + inverse_e_low;
+    if q >= 0.0
+    {
+        Some((2.0*std::f64::consts::E*q).sqrt())
+    }
+    else if q > -1e-16
+    {
+        Some(0.0)
     }
     else
+    {
+        None
+    }
+}
+
+fn lambert_w_branch_point_series(p: &f64) -> f64
+{
+    -1.0 + p*(1.0 + p*(-1.0/3.0 + p*(11.0/72.0 + p*(-43.0/540.0 + p*(769.0/17280.0 + p*(-221.0/8505.0 + p*680863.0/43545600.0))))))
+}
+
+fn lambert_w_fritsch(x: &f64, guess: &f64, function: &'static str) -> Result<f64, MathError>
+{
+    let maximum_iterations = 10;
+    let mut w = *guess;
+    for _ in 0..maximum_iterations
+    {
+        let z = (x/w).ln() - w;
+        let t = 2.0*(1.0 + w)*(1.0 + w + 2.0*z/3.0);
+        let epsilon = z/(1.0 + w)*(t - z)/(t - 2.0*z);
+        w *= 1.0 + epsilon;
+        if epsilon.abs() <= 4.0*f64::EPSILON*(1.0 + 1.0/(1.0 + w).abs())
+        {
+            return Ok(w)
+        }
+    }
+    Err(MathError::Convergence(function, maximum_iterations))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MathError
+{
+    Domain(&'static str, f64),
+    Pole(&'static str, f64),
+    Convergence(&'static str, u32),
+    Bracket(&'static str, f64, f64),
+    Length(&'static str, usize, usize)
+}
+
+impl std::fmt::Display for MathError
+{
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        match self
+        {
+            MathError::Domain(function, argument) => write!(formatter, "The argument {} is outside the domain of {}.", argument, function),
+            MathError::Pole(function, argument) => write!(formatter, "The argument {} is a pole of {}.", argument, function),
+            MathError::Convergence(function, iterations) => write!(formatter, "The evaluation of {} did not converge within {} iterations.", function, iterations),
+            MathError::Bracket(function, lower, upper) => write!(formatter, "The interval [{}, {}] does not bracket a solution in {}.", lower, upper, function),
+            MathError::Length(function, input, output) => write!(formatter, "The input length {} and output length {} of {} differ.", input, output, function)
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+fn bessel_i0(x: &f64) -> f64
+{
+    if x < &7.75
     {
         let coefficients = [
-             3.989_422_804_014_314e-1,
-            -1.496_033_551_467_584e-1,
-            -4.675_105_322_571_775e-2,
-            -4.090_421_597_376_992e-2,
-            -5.843_630_344_778_927e-2
+            1.0,
+            2.499_999_999_999_999e-1,
+            2.777_777_777_777_822e-2,
+            1.736_111_111_110_237e-3,
+            6.944_444_444_533_525e-5,
+            1.929_012_345_132_199e-6,
+            3.936_759_911_025_107e-8,
+            6.151_186_727_044_392e-10,
+            7.594_070_020_589_734e-12,
+            7.593_897_933_698_363e-14,
+            6.277_677_736_362_926e-16,
+            4.347_097_041_532_722e-18,
+            2.634_177_426_901_091e-20,
+            1.139_430_377_448_228e-22,
+            9.079_269_200_856_248e-25
         ];
-        let expf = (0.5*x).exp();
-        (expf/x.sqrt()*coefficients.iter().enumerate().map(|(i, c)| c/x.powi(i.try_into().unwrap())).sum::<f64>())*expf
+        let t = 0.25*x.powi(2);
+        1.0 + t*coefficients.iter().enumerate().map(|(i, c)| c*t.powi(i.try_into().unwrap())).sum::<f64>()
+    }
+    else if x < &500.0
+    {
+        let coefficients = [
+             3.989_422_804_014_25e-1,
+             4.986_778_506_049_619e-2,
+             2.805_062_339_283_126e-2,
+             2.922_112_251_660_478e-2,
+             4.442_072_994_936_595e-2,
+             1.309_705_746_058_567e-1,
+            -3.350_522_802_317_27,
+             2.330_257_115_835_147e2,
+            -1.133_663_506_971_723e4,
+             4.240_576_743_178_673e5,
+            -1.231_570_285_956_987e7,
+             2.802_319_381_552_675e8,
+            -5.018_839_997_137_779e9,
+             7.080_292_430_151_091e10,
+            -7.842_610_821_248_111e11,
+             6.768_257_378_540_965e12,
+            -4.490_348_496_961_38e13,
+             2.241_552_399_669_589e14,
+            -8.134_264_678_656_593e14,
+             2.023_910_973_916_877e15,
+            -3.086_757_152_953_708e15,
+             2.175_875_438_638_19e15
+        ];
+        x.exp()/x.sqrt()*coefficients.iter().enumerate().map(|(i, c)| c/x.powi(i.try_into().unwrap())).sum::<f64>()
+    }
+    else
+    {
+        let coefficients = [
+            3.989_422_804_014_329e-1,
+            4.986_778_504_914_345e-2,
+            2.805_063_089_165_061e-2,
+            2.921_790_968_539_151e-2,
+            4.533_712_087_625_794e-2
+        ];
+        let expf = (0.5*x).exp();
+        (expf/x.sqrt()*coefficients.iter().enumerate().map(|(i, c)| c/x.powi(i.try_into().unwrap())).sum::<f64>())*expf
+    }
+}
+
+fn bessel_i1(x: &f64) -> f64
+{
+    if x < &7.75
+    {
+        let coefficients = [
+            8.333_333_333_333_333e-2,
+            6.944_444_444_444_341e-3,
+            3.472_222_222_225_921e-4,
+            1.157_407_407_354_987e-5,
+            2.755_731_926_254_79e-7,
+            4.920_949_692_800_671e-9,
+            6.834_657_311_305_621e-11,
+            7.593_969_849_687_574e-13,
+            6.904_822_652_741_917e-15,
+            5.220_157_095_351_373e-17,
+            3.410_720_494_727_771e-19,
+            1.625_212_890_947_171e-21,
+            1.332_898_928_162_29e-23
+        ];
+        let t = 0.25*x.powi(2);
+        let more_coefficients = [
+            1.0,
+            0.5,
+            coefficients.iter().enumerate().map(|(i, c)| c*t.powi(i.try_into().unwrap())).sum::<f64>()
+        ];
+        0.5*x*more_coefficients.iter().enumerate().map(|(i, c)| c*t.powi(i.try_into().unwrap())).sum::<f64>()
+    }
+    else if x < &500.0
+    {
+        let coefficients = [
+             3.989_422_804_014_406e-1,
+            -1.496_033_551_613_111e-1,
+            -4.675_104_253_598_537e-2,
+            -4.090_895_951_581_637e-2,
+            -5.719_036_414_430_205e-2,
+            -1.528_189_554_374_492e-1,
+             3.458_284_470_977_172e0,
+            -2.426_181_371_595_021e2,
+             1.178_785_865_993_44e4,
+            -4.404_655_582_443_487e5,
+             1.277_677_779_341_446e7,
+            -2.903_390_398_236_656e8,
+             5.192_386_898_222_206e9,
+            -7.313_784_438_967_834e10,
+             8.087_824_484_994_859e11,
+            -6.967_602_516_005_787e12,
+             4.614_040_809_616_582e13,
+            -2.298_849_639_457_172e14,
+             8.325_554_073_334_618e14,
+            -2.067_285_045_778_906e15,
+             3.146_401_654_361_325e15,
+            -2.213_318_202_179_221e15
+        ];
+        x.exp()/x.sqrt()*coefficients.iter().enumerate().map(|(i, c)| c/x.powi(i.try_into().unwrap())).sum::<f64>()
+    }
+    else
+    {
+        let coefficients = [
+             3.989_422_804_014_314e-1,
+            -1.496_033_551_467_584e-1,
+            -4.675_105_322_571_775e-2,
+            -4.090_421_597_376_992e-2,
+            -5.843_630_344_778_927e-2
+        ];
+        let expf = (0.5*x).exp();
+        (expf/x.sqrt()*coefficients.iter().enumerate().map(|(i, c)| c/x.powi(i.try_into().unwrap())).sum::<f64>())*expf
+    }
+}
+
+pub fn bessel_i(nu: &u8, x: &f64) -> f64
+{
+    let sign = if x < &0.0 && nu % 2 == 1
+    {
+        -1.0
+    }
+    else
+    {
+        1.0
+    };
+    if nu == &0
+    {
+        bessel_i0(&x.abs())
+    }
+    else if nu == &1
+    {
+        sign*bessel_i1(&x.abs())
+    }
+    else if x == &0.0
+    {
+        0.0
+    }
+    else
+    {
+        let exponential = (0.5*x.abs()).exp();
+        bessel_i_scaled(nu, x)*exponential*exponential
+    }
+}
+
+pub fn bessel_i_scaled(nu: &u8, x: &f64) -> f64
+{
+    if x == &0.0
+    {
+        if nu == &0
+        {
+            1.0
+        }
+        else
+        {
+            0.0
+        }
+    }
+    else
+    {
+        match bessel_ik_scaled(&(*nu as f64), &x.abs())
+        {
+            Ok((i_scaled, _)) => if x < &0.0 && nu % 2 == 1
+            {
+                -i_scaled
+            }
+            else
+            {
+                i_scaled
+            },
+            Err(_) => f64::NAN
+        }
+    }
+}
+
+pub fn bessel_i_nu(nu: &f64, x: &f64) -> Result<f64, MathError>
+{
+    let i_scaled = bessel_i_nu_scaled(nu, x)?;
+    let exponential = (0.5*x.abs()).exp();
+    Ok(i_scaled*exponential*exponential)
+}
+
+pub fn bessel_i_nu_scaled(nu: &f64, x: &f64) -> Result<f64, MathError>
+{
+    if !nu.is_finite() || x.is_nan()
+    {
+        return Err(MathError::Domain("bessel_i_nu", *x))
+    }
+    let integer_order = nu.fract() == 0.0;
+    if x < &0.0
+    {
+        if integer_order
+        {
+            let i_scaled = bessel_i_nu_scaled(nu, &x.abs())?;
+            return Ok(if nu.abs() % 2.0 == 1.0 {-i_scaled} else {i_scaled})
+        }
+        return Err(MathError::Domain("bessel_i_nu", *x))
+    }
+    if x == &0.0
+    {
+        return if nu == &0.0
+        {
+            Ok(1.0)
+        }
+        else if nu > &0.0 || integer_order
+        {
+            Ok(0.0)
+        }
+        else
+        {
+            Err(MathError::Pole("bessel_i_nu", *x))
+        }
+    }
+    let (i_scaled, k_scaled) = bessel_ik_scaled(&nu.abs(), x)?;
+    if nu >= &0.0 || integer_order
+    {
+        Ok(i_scaled)
+    }
+    else
+    {
+        Ok(i_scaled + 2.0/PI*(PI*nu.abs()).sin()*k_scaled*(-2.0*x).exp())
+    }
+}
+
+pub fn bessel_k(nu: &u8, x: &f64) -> Result<f64, MathError>
+{
+    bessel_k_nu(&(*nu as f64), x)
+}
+
+pub fn bessel_k_scaled(nu: &u8, x: &f64) -> Result<f64, MathError>
+{
+    bessel_k_nu_scaled(&(*nu as f64), x)
+}
+
+pub fn bessel_k_nu(nu: &f64, x: &f64) -> Result<f64, MathError>
+{
+    Ok(bessel_k_nu_scaled(nu, x)?*(-x).exp())
+}
+
+pub fn bessel_k_nu_scaled(nu: &f64, x: &f64) -> Result<f64, MathError>
+{
+    if !nu.is_finite() || x.is_nan() || x < &0.0
+    {
+        Err(MathError::Domain("bessel_k_nu", *x))
+    }
+    else if x == &0.0
+    {
+        Err(MathError::Pole("bessel_k_nu", *x))
+    }
+    else
+    {
+        Ok(bessel_ik_scaled(&nu.abs(), x)?.1)
+    }
+}
+
+fn bessel_ik_scaled(nu: &f64, x: &f64) -> Result<(f64, f64), MathError>
+{
+    if x.is_infinite()
+    {
+        return Ok((0.0, 0.0))
+    }
+    if x > &1e4 && nu.powi(2) < 0.1*x
+    {
+        return Ok(bessel_ik_scaled_asymptotic(nu, x))
+    }
+    let number_of_recurrences = (nu + 0.5).floor() as u32;
+    let mu = nu - number_of_recurrences as f64;
+    let (mut k_mu, mut k_mu_1) = if x < &2.0
+    {
+        bessel_k_mu_temme(&mu, x)?
+    }
+    else
+    {
+        bessel_k_mu_steed(&mu, x)?
+    };
+    let i_scaled = if x < &2.0
+    {
+        bessel_i_series_scaled(nu, x)?
+    }
+    else
+    {
+        bessel_i_wronskian_scaled(nu, x, &mu, &number_of_recurrences, &k_mu, &k_mu_1)?
+    };
+    for index in 1..=number_of_recurrences
+    {
+        let k_mu_2 = 2.0*(mu + index as f64)/x*k_mu_1 + k_mu;
+        k_mu = k_mu_1;
+        k_mu_1 = k_mu_2;
+    }
+    Ok((i_scaled, k_mu))
+}
+
+fn bessel_i_series_scaled(nu: &f64, x: &f64) -> Result<f64, MathError>
+{
+    let maximum_iterations = 10_000;
+    let quarter_x_squared = 0.25*x*x;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for index in 1..=maximum_iterations
+    {
+        term *= quarter_x_squared/(index as f64*(nu + index as f64));
+        sum += term;
+        if term < sum*f64::EPSILON
+        {
+            return Ok((nu*(0.5*x).ln() - ln_gamma(&(nu + 1.0))? - x).exp()*sum)
+        }
+    }
+    Err(MathError::Convergence("bessel_i_series", maximum_iterations))
+}
+
+fn bessel_i_wronskian_scaled(nu: &f64, x: &f64, mu: &f64, number_of_recurrences: &u32, k_mu: &f64, k_mu_1: &f64) -> Result<f64, MathError>
+{
+    let maximum_iterations = 1_000_000;
+    let tiny = 1e-30;
+    let xi = 1.0/x;
+    let xi2 = 2.0*xi;
+    let mut h = (nu*xi).max(tiny);
+    let mut b = xi2*nu;
+    let mut c = h;
+    let mut d = 0.0;
+    let mut converged = false;
+    for _ in 0..maximum_iterations
+    {
+        b += xi2;
+        d = 1.0/(b + d);
+        c = b + 1.0/c;
+        let delta = c*d;
+        h *= delta;
+        if (delta - 1.0).abs() < f64::EPSILON
+        {
+            converged = true;
+            break
+        }
+    }
+    if !converged
+    {
+        return Err(MathError::Convergence("bessel_i_wronskian", maximum_iterations))
+    }
+    let mut i_l = tiny;
+    let mut ip_l = h*i_l;
+    let mut i_nu = i_l;
+    let mut factor = nu*xi;
+    for _ in 0..*number_of_recurrences
+    {
+        let i_l_new = factor*i_l + ip_l;
+        factor -= xi;
+        ip_l = factor*i_l_new + i_l;
+        i_l = i_l_new;
+        if i_l.abs() > 1e250
+        {
+            i_l *= 1e-250;
+            ip_l *= 1e-250;
+            i_nu *= 1e-250;
+        }
+    }
+    let kp_mu = mu*xi*k_mu - k_mu_1;
+    Ok(xi/(ip_l/i_l*k_mu - kp_mu)*i_nu/i_l)
+}
+
+fn bessel_ik_scaled_asymptotic(nu: &f64, x: &f64) -> (f64, f64)
+{
+    let mut term = 1.0;
+    let mut sum_i = 1.0;
+    let mut sum_k = 1.0;
+    for index in 1..100
+    {
+        let next_term = term*(4.0*nu.powi(2) - (2.0*index as f64 - 1.0).powi(2))/(8.0*index as f64*x);
+        if next_term.abs() >= term.abs() || next_term.abs() < f64::EPSILON
+        {
+            break
+        }
+        term = next_term;
+        sum_i += if index % 2 == 1 {-term} else {term};
+        sum_k += term;
+    }
+    (sum_i/(2.0*PI*x).sqrt(), sum_k*(0.5*PI/x).sqrt())
+}
+
+fn bessel_k_mu_temme(mu: &f64, x: &f64) -> Result<(f64, f64), MathError>
+{
+    let maximum_iterations = 10_000;
+    let half_x = 0.5*x;
+    let pi_mu = PI*mu;
+    let factor = if pi_mu.abs() < f64::EPSILON
+    {
+        1.0
+    }
+    else
+    {
+        pi_mu/pi_mu.sin()
+    };
+    let d = -half_x.ln();
+    let e = mu*d;
+    let factor_2 = if e.abs() < f64::EPSILON
+    {
+        1.0
+    }
+    else
+    {
+        e.sinh()/e
+    };
+    let (gamma_1, gamma_2) = temme_gamma(mu);
+    let gamma_plus = gamma_2 - mu*gamma_1;
+    let gamma_minus = gamma_2 + mu*gamma_1;
+    let mut f = factor*(gamma_1*e.cosh() + gamma_2*factor_2*d);
+    let mut sum = f;
+    let exponential = e.exp();
+    let mut p = 0.5*exponential/gamma_plus;
+    let mut q = 0.5/(exponential*gamma_minus);
+    let mut c = 1.0;
+    let half_x_squared = half_x*half_x;
+    let mut sum_1 = p;
+    for index in 1..=maximum_iterations
+    {
+        let i = index as f64;
+        f = (i*f + p + q)/(i*i - mu*mu);
+        c *= half_x_squared/i;
+        p /= i - mu;
+        q /= i + mu;
+        let delta = c*f;
+        sum += delta;
+        sum_1 += c*(p - i*f);
+        if delta.abs() < sum.abs()*f64::EPSILON
+        {
+            let exponential = x.exp();
+            return Ok((sum*exponential, 2.0*sum_1/x*exponential))
+        }
+    }
+    Err(MathError::Convergence("bessel_k_mu_temme", maximum_iterations))
+}
+
+fn bessel_k_mu_steed(mu: &f64, x: &f64) -> Result<(f64, f64), MathError>
+{
+    let maximum_iterations = 10_000;
+    let mut b = 2.0*(1.0 + x);
+    let mut d = 1.0/b;
+    let mut h = d;
+    let mut delta_h = d;
+    let mut q_1 = 0.0;
+    let mut q_2 = 1.0;
+    let a_1 = 0.25 - mu*mu;
+    let mut q = a_1;
+    let mut c = a_1;
+    let mut a = -a_1;
+    let mut s = 1.0 + q*delta_h;
+    for index in 2..=maximum_iterations
+    {
+        let i = index as f64;
+        a -= 2.0*(i - 1.0);
+        c = -a*c/i;
+        let q_new = (q_1 - b*q_2)/a;
+        q_1 = q_2;
+        q_2 = q_new;
+        q += c*q_new;
+        b += 2.0;
+        d = 1.0/(b + a*d);
+        delta_h *= b*d - 1.0;
+        h += delta_h;
+        let delta_s = q*delta_h;
+        s += delta_s;
+        if (delta_s/s).abs() < f64::EPSILON
+        {
+            let k_mu = (0.5*PI/x).sqrt()/s;
+            return Ok((k_mu, k_mu*(mu + x + 0.5 - a_1*h)/x))
+        }
+    }
+    Err(MathError::Convergence("bessel_k_mu_steed", maximum_iterations))
+}
+
+fn temme_gamma(mu: &f64) -> (f64, f64)
+{
+    let coefficients = [
+         1.0,
+         0.577_215_664_901_532_9,
+        -0.655_878_071_520_253_9,
+        -0.042_002_635_034_095_24,
+         0.166_538_611_382_291_5,
+        -0.042_197_734_555_544_34,
+        -0.009_621_971_527_876_974,
+         0.007_218_943_246_663_1,
+        -0.001_165_167_591_859_065,
+        -0.000_215_241_674_114_951,
+         0.000_128_050_282_388_116_2,
+        -2.013_485_478_078_824e-5,
+        -1.250_493_482_142_671e-6,
+         1.133_027_231_981_696e-6,
+        -2.056_338_416_977_607e-7,
+         6.116_095_104_481_416e-9,
+         5.002_007_644_469_223e-9,
+        -1.181_274_570_487_02e-9,
+         1.043_426_711_691_1e-10,
+         7.782_263_439_905_071e-12,
+        -3.696_805_618_642_206e-12,
+         5.100_370_287_454_476e-13,
+        -2.058_326_053_566_507e-14,
+        -5.348_122_539_423_018e-15,
+         1.226_778_628_238_261e-15,
+        -1.181_259_301_697_459e-16
+    ];
+    let gamma_1 = -coefficients.iter().skip(1).step_by(2).enumerate().map(|(i, c)| c*mu.powi(2*i as i32)).sum::<f64>();
+    let gamma_2 = coefficients.iter().step_by(2).enumerate().map(|(i, c)| c*mu.powi(2*i as i32)).sum::<f64>();
+    (gamma_1, gamma_2)
+}
+
+pub fn spherical_bessel_j(n: &u8, x: &f64) -> f64
+{
+    let order = *n as f64;
+    if x < &0.0
+    {
+        let j = spherical_bessel_j(n, &x.abs());
+        return if n % 2 == 1 {-j} else {j}
+    }
+    if x < &1e-4
+    {
+        let double_factorial = (1..=*n).map(|k| 2.0*k as f64 + 1.0).product::<f64>();
+        return x.powi(*n as i32)/double_factorial*(1.0 - x.powi(2)/(4.0*order + 6.0))
+    }
+    let j_0 = x.sin()/x;
+    if n == &0
+    {
+        return j_0
+    }
+    let j_1 = (j_0 - x.cos())/x;
+    if x >= &order
+    {
+        let mut j_k_minus_1 = j_0;
+        let mut j_k = j_1;
+        for k in 1..*n
+        {
+            let j_k_plus_1 = (2.0*k as f64 + 1.0)/x*j_k - j_k_minus_1;
+            j_k_minus_1 = j_k;
+            j_k = j_k_plus_1;
+        }
+        j_k
+    }
+    else
+    {
+        let start = *n as u32 + (40.0*order).sqrt() as u32 + 20;
+        let mut f_k_plus_1 = 0.0;
+        let mut f_k = 1e-300;
+        let mut f_n = 0.0;
+        for k in (1..=start).rev()
+        {
+            let f_k_minus_1 = (2.0*k as f64 + 1.0)/x*f_k - f_k_plus_1;
+            f_k_plus_1 = f_k;
+            f_k = f_k_minus_1;
+            if k - 1 == *n as u32
+            {
+                f_n = f_k;
+            }
+            if f_k.abs() > 1e250
+            {
+                f_k *= 1e-250;
+                f_k_plus_1 *= 1e-250;
+                f_n *= 1e-250;
+            }
+        }
+        if j_0.abs() >= j_1.abs()
+        {
+            f_n*j_0/f_k
+        }
+        else
+        {
+            f_n*j_1/f_k_plus_1
+        }
+    }
+}
+
+pub fn spherical_bessel_i(n: &u8, x: &f64) -> f64
+{
+    if x == &0.0
+    {
+        if n == &0
+        {
+            1.0
+        }
+        else
+        {
+            0.0
+        }
+    }
+    else
+    {
+        match bessel_i_nu(&(*n as f64 + 0.5), &x.abs())
+        {
+            Ok(i) => if x < &0.0 && n % 2 == 1
+            {
+                -(0.5*PI/x.abs()).sqrt()*i
+            }
+            else
+            {
+                (0.5*PI/x.abs()).sqrt()*i
+            },
+            Err(_) => f64::NAN
+        }
+    }
+}
+
+pub fn spherical_bessel_k(n: &u8, x: &f64) -> Result<f64, MathError>
+{
+    Ok((0.5*PI/x).sqrt()*bessel_k_nu(&(*n as f64 + 0.5), x)?)
+}
+
+pub fn gamma(x: &f64) -> Result<f64, MathError>
+{
+    if x.is_nan()
+    {
+        Err(MathError::Domain("gamma", *x))
+    }
+    else if x <= &0.0 && x.fract() == 0.0
+    {
+        Err(MathError::Pole("gamma", *x))
+    }
+    else if x < &0.5
+    {
+        Ok(PI/(sin_pi(x)*gamma(&(1.0 - x))?))
+    }
+    else if x > &171.7
+    {
+        Ok(f64::INFINITY)
+    }
+    else
+    {
+        Ok(ln_gamma_stirling(x).exp())
+    }
+}
+
+pub fn ln_gamma(x: &f64) -> Result<f64, MathError>
+{
+    if x.is_nan()
+    {
+        Err(MathError::Domain("ln_gamma", *x))
+    }
+    else if x <= &0.0 && x.fract() == 0.0
+    {
+        Err(MathError::Pole("ln_gamma", *x))
+    }
+    else if x < &0.5
+    {
+        Ok(PI.ln() - sin_pi(x).abs().ln() - ln_gamma(&(1.0 - x))?)
+    }
+    else
+    {
+        Ok(ln_gamma_stirling(x))
+    }
+}
+
+fn ln_gamma_stirling(x: &f64) -> f64
+{
+    let coefficients = [
+         1.0/12.0,
+        -1.0/360.0,
+         1.0/1260.0,
+        -1.0/1680.0,
+         1.0/1188.0,
+        -691.0/360360.0,
+         1.0/156.0,
+        -3617.0/122400.0
+    ];
+    let shift = (15.0 - x).max(0.0).ceil();
+    let shifted_x = x + shift;
+    let product = (0..shift as u8).map(|k| x + k as f64).product::<f64>();
+    (shifted_x - 0.5)*shifted_x.ln() - shifted_x + 0.5*(2.0*PI).ln() + coefficients.iter().enumerate().map(|(k, c)| c/shifted_x.powi(2*k as i32 + 1)).sum::<f64>() - product.ln()
+}
+
+fn sin_pi(x: &f64) -> f64
+{
+    let nearest_integer = x.round();
+    let sine = (PI*(x - nearest_integer)).sin();
+    if nearest_integer % 2.0 == 0.0
+    {
+        sine
+    }
+    else
+    {
+        -sine
+    }
+}
+
+pub fn lower_incomplete_gamma(a: &f64, x: &f64) -> Result<f64, MathError>
+{
+    Ok(regularized_lower_incomplete_gamma(a, x)?*gamma(a)?)
+}
+
+pub fn upper_incomplete_gamma(a: &f64, x: &f64) -> Result<f64, MathError>
+{
+    Ok(regularized_upper_incomplete_gamma(a, x)?*gamma(a)?)
+}
+
+pub fn regularized_lower_incomplete_gamma(a: &f64, x: &f64) -> Result<f64, MathError>
+{
+    Ok(regularized_incomplete_gamma(a, x)?.0)
+}
+
+pub fn regularized_upper_incomplete_gamma(a: &f64, x: &f64) -> Result<f64, MathError>
+{
+    Ok(regularized_incomplete_gamma(a, x)?.1)
+}
+
+fn regularized_incomplete_gamma(a: &f64, x: &f64) -> Result<(f64, f64), MathError>
+{
+    if a.is_nan() || a <= &0.0
+    {
+        return Err(MathError::Domain("incomplete_gamma", *a))
+    }
+    if x.is_nan() || x < &0.0
+    {
+        return Err(MathError::Domain("incomplete_gamma", *x))
+    }
+    if x == &0.0
+    {
+        return Ok((0.0, 1.0))
+    }
+    if x.is_infinite()
+    {
+        return Ok((1.0, 0.0))
+    }
+    let maximum_iterations = 100_000;
+    let prefactor = (a*x.ln() - x - ln_gamma(a)?).exp();
+    if x < &(a + 1.0)
+    {
+        let mut term = 1.0/a;
+        let mut sum = term;
+        let mut shifted_a = *a;
+        for _ in 0..maximum_iterations
+        {
+            shifted_a += 1.0;
+            term *= x/shifted_a;
+            sum += term;
+            if term.abs() < sum.abs()*f64::EPSILON
+            {
+                return Ok((sum*prefactor, 1.0 - sum*prefactor))
+            }
+        }
+    }
+    else
+    {
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0/tiny;
+        let mut d = 1.0/b;
+        let mut h = d;
+        for index in 1..=maximum_iterations
+        {
+            let i = index as f64;
+            let an = -i*(i - a);
+            b += 2.0;
+            d = an*d + b;
+            if d.abs() < tiny
+            {
+                d = tiny;
+            }
+            c = b + an/c;
+            if c.abs() < tiny
+            {
+                c = tiny;
+            }
+            d = 1.0/d;
+            let delta = d*c;
+            h *= delta;
+            if (delta - 1.0).abs() < f64::EPSILON
+            {
+                return Ok((1.0 - prefactor*h, prefactor*h))
+            }
+        }
     }
+    Err(MathError::Convergence("incomplete_gamma", maximum_iterations))
 }
 
 pub fn erf(x: &f64) -> f64
@@ -0,0 +1,69 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    PyArrayDyn,
+    PyReadonlyArrayDyn
+};
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let math = PyModule::new(py, "math")?;
+    math.add_function(wrap_pyfunction!(inverse_langevin, math)?)?;
+    math.add_function(wrap_pyfunction!(inverse_langevin_derivative, math)?)?;
+    math.add_function(wrap_pyfunction!(inverse_langevin_second_derivative, math)?)?;
+    parent_module.add_submodule(math)?;
+    Ok(())
+}
+
+/// The inverse Langevin function,
+///
+/// .. math::
+///     x = \mathcal{L}^{-1}(y),\qquad y = \mathcal{L}(x) = \coth(x) - 1/x,
+///
+/// which behaves as :math:`\mathcal{L}^{-1}(y)\sim 1/(1 - y)` as :math:`y\to 1`.
+///
+/// Args:
+///     y (numpy.ndarray): The argument :math:`y\in[-1, 1]`.
+///
+/// Returns:
+///     numpy.ndarray: The inverse Langevin function :math:`\mathcal{L}^{-1}(y)`.
+///
+#[pyfunction]
+pub fn inverse_langevin<'py>(py: Python<'py>, y: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+{
+    crate::py::batch(py, y, |y: f64| super::inverse_langevin(&y))
+}
+
+/// The first derivative of the inverse Langevin function,
+///
+/// .. math::
+///     \frac{d\mathcal{L}^{-1}}{dy} = \frac{1}{\mathcal{L}'(x)}.
+///
+/// Args:
+///     y (numpy.ndarray): The argument :math:`y\in[-1, 1]`.
+///
+/// Returns:
+///     numpy.ndarray: The first derivative of the inverse Langevin function.
+///
+#[pyfunction]
+pub fn inverse_langevin_derivative<'py>(py: Python<'py>, y: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+{
+    crate::py::batch(py, y, |y: f64| super::inverse_langevin_derivative(&y))
+}
+
+/// The second derivative of the inverse Langevin function,
+///
+/// .. math::
+///     \frac{d^2\mathcal{L}^{-1}}{dy^2} = -\frac{\mathcal{L}''(x)}{\mathcal{L}'(x)^3}.
+///
+/// Args:
+///     y (numpy.ndarray): The argument :math:`y\in[-1, 1]`.
+///
+/// Returns:
+///     numpy.ndarray: The second derivative of the inverse Langevin function.
+///
+#[pyfunction]
+pub fn inverse_langevin_second_derivative<'py>(py: Python<'py>, y: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+{
+    crate::py::batch(py, y, |y: f64| super::inverse_langevin_second_derivative(&y))
+}
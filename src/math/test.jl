@@ -0,0 +1,39 @@
+module Test
+
+using Test
+using Polymers.Math:
+    inverse_langevin, inverse_langevin_derivative, inverse_langevin_second_derivative
+
+@testset "math::test::inverse_langevin::function" begin
+    for x in [1e-3, 0.1, 0.9, 1.5, 5.0, 20.0]
+        y = coth(x) - 1.0 / x
+        @test abs(inverse_langevin(y) - x) / x <= 1e-9
+        @test abs(inverse_langevin(-y) + x) / x <= 1e-9
+    end
+end
+
+@testset "math::test::inverse_langevin::derivative" begin
+    for x in [1e-3, 0.1, 0.9, 1.5, 5.0, 20.0]
+        y = coth(x) - 1.0 / x
+        derivative = 1.0 / (1.0 / x^2 - csch(x)^2)
+        @test abs(inverse_langevin_derivative(y) - derivative) / derivative <= 1e-9
+    end
+end
+
+@testset "math::test::inverse_langevin::second_derivative" begin
+    for x in [0.1, 0.9, 1.5, 5.0, 20.0]
+        y = coth(x) - 1.0 / x
+        first = 1.0 / x^2 - csch(x)^2
+        second = -(2.0 * coth(x) * csch(x)^2 - 2.0 / x^3) / first^3
+        @test abs(inverse_langevin_second_derivative(y) - second) / second <= 1e-9
+    end
+end
+
+@testset "math::test::inverse_langevin::limits" begin
+    @test inverse_langevin(0.0) == 0.0
+    @test inverse_langevin(1.0) == Inf
+    @test isnan(inverse_langevin(1.5))
+    @test abs(inverse_langevin(1.0 - 1e-10) * 1e-10 - 1.0) <= 1e-6
+end
+
+end
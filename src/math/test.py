@@ -0,0 +1,87 @@
+"""Module to test the local module.
+
+"""
+import unittest
+import numpy as np
+from polymers import math
+
+
+class InverseLangevin(unittest.TestCase):
+    """Class for tests of the inverse Langevin function.
+
+    """
+    def test_function(self):
+        """Function to test the inverse Langevin function.
+
+        """
+        x = np.array([1e-3, 0.1, 0.9, 1.5, 5.0, 20.0])
+        y = 1/np.tanh(x) - 1/x
+        self.assertTrue(
+            np.all(np.abs(math.inverse_langevin(y) - x)/x <= 1e-9)
+        )
+        self.assertTrue(
+            np.all(np.abs(math.inverse_langevin(-y) + x)/x <= 1e-9)
+        )
+
+    def test_derivative(self):
+        """Function to test the first derivative.
+
+        """
+        x = np.array([1e-3, 0.1, 0.9, 1.5, 5.0, 20.0])
+        y = 1/np.tanh(x) - 1/x
+        derivative = 1/(1/x**2 - 1/np.sinh(x)**2)
+        self.assertTrue(
+            np.all(
+                np.abs(
+                    math.inverse_langevin_derivative(y) - derivative
+                )/derivative <= 1e-9
+            )
+        )
+
+    def test_second_derivative(self):
+        """Function to test the second derivative.
+
+        """
+        x = np.array([0.1, 0.9, 1.5, 5.0, 20.0])
+        y = 1/np.tanh(x) - 1/x
+        first = 1/x**2 - 1/np.sinh(x)**2
+        second = -(2/np.tanh(x)/np.sinh(x)**2 - 2/x**3)/first**3
+        self.assertTrue(
+            np.all(
+                np.abs(
+                    math.inverse_langevin_second_derivative(y) - second
+                )/second <= 1e-9
+            )
+        )
+
+    def test_limits(self):
+        """Function to test the limits.
+
+        """
+        self.assertEqual(math.inverse_langevin(np.array(0.0)), 0.0)
+        self.assertEqual(math.inverse_langevin(np.array(1.0)), np.inf)
+        self.assertTrue(np.isnan(math.inverse_langevin(np.array(1.5))))
+        self.assertAlmostEqual(
+            math.inverse_langevin(np.array(1.0 - 1e-10))*1e-10, 1.0, 6
+        )
+
+
+class Batch(unittest.TestCase):
+    """Class for tests of batch evaluation over arrays.
+
+    """
+    def test_shape(self):
+        """Function to test that the shape of the array is preserved.
+
+        """
+        y = np.linspace(-0.99, 0.99, 60000).reshape(200, 300)
+        for array in [y, y.T, y[::2, ::3]]:
+            result = math.inverse_langevin(array)
+            self.assertEqual(result.shape, array.shape)
+            self.assertTrue(
+                np.all(
+                    result == np.vectorize(
+                        lambda y_i: math.inverse_langevin(np.array(y_i))
+                    )(array)
+                )
+            )
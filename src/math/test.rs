@@ -0,0 +1,770 @@
+#![cfg(test)]
+use super::*;
+const REL_TOL: f64 = 1e-12;
+fn residual_rel(value: &f64, reference: &f64) -> f64
+{
+    (value - reference).abs()/reference.abs()
+}
+mod bessel_i
+{
+    use super::*;
+    #[test]
+    fn integer_order()
+    {
+        let values = [
+            (0_u8, 0.5, 1.0634833707413236),
+            (0_u8, 12.0, 18948.925349296307),
+            (1_u8, 3.0, 3.9533702174026093),
+            (2_u8, 0.1, 0.0012510419922417593),
+            (2_u8, 8.0, 327.5958315261648),
+            (5_u8, -3.0, -0.09120647766151335),
+            (5_u8, 40.0, 1.0858318337624282e+16),
+            (12_u8, 1.5, 6.904955065114026e-11)
+        ];
+        for (n, x, reference) in values
+        {
+            assert!(residual_rel(&bessel_i(&n, &x), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn integer_order_scaled()
+    {
+        let values = [
+            (0_u8, 700.0, 0.015081295651531358),
+            (3_u8, 2000.0, 0.008901123184286763),
+            (4_u8, -5.5, 0.03803545609676296),
+            (7_u8, 0.3, 2.5184955905684605e-10)
+        ];
+        for (n, x, reference) in values
+        {
+            assert!(residual_rel(&bessel_i_scaled(&n, &x), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn real_order()
+    {
+        let values = [
+            (0.3, 0.5, 0.7709517345792195),
+            (2.5, 3.0, 1.5153394466819652),
+            (7.3, 10.0, 193.52724916037613),
+            (40.0, 5.0, 1.1804269803595625e-32),
+            (-0.3, 2.5, 3.226518298538889),
+            (-2.5, 0.7, 5.425153459061945),
+            (2.0, -2.0, 0.6889484476987382)
+        ];
+        for (nu, x, reference) in values
+        {
+            assert!(residual_rel(&bessel_i_nu(&nu, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn real_order_scaled()
+    {
+        let values = [
+            (0.7, 1000.0, 0.012614148063840197),
+            (3.7, 100000.0, 0.0012614814861819396),
+            (10.5, 20.0, 0.005674626958118978)
+        ];
+        for (nu, x, reference) in values
+        {
+            assert!(residual_rel(&bessel_i_nu_scaled(&nu, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+}
+mod bessel_k
+{
+    use super::*;
+    #[test]
+    fn integer_order()
+    {
+        let values = [
+            (0_u8, 1.0, 0.42102443824070834),
+            (1_u8, 0.2, 4.775972543220472),
+            (4_u8, 6.0, 0.004163865663165997)
+        ];
+        for (n, x, reference) in values
+        {
+            assert!(residual_rel(&bessel_k(&n, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn real_order()
+    {
+        let values = [
+            (0.0, 0.1, 2.4270690247020164),
+            (0.3, 1.9, 0.13137942527906504),
+            (1.0, 2.1, 0.12274641153350789),
+            (2.5, 3.0, 0.08406063197411738),
+            (7.3, 10.0, 0.00020859252060436215),
+            (40.0, 5.0, 1.0507567219474984e+30),
+            (-1.5, 0.4, 4.649221039024742)
+        ];
+        for (nu, x, reference) in values
+        {
+            assert!(residual_rel(&bessel_k_nu(&nu, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn real_order_scaled()
+    {
+        let values = [
+            (0.0, 1000.0, 0.03962832160075422),
+            (2.2, 100000.0, 0.003963418256556445),
+            (5.0, 30.0, 0.3430714745912582)
+        ];
+        for (nu, x, reference) in values
+        {
+            assert!(residual_rel(&bessel_k_nu_scaled(&nu, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+}
+mod spherical_bessel
+{
+    use super::*;
+    #[test]
+    fn first_kind()
+    {
+        let values = [
+            (0_u8, 1e-05, 0.9999999999833333),
+            (0_u8, 3.0, 0.04704000268662241),
+            (1_u8, 0.5, 0.16253703063606656),
+            (3_u8, 9.5, -0.08266190471070584),
+            (10_u8, 1e-05, 7.273091945541618e-61),
+            (10_u8, 3.0, 3.5260038931752564e-06),
+            (10_u8, 50.0, -0.01503922146346596),
+            (30_u8, 9.5, 5.836624781775872e-14)
+        ];
+        for (n, x, reference) in values
+        {
+            assert!(residual_rel(&spherical_bessel_j(&n, &x), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn modified_first_kind()
+    {
+        let values = [
+            (0_u8, 1e-05, 1.0000000000166667),
+            (1_u8, 0.5, 0.17087070843777213),
+            (3_u8, 9.5, 363.61716067856133),
+            (10_u8, 3.0, 5.214946122763417e-06),
+            (30_u8, 50.0, 5676593928693482.0)
+        ];
+        for (n, x, reference) in values
+        {
+            assert!(residual_rel(&spherical_bessel_i(&n, &x), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn modified_second_kind()
+    {
+        let values = [
+            (0_u8, 1e-05, 157078.0618910168),
+            (1_u8, 0.5, 5.71641679419054),
+            (3_u8, 9.5, 2.246684680175604e-05),
+            (10_u8, 3.0, 4595.8396399769945),
+            (30_u8, 50.0, 4.724600057268812e-20)
+        ];
+        for (n, x, reference) in values
+        {
+            assert!(residual_rel(&spherical_bessel_k(&n, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+}
+mod gamma
+{
+    use super::*;
+    #[test]
+    fn function()
+    {
+        let values = [
+            (0.1, 9.51350769866873),
+            (0.5, 1.772453850905516),
+            (3.3, 2.6834373819557684),
+            (10.0, 362880.0),
+            (100.5, 9.320963104082716e+156),
+            (-0.5, -3.544907701811032),
+            (-2.7, -0.931082784838964),
+            (-10.3, -5.26236323953561e-07)
+        ];
+        for (x, reference) in values
+        {
+            assert!(residual_rel(&gamma(&x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn logarithm()
+    {
+        let values = [
+            (0.1, 2.252712651734206),
+            (0.5, 0.5723649429247001),
+            (3.3, 0.9870985778947344),
+            (10.0, 12.801827480081469),
+            (1000.5, 5908.674175848678),
+            (-2.7, -0.07140708531564569),
+            (-10.3, -14.457515440024208)
+        ];
+        for (x, reference) in values
+        {
+            assert!(residual_rel(&ln_gamma(&x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+}
+mod incomplete_gamma
+{
+    use super::*;
+    #[test]
+    fn regularized_lower()
+    {
+        let values = [
+            (0.5, 0.1, 0.345279153981423),
+            (1.0, 2.0, 0.8646647167633873),
+            (3.5, 2.0, 0.22022259152428408),
+            (3.5, 10.0, 0.9944303169270544),
+            (50.0, 45.0, 0.24680203440017026),
+            (100.0, 130.0, 0.9972495916326934)
+        ];
+        for (a, x, reference) in values
+        {
+            assert!(residual_rel(&regularized_lower_incomplete_gamma(&a, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn regularized_upper()
+    {
+        let values = [
+            (0.5, 0.1, 0.654720846018577),
+            (1.0, 2.0, 0.1353352832366127),
+            (3.5, 10.0, 0.005569683072945571),
+            (50.0, 45.0, 0.7531979655998298),
+            (0.1, 20.0, 1.401358980217001e-11),
+            (100.0, 130.0, 0.002750408367306526)
+        ];
+        for (a, x, reference) in values
+        {
+            assert!(residual_rel(&regularized_upper_incomplete_gamma(&a, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn lower()
+    {
+        let values = [
+            (0.5, 0.1, 0.6119913661117719),
+            (3.5, 2.0, 0.7318769632567683),
+            (50.0, 45.0, 1.50125201532385e+62)
+        ];
+        for (a, x, reference) in values
+        {
+            assert!(residual_rel(&lower_incomplete_gamma(&a, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn upper()
+    {
+        let values = [
+            (0.5, 0.1, 1.1604624847937441),
+            (3.5, 10.0, 0.018510011645560587),
+            (50.0, 45.0, 4.581566625018826e+62)
+        ];
+        for (a, x, reference) in values
+        {
+            assert!(residual_rel(&upper_incomplete_gamma(&a, &x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn complement()
+    {
+        for (a, x) in [(0.5, 0.1), (3.5, 2.0), (3.5, 10.0), (50.0, 45.0)]
+        {
+            let sum = regularized_lower_incomplete_gamma(&a, &x).unwrap() + regularized_upper_incomplete_gamma(&a, &x).unwrap();
+            assert!((sum - 1.0).abs() <= REL_TOL);
+        }
+    }
+}
+mod lambert_w
+{
+    use super::*;
+    #[test]
+    fn principal_branch()
+    {
+        let values = [
+            (-0.36787944117144233, -1.0),
+            (-0.36787944117144133, -0.9999999267562402),
+            (-0.3678794410714423, -0.9999766837418852),
+            (-0.36787844117144236, -0.9976701662720535),
+            (-0.3, -0.4894022271802149),
+            (-0.25, -0.3574029561813889),
+            (-0.1, -0.11183255915896297),
+            (-0.001, -0.001001001502671886),
+            (1e-10, 9.999999999e-11),
+            (0.001, 0.0009990014973385308),
+            (0.5, 0.35173371124919584),
+            (1.0, 0.5671432904097838),
+            (2.5, 0.958586356728703),
+            (3.0, 1.04990889496404),
+            (10.0, 1.7455280027406994),
+            (1e3, 5.249602852401596),
+            (1e10, 20.028685413304952),
+            (1e100, 224.8431064451185),
+            (1e300, 684.2472086297608)
+        ];
+        for (x, reference) in values
+        {
+            assert!(residual_rel(&lambert_w_0(&x).unwrap(), &reference) <= REL_TOL);
+            assert!(residual_rel(&lambert_w(&x), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn lower_branch()
+    {
+        let values = [
+            (-0.36787944117144233, -1.0),
+            (-0.36787944117144133, -1.0000000732437635),
+            (-0.3678794410714423, -1.0000233166205523),
+            (-0.36787844117144236, -1.0023334581084247),
+            (-0.3, -1.7813370234216277),
+            (-0.25, -2.15329236411035),
+            (-0.2, -2.5426413577735265),
+            (-0.1, -3.577152063957297),
+            (-0.001, -9.11800647040274),
+            (-1e-10, -26.295238819246926),
+            (-1e-100, -235.72115887568532),
+            (-1e-300, -697.3227762954601)
+        ];
+        for (x, reference) in values
+        {
+            assert!(residual_rel(&lambert_w_m1(&x).unwrap(), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn inverse()
+    {
+        for x in [-0.367, -0.2, -1e-5, 1e-5, 1.0, 1e5]
+        {
+            let w = lambert_w_0(&x).unwrap();
+            assert!(residual_rel(&(w*w.exp()), &x) <= REL_TOL);
+        }
+        for x in [-0.367, -0.2, -1e-5, -1e-50]
+        {
+            let w = lambert_w_m1(&x).unwrap();
+            assert!(residual_rel(&(w*w.exp()), &x) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn limits()
+    {
+        assert_eq!(lambert_w_0(&0.0), Ok(0.0));
+        assert_eq!(lambert_w_0(&f64::INFINITY), Ok(f64::INFINITY));
+        assert_eq!(lambert_w_m1(&0.0), Ok(f64::NEG_INFINITY));
+    }
+}
+mod batch
+{
+    use super::*;
+    #[test]
+    fn serial()
+    {
+        let x: Vec<f64> = (0..100).map(|i| (i as f64)/100.0).collect();
+        let y = batch(&inverse_langevin, &x);
+        x.iter().zip(y.iter()).for_each(|(x_i, y_i)| assert_eq!(y_i, &inverse_langevin(x_i)));
+    }
+    #[test]
+    fn threaded()
+    {
+        let x: Vec<f64> = (0..100_001).map(|i| (i as f64)/100_001.0).collect();
+        let y = batch(&inverse_langevin, &x);
+        x.iter().zip(y.iter()).for_each(|(x_i, y_i)| assert_eq!(y_i, &inverse_langevin(x_i)));
+    }
+    #[test]
+    fn into()
+    {
+        let x: Vec<f64> = (0..10_000).map(|i| (i as f64)/10_000.0).collect();
+        let mut y = vec![0.0; x.len()];
+        let factor = 2.0;
+        batch_into(&|x_i: &f64| factor*x_i, &x, &mut y).unwrap();
+        x.iter().zip(y.iter()).for_each(|(x_i, y_i)| assert_eq!(y_i, &(factor*x_i)));
+    }
+    #[test]
+    fn model()
+    {
+        let model = crate::physics::single_chain::fjc::thermodynamics::isometric::FJC::init(8, 1.0, 1.0);
+        let x: Vec<f64> = (1..10_000).map(|i| (i as f64)/10_000.0).collect();
+        let y = model.nondimensional_force_batch(&x);
+        x.iter().zip(y.iter()).for_each(|(x_i, y_i)| assert_eq!(y_i, &model.nondimensional_force(x_i)));
+        let model = crate::physics::single_chain::ufjc::morse::thermodynamics::isometric::MORSEFJC::init(8, 1.0, 1.0, 1e5, 1e5);
+        let y = model.nondimensional_force_batch(&x, &300.0).unwrap();
+        x.iter().zip(y.iter()).for_each(|(x_i, y_i)| assert_eq!(y_i, &model.nondimensional_force(x_i, &300.0).unwrap()));
+        assert!(model.nondimensional_force_batch(&[0.5, -0.5], &300.0).is_err());
+    }
+    #[test]
+    fn empty()
+    {
+        assert!(batch(&inverse_langevin, &[]).is_empty());
+    }
+    #[test]
+    fn mismatched()
+    {
+        assert_eq!(batch_into(&inverse_langevin, &[0.1, 0.2], &mut [0.0]), Err(MathError::Length("batch_into", 2, 1)));
+    }
+    #[test]
+    fn fallible()
+    {
+        let x: Vec<f64> = (1..100_001).map(|i| (i as f64)/100_001.0).collect();
+        let y = try_batch(&|x_i: &f64| lambert_w_0(x_i), &x).unwrap();
+        x.iter().zip(y.iter()).for_each(|(x_i, y_i)| assert_eq!(y_i, &lambert_w_0(x_i).unwrap()));
+        let mut x = x;
+        x[50_000] = -1.0;
+        assert_eq!(try_batch(&|x_i: &f64| lambert_w_0(x_i), &x), Err(MathError::Domain("lambert_w_0", -1.0)));
+    }
+}
+mod gauss_legendre
+{
+    use super::*;
+    #[test]
+    fn weights()
+    {
+        (1..=64).for_each(|n|
+        {
+            let (nodes, weights) = gauss_legendre(&n);
+            assert_eq!(nodes.len(), n);
+            assert!((weights.iter().sum::<f64>() - 2.0).abs() <= 1e-13);
+            nodes.windows(2).for_each(|pair| assert!(pair[0] < pair[1]));
+            nodes.iter().zip(nodes.iter().rev()).for_each(|(node, mirror)| assert!((node + mirror).abs() <= 1e-15));
+        })
+    }
+    #[test]
+    fn exact()
+    {
+        (1..=32).for_each(|n|
+        {
+            let (nodes, weights) = gauss_legendre(&n);
+            (0..2*n).for_each(|power|
+            {
+                let integral = nodes.iter().zip(weights.iter()).map(|(node, weight)| weight*node.powi(power as i32)).sum::<f64>();
+                let exact = if power % 2 == 0 {2.0/(power as f64 + 1.0)} else {0.0};
+                assert!((integral - exact).abs() <= 1e-13);
+            })
+        })
+    }
+    #[test]
+    fn empty()
+    {
+        assert!(gauss_legendre(&0).0.is_empty());
+    }
+}
+mod quadrature
+{
+    use super::*;
+    #[test]
+    fn gauss_kronrod()
+    {
+        let (integral, error) = integrate_1d_gauss_kronrod(&|x: &f64| x.sin()*x.exp(), &-1.0, &3.0, &0.0, &1e-13);
+        let exact = 0.5*(3.0_f64.exp()*(3.0_f64.sin() - 3.0_f64.cos()) + (-1.0_f64).exp()*(1.0_f64.sin() + 1.0_f64.cos()));
+        assert!(residual_rel(&integral, &exact) <= REL_TOL);
+        assert!(error <= 1e-13*exact.abs());
+    }
+    #[test]
+    fn tanh_sinh()
+    {
+        let (integral, error) = integrate_1d_tanh_sinh(&|x: &f64| x.ln(), &0.0, &1.0, &0.0, &1e-12);
+        assert!(residual_rel(&integral, &-1.0) <= REL_TOL);
+        assert!(error <= 1e-12);
+        let (integral, _) = integrate_1d_tanh_sinh(&|x: &f64| 1.0/x.sqrt(), &0.0, &1.0, &0.0, &1e-10);
+        assert!(residual_rel(&integral, &2.0) <= 1e-10);
+    }
+    #[test]
+    fn tolerance()
+    {
+        let evaluations = std::cell::Cell::new(0);
+        let integrand = |x: &f64| {evaluations.set(evaluations.get() + 1); (10.0*x).sin()*x.exp()};
+        let exact = (1.0_f64.exp()*(10.0_f64.sin() - 10.0*10.0_f64.cos()) + 10.0)/101.0;
+        let (integral_tight, _) = integrate_1d_gauss_kronrod(&integrand, &0.0, &1.0, &0.0, &1e-14);
+        let evaluations_tight = evaluations.replace(0);
+        let (integral_loose, _) = integrate_1d_gauss_kronrod(&integrand, &0.0, &1.0, &0.0, &1e-3);
+        let evaluations_loose = evaluations.replace(0);
+        assert!(evaluations_loose < evaluations_tight);
+        assert!(residual_rel(&integral_tight, &exact) < residual_rel(&integral_loose, &exact));
+        let (integral_tight, _) = integrate_1d_tanh_sinh(&integrand, &0.0, &1.0, &0.0, &1e-14);
+        let evaluations_tight = evaluations.replace(0);
+        let (integral_loose, _) = integrate_1d_tanh_sinh(&integrand, &0.0, &1.0, &0.0, &1e-3);
+        let evaluations_loose = evaluations.replace(0);
+        assert!(evaluations_loose < evaluations_tight);
+        assert!(residual_rel(&integral_tight, &exact) < residual_rel(&integral_loose, &exact));
+    }
+    #[test]
+    fn errors()
+    {
+        assert!(integrate_1d_gauss_kronrod(&|_: &f64| f64::NAN, &0.0, &1.0, &0.0, &1e-12).0.is_nan());
+        assert!(matches!(try_integrate_1d_gauss_kronrod(&|_: &f64| f64::NAN, &0.0, &1.0, &0.0, &1e-12), Err(MathError::Convergence(_, _))));
+        assert!(matches!(try_integrate_1d_tanh_sinh(&|_: &f64| f64::NAN, &0.0, &1.0, &0.0, &1e-12), Err(MathError::Convergence(_, _))));
+        assert!(matches!(try_integrate_1d_gauss_kronrod(&|x: &f64| 1.0/x, &0.0, &1.0, &0.0, &1e-12), Err(MathError::Convergence(_, _))));
+    }
+}
+mod table
+{
+    use super::*;
+    #[test]
+    fn value()
+    {
+        let table = Table::init(&|x: &f64| x.sin()*x.exp(), &-1.0, &3.0, &0.0, &1e-13).unwrap();
+        for i in 0..=1000
+        {
+            let x = -1.0 + 4.0*(i as f64)/1000.0;
+            assert!((table.value(&x) - x.sin()*x.exp()).abs() <= 1e1*table.error().max(1e-13*3.0_f64.exp()));
+        }
+    }
+    #[test]
+    fn derivatives()
+    {
+        let table = Table::init(&|x: &f64| x.sin()*x.exp(), &-1.0, &3.0, &0.0, &1e-14).unwrap();
+        for i in 0..=1000
+        {
+            let x = -1.0 + 4.0*(i as f64)/1000.0;
+            assert!((table.derivative(&x) - (x.sin() + x.cos())*x.exp()).abs() <= 1e-10);
+            assert!((table.second_derivative(&x) - 2.0*x.cos()*x.exp()).abs() <= 1e-8);
+        }
+    }
+    #[test]
+    fn inverse_langevin()
+    {
+        let table = Table::init(&super::inverse_langevin, &0.0, &0.95, &0.0, &1e-12).unwrap();
+        for i in 0..=1000
+        {
+            let y = 0.95*(i as f64)/1000.0;
+            assert!((table.value(&y) - super::inverse_langevin(&y)).abs() <= 1e-10);
+            assert!(residual_rel(&table.derivative(&y), &inverse_langevin_derivative(&y)) <= 1e-8);
+        }
+    }
+    #[test]
+    fn refinement()
+    {
+        let coarse = Table::init(&|x: &f64| (5.0*x).tanh(), &-1.0, &1.0, &1e-6, &0.0).unwrap();
+        let fine = Table::init(&|x: &f64| (5.0*x).tanh(), &-1.0, &1.0, &1e-14, &0.0).unwrap();
+        assert!(coarse.number_of_segments() < fine.number_of_segments());
+        assert!(coarse.error() <= 1e-6);
+        assert!(fine.error() <= 1e-14);
+    }
+    #[test]
+    fn range()
+    {
+        let table = Table::init(&|x: &f64| x.exp(), &0.0, &1.0, &0.0, &1e-12).unwrap();
+        assert_eq!(table.lower(), 0.0);
+        assert_eq!(table.upper(), 1.0);
+        assert!(residual_rel(&table.value(&1.0), &1.0_f64.exp()) <= REL_TOL);
+        assert!(table.value(&-1e-3).is_nan());
+        assert!(table.value(&1.001).is_nan());
+    }
+    #[test]
+    fn errors()
+    {
+        assert!(matches!(Table::init(&|x: &f64| x.ln(), &-1.0, &1.0, &0.0, &1e-12), Err(MathError::Domain(_, _))));
+        assert!(matches!(Table::init(&|x: &f64| x.exp(), &1.0, &0.0, &0.0, &1e-12), Err(MathError::Bracket(_, _, _))));
+        assert!(matches!(Table::init(&|x: &f64| x.signum(), &-1.0, &2.0, &0.0, &1e-12), Err(MathError::Convergence(_, _))));
+    }
+}
+mod inverse_langevin
+{
+    use super::*;
+    static VALUES: [(f64, f64, f64, f64); 10] = [
+        (1e-5, 3.0000000001800003e-5, 3.00000000054, 0.00010800000003394287),
+        (0.005, 0.01500022500530371, 3.000135005303764, 0.05400424308800339),
+        (0.1, 0.3018171492063381, 3.05486105412132, 1.1146952776209749),
+        (0.3, 0.9531494728574059, 3.564961141236512, 4.370433078423452),
+        (0.5, 1.7967559847237131, 5.169524275757092, 13.677825387308701),
+        (0.7, 3.303544490100766, 11.599210999761256, 69.61638416046685),
+        (0.9, 9.999999587768954, 100.00007420164684, 1999.9882101618964),
+        (0.97, 33.3333333333333, 1111.111111111109, 74074.07407407388),
+        (0.99, 99.99999999999991, 9999.999999999982, 1999999.9999999946),
+        (0.999999, 999999.9999712444, 999999999942.4886, 1.999999999827466e18)
+    ];
+    #[test]
+    fn function()
+    {
+        for (y, reference, _, _) in VALUES
+        {
+            assert!(residual_rel(&inverse_langevin(&y), &reference) <= REL_TOL);
+            assert!(residual_rel(&inverse_langevin(&(-y)), &(-reference)) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn derivative()
+    {
+        for (y, _, reference, _) in VALUES
+        {
+            assert!(residual_rel(&inverse_langevin_derivative(&y), &reference) <= REL_TOL);
+            assert!(residual_rel(&inverse_langevin_derivative(&(-y)), &reference) <= REL_TOL);
+        }
+    }
+    #[test]
+    fn second_derivative()
+    {
+        for (y, _, _, reference) in VALUES
+        {
+            assert!(residual_rel(&inverse_langevin_second_derivative(&y), &reference) <= 1e2*REL_TOL);
+            assert!(residual_rel(&inverse_langevin_second_derivative(&(-y)), &(-reference)) <= 1e2*REL_TOL);
+        }
+    }
+    #[test]
+    fn langevin()
+    {
+        for (y, x, _, _) in VALUES
+        {
+            assert!(residual_rel(&super::langevin(&x), &y) <= REL_TOL);
+            assert!(residual_rel(&super::langevin(&(-x)), &(-y)) <= REL_TOL);
+        }
+        assert_eq!(super::langevin(&0.0), 0.0);
+    }
+    #[test]
+    fn inverse()
+    {
+        for x in [1e-3_f64, 0.1, 0.9, 1.5, 5.0, 20.0]
+        {
+            let y = 1.0/x.tanh() - 1.0/x;
+            assert!(residual_rel(&inverse_langevin(&y), &x) <= 1e3*REL_TOL);
+        }
+    }
+    #[test]
+    fn accuracy_small()
+    {
+        let maximum = (1..=1000).map(|k| 1e-3 + 2e-5*(k as f64)).fold(0.0_f64, |maximum, y|
+        {
+            let y2 = y*y;
+            let reference = y*(3.0 + y2*(9.0/5.0 + y2*(297.0/175.0 + y2*(1539.0/875.0 + y2*(126117.0/67375.0 + y2*43733439.0/21896875.0)))));
+            maximum.max(residual_rel(&inverse_langevin(&y), &reference))
+        });
+        assert!(maximum <= REL_TOL);
+        let y2 = 1e-4;
+        assert!(residual_rel(&inverse_langevin(&1e-2), &(1e-2*(3.0 + y2*(9.0/5.0 + y2*(297.0/175.0 + y2*(1539.0/875.0 + y2*126117.0/67375.0)))))) <= REL_TOL);
+    }
+    #[test]
+    fn accuracy_near_one()
+    {
+        let maximum = (1..=1000).map(|k| 1.0 - 10_f64.powf(-1.0 - 14.0*(k as f64)/1000.0)).fold(0.0_f64, |maximum, y|
+        {
+            let x = inverse_langevin(&y);
+            let [_, langevin_complement, _, _] = langevin_and_derivatives(&x);
+            maximum.max(residual_rel(&langevin_complement, &(1.0 - y)))
+        });
+        assert!(maximum <= REL_TOL);
+        assert!(residual_rel(&inverse_langevin(&(1.0 - 1e-15)), &(1.0/(1.0 - (1.0 - 1e-15)))) <= REL_TOL);
+    }
+    #[test]
+    fn limits()
+    {
+        assert_eq!(inverse_langevin(&0.0), 0.0);
+        assert_eq!(inverse_langevin_derivative(&0.0), 3.0);
+        assert_eq!(inverse_langevin_second_derivative(&0.0), 0.0);
+        assert_eq!(inverse_langevin(&1.0), f64::INFINITY);
+        assert_eq!(inverse_langevin(&-1.0), f64::NEG_INFINITY);
+        assert!(inverse_langevin(&1.5).is_nan());
+        for y in [1.0 - 1e-8, 1.0 - 1e-12]
+        {
+            assert!(residual_rel(&inverse_langevin(&y), &(1.0/(1.0 - y))) <= REL_TOL);
+            assert!(residual_rel(&inverse_langevin_derivative(&y), &(1.0/(1.0 - y)).powi(2)) <= REL_TOL);
+            assert!(residual_rel(&inverse_langevin_second_derivative(&y), &(2.0/(1.0 - y).powi(3))) <= REL_TOL);
+        }
+    }
+}
+mod root_finding
+{
+    use super::*;
+    fn langevin(x: &f64) -> f64
+    {
+        1.0/x.tanh() - 1.0/x
+    }
+    fn langevin_derivative(x: &f64) -> f64
+    {
+        1.0/x.powi(2) - 1.0/x.sinh().powi(2)
+    }
+    #[test]
+    fn brent()
+    {
+        for x in [1e-2, 1.0, 25.0, 1e3]
+        {
+            let y = langevin(&x);
+            assert!(residual_rel(&inverse_brent(&y, &langevin, &1e-3, &1e4, &REL_TOL, &100).unwrap(), &x) <= 1e1*REL_TOL);
+        }
+    }
+    #[test]
+    fn newton_raphson_bracketed()
+    {
+        for x in [1e-2, 1.0, 25.0, 1e3]
+        {
+            let y = langevin(&x);
+            assert!(residual_rel(&inverse_newton_raphson_bracketed(&y, &langevin, &langevin_derivative, &1e-3, &1e4, &REL_TOL, &100).unwrap(), &x) <= 1e1*REL_TOL);
+        }
+    }
+    #[test]
+    fn bracket_expanding()
+    {
+        let (lower, upper) = bracket(&0.999, &langevin, &0.0, &1.0, &f64::INFINITY, &100).unwrap();
+        assert!(langevin(&lower) <= 0.999 || lower == 0.0);
+        assert!(langevin(&upper) >= 0.999);
+    }
+    #[test]
+    fn bracket_bounded()
+    {
+        let f = |x: &f64| if x < &1.0
+        {
+            x.atanh()
+        }
+        else
+        {
+            f64::NAN
+        };
+        let (lower, upper) = bracket(&1e1, &f, &0.0, &0.5, &1.0, &100).unwrap();
+        assert!(f(&lower) <= 1e1);
+        assert!(f(&upper) >= 1e1);
+        assert!(upper < 1.0);
+        assert!(residual_rel(&inverse_brent(&1e1, &f, &lower, &upper, &REL_TOL, &100).unwrap(), &1e1_f64.tanh()) <= REL_TOL);
+    }
+    #[test]
+    fn bracket_maximum()
+    {
+        let (lower, upper) = bracket(&1.0, &|x: &f64| *x, &0.0, &0.5, &1.0, &100).unwrap();
+        assert!(lower < 1.0);
+        assert_eq!(upper, 1.0);
+    }
+    #[test]
+    fn not_bracketed()
+    {
+        assert_eq!(inverse_brent(&2.0, &langevin, &1.0, &2.0, &REL_TOL, &100), Err(MathError::Bracket("inverse_brent", 1.0, 2.0)));
+        assert_eq!(inverse_newton_raphson_bracketed(&2.0, &langevin, &langevin_derivative, &1.0, &2.0, &REL_TOL, &100), Err(MathError::Bracket("inverse_newton_raphson_bracketed", 1.0, 2.0)));
+        assert!(bracket(&2.0, &langevin, &0.0, &1.0, &f64::INFINITY, &100).is_err());
+    }
+}
+mod errors
+{
+    use super::*;
+    #[test]
+    fn domain()
+    {
+        assert_eq!(bessel_i_nu(&0.5, &-1.0), Err(MathError::Domain("bessel_i_nu", -1.0)));
+        assert_eq!(bessel_k_nu(&1.0, &-1.0), Err(MathError::Domain("bessel_k_nu", -1.0)));
+        assert_eq!(spherical_bessel_k(&1, &-1.0), Err(MathError::Domain("bessel_k_nu", -1.0)));
+        assert_eq!(regularized_lower_incomplete_gamma(&-1.0, &1.0), Err(MathError::Domain("incomplete_gamma", -1.0)));
+        assert_eq!(regularized_upper_incomplete_gamma(&1.0, &-1.0), Err(MathError::Domain("incomplete_gamma", -1.0)));
+        assert!(ln_gamma(&f64::NAN).is_err());
+        assert_eq!(lambert_w_0(&-0.5), Err(MathError::Domain("lambert_w_0", -0.5)));
+        assert_eq!(lambert_w_m1(&-0.5), Err(MathError::Domain("lambert_w_m1", -0.5)));
+        assert_eq!(lambert_w_m1(&1.0), Err(MathError::Domain("lambert_w_m1", 1.0)));
+        assert!(lambert_w(&-0.5).is_nan());
+    }
+    #[test]
+    fn pole()
+    {
+        assert_eq!(bessel_k(&0, &0.0), Err(MathError::Pole("bessel_k_nu", 0.0)));
+        assert_eq!(bessel_i_nu(&-0.5, &0.0), Err(MathError::Pole("bessel_i_nu", 0.0)));
+        assert_eq!(gamma(&-3.0), Err(MathError::Pole("gamma", -3.0)));
+        assert_eq!(ln_gamma(&0.0), Err(MathError::Pole("ln_gamma", 0.0)));
+    }
+    #[test]
+    fn zero()
+    {
+        assert_eq!(bessel_i(&0, &0.0), 1.0);
+        assert_eq!(bessel_i(&3, &0.0), 0.0);
+        assert_eq!(bessel_i_nu(&2.5, &0.0), Ok(0.0));
+        assert_eq!(spherical_bessel_j(&0, &0.0), 1.0);
+        assert_eq!(spherical_bessel_i(&2, &0.0), 0.0);
+        assert_eq!(regularized_lower_incomplete_gamma(&2.0, &0.0), Ok(0.0));
+    }
+}
@@ -1,7 +1,6 @@
 use pyo3::prelude::*;
 use numpy::
 {
-    IntoPyArray,
     PyArrayDyn,
     PyReadonlyArrayDyn
 };
@@ -41,15 +40,19 @@ pub struct EFJC
 impl EFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> PyResult<Self>
     {
-        EFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness
-        }
+        })
     }
     /// The expected force as a function of the applied end-to-end length and temperature.
     ///
@@ -60,9 +63,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The force :math:`f`.
     ///
-    pub fn force<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn force<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::force(&self.number_of_links, &self.link_length, &self.link_stiffness, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::try_batch(py, end_to_end_length, |end_to_end_length: f64| super::try_force(&self.number_of_links, &self.link_length, &self.link_stiffness, &end_to_end_length, &temperature))
     }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link.
     ///
@@ -72,9 +78,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional force :math:`\eta\equiv\beta f\ell_b`.
     ///
-    pub fn nondimensional_force<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn nondimensional_force<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_force(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::try_batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::try_nondimensional_force(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link))
     }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature,
     ///
@@ -90,9 +99,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The Helmholtz free energy :math:`\psi`.
     ///
-    pub fn helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::try_batch(py, end_to_end_length, |end_to_end_length: f64| super::try_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &end_to_end_length, &temperature))
     }
     /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     ///
@@ -103,9 +115,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The Helmholtz free energy per link :math:`\psi/N_b`.
     ///
-    pub fn helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::try_batch(py, end_to_end_length, |end_to_end_length: f64| super::try_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &end_to_end_length, &temperature))
     }
     /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature.
     ///
@@ -116,9 +131,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The relative Helmholtz free energy :math:`\Delta\psi\equiv\psi(\xi,T)-\psi(0,T)`.
     ///
-    pub fn relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::try_batch(py, end_to_end_length, |end_to_end_length: f64| super::try_relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, &end_to_end_length, &temperature))
     }
     /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     ///
@@ -129,9 +147,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The relative Helmholtz free energy per link :math:`\Delta\psi/N_b`.
     ///
-    pub fn relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.link_stiffness, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::try_batch(py, end_to_end_length, |end_to_end_length: f64| super::try_relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.link_stiffness, &end_to_end_length, &temperature))
     }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     ///
@@ -142,9 +163,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Helmholtz free energy :math:`\beta\psi=N_b\vartheta`.
     ///
-    pub fn nondimensional_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn nondimensional_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link, &temperature)).into_pyarray(py)
+        crate::py::try_batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::try_nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link, &temperature))
     }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     ///
@@ -155,9 +179,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Helmholtz free energy per link :math:`\vartheta\equiv\beta\psi/N_b`.
     ///
-    pub fn nondimensional_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn nondimensional_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link, &temperature)).into_pyarray(py)
+        crate::py::try_batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::try_nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link, &temperature))
     }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
     ///
@@ -167,9 +194,12 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Helmholtz free energy :math:`\beta\Delta\psi=N_b\Delta\vartheta`.
     ///
-    pub fn nondimensional_relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn nondimensional_relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::try_batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::try_nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link))
     }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link.
     ///
@@ -179,8 +209,11 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Helmholtz free energy per link :math:`\Delta\vartheta\equiv\beta\Delta\psi/N_b`.
     ///
-    pub fn nondimensional_relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional force cannot be found.
+    ///
+    pub fn nondimensional_relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::try_batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::try_nondimensional_relative_helmholtz_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link))
     }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 #![cfg(test)]
 use super::*;
 use crate::physics::single_chain::test::Parameters;
+use crate::physics::single_chain::efjc::thermodynamics::isotensional::asymptotic::nondimensional_end_to_end_length_per_link as isotensional_nondimensional_end_to_end_length_per_link;
 mod base
 {
     use super::*;
@@ -546,3 +547,75 @@ mod connection
         }
     }
 }
+mod fluctuation
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_stiffness()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_stiffness = model.nondimensional_stiffness(&nondimensional_end_to_end_length_per_link, &temperature);
+            let nondimensional_link_stiffness = link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_force = model.nondimensional_force(&nondimensional_end_to_end_length_per_link, &temperature);
+            let h = parameters.rel_tol;
+            let nondimensional_stiffness_from_derivative = h/(number_of_links as f64)/(isotensional_nondimensional_end_to_end_length_per_link(&nondimensional_link_stiffness, &(nondimensional_force + 0.5*h)) - isotensional_nondimensional_end_to_end_length_per_link(&nondimensional_link_stiffness, &(nondimensional_force - 0.5*h)));
+            let residual_abs = &nondimensional_stiffness - &nondimensional_stiffness_from_derivative;
+            let residual_rel = &residual_abs/&nondimensional_stiffness;
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+    #[test]
+    fn stiffness()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_stiffness = model.nondimensional_stiffness(&nondimensional_end_to_end_length_per_link, &temperature);
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let stiffness = model.stiffness(&end_to_end_length, &temperature);
+            let residual_abs = &stiffness/BOLTZMANN_CONSTANT/temperature*link_length.powi(2) - &nondimensional_stiffness;
+            let residual_rel = &residual_abs/&nondimensional_stiffness;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod legendre
+{
+    use super::*;
+    #[test]
+    fn nondimensional_force()
+    {
+        let parameters = Parameters::default();
+        for nondimensional_link_stiffness in [parameters.nondimensional_link_stiffness_medium, parameters.nondimensional_link_stiffness_big, parameters.nondimensional_link_stiffness_large]
+        {
+            for nondimensional_force in [-1e3, -5e1, -1.0, -1e-2, 1e-2, 1.0, 5e1, 1e3]
+            {
+                let nondimensional_end_to_end_length_per_link = isotensional_nondimensional_end_to_end_length_per_link(&nondimensional_link_stiffness, &nondimensional_force);
+                let nondimensional_force_legendre = super::nondimensional_force(&nondimensional_link_stiffness, &nondimensional_end_to_end_length_per_link);
+                let residual_abs = &nondimensional_force_legendre - &nondimensional_force;
+                let residual_rel = &residual_abs/&nondimensional_force;
+                assert!(residual_rel.abs() <= parameters.rel_tol);
+            }
+        }
+    }
+}
@@ -6,17 +6,21 @@ pub mod py;
 
 mod test;
 
-use std::f64::consts::PI;
-use crate::math::
+use crate::error::
 {
-    inverse_langevin,
-    inverse_newton_raphson
+    self,
+    Error
 };
+use std::f64::consts::PI;
 use crate::physics::
 {
     PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT,
-    single_chain::ZERO
+    BOLTZMANN_CONSTANT
+};
+use crate::physics::single_chain::
+{
+    ZERO,
+    inverse
 };
 
 /// The structure of the thermodynamics of the EFJC model thermodynamics in the isometric ensemble approximated using a reduced asymptotic approach and a Legendre transformation.
@@ -38,70 +42,123 @@ pub struct EFJC
 /// The expected force as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness.
 pub fn force(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature/link_length*nondimensional_force(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)))
+    try_force(number_of_links, link_length, link_stiffness, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The expected force as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness, or an error if the inversion does not converge.
+pub fn try_force(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length*try_nondimensional_force(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
 }
 
 /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, parameterized by the nondimensional link stiffness.
 pub fn nondimensional_force(nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
 {
-    let guess: f64 = if nondimensional_end_to_end_length_per_link < &1.0
-    {
-        inverse_langevin(nondimensional_end_to_end_length_per_link)
-    }
-    else
-    {
-        nondimensional_link_stiffness*(nondimensional_end_to_end_length_per_link - 1.0)
-    };
-    inverse_newton_raphson(nondimensional_end_to_end_length_per_link, &|nondimensional_force: &f64| 1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_force/nondimensional_link_stiffness, &|nondimensional_force: &f64| 1.0/nondimensional_force.powi(2) - 1.0/nondimensional_force.sinh().powi(2) + 1.0/nondimensional_link_stiffness, &guess, &1e-6, &100)
+    try_nondimensional_force(nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, parameterized by the nondimensional link stiffness, or an error if the inversion does not converge.
+pub fn try_nondimensional_force(nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    inverse(nondimensional_end_to_end_length_per_link, &|nondimensional_force: &f64| 1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_force/nondimensional_link_stiffness, &f64::INFINITY)
 }
 
 /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, hinge mass, and link stiffness.
 pub fn helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/(*number_of_links as f64)/link_length), temperature)
+    try_helmholtz_free_energy(number_of_links, link_length, hinge_mass, link_stiffness, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, hinge mass, and link stiffness, or an error if the inversion does not converge.
+pub fn try_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/(*number_of_links as f64)/link_length), temperature)?)
 }
 
 /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, hinge mass, and link stiffness.
 pub fn helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/(*number_of_links as f64)/link_length), temperature)
+    try_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, link_stiffness, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, hinge mass, and link stiffness, or an error if the inversion does not converge.
+pub fn try_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/(*number_of_links as f64)/link_length), temperature)?)
 }
 
 /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness.
 pub fn relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    helmholtz_free_energy(number_of_links, link_length, &1.0, link_stiffness, end_to_end_length, temperature) - helmholtz_free_energy(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*(*number_of_links as f64)*link_length), temperature)
+    try_relative_helmholtz_free_energy(number_of_links, link_length, link_stiffness, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness, or an error if the inversion does not converge.
+pub fn try_relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(try_helmholtz_free_energy(number_of_links, link_length, &1.0, link_stiffness, end_to_end_length, temperature)? - try_helmholtz_free_energy(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*(*number_of_links as f64)*link_length), temperature)?)
 }
 
 /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness.
 pub fn relative_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    helmholtz_free_energy_per_link(number_of_links, link_length, &1.0, link_stiffness, end_to_end_length, temperature) - helmholtz_free_energy_per_link(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*(*number_of_links as f64)*link_length), temperature)
+    try_relative_helmholtz_free_energy_per_link(number_of_links, link_length, link_stiffness, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness, or an error if the inversion does not converge.
+pub fn try_relative_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(try_helmholtz_free_energy_per_link(number_of_links, link_length, &1.0, link_stiffness, end_to_end_length, temperature)? - try_helmholtz_free_energy_per_link(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*(*number_of_links as f64)*link_length), temperature)?)
 }
 
 /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, hinge mass, and nondimensional link stiffness.
 pub fn nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
 {
-    (*number_of_links as f64)*nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link, temperature)
+    try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, hinge mass, and nondimensional link stiffness, or an error if the inversion does not converge.
+pub fn try_nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link, temperature)?)
 }
 
 /// The nondimensional Helmholtz free energy per link as a function of the nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, hinge mass, and nondimensional link stiffness.
 pub fn nondimensional_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
 {
-    let nondimensional_force = nondimensional_force(nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link);
-    -(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/nondimensional_link_stiffness + nondimensional_force*nondimensional_end_to_end_length_per_link - (1.0 - 1.0/(*number_of_links as f64))*(0.5*(2.0*PI*link_length.powi(2)/nondimensional_link_stiffness).ln() + (8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+    try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy per link as a function of the nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, hinge mass, and nondimensional link stiffness, or an error if the inversion does not converge.
+pub fn try_nondimensional_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    let nondimensional_force = try_nondimensional_force(nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link)?;
+    let log_sinhc = if nondimensional_force.abs() < 1e2 { (nondimensional_force.sinh()/nondimensional_force).ln() } else { nondimensional_force.abs() - (2.0*nondimensional_force.abs()).ln() };
+    Ok(-log_sinhc - 0.5*nondimensional_force.powi(2)/nondimensional_link_stiffness + nondimensional_force*nondimensional_end_to_end_length_per_link - (1.0 - 1.0/(*number_of_links as f64))*(0.5*(2.0*PI*link_length.powi(2)/nondimensional_link_stiffness).ln() + (8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()))
 }
 
 /// The nondimensional relative Helmholtz free energy as a function of the nondimensional end-to-end length per link, parameterized by the number of links and nondimensional link stiffness.
 pub fn nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link, &300.0) - nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
+    try_nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy as a function of the nondimensional end-to-end length per link, parameterized by the number of links and nondimensional link stiffness, or an error if the inversion does not converge.
+pub fn try_nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link, &300.0)? - try_nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)?)
 }
 
 /// The nondimensional relative Helmholtz free energy per link as a function of the nondimensional end-to-end length per link, parameterized by the nondimensional link stiffness.
 pub fn nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy_per_link(&8, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link, &300.0) - nondimensional_helmholtz_free_energy_per_link(&8, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
+    try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy per link as a function of the nondimensional end-to-end length per link, parameterized by the nondimensional link stiffness, or an error if the inversion does not converge.
+pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy_per_link(&8, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link, &300.0)? - try_nondimensional_helmholtz_free_energy_per_link(&8, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)?)
 }
 
 /// The implemented functionality of the thermodynamics of the EFJC model in the isometric ensemble approximated using a reduced asymptotic approach and a Legendre transformation.
@@ -118,54 +175,118 @@ impl EFJC
             link_stiffness
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
     /// The expected force as a function of the applied end-to-end length and temperature.
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        force(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
+        self.try_force(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The expected force as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_force(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
     }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        nondimensional_force(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+        self.try_nondimensional_force(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link and temperature for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64], temperature: &f64) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the inversion does not converge.
+    pub fn try_nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_force(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
     }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, end_to_end_length, temperature)
+        self.try_helmholtz_free_energy(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, end_to_end_length, temperature)
     }
     /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, end_to_end_length, temperature)
+        self.try_helmholtz_free_energy_per_link(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, end_to_end_length, temperature)
     }
     /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
+        self.try_relative_helmholtz_free_energy(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
     }
     /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
+        self.try_relative_helmholtz_free_energy_per_link(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
     }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link, temperature)
+        self.try_nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the inversion does not converge.
+    pub fn try_nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link, temperature)
     }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link, temperature)
+        self.try_nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the inversion does not converge.
+    pub fn try_nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link, temperature)
     }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+        self.try_nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
     }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        nondimensional_relative_helmholtz_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+        self.try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_helmholtz_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
     }
 }
\ No newline at end of file
@@ -545,4 +545,16 @@ mod connection
             assert!(residual_rel.abs() <= h);
         }
     }
+    #[test]
+    fn large_nondimensional_force()
+    {
+        let nondimensional_link_stiffness = 1e2;
+        let h = 1e-6;
+        [2.0, 8.0, 20.0].iter().for_each(|nondimensional_end_to_end_length_per_link: &f64|{
+            let nondimensional_force = super::nondimensional_force(&nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link);
+            let nondimensional_force_from_derivative = (nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_link_stiffness, &(nondimensional_end_to_end_length_per_link + 0.5*h)) - nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_link_stiffness, &(nondimensional_end_to_end_length_per_link - 0.5*h)))/h;
+            assert!(nondimensional_force > 1e2);
+            assert!((nondimensional_force_from_derivative/nondimensional_force - 1.0).abs() <= 1e-6);
+        });
+    }
 }
@@ -4,7 +4,7 @@ The extensible freely-jointed chain (EFJC) model thermodynamics in the isometric
 module Reduced
 
 using DocStringExtensions
-using ........Polymers: PROJECT_ROOT
+using ........Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 include("legendre/mod.jl")
 
@@ -47,6 +47,10 @@ function EFJC(
     hinge_mass::Float64,
     link_stiffness::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("link_stiffness", link_stiffness)
     return EFJC(
         number_of_links,
         link_length,
@@ -3,6 +3,12 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
 /// The extensible freely-jointed chain (EFJC) model thermodynamics in the isometric ensemble approximated using an asymptotic approach.
 pub mod asymptotic;
 
@@ -43,4 +49,13 @@ impl EFJC
             asymptotic: self::asymptotic::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
 }
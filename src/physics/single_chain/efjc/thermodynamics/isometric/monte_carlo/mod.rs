@@ -1,5 +1,10 @@
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use rand::prelude::*;
 
 use rand_distr::{Normal, Distribution};
@@ -34,8 +39,14 @@ pub fn random_nondimensional_end_to_end_length<const NUMBER_OF_LINKS: usize>(dis
 
 pub fn nondimensional_equilibrium_radial_distribution<const NUMBER_OF_BINS: usize, const NUMBER_OF_LINKS: usize>(gamma_max: &f64, kappa: &f64, number_of_samples: usize) -> ([f64; NUMBER_OF_BINS], [f64; NUMBER_OF_BINS])
 {
+    try_nondimensional_equilibrium_radial_distribution::<NUMBER_OF_BINS, NUMBER_OF_LINKS>(gamma_max, kappa, number_of_samples).unwrap_or(([f64::NAN; NUMBER_OF_BINS], [f64::NAN; NUMBER_OF_BINS]))
+}
+
+pub fn try_nondimensional_equilibrium_radial_distribution<const NUMBER_OF_BINS: usize, const NUMBER_OF_LINKS: usize>(gamma_max: &f64, kappa: &f64, number_of_samples: usize) -> Result<([f64; NUMBER_OF_BINS], [f64; NUMBER_OF_BINS]), Error>
+{
+    error::positive("kappa", kappa)?;
     let mut rng = rand::thread_rng();
-    let dist = Normal::new(1.0, 1.0/kappa.sqrt()).unwrap();
+    let dist = Normal::new(1.0, 1.0/kappa.sqrt()).map_err(|_| Error::Parameter("kappa", *kappa, "positive and finite"))?;
     let number_of_links_f64 = NUMBER_OF_LINKS as f64;
     let mut bin_edges = [0.0_f64; NUMBER_OF_BINS];
     bin_edges.iter_mut().enumerate().for_each(|(bin_index, bin_edge)|
@@ -43,13 +54,13 @@ pub fn nondimensional_equilibrium_radial_distribution<const NUMBER_OF_BINS: usiz
     );
     let mut bin_counts = [0_u128; NUMBER_OF_BINS];
     let mut gamma: f64 = 0.0;
-    (0..number_of_samples).for_each(|_|{
+    (0..number_of_samples).try_for_each(|_|{
         gamma = random_nondimensional_end_to_end_length::<NUMBER_OF_LINKS>(dist, &mut rng)/number_of_links_f64;
         for (bin_edge, bin_count) in bin_edges.iter().zip(bin_counts.iter_mut())
         {
             if &gamma > gamma_max
             {
-                panic!()
+                return Err(Error::Domain("nondimensional_end_to_end_length_per_link", gamma, "less than the maximum nondimensional end-to-end length per link"))
             }
             if &gamma < bin_edge
             {
@@ -57,7 +68,8 @@ pub fn nondimensional_equilibrium_radial_distribution<const NUMBER_OF_BINS: usiz
                 break
             }
         }
-    });
+        Ok(())
+    })?;
     let normalization = gamma_max * (number_of_samples as f64)/(NUMBER_OF_BINS as f64);
     let mut bin_probabilities = [0.0_f64; NUMBER_OF_BINS];
     bin_probabilities.iter_mut().zip(bin_counts.iter()).for_each(|(bin_probability, bin_count)|
@@ -67,5 +79,5 @@ pub fn nondimensional_equilibrium_radial_distribution<const NUMBER_OF_BINS: usiz
     bin_centers.iter_mut().enumerate().for_each(|(bin_index, bin_center)|
         *bin_center = gamma_max * (bin_index as f64 + 0.5)/(NUMBER_OF_BINS as f64)
     );
-    (bin_centers, bin_probabilities)
+    Ok((bin_centers, bin_probabilities))
 }
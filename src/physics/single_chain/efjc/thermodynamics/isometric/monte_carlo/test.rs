@@ -37,4 +37,13 @@ fn monte_carlo_nondimensional_equilibrium_radial_distribution()
         assert!(gamma_i > &0.0);
         assert!(g_eq_i >= &0.0);
     });
+}
+
+#[test]
+fn monte_carlo_nondimensional_equilibrium_radial_distribution_errors()
+{
+    assert_eq!(try_nondimensional_equilibrium_radial_distribution::<NUMBER_OF_BINS, NUMBER_OF_LINKS>(&GAMMA_MAX, &-KAPPA, NUMBER_OF_SAMPLES), Err(Error::Parameter("kappa", -KAPPA, "positive and finite")));
+    assert!(matches!(try_nondimensional_equilibrium_radial_distribution::<NUMBER_OF_BINS, NUMBER_OF_LINKS>(&0.1, &KAPPA, NUMBER_OF_SAMPLES), Err(Error::Domain("nondimensional_end_to_end_length_per_link", _, _))));
+    let (bin_centers, bin_probabilities) = nondimensional_equilibrium_radial_distribution::<NUMBER_OF_BINS, NUMBER_OF_LINKS>(&GAMMA_MAX, &-KAPPA, NUMBER_OF_SAMPLES);
+    assert!(bin_centers.iter().chain(bin_probabilities.iter()).all(|value| value.is_nan()));
 }
\ No newline at end of file
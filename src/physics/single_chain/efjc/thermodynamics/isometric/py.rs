@@ -39,15 +39,19 @@ pub struct EFJC
 impl EFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> PyResult<Self>
     {
-        EFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness,
-            asymptotic: super::asymptotic::py::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
-        }
+            asymptotic: super::asymptotic::py::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)?
+        })
     }
 }
\ No newline at end of file
@@ -5,7 +5,7 @@ module Asymptotic
 
 using DocStringExtensions
 using Polymers_jll
-using .......Polymers: PROJECT_ROOT
+using .......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 import ......Physics: BOLTZMANN_CONSTANT
 
@@ -542,6 +542,10 @@ function EFJC(
     hinge_mass::Float64,
     link_stiffness::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("link_stiffness", link_stiffness)
     BOLTZMANN_CONSTANT::Float64 = 8.314462618
     return EFJC(
         number_of_links,
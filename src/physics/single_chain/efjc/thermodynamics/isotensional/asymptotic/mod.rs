@@ -15,6 +15,11 @@ pub mod reduced;
 /// The extensible freely-jointed chain (EFJC) model thermodynamics in the isotensional ensemble approximated using an asymptotic approach and a Legendre transformation.
 pub mod legendre;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use std::f64::consts::PI;
 use crate::physics::
 {
@@ -141,6 +146,15 @@ impl EFJC
             legendre: self::legendre::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
     /// The expected end-to-end length as a function of the applied force and temperature.
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
@@ -161,6 +175,11 @@ impl EFJC
     {
         nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
     }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64], temperature: &f64) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature), nondimensional_force)
+    }
     /// The Gibbs free energy as a function of the applied force and temperature.
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
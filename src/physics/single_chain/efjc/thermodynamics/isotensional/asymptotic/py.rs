@@ -1,7 +1,6 @@
 use pyo3::prelude::*;
 use numpy::
 {
-    IntoPyArray,
     PyArrayDyn,
     PyReadonlyArrayDyn
 };
@@ -56,18 +55,22 @@ pub struct EFJC
 impl EFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> PyResult<Self>
     {
-        EFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness,
-            alternative: super::alternative::py::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness),
-            reduced: super::reduced::py::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness),
-            legendre: super::legendre::py::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
-        }
+            alternative: super::alternative::py::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)?,
+            reduced: super::reduced::py::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)?,
+            legendre: super::legendre::py::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)?
+        })
     }
     /// The expected end-to-end length as a function of the applied force and temperature,
     ///
@@ -81,9 +84,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The end-to-end length :math:`\xi`.
     ///
-    pub fn end_to_end_length<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn end_to_end_length<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::end_to_end_length(&self.number_of_links, &self.link_length, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::end_to_end_length(&self.number_of_links, &self.link_length, &self.link_stiffness, &force, &temperature))
     }
     /// The expected end-to-end length per link as a function of the applied force and temperature.
     ///
@@ -94,9 +97,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The end-to-end length per link :math:`\xi/N_b=\ell_b\gamma`.
     ///
-    pub fn end_to_end_length_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn end_to_end_length_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::end_to_end_length_per_link(&self.link_length, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::end_to_end_length_per_link(&self.link_length, &self.link_stiffness, &force, &temperature))
     }
     /// The expected nondimensional end-to-end length as a function of the applied nondimensional force.
     ///
@@ -107,9 +110,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional end-to-end length :math:`N_b\gamma=\xi/\ell_b`.
     ///
-    pub fn nondimensional_end_to_end_length<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_end_to_end_length<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_end_to_end_length(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_end_to_end_length(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
     }
     /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, given by :footcite:t:`radiom2017influence,fiasconaro2019analytical,buche2020statistical,buche2022freely` as
     ///
@@ -125,9 +128,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional end-to-end length per link :math:`\gamma\equiv \xi/N_b\ell_b`.
     ///
-    pub fn nondimensional_end_to_end_length_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_end_to_end_length_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
     }
     /// The Gibbs free energy as a function of the applied force and temperature,
     ///
@@ -141,9 +144,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The Gibbs free energy :math:`\varphi`.
     ///
-    pub fn gibbs_free_energy<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn gibbs_free_energy<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &force, &temperature))
     }
     /// The Gibbs free energy per link as a function of the applied force and temperature.
     ///
@@ -154,9 +157,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The Gibbs free energy per link :math:`\varphi/N_b`.
     ///
-    pub fn gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, &force, &temperature))
     }
     /// The relative Gibbs free energy as a function of the applied force and temperature.
     ///
@@ -167,9 +170,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The relative Gibbs free energy :math:`\Delta\varphi\equiv\varphi(f,T)-\varphi(0,T)`.
     ///
-    pub fn relative_gibbs_free_energy<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn relative_gibbs_free_energy<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, &force, &temperature))
     }
     /// The relative Gibbs free energy per link as a function of the applied force and temperature.
     ///
@@ -180,9 +183,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The relative Gibbs free energy per link :math:`\Delta\varphi/N_b`.
     ///
-    pub fn relative_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn relative_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::relative_gibbs_free_energy_per_link(&self.link_length, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::relative_gibbs_free_energy_per_link(&self.link_length, &self.link_stiffness, &force, &temperature))
     }
     /// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature.
     ///
@@ -193,9 +196,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Gibbs free energy :math:`\beta\varphi=N_b\varrho`.
     ///
-    pub fn nondimensional_gibbs_free_energy<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_gibbs_free_energy<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &temperature))
     }
     /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature.
     ///
@@ -206,9 +209,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Gibbs free energy per link :math:`\varrho\equiv\beta\varphi/N_b`.
     ///
-    pub fn nondimensional_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &temperature))
     }
     /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force.
     ///
@@ -219,9 +222,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Gibbs free energy :math:`\beta\Delta\varphi=N_b\Delta\varrho`.
     ///
-    pub fn nondimensional_relative_gibbs_free_energy<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_relative_gibbs_free_energy<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_relative_gibbs_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_relative_gibbs_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
     }
     /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force, given by :footcite:t:`fiasconaro2019analytical,buche2022freely` as
     ///
@@ -235,8 +238,8 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Gibbs free energy per link :math:`\Delta\varrho\equiv\beta\Delta\varphi/N_b`.
     ///
-    pub fn nondimensional_relative_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_relative_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_relative_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_relative_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
     }
 }
@@ -1,7 +1,6 @@
 use pyo3::prelude::*;
 use numpy::
 {
-    IntoPyArray,
     PyArrayDyn,
     PyReadonlyArrayDyn
 };
@@ -41,15 +40,19 @@ pub struct EFJC
 impl EFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> PyResult<Self>
     {
-        EFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness
-        }
+        })
     }
     /// The Helmholtz free energy as a function of the applied force and temperature,
     ///
@@ -63,9 +66,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The Helmholtz free energy :math:`\psi`.
     ///
-    pub fn helmholtz_free_energy<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn helmholtz_free_energy<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &force, &temperature))
     }
     /// The Helmholtz free energy per link as a function of the applied force and temperature.
     ///
@@ -76,9 +79,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The Helmholtz free energy per link :math:`\psi/N_b`.
     ///
-    pub fn helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::helmholtz_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::helmholtz_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, &force, &temperature))
     }
     /// The relative Helmholtz free energy as a function of the applied force and temperature.
     ///
@@ -89,9 +92,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The relative Helmholtz free energy :math:`\Delta\psi\equiv\psi(f,T)-\psi(0,T)`.
     ///
-    pub fn relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, &force, &temperature))
     }
     /// The relative Helmholtz free energy per link as a function of the applied force and temperature.
     ///
@@ -102,9 +105,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The relative Helmholtz free energy per link :math:`\Delta\psi/N_b`.
     ///
-    pub fn relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        force.as_array().mapv(|force: f64| super::relative_helmholtz_free_energy_per_link(&self.link_length, &self.link_stiffness, &force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, force, |force: f64| super::relative_helmholtz_free_energy_per_link(&self.link_length, &self.link_stiffness, &force, &temperature))
     }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional force and temperature.
     ///
@@ -115,9 +118,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Helmholtz free energy :math:`\beta\psi=N_b\vartheta`.
     ///
-    pub fn nondimensional_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &temperature))
     }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional force and temperature.
     ///
@@ -128,9 +131,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Helmholtz free energy per link :math:`\vartheta\equiv\beta\psi/N_b`.
     ///
-    pub fn nondimensional_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_helmholtz_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &temperature)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_helmholtz_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &temperature))
     }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional force.
     ///
@@ -141,9 +144,9 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Helmholtz free energy :math:`\beta\Delta\psi=N_b\Delta\vartheta`.
     ///
-    pub fn nondimensional_relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
     }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional force.
     ///
@@ -154,8 +157,8 @@ impl EFJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Helmholtz free energy per link :math:`\Delta\vartheta\equiv\beta\Delta\psi/N_b`.
     ///
-    pub fn nondimensional_relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    pub fn nondimensional_relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_force.as_array().mapv(|nondimensional_force: f64| super::nondimensional_relative_helmholtz_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force)).into_pyarray(py)
+        crate::py::batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_relative_helmholtz_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
     }
 }
@@ -12,13 +12,26 @@ pub mod asymptotic;
 /// The extensible freely-jointed chain (EFJC) model thermodynamics in the isotensional ensemble approximated using a Legendre transformation.
 pub mod legendre;
 
-use crate::math::erf;
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::math::
+{
+    erf,
+    erfc
+};
 use std::f64::consts::PI;
 use crate::physics::
 {
     PLANCK_CONSTANT,
     BOLTZMANN_CONSTANT,
-    single_chain::ZERO
+    single_chain::
+    {
+        ZERO,
+        inverse
+    }
 };
 
 /// The structure of the thermodynamics of the EFJC model in the isotensional ensemble.
@@ -69,6 +82,30 @@ pub fn nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness:
     1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_force/(nondimensional_link_stiffness)*(1.0 + (nondimensional_force.tanh() - 1.0/nondimensional_force.tanh() + 1.0/nondimensional_force)/(nondimensional_force.tanh() + nondimensional_force/(nondimensional_link_stiffness))) + (nondimensional_force.exp()*((2.0/PI/nondimensional_link_stiffness).sqrt()*(nondimensional_force/nondimensional_link_stiffness + 1.0)*(-(nondimensional_force + nondimensional_link_stiffness).powi(2)/2.0/nondimensional_link_stiffness).exp() + (1.0 + (1.0 + nondimensional_force)/nondimensional_link_stiffness)) - 1.0/nondimensional_force.exp()*((2.0/PI/nondimensional_link_stiffness).sqrt()*(nondimensional_force/nondimensional_link_stiffness - 1.0)*(-(nondimensional_force - nondimensional_link_stiffness).powi(2)/2.0/nondimensional_link_stiffness).exp() + (1.0 + (1.0 - nondimensional_force)/nondimensional_link_stiffness)*erf(&((nondimensional_force - nondimensional_link_stiffness)/(2.0*nondimensional_link_stiffness).sqrt()))) - fraction*(4.0*(nondimensional_force.cosh()*(1.0 + (1.0 + nondimensional_force/nondimensional_force.tanh())/nondimensional_link_stiffness) - nondimensional_force/nondimensional_link_stiffness/nondimensional_force.sinh())))/denominator/(1.0 + fraction)
 }
 
+/// The applied force as a function of the expected end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness.
+pub fn force(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    try_force(number_of_links, link_length, link_stiffness, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The applied force as a function of the expected end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness, or an error if the inversion does not converge.
+pub fn try_force(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length*try_nondimensional_force(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
+}
+
+/// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, parameterized by the nondimensional link stiffness.
+pub fn nondimensional_force(nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_force(nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, parameterized by the nondimensional link stiffness, or an error if the inversion does not converge.
+pub fn try_nondimensional_force(nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    inverse(nondimensional_end_to_end_length_per_link, &|nondimensional_force: &f64| self::nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force), &f64::INFINITY)
+}
+
 /// The Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, hinge mass, and link stiffness.
 pub fn gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
@@ -121,6 +158,71 @@ pub fn nondimensional_relative_gibbs_free_energy_per_link(nondimensional_link_st
     nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0) - nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
 }
 
+/// The compliance as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness.
+pub fn compliance(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
+{
+    link_length.powi(2)/BOLTZMANN_CONSTANT/temperature*nondimensional_compliance(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+}
+
+/// The compliance per link as a function of the applied force and temperature, parameterized by the link length and link stiffness.
+pub fn compliance_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
+{
+    link_length.powi(2)/BOLTZMANN_CONSTANT/temperature*nondimensional_compliance_per_link(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+}
+
+/// The nondimensional compliance as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness.
+pub fn nondimensional_compliance(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
+{
+    (*number_of_links as f64)*nondimensional_compliance_per_link(nondimensional_link_stiffness, nondimensional_force)
+}
+
+/// The nondimensional compliance per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness.
+pub fn nondimensional_compliance_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
+{
+    let moments = |nondimensional_mean: f64|
+    {
+        let gaussian = (-nondimensional_link_stiffness*nondimensional_mean.powi(2)/2.0).exp()/nondimensional_link_stiffness;
+        let argument = nondimensional_mean*(nondimensional_link_stiffness/2.0).sqrt();
+        let moment_0 = (PI/2.0/nondimensional_link_stiffness).sqrt()*if argument > 0.0
+        {
+            2.0 - erfc(&argument)
+        }
+        else
+        {
+            erfc(&(-argument))
+        };
+        let moment_2 = moment_0/nondimensional_link_stiffness - nondimensional_mean*gaussian;
+        let moment_3 = 2.0*gaussian/nondimensional_link_stiffness + nondimensional_mean.powi(2)*gaussian;
+        [
+            nondimensional_mean*moment_0 + gaussian,
+            nondimensional_mean.powi(2)*moment_0 + 2.0*nondimensional_mean*gaussian + moment_2,
+            nondimensional_mean.powi(3)*moment_0 + 3.0*nondimensional_mean.powi(2)*gaussian + 3.0*nondimensional_mean*moment_2 + moment_3
+        ]
+    };
+    let [moment_1_plus, moment_2_plus, moment_3_plus] = moments(1.0 + nondimensional_force/nondimensional_link_stiffness);
+    let [moment_1_minus, moment_2_minus, moment_3_minus] = moments(1.0 - nondimensional_force/nondimensional_link_stiffness);
+    let ratio = (-2.0*nondimensional_force).exp();
+    let odd_1 = moment_1_plus - ratio*moment_1_minus;
+    let even_2 = moment_2_plus + ratio*moment_2_minus;
+    let odd_3 = moment_3_plus - ratio*moment_3_minus;
+    let rescaled_partition_function = odd_1/nondimensional_force;
+    let first_derivative = even_2/nondimensional_force - odd_1/nondimensional_force.powi(2);
+    let second_derivative = odd_3/nondimensional_force - 2.0*even_2/nondimensional_force.powi(2) + 2.0*odd_1/nondimensional_force.powi(3);
+    second_derivative/rescaled_partition_function - (first_derivative/rescaled_partition_function).powi(2)
+}
+
+/// The variance of the end-to-end length as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness.
+pub fn end_to_end_length_variance(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
+{
+    BOLTZMANN_CONSTANT*temperature*compliance(number_of_links, link_length, link_stiffness, force, temperature)
+}
+
+/// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness.
+pub fn nondimensional_end_to_end_length_variance(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
+{
+    nondimensional_compliance(number_of_links, nondimensional_link_stiffness, nondimensional_force)
+}
+
 /// The implemented functionality of the thermodynamics of the EFJC model in the isotensional ensemble.
 impl EFJC
 {
@@ -137,6 +239,15 @@ impl EFJC
             legendre: self::legendre::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
     /// The expected end-to-end length as a function of the applied force and temperature.
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
@@ -157,6 +268,36 @@ impl EFJC
     {
         nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
     }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64], temperature: &f64) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature), nondimensional_force)
+    }
+    /// The applied force as a function of the expected end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.try_force(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied force as a function of the expected end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_force(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link.
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.try_nondimensional_force(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64], temperature: &f64) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_force(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+    }
     /// The Gibbs free energy as a function of the applied force and temperature.
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
@@ -197,4 +338,34 @@ impl EFJC
     {
         nondimensional_relative_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
     }
+    /// The compliance as a function of the applied force and temperature.
+    pub fn compliance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        compliance(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
+    }
+    /// The compliance per link as a function of the applied force and temperature.
+    pub fn compliance_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        compliance_per_link(&self.link_length, &self.link_stiffness, force, temperature)
+    }
+    /// The nondimensional compliance as a function of the applied nondimensional force.
+    pub fn nondimensional_compliance(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        nondimensional_compliance(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+    }
+    /// The nondimensional compliance per link as a function of the applied nondimensional force.
+    pub fn nondimensional_compliance_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        nondimensional_compliance_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+    }
+    /// The variance of the end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length_variance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        end_to_end_length_variance(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
+    }
+    /// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force.
+    pub fn nondimensional_end_to_end_length_variance(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        nondimensional_end_to_end_length_variance(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+    }
 }
@@ -6,17 +6,18 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use super::
 {
     treloar_sums,
     treloar_sum_0_with_prefactor
 };
 use std::f64::consts::PI;
-use crate::math::
-{
-    inverse_langevin,
-    integrate_1d
-};
+use crate::math::inverse_langevin;
 use crate::physics::
 {
     PLANCK_CONSTANT,
@@ -26,7 +27,8 @@ use crate::physics::single_chain::
 {
     ONE,
     ZERO,
-    POINTS
+    INTEGRATION_TOLERANCE,
+    try_integrate
 };
 
 /// The structure of the thermodynamics of the FJC model in the isometric ensemble approximated using a Legendre transformation.
@@ -116,7 +118,7 @@ pub fn equilibrium_distribution(number_of_links: &u8, link_length: &f64, normali
 pub fn nondimensional_equilibrium_distribution(number_of_links: &u8, normalization_nondimensional_equilibrium_distribution: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
 {
     let nondimensional_force = nondimensional_force(nondimensional_end_to_end_length_per_link);
-    (nondimensional_force.sinh()/nondimensional_force*(-nondimensional_force**nondimensional_end_to_end_length_per_link).exp()).powi(*number_of_links as i32)/normalization_nondimensional_equilibrium_distribution
+    ((*number_of_links as f64)*(nondimensional_force*(1.0 - nondimensional_end_to_end_length_per_link) + (-(-2.0*nondimensional_force).exp_m1()).ln() - (2.0*nondimensional_force).ln())).exp()/normalization_nondimensional_equilibrium_distribution
 }
 
 /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length, parameterized by the number of links and link length.
@@ -182,13 +184,32 @@ pub fn nondimensional_relative_gibbs_free_energy_per_link(number_of_links: &u8,
     nondimensional_gibbs_free_energy_per_link(number_of_links, &1.0, &1.0, nondimensional_end_to_end_length_per_link, &300.0) - nondimensional_gibbs_free_energy_per_link(number_of_links, &1.0, &1.0, &ZERO, &300.0)
 }
 
+/// The stiffness as a function of the applied end-to-end length and temperature, parameterized by the number of links and link length.
+pub fn stiffness(number_of_links: &u8, link_length: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    BOLTZMANN_CONSTANT*temperature/link_length.powi(2)*nondimensional_stiffness(number_of_links, &(end_to_end_length/((*number_of_links as f64)*link_length)))
+}
+
+/// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links.
+pub fn nondimensional_stiffness(number_of_links: &u8, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    let nondimensional_force = nondimensional_force(nondimensional_end_to_end_length_per_link);
+    1.0/(*number_of_links as f64)/(1.0/nondimensional_force.powi(2) - 1.0/nondimensional_force.sinh().powi(2))
+}
+
+/// The normalization of the nondimensional equilibrium radial distribution, or an error if the numerical integration does not converge.
+fn normalization_nondimensional_equilibrium_distribution(number_of_links: &u8) -> Result<f64, Error>
+{
+    try_integrate(&|nondimensional_end_to_end_length_per_link: &f64| nondimensional_equilibrium_radial_distribution(number_of_links, &1.0, nondimensional_end_to_end_length_per_link), &0.0, &ONE, &INTEGRATION_TOLERANCE)
+}
+
 /// The implemented functionality of the thermodynamics of the FJC model in the isometric ensemble approximated using a Legendre transformation.
 impl FJC
 {
     /// Initializes and returns an instance of the thermodynamics of the FJC model in the isometric ensemble approximated using a Legendre transformation.
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Self
     {
-        let normalization = integrate_1d(&|nondimensional_end_to_end_length_per_link: &f64| nondimensional_equilibrium_radial_distribution(&number_of_links, &1.0, nondimensional_end_to_end_length_per_link), &ZERO, &ONE, &POINTS);
+        let normalization = normalization_nondimensional_equilibrium_distribution(&number_of_links).unwrap_or(f64::NAN);
         FJC
         {
             hinge_mass,
@@ -197,114 +218,287 @@ impl FJC
             normalization_nondimensional_equilibrium_distribution: normalization
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        Ok(FJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            normalization_nondimensional_equilibrium_distribution: normalization_nondimensional_equilibrium_distribution(&number_of_links)?
+        })
+    }
     /// The expected force as a function of the applied end-to-end length and temperature.
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         force(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
     }
+    /// The expected force as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.force(end_to_end_length, temperature))
+    }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_force(nondimensional_end_to_end_length_per_link)
     }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link), nondimensional_end_to_end_length_per_link)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_force(nondimensional_end_to_end_length_per_link))
+    }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, end_to_end_length, temperature)
     }
+    /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.helmholtz_free_energy(end_to_end_length, temperature))
+    }
     /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, end_to_end_length, temperature)
     }
+    /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.helmholtz_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
     }
+    /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_helmholtz_free_energy(end_to_end_length, temperature))
+    }
     /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
     }
+    /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_helmholtz_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_helmholtz_free_energy(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link))
+    }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link))
+    }
     /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length.
     pub fn equilibrium_distribution(&self, end_to_end_length: &f64) -> f64
     {
         equilibrium_distribution(&self.number_of_links, &self.link_length, &self.normalization_nondimensional_equilibrium_distribution, end_to_end_length)
     }
+    /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length, or an error if the end-to-end length is outside the domain.
+    pub fn try_equilibrium_distribution(&self, end_to_end_length: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.equilibrium_distribution(end_to_end_length))
+    }
     /// The equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link.
     pub fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_equilibrium_distribution(&self.number_of_links, &self.normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
     }
+    /// The equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link))
+    }
     /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length.
     pub fn equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> f64
     {
         equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.normalization_nondimensional_equilibrium_distribution, end_to_end_length)
     }
+    /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length, or an error if the end-to-end length is outside the domain.
+    pub fn try_equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.equilibrium_radial_distribution(end_to_end_length))
+    }
     /// The equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link.
     pub fn nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_equilibrium_radial_distribution(&self.number_of_links, &self.normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
     }
+    /// The equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link))
+    }
     /// The Gibbs free energy as a function of the applied end-to-end length and temperature.
     pub fn gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, end_to_end_length, temperature)
     }
+    /// The Gibbs free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.gibbs_free_energy(end_to_end_length, temperature))
+    }
     /// The Gibbs free energy per link as a function of the applied end-to-end length and temperature.
     pub fn gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, end_to_end_length, temperature)
     }
+    /// The Gibbs free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.gibbs_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The relative Gibbs free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_gibbs_free_energy(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
     }
+    /// The relative Gibbs free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_gibbs_free_energy(end_to_end_length, temperature))
+    }
     /// The relative Gibbs free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
     }
+    /// The relative Gibbs free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_gibbs_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The nondimensional Gibbs free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Gibbs free energy as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_gibbs_free_energy(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_gibbs_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_gibbs_free_energy(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_gibbs_free_energy(nondimensional_end_to_end_length_per_link))
+    }
     /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_gibbs_free_energy_per_link(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_gibbs_free_energy_per_link(nondimensional_end_to_end_length_per_link))
+    }
+    /// The stiffness as a function of the applied end-to-end length and temperature.
+    pub fn stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        stiffness(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
+    }
+    /// The stiffness as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.stiffness(end_to_end_length, temperature))
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link.
+    pub fn nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        nondimensional_stiffness(&self.number_of_links, nondimensional_end_to_end_length_per_link)
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_stiffness(nondimensional_end_to_end_length_per_link))
+    }
 }
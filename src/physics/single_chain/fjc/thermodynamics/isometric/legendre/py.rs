@@ -1,17 +1,9 @@
 use pyo3::prelude::*;
 use numpy::
 {
-    IntoPyArray,
     PyArrayDyn,
     PyReadonlyArrayDyn
 };
-use crate::math::integrate_1d;
-use crate::physics::single_chain::
-{
-    ONE,
-    ZERO,
-    POINTS
-};
 
 pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
 {
@@ -45,16 +37,19 @@ pub struct FJC
 impl FJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> PyResult<Self>
     {
-        let normalization = integrate_1d(&|nondimensional_end_to_end_length_per_link| super::nondimensional_equilibrium_radial_distribution(&number_of_links, &1.0, nondimensional_end_to_end_length_per_link), &ZERO, &ONE, &POINTS);
-        FJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        let normalization = super::normalization_nondimensional_equilibrium_distribution(&number_of_links)?;
+        Ok(FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             normalization_nondimensional_equilibrium_distribution: normalization
-        }
+        })
     }
     /// The expected force as a function of the applied end-to-end length and temperature,
     ///
@@ -70,9 +65,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The force :math:`f`.
     ///
-    pub fn force<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn force<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::force(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::force(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature))
     }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link,
     ///
@@ -87,9 +86,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional force :math:`\eta\equiv\beta f\ell_b`.
     ///
-    pub fn nondimensional_force<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_force<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_force(&nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_force(&nondimensional_end_to_end_length_per_link))
     }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature,
     ///
@@ -105,9 +108,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The Helmholtz free energy :math:`\psi`.
     ///
-    pub fn helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature))
     }
     /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     ///
@@ -118,9 +125,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The Helmholtz free energy per link :math:`\psi/N_b`.
     ///
-    pub fn helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature))
     }
     /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature.
     ///
@@ -131,9 +142,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The relative Helmholtz free energy :math:`\Delta\psi\equiv\psi(\xi,T)-\psi(0,T)`.
     ///
-    pub fn relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature))
     }
     /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     ///
@@ -144,9 +159,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The relative Helmholtz free energy per link :math:`\Delta\psi/N_b`.
     ///
-    pub fn relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature))
     }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     ///
@@ -157,9 +176,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Helmholtz free energy :math:`\beta\psi=N_b\vartheta`.
     ///
-    pub fn nondimensional_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature))
     }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, given by :footcite:t:`buche2020statistical` as
     ///
@@ -175,9 +198,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Helmholtz free energy per link :math:`\vartheta\equiv\beta\psi/N_b`.
     ///
-    pub fn nondimensional_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature))
     }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
     ///
@@ -187,9 +214,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Helmholtz free energy :math:`\beta\Delta\psi=N_b\Delta\vartheta`.
     ///
-    pub fn nondimensional_relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &nondimensional_end_to_end_length_per_link))
     }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, given by :footcite:t:`buche2021chain` as
     ///
@@ -204,9 +235,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Helmholtz free energy per link :math:`\Delta\vartheta\equiv\beta\Delta\psi/N_b`.
     ///
-    pub fn nondimensional_relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link))
     }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link,
     ///
@@ -219,9 +254,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The equilibrium probability density :math:`P_\mathrm{eq}`.
     ///
-    pub fn equilibrium_distribution<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn equilibrium_distribution<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::equilibrium_distribution(&self.number_of_links, &self.link_length, &self.normalization_nondimensional_equilibrium_distribution, &end_to_end_length)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::equilibrium_distribution(&self.number_of_links, &self.link_length, &self.normalization_nondimensional_equilibrium_distribution, &end_to_end_length))
     }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link,
     ///
@@ -234,9 +273,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional equilibrium probability density :math:`\mathscr{P}_\mathrm{eq}\equiv (N_b\ell_b)^3 P_\mathrm{eq}`.
     ///
-    pub fn nondimensional_equilibrium_distribution<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_equilibrium_distribution<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_equilibrium_distribution(&self.number_of_links, &self.normalization_nondimensional_equilibrium_distribution, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_equilibrium_distribution(&self.number_of_links, &self.normalization_nondimensional_equilibrium_distribution, &nondimensional_end_to_end_length_per_link))
     }
     /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length,
     ///
@@ -249,9 +292,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The equilibrium probability density :math:`g_\mathrm{eq}`.
     ///
-    pub fn equilibrium_radial_distribution<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn equilibrium_radial_distribution<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.normalization_nondimensional_equilibrium_distribution, &end_to_end_length)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.normalization_nondimensional_equilibrium_distribution, &end_to_end_length))
     }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link,
     ///
@@ -264,9 +311,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional equilibrium probability density :math:`\mathscr{g}_\mathrm{eq}\equiv N_b\ell_b g_\mathrm{eq}`.
     ///
-    pub fn nondimensional_equilibrium_radial_distribution<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_equilibrium_radial_distribution<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_equilibrium_radial_distribution(&self.number_of_links, &self.normalization_nondimensional_equilibrium_distribution, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_equilibrium_radial_distribution(&self.number_of_links, &self.normalization_nondimensional_equilibrium_distribution, &nondimensional_end_to_end_length_per_link))
     }
     /// The Gibbs free energy as a function of the applied end-to-end length and temperature,
     ///
@@ -282,9 +333,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The Gibbs free energy :math:`\varphi`.
     ///
-    pub fn gibbs_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn gibbs_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature))
     }
     /// The Gibbs free energy per link as a function of the applied end-to-end length and temperature.
     ///
@@ -295,9 +350,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The Gibbs free energy per link :math:`\varphi/N_b`.
     ///
-    pub fn gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature))
     }
     /// The relative Gibbs free energy as a function of the applied end-to-end length and temperature.
     ///
@@ -308,9 +367,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The relative Gibbs free energy :math:`\Delta\varphi\equiv\varphi(\xi,T)-\varphi(0,T)`.
     ///
-    pub fn relative_gibbs_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn relative_gibbs_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature))
     }
     /// The relative Gibbs free energy per link as a function of the applied end-to-end length and temperature.
     ///
@@ -321,9 +384,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The relative Gibbs free energy per link :math:`\Delta\varphi/N_b`.
     ///
-    pub fn relative_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn relative_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::relative_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::relative_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature))
     }
     /// The nondimensional Gibbs free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     ///
@@ -334,9 +401,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Gibbs free energy :math:`N_b\varrho=\beta\varphi`.
     ///
-    pub fn nondimensional_gibbs_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_gibbs_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature))
     }
     /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     ///
@@ -347,9 +418,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Gibbs free energy per link :math:`\varrho\equiv\beta\varphi/N_b`.
     ///
-    pub fn nondimensional_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature))
     }
     /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional end-to-end length per link.
     ///
@@ -359,9 +434,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Gibbs free energy :math:`\beta\Delta\varphi=N_b\Delta\varrho`.
     ///
-    pub fn nondimensional_relative_gibbs_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_relative_gibbs_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_gibbs_free_energy(&self.number_of_links, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_gibbs_free_energy(&self.number_of_links, &nondimensional_end_to_end_length_per_link))
     }
     /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link.
     ///
@@ -371,8 +450,12 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Gibbs free energy per link :math:`\Delta\varrho\equiv\beta\Delta\varphi/N_b`.
     ///
-    pub fn nondimensional_relative_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_relative_gibbs_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_gibbs_free_energy_per_link(&self.number_of_links, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_gibbs_free_energy_per_link(&self.number_of_links, &nondimensional_end_to_end_length_per_link))
     }
 }
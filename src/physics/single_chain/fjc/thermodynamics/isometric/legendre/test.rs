@@ -66,6 +66,7 @@ mod normalization
     use super::*;
     use rand::Rng;
     use crate::math::integrate_1d;
+    use crate::physics::single_chain::POINTS;
     #[test]
     fn equilibrium_distribution()
     {
@@ -965,3 +966,50 @@ mod connection
         }
     }
 }
+mod fluctuation
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_stiffness()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_stiffness = model.nondimensional_stiffness(&nondimensional_end_to_end_length_per_link);
+            let h = parameters.rel_tol;
+            let nondimensional_stiffness_from_derivative = (model.nondimensional_force(&(nondimensional_end_to_end_length_per_link + 0.5*h)) - model.nondimensional_force(&(nondimensional_end_to_end_length_per_link - 0.5*h)))/h/(number_of_links as f64);
+            let residual_abs = &nondimensional_stiffness - &nondimensional_stiffness_from_derivative;
+            let residual_rel = &residual_abs/&nondimensional_stiffness;
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+    #[test]
+    fn stiffness()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_stiffness = model.nondimensional_stiffness(&nondimensional_end_to_end_length_per_link);
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let stiffness = model.stiffness(&end_to_end_length, &temperature);
+            let residual_abs = &stiffness/BOLTZMANN_CONSTANT/temperature*link_length.powi(2) - &nondimensional_stiffness;
+            let residual_rel = &residual_abs/&nondimensional_stiffness;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
@@ -12,6 +12,11 @@ pub mod legendre;
 /// The freely-jointed chain (FJC) model thermodynamics in the isometric ensemble calculated using Monte Carlo methods.
 pub mod monte_carlo;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use super::
 {
     treloar_sums,
@@ -23,7 +28,11 @@ use crate::physics::
     PLANCK_CONSTANT,
     BOLTZMANN_CONSTANT
 };
-use crate::physics::single_chain::ZERO;
+use crate::physics::single_chain::
+{
+    ZERO,
+    inverse
+};
 
 /// The structure of the thermodynamics of the FJC model in the isometric ensemble.
 pub struct FJC
@@ -55,6 +64,30 @@ pub fn nondimensional_force(number_of_links: &u8, nondimensional_end_to_end_leng
     (1.0/nondimensional_end_to_end_length_per_link + (0.5*number_of_links_f64 - 1.0)*sums[1]/sums[0])/number_of_links_f64
 }
 
+/// The applied end-to-end length as a function of the expected force and temperature, parameterized by the number of links and link length.
+pub fn end_to_end_length(number_of_links: &u8, link_length: &f64, force: &f64, temperature: &f64) -> f64
+{
+    try_end_to_end_length(number_of_links, link_length, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The applied end-to-end length as a function of the expected force and temperature, parameterized by the number of links and link length, or an error if the inversion does not converge.
+pub fn try_end_to_end_length(number_of_links: &u8, link_length: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*link_length*try_nondimensional_end_to_end_length_per_link(number_of_links, &(force*link_length/BOLTZMANN_CONSTANT/temperature))?)
+}
+
+/// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, parameterized by the number of links.
+pub fn nondimensional_end_to_end_length_per_link(number_of_links: &u8, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_end_to_end_length_per_link(number_of_links, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, parameterized by the number of links, or an error if the inversion does not converge.
+pub fn try_nondimensional_end_to_end_length_per_link(number_of_links: &u8, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    inverse(nondimensional_force, &|nondimensional_end_to_end_length_per_link: &f64| self::nondimensional_force(number_of_links, nondimensional_end_to_end_length_per_link), &1.0)
+}
+
 /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
@@ -129,6 +162,20 @@ pub fn nondimensional_equilibrium_radial_distribution(number_of_links: &u8, nond
     4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*nondimensional_equilibrium_distribution(number_of_links, nondimensional_end_to_end_length_per_link)
 }
 
+/// The stiffness as a function of the applied end-to-end length and temperature, parameterized by the number of links and link length.
+pub fn stiffness(number_of_links: &u8, link_length: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    BOLTZMANN_CONSTANT*temperature/link_length.powi(2)*nondimensional_stiffness(number_of_links, &(end_to_end_length/((*number_of_links as f64)*link_length)))
+}
+
+/// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links.
+pub fn nondimensional_stiffness(number_of_links: &u8, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    let sums = treloar_sums(number_of_links, nondimensional_end_to_end_length_per_link, &[0, 1, 2]);
+    let number_of_links_f64 = *number_of_links as f64;
+    (-1.0/nondimensional_end_to_end_length_per_link.powi(2) + (0.5*number_of_links_f64 - 1.0)*0.5*((number_of_links_f64 - 2.0)*(sums[1]/sums[0]).powi(2) - (number_of_links_f64 - 3.0)*sums[2]/sums[0]))/number_of_links_f64.powi(2)
+}
+
 /// The implemented functionality of the thermodynamics of the FJC model in the isometric ensemble.
 impl FJC
 {
@@ -143,74 +190,218 @@ impl FJC
             legendre: legendre::FJC::init(number_of_links, link_length, hinge_mass)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass))
+    }
     /// The expected force as a function of the applied end-to-end length and temperature.
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         force(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
     }
+    /// The expected force as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.force(end_to_end_length, temperature))
+    }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_force(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link), nondimensional_end_to_end_length_per_link)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_force(nondimensional_end_to_end_length_per_link))
+    }
+    /// The applied end-to-end length as a function of the expected force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.try_end_to_end_length(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied end-to-end length as a function of the expected force and temperature, or an error if the inversion does not converge.
+    pub fn try_end_to_end_length(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length(&self.number_of_links, &self.link_length, force, temperature)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        self.try_nondimensional_end_to_end_length_per_link(nondimensional_force).unwrap_or(f64::NAN)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force), nondimensional_force)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, or an error if the inversion does not converge.
+    pub fn try_nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length_per_link(&self.number_of_links, nondimensional_force)
+    }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, end_to_end_length, temperature)
     }
+    /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.helmholtz_free_energy(end_to_end_length, temperature))
+    }
     /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, end_to_end_length, temperature)
     }
+    /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.helmholtz_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
     }
+    /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_helmholtz_free_energy(end_to_end_length, temperature))
+    }
     /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
     }
+    /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_helmholtz_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_helmholtz_free_energy(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link))
+    }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_helmholtz_free_energy_per_link(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link))
+    }
     /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length.
     pub fn equilibrium_distribution(&self, end_to_end_length: &f64) -> f64
     {
         equilibrium_distribution(&self.number_of_links, &self.link_length, end_to_end_length)
     }
+    /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length, or an error if the end-to-end length is outside the domain.
+    pub fn try_equilibrium_distribution(&self, end_to_end_length: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.equilibrium_distribution(end_to_end_length))
+    }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link.
     pub fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_equilibrium_distribution(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link))
+    }
     /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length.
     pub fn equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> f64
     {
         equilibrium_radial_distribution(&self.number_of_links, &self.link_length, end_to_end_length)
     }
+    /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length, or an error if the end-to-end length is outside the domain.
+    pub fn try_equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.equilibrium_radial_distribution(end_to_end_length))
+    }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link.
     pub fn nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_equilibrium_radial_distribution(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link))
+    }
+    /// The stiffness as a function of the applied end-to-end length and temperature.
+    pub fn stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        stiffness(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
+    }
+    /// The stiffness as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.stiffness(end_to_end_length, temperature))
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link.
+    pub fn nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        nondimensional_stiffness(&self.number_of_links, nondimensional_end_to_end_length_per_link)
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_stiffness(nondimensional_end_to_end_length_per_link))
+    }
 }
@@ -1,7 +1,6 @@
 use pyo3::prelude::*;
 use numpy::
 {
-    IntoPyArray,
     PyArrayDyn,
     PyReadonlyArrayDyn
 };
@@ -41,15 +40,18 @@ pub struct FJC
 impl FJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> PyResult<Self>
     {
-        FJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        Ok(FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
-            legendre: super::legendre::py::FJC::init(number_of_links, link_length, hinge_mass)
-        }
+            legendre: super::legendre::py::FJC::init(number_of_links, link_length, hinge_mass)?
+        })
     }
     /// The expected force as a function of the applied end-to-end length and temperature,
     ///
@@ -65,9 +67,29 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The force :math:`f`.
     ///
-    pub fn force<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn force<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::force(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature))
+    }
+    /// The applied end-to-end length as a function of the expected force and temperature.
+    ///
+    /// Args:
+    ///     force (numpy.ndarray): The force :math:`f`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The end-to-end length :math:`\xi`.
+    ///
+    /// Raises:
+    ///     ValueError: If the inversion does not converge.
+    ///
+    pub fn end_to_end_length<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::force(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::try_batch(py, force, |force: f64| super::try_end_to_end_length(&self.number_of_links, &self.link_length, &force, &temperature))
     }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, given by :footcite:t:`buche2023modeling` as
     ///
@@ -82,9 +104,28 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional force :math:`\eta\equiv\beta f\ell_b`.
     ///
-    pub fn nondimensional_force<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_force<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_force(&self.number_of_links, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_force(&self.number_of_links, &nondimensional_end_to_end_length_per_link))
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force.
+    ///
+    /// Args:
+    ///     nondimensional_force (numpy.ndarray): The nondimensional force :math:`\eta\equiv\beta f\ell_b`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional end-to-end length per link :math:`\gamma\equiv \xi/N_b\ell_b`.
+    ///
+    /// Raises:
+    ///     ValueError: If the inversion does not converge.
+    ///
+    pub fn nondimensional_end_to_end_length_per_link<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_force, |nondimensional_force: f64| super::try_nondimensional_end_to_end_length_per_link(&self.number_of_links, &nondimensional_force))
     }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature,
     ///
@@ -98,9 +139,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The Helmholtz free energy :math:`\psi`.
     ///
-    pub fn helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature))
     }
     /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     ///
@@ -111,9 +156,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The Helmholtz free energy per link :math:`\psi/N_b`.
     ///
-    pub fn helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &end_to_end_length, &temperature))
     }
     /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature,
     ///
@@ -127,9 +176,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The relative Helmholtz free energy :math:`\Delta\psi\equiv\psi(\xi,T)-\psi(0,T)`.
     ///
-    pub fn relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature))
     }
     /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     ///
@@ -140,9 +193,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The relative Helmholtz free energy per link :math:`\Delta\psi/N_b`.
     ///
-    pub fn relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &end_to_end_length, &temperature))
     }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     ///
@@ -153,9 +210,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Helmholtz free energy :math:`N_b\vartheta=\beta\psi`.
     ///
-    pub fn nondimensional_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature))
     }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     ///
@@ -166,9 +227,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional Helmholtz free energy per link :math:`\vartheta\equiv\beta\psi/N_b`.
     ///
-    pub fn nondimensional_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &nondimensional_end_to_end_length_per_link, &temperature))
     }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link,
     ///
@@ -181,9 +246,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Helmholtz free energy :math:`N_b\Delta\vartheta=\beta\Delta\psi`.
     ///
-    pub fn nondimensional_relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_relative_helmholtz_free_energy<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &nondimensional_end_to_end_length_per_link))
     }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link,
     ///
@@ -196,9 +265,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional relative Helmholtz free energy per link :math:`\Delta\vartheta\equiv\beta\Delta\psi/N_b`.
     ///
-    pub fn nondimensional_relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_relative_helmholtz_free_energy_per_link<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy_per_link(&self.number_of_links, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_relative_helmholtz_free_energy_per_link(&self.number_of_links, &nondimensional_end_to_end_length_per_link))
     }
     /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length,
     ///
@@ -213,9 +286,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The equilibrium probability density :math:`P_\mathrm{eq}`.
     ///
-    pub fn equilibrium_distribution<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn equilibrium_distribution<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::equilibrium_distribution(&self.number_of_links, &self.link_length, &end_to_end_length)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::equilibrium_distribution(&self.number_of_links, &self.link_length, &end_to_end_length))
     }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link,
     ///
@@ -230,9 +307,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional equilibrium probability density :math:`\mathscr{P}_\mathrm{eq}\equiv (N_b\ell_b)^3 P_\mathrm{eq}`.
     ///
-    pub fn nondimensional_equilibrium_distribution<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_equilibrium_distribution<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_equilibrium_distribution(&self.number_of_links, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_equilibrium_distribution(&self.number_of_links, &nondimensional_end_to_end_length_per_link))
     }
     /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length, given by :footcite:t:`treloar1949physics` as
     ///
@@ -247,9 +328,13 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The equilibrium probability density :math:`g_\mathrm{eq}`.
     ///
-    pub fn equilibrium_radial_distribution<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the end-to-end length is not less than the contour length in magnitude.
+    ///
+    pub fn equilibrium_radial_distribution<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        end_to_end_length.as_array().mapv(|end_to_end_length: f64| super::equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &end_to_end_length)).into_pyarray(py)
+        crate::py::validate(&end_to_end_length, |end_to_end_length| crate::error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length)))?;
+        crate::py::batch(py, end_to_end_length, |end_to_end_length: f64| super::equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &end_to_end_length))
     }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link,
     ///
@@ -264,8 +349,12 @@ impl FJC
     /// Returns:
     ///     numpy.ndarray: The nondimensional equilibrium probability density :math:`\mathscr{g}_\mathrm{eq}\equiv N_b\ell_b g_\mathrm{eq}`.
     ///
-    pub fn nondimensional_equilibrium_radial_distribution<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> &'py PyArrayDyn<f64>
+    /// Raises:
+    ///     ValueError: If the nondimensional end-to-end length per link is not less than one in magnitude.
+    ///
+    pub fn nondimensional_equilibrium_radial_distribution<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>) -> PyResult<&'py PyArrayDyn<f64>>
     {
-        nondimensional_end_to_end_length_per_link.as_array().mapv(|nondimensional_end_to_end_length_per_link: f64| super::nondimensional_equilibrium_radial_distribution(&self.number_of_links, &nondimensional_end_to_end_length_per_link)).into_pyarray(py)
+        crate::py::validate(&nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link| crate::error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0))?;
+        crate::py::batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_equilibrium_radial_distribution(&self.number_of_links, &nondimensional_end_to_end_length_per_link))
     }
 }
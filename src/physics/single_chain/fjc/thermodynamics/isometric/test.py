@@ -26,6 +26,38 @@ class Base(unittest.TestCase):
                 parameters.hinge_mass_reference
             )
 
+    def test_invalid(self):
+        """Function to test invalid parameters and arguments.
+
+        """
+        with self.assertRaises(ValueError):
+            _ = FJC(
+                0,
+                parameters.link_length_reference,
+                parameters.hinge_mass_reference
+            )
+        with self.assertRaises(ValueError):
+            _ = FJC(
+                parameters.number_of_links_minimum,
+                -parameters.link_length_reference,
+                parameters.hinge_mass_reference
+            )
+        model = FJC(
+            parameters.number_of_links_minimum,
+            parameters.link_length_reference,
+            parameters.hinge_mass_reference
+        )
+        with self.assertRaises(ValueError):
+            _ = model.nondimensional_force(np.array([0.5, 1.0]))
+        with self.assertRaises(ValueError):
+            _ = model.force(
+                np.array(
+                    parameters.number_of_links_minimum *
+                    parameters.link_length_reference
+                ),
+                parameters.temperature_reference
+            )
+
     def test_number_of_links(self):
         """Function to test the number of links during instantiation.
 
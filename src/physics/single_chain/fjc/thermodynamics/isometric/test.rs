@@ -1184,3 +1184,121 @@ mod thermodynamic_limit
         }
     }
 }
+mod fluctuation
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_stiffness()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_stiffness = model.nondimensional_stiffness(&nondimensional_end_to_end_length_per_link);
+            let h = parameters.rel_tol;
+            let nondimensional_stiffness_from_derivative = (model.nondimensional_force(&(nondimensional_end_to_end_length_per_link + 0.5*h)) - model.nondimensional_force(&(nondimensional_end_to_end_length_per_link - 0.5*h)))/h/(number_of_links as f64);
+            let residual_abs = &nondimensional_stiffness - &nondimensional_stiffness_from_derivative;
+            let residual_rel = &residual_abs/&nondimensional_stiffness;
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+    #[test]
+    fn stiffness()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_stiffness = model.nondimensional_stiffness(&nondimensional_end_to_end_length_per_link);
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let stiffness = model.stiffness(&end_to_end_length, &temperature);
+            let residual_abs = &stiffness/BOLTZMANN_CONSTANT/temperature*link_length.powi(2) - &nondimensional_stiffness;
+            let residual_rel = &residual_abs/&nondimensional_stiffness;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_end_to_end_length_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = model.nondimensional_force(&nondimensional_end_to_end_length_per_link);
+            let residual_abs = model.nondimensional_end_to_end_length_per_link(&nondimensional_force) - &nondimensional_end_to_end_length_per_link;
+            let residual_rel = &residual_abs/&nondimensional_end_to_end_length_per_link;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let force = model.force(&end_to_end_length, &temperature);
+            let residual_abs = (model.end_to_end_length(&force, &temperature) - &end_to_end_length)/(number_of_links as f64)/link_length;
+            let residual_rel = &residual_abs/&nondimensional_end_to_end_length_per_link;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        assert!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).is_ok());
+        assert_eq!(FJC::try_init(0, parameters.link_length_reference, parameters.hinge_mass_reference).err(), Some(Error::Parameter("number_of_links", 0.0, "positive")));
+        assert_eq!(FJC::try_init(parameters.number_of_links_minimum, -1.0, parameters.hinge_mass_reference).err(), Some(Error::Parameter("link_length", -1.0, "positive and finite")));
+        assert!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, f64::NAN).is_err());
+    }
+    #[test]
+    fn domain()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let contour_length = (parameters.number_of_links_minimum as f64)*parameters.link_length_reference;
+        assert_eq!(model.try_nondimensional_force(&0.5), Ok(model.nondimensional_force(&0.5)));
+        assert_eq!(model.try_force(&(0.5*contour_length), &parameters.temperature_reference), Ok(model.force(&(0.5*contour_length), &parameters.temperature_reference)));
+        assert!(model.try_nondimensional_force(&1.0).is_err());
+        assert!(model.try_nondimensional_helmholtz_free_energy(&-1.5, &parameters.temperature_reference).is_err());
+        assert_eq!(model.try_force(&contour_length, &parameters.temperature_reference), Err(Error::Domain("end_to_end_length", contour_length, "less than the contour length in magnitude")));
+        assert!(model.legendre.try_nondimensional_gibbs_free_energy(&1.0, &parameters.temperature_reference).is_err());
+    }
+}
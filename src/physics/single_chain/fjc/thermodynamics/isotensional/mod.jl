@@ -5,7 +5,7 @@ module Isotensional
 
 using DocStringExtensions
 using Polymers_jll
-using ......Polymers: PROJECT_ROOT
+using ......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 include("legendre/mod.jl")
 
@@ -456,6 +456,9 @@ Initializes and returns an instance of the thermodynamics of the FJC model in th
 $(TYPEDSIGNATURES)
 """
 function FJC(number_of_links::UInt8, link_length::Float64, hinge_mass::Float64)
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
     return FJC(
         number_of_links,
         link_length,
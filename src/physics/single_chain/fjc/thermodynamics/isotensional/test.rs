@@ -877,4 +877,197 @@ mod legendre_connection
             assert!(residual_rel.abs() <= h);
         }
     }
-}
\ No newline at end of file
+}
+mod fluctuation
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_compliance()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + 0.5*parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_compliance = model.nondimensional_compliance(&nondimensional_force);
+            let h = parameters.rel_tol;
+            let nondimensional_compliance_from_derivative = (model.nondimensional_end_to_end_length(&(nondimensional_force + 0.5*h)) - model.nondimensional_end_to_end_length(&(nondimensional_force - 0.5*h)))/h;
+            let residual_abs = &nondimensional_compliance - &nondimensional_compliance_from_derivative;
+            let residual_rel = &residual_abs/&nondimensional_compliance;
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+    #[test]
+    fn compliance()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + 0.5*parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_compliance = model.nondimensional_compliance(&nondimensional_force);
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let compliance = model.compliance(&force, &temperature);
+            let residual_abs = &compliance*BOLTZMANN_CONSTANT*temperature/link_length.powi(2) - &nondimensional_compliance;
+            let residual_rel = &residual_abs/&nondimensional_compliance;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn nondimensional_compliance_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + 0.5*parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_compliance_per_link = model.nondimensional_compliance_per_link(&nondimensional_force);
+            let h = parameters.rel_tol;
+            let nondimensional_compliance_per_link_from_derivative = (model.nondimensional_end_to_end_length_per_link(&(nondimensional_force + 0.5*h)) - model.nondimensional_end_to_end_length_per_link(&(nondimensional_force - 0.5*h)))/h;
+            let residual_abs = &nondimensional_compliance_per_link - &nondimensional_compliance_per_link_from_derivative;
+            let residual_rel = &residual_abs/&nondimensional_compliance_per_link;
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+    #[test]
+    fn compliance_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + 0.5*parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_compliance_per_link = model.nondimensional_compliance_per_link(&nondimensional_force);
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let compliance_per_link = model.compliance_per_link(&force, &temperature);
+            let residual_abs = &compliance_per_link*BOLTZMANN_CONSTANT*temperature/link_length.powi(2) - &nondimensional_compliance_per_link;
+            let residual_rel = &residual_abs/&nondimensional_compliance_per_link;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn end_to_end_length_variance()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + 0.5*parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length_variance = model.end_to_end_length_variance(&force, &temperature);
+            let compliance = model.compliance(&force, &temperature);
+            let residual_abs = &end_to_end_length_variance - BOLTZMANN_CONSTANT*temperature*&compliance;
+            let residual_rel = &residual_abs/&end_to_end_length_variance;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn nondimensional_end_to_end_length_variance()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + 0.5*parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_variance = model.nondimensional_end_to_end_length_variance(&nondimensional_force);
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length_variance = model.end_to_end_length_variance(&force, &temperature);
+            let residual_abs = &end_to_end_length_variance/link_length.powi(2) - &nondimensional_end_to_end_length_variance;
+            let residual_rel = &residual_abs/&nondimensional_end_to_end_length_variance;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force);
+            let residual_abs = model.nondimensional_force(&nondimensional_end_to_end_length_per_link) - &nondimensional_force;
+            let residual_rel = &residual_abs/&nondimensional_force;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            let residual_abs = (model.force(&end_to_end_length, &temperature) - &force)/BOLTZMANN_CONSTANT/temperature*link_length;
+            let residual_rel = &residual_abs/&nondimensional_force;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn inverse()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let contour_length = (parameters.number_of_links_minimum as f64)*parameters.link_length_reference;
+        assert!(model.try_nondimensional_force(&0.5).is_ok());
+        assert!(model.try_nondimensional_force(&1.0).is_err());
+        assert!(model.try_nondimensional_force(&-1.5).is_err());
+        assert!(model.try_force(&contour_length, &parameters.temperature_reference).is_err());
+    }
+}
@@ -4,6 +4,7 @@ The freely-jointed chain (FJC) model thermodynamics.
 module Thermodynamics
 
 using DocStringExtensions
+using .....Polymers: validate_number_of_links, validate_positive
 
 include("isometric/mod.jl")
 include("isotensional/mod.jl")
@@ -47,6 +48,9 @@ Initializes and returns an instance of the thermodynamics of the FJC model.
 $(TYPEDSIGNATURES)
 """
 function FJC(number_of_links::UInt8, link_length::Float64, hinge_mass::Float64)
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
     return FJC(
         number_of_links,
         link_length,
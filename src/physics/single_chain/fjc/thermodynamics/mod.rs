@@ -12,6 +12,11 @@ pub mod isotensional;
 /// The freely-jointed chain (FJC) model thermodynamics in the modified canonical ensemble.
 pub mod modified_canonical;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use std::f64::consts::PI;
 
 /// The structure of the thermodynamics of the FJC model.
@@ -52,6 +57,14 @@ impl FJC
             modified_canonical: modified_canonical::FJC::init(number_of_links, link_length, hinge_mass)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass))
+    }
 }
 
 fn treloar_sums(number_of_links: &u8, nondimensional_end_to_end_length_per_link: &f64, orders: &[i32]) -> Vec<f64>
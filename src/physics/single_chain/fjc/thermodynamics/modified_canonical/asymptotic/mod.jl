@@ -4,7 +4,7 @@ The freely-jointed chain (FJC) model thermodynamics in the modified canonical en
 module Asymptotic
 
 using DocStringExtensions
-using .......Polymers: PROJECT_ROOT
+using .......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 include("strong_potential/mod.jl")
 include("weak_potential/mod.jl")
@@ -43,6 +43,9 @@ Initializes and returns an instance of the thermodynamics of the FJC model in th
 $(TYPEDSIGNATURES)
 """
 function FJC(number_of_links::UInt8, link_length::Float64, hinge_mass::Float64)
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
     return FJC(
         number_of_links,
         link_length,
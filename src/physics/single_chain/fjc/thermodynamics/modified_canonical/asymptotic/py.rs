@@ -40,15 +40,18 @@ pub struct FJC
 impl FJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> PyResult<Self>
     {
-        FJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        Ok(FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
-            weak_potential: super::weak_potential::py::FJC::init(number_of_links, link_length, hinge_mass),
-            strong_potential: super::strong_potential::py::FJC::init(number_of_links, link_length, hinge_mass)
-        }
+            weak_potential: super::weak_potential::py::FJC::init(number_of_links, link_length, hinge_mass)?,
+            strong_potential: super::strong_potential::py::FJC::init(number_of_links, link_length, hinge_mass)?
+        })
     }
 }
\ No newline at end of file
@@ -6,6 +6,11 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use super::super::super::
 {
     treloar_sums,
@@ -111,6 +116,14 @@ impl FJC
             number_of_links
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass))
+    }
     /// The expected force as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn force(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
@@ -9,9 +9,13 @@ mod test;
 /// The freely-jointed chain (FJC) model thermodynamics in the modified canonical ensemble approximated using an asymptotic approach.
 pub mod asymptotic;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use super::treloar_sum_0_with_prefactor;
 use std::f64::consts::PI;
-use crate::math::integrate_1d;
 use crate::physics::
 {
     PLANCK_CONSTANT,
@@ -21,7 +25,8 @@ use crate::physics::single_chain::
 {
     ONE,
     ZERO,
-    POINTS
+    try_integrate,
+    INTEGRATION_TOLERANCE
 };
 
 /// The structure of the thermodynamics of the FJC model in the modified canonical ensemble.
@@ -43,35 +48,71 @@ pub struct FJC
 /// The expected end-to-end length as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length.
 pub fn end_to_end_length(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    potential_distance - force(number_of_links, link_length, potential_distance, potential_stiffness, temperature)/potential_stiffness
+    try_end_to_end_length(number_of_links, link_length, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected end-to-end length as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_end_to_end_length(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(potential_distance - try_force(number_of_links, link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)?/potential_stiffness)
 }
 
 /// The expected end-to-end length per link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length.
 pub fn end_to_end_length_per_link(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    (potential_distance - force(number_of_links, link_length, potential_distance, potential_stiffness, temperature)/potential_stiffness)/(*number_of_links as f64)
+    try_end_to_end_length_per_link(number_of_links, link_length, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected end-to-end length per link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_end_to_end_length_per_link(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok((potential_distance - try_force(number_of_links, link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)?/potential_stiffness)/(*number_of_links as f64))
 }
 
 /// The expected nondimensional end-to-end length as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links.
 pub fn nondimensional_end_to_end_length(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
 {
-    (*number_of_links as f64)*nondimensional_potential_distance - nondimensional_force(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)/nondimensional_potential_stiffness
+    try_nondimensional_end_to_end_length(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional end-to-end length as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_end_to_end_length(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*nondimensional_potential_distance - try_nondimensional_force(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)?/nondimensional_potential_stiffness)
 }
 
 /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links.
 pub fn nondimensional_end_to_end_length_per_link(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
 {
-    nondimensional_potential_distance - nondimensional_force(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)/nondimensional_potential_stiffness/(*number_of_links as f64)
+    try_nondimensional_end_to_end_length_per_link(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional end-to-end length per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_end_to_end_length_per_link(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(nondimensional_potential_distance - try_nondimensional_force(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)?/nondimensional_potential_stiffness/(*number_of_links as f64))
 }
 
 /// The expected force as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length.
 pub fn force(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature/link_length*nondimensional_force(number_of_links, &(*potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature))
+    try_force(number_of_links, link_length, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected force as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_force(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length*try_nondimensional_force(number_of_links, &(*potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), integration_tolerance)?)
 }
 
 /// The expected nondimensional force as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links.
 pub fn nondimensional_force(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
+{
+    try_nondimensional_force(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional force as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_force(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
 {
     let number_of_links_f64 = *number_of_links as f64;
     let number_of_links_squared_times_nondimensional_potential_stiffness = number_of_links_f64.powi(2)*nondimensional_potential_stiffness;
@@ -83,35 +124,65 @@ pub fn nondimensional_force(number_of_links: &u8, nondimensional_potential_dista
     {
         4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*treloar_sum_0_with_prefactor(number_of_links, nondimensional_end_to_end_length_per_link)*((-0.5*number_of_links_squared_times_nondimensional_potential_stiffness*(nondimensional_potential_distance - nondimensional_end_to_end_length_per_link).powi(2)).exp() - (-0.5*number_of_links_squared_times_nondimensional_potential_stiffness*(nondimensional_potential_distance + nondimensional_end_to_end_length_per_link).powi(2)).exp())/(2.0*number_of_links_squared_times_nondimensional_potential_stiffness*nondimensional_potential_distance*nondimensional_end_to_end_length_per_link)
     };
-    integrate_1d(&integrand_numerator, &ZERO, &ONE, &POINTS)/integrate_1d(&integrand_denominator, &ZERO, &ONE, &POINTS)/number_of_links_f64
+    Ok(try_integrate(&integrand_numerator, &0.0, &ONE, integration_tolerance)?/try_integrate(&integrand_denominator, &0.0, &ONE, integration_tolerance)?/number_of_links_f64)
 }
 
 /// The Helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, &(potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), temperature)
+    try_helmholtz_free_energy(number_of_links, link_length, hinge_mass, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The Helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, &(potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), temperature, integration_tolerance)?)
 }
 
 /// The Helmholtz free energy per link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, &(*potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), temperature)
+    try_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The Helmholtz free energy per link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, &(*potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), temperature, integration_tolerance)?)
 }
 
 /// The relative Helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length.
 pub fn relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_relative_helmholtz_free_energy(number_of_links, &(*potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature))
+    try_relative_helmholtz_free_energy(number_of_links, link_length, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The relative Helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_relative_helmholtz_free_energy(number_of_links, &(*potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), integration_tolerance)?)
 }
 
 /// The relative Helmholtz free energy per link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length.
 pub fn relative_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_relative_helmholtz_free_energy_per_link(number_of_links, &(*potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature))
+    try_relative_helmholtz_free_energy_per_link(number_of_links, link_length, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The relative Helmholtz free energy per link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_relative_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_relative_helmholtz_free_energy_per_link(number_of_links, &(*potential_distance/((*number_of_links as f64)*link_length)), &(potential_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), integration_tolerance)?)
 }
 
 /// The nondimensional Helmholtz free energy as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
+{
+    try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
 {
     let number_of_links_f64 = *number_of_links as f64;
     let number_of_links_squared_times_nondimensional_potential_stiffness = number_of_links_f64.powi(2)*nondimensional_potential_stiffness;
@@ -119,74 +190,140 @@ pub fn nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &
     {
         4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*treloar_sum_0_with_prefactor(number_of_links, nondimensional_end_to_end_length_per_link)*((-0.5*number_of_links_squared_times_nondimensional_potential_stiffness*(nondimensional_potential_distance - nondimensional_end_to_end_length_per_link).powi(2)).exp() - (-0.5*number_of_links_squared_times_nondimensional_potential_stiffness*(nondimensional_potential_distance + nondimensional_end_to_end_length_per_link).powi(2)).exp())/(2.0*number_of_links_squared_times_nondimensional_potential_stiffness*nondimensional_potential_distance*nondimensional_end_to_end_length_per_link)
     };
-    let nondimensional_configurational_partition_function = integrate_1d(&integrand, &ZERO, &ONE, &POINTS);
-    -nondimensional_configurational_partition_function.ln() - (number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+    let nondimensional_configurational_partition_function = try_integrate(&integrand, &0.0, &ONE, integration_tolerance)?;
+    Ok(-nondimensional_configurational_partition_function.ln() - (number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
 }
 
 /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn nondimensional_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature)/(*number_of_links as f64)
+    try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, integration_tolerance)?/(*number_of_links as f64))
 }
 
 /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links.
 pub fn nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_potential_distance, nondimensional_potential_stiffness, &300.0) - nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, &ZERO, nondimensional_potential_stiffness, &300.0)
+    try_nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_potential_distance, nondimensional_potential_stiffness, &300.0, integration_tolerance)? - try_nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, &ZERO, nondimensional_potential_stiffness, &300.0, integration_tolerance)?)
 }
 
 /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links.
 pub fn nondimensional_relative_helmholtz_free_energy_per_link(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
 {
-    nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)/(*number_of_links as f64)
+    try_nondimensional_relative_helmholtz_free_energy_per_link(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)?/(*number_of_links as f64))
 }
 
 /// The Gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    helmholtz_free_energy(number_of_links, link_length, hinge_mass, potential_distance, potential_stiffness, temperature) - 0.5*potential_stiffness*potential_distance.powi(2)
+    try_gibbs_free_energy(number_of_links, link_length, hinge_mass, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The Gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_helmholtz_free_energy(number_of_links, link_length, hinge_mass, potential_distance, potential_stiffness, temperature, integration_tolerance)? - 0.5*potential_stiffness*potential_distance.powi(2))
 }
 
 /// The Gibbs free energy epr link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn gibbs_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, potential_distance, potential_stiffness, temperature) - 0.5*potential_stiffness*potential_distance.powi(2)/(*number_of_links as f64)
+    try_gibbs_free_energy_per_link(number_of_links, link_length, hinge_mass, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The Gibbs free energy epr link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_gibbs_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, potential_distance, potential_stiffness, temperature, integration_tolerance)? - 0.5*potential_stiffness*potential_distance.powi(2)/(*number_of_links as f64))
 }
 
 /// The relative Gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length.
 pub fn relative_gibbs_free_energy(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    relative_helmholtz_free_energy(number_of_links, link_length, potential_distance, potential_stiffness, temperature) - 0.5*potential_stiffness*potential_distance.powi(2)
+    try_relative_gibbs_free_energy(number_of_links, link_length, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The relative Gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_relative_gibbs_free_energy(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_relative_helmholtz_free_energy(number_of_links, link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)? - 0.5*potential_stiffness*potential_distance.powi(2))
 }
 
 /// The relative Gibbs free energy per link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length.
 pub fn relative_gibbs_free_energy_per_link(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    relative_helmholtz_free_energy_per_link(number_of_links, link_length, potential_distance, potential_stiffness, temperature) - 0.5*potential_stiffness*potential_distance.powi(2)/(*number_of_links as f64)
+    try_relative_gibbs_free_energy_per_link(number_of_links, link_length, potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The relative Gibbs free energy per link as a function of the applied potential distance, potential stiffness, and temperature, parameterized by the number of links and link length to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_relative_gibbs_free_energy_per_link(number_of_links: &u8, link_length: &f64, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_relative_helmholtz_free_energy_per_link(number_of_links, link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)? - 0.5*potential_stiffness*potential_distance.powi(2)/(*number_of_links as f64))
 }
 
 /// The nondimensional Gibbs free energy as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn nondimensional_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature) - 0.5*(*number_of_links as f64).powi(2)*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2)
+    try_nondimensional_gibbs_free_energy(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Gibbs free energy as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, integration_tolerance)? - 0.5*(*number_of_links as f64).powi(2)*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2))
 }
 
 /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn nondimensional_gibbs_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature) - 0.5*(*number_of_links as f64)*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2)
+    try_nondimensional_gibbs_free_energy_per_link(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Gibbs free energy per link as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature, parameterized by the number of links, link length, and hinge mass to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_gibbs_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, integration_tolerance)? - 0.5*(*number_of_links as f64)*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2))
 }
 
 /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links.
 pub fn nondimensional_relative_gibbs_free_energy(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
 {
-    nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness) - 0.5*(*number_of_links as f64).powi(2)*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2)
+    try_nondimensional_relative_gibbs_free_energy(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Gibbs free energy as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_relative_gibbs_free_energy(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)? - 0.5*(*number_of_links as f64).powi(2)*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2))
 }
 
 /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links.
 pub fn nondimensional_relative_gibbs_free_energy_per_link(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
 {
-    nondimensional_relative_helmholtz_free_energy_per_link(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness) - 0.5*(*number_of_links as f64)*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2)
+    try_nondimensional_relative_gibbs_free_energy_per_link(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness, parameterized by the number of links to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_relative_gibbs_free_energy_per_link(number_of_links: &u8, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_relative_helmholtz_free_energy_per_link(number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)? - 0.5*(*number_of_links as f64)*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2))
 }
 
 /// The implemented functionality of the thermodynamics of the FJC model in the modified canonical ensemble.
@@ -203,114 +340,232 @@ impl FJC
             asymptotic: asymptotic::FJC::init(number_of_links, link_length, hinge_mass)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass))
+    }
     /// The expected end-to-end length as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn end_to_end_length(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        end_to_end_length(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature)
+        self.try_end_to_end_length(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected end-to-end length as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_end_to_end_length(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The expected end-to-end length per link as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn end_to_end_length_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        end_to_end_length_per_link(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature)
+        self.try_end_to_end_length_per_link(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected end-to-end length per link as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_end_to_end_length_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length_per_link(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The expected nondimensional end-to-end length as a function of the applied nondimensional potential distance and nondimensional potential stiffness.
     pub fn nondimensional_end_to_end_length(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        nondimensional_end_to_end_length(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)
+        self.try_nondimensional_end_to_end_length(nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional end-to-end length as a function of the applied nondimensional potential distance and nondimensional potential stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_end_to_end_length(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)
     }
     /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness.
     pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        nondimensional_end_to_end_length_per_link(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)
+        self.try_nondimensional_end_to_end_length_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_end_to_end_length_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length_per_link(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)
     }
     /// The expected force as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn force(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        force(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature)
+        self.try_force(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected force as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_force(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_force(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The expected nondimensional force as a function of the applied nondimensional potential distance and nondimensional potential stiffness.
     pub fn nondimensional_force(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        nondimensional_force(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)
+        self.try_nondimensional_force(nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional potential distance and nondimensional potential stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_force(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_force(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)
     }
     /// The Helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, potential_distance, potential_stiffness, temperature)
+        self.try_helmholtz_free_energy(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The Helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The Helmholtz free energy per link as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn helmholtz_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, potential_distance, potential_stiffness, temperature)
+        self.try_helmholtz_free_energy_per_link(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The Helmholtz free energy per link as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_helmholtz_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The relative Helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn relative_helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature)
+        self.try_relative_helmholtz_free_energy(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The relative Helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_relative_helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The relative Helmholtz free energy per link as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature)
+        self.try_relative_helmholtz_free_energy_per_link(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The relative Helmholtz free energy per link as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_relative_helmholtz_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature)
+        self.try_nondimensional_helmholtz_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Helmholtz free energy as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_helmholtz_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, integration_tolerance)
     }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature)
+        self.try_nondimensional_helmholtz_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, integration_tolerance)
     }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional potential distance and nondimensional potential stiffness.
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        nondimensional_relative_helmholtz_free_energy(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)
+        self.try_nondimensional_relative_helmholtz_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional potential distance and nondimensional potential stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_relative_helmholtz_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_helmholtz_free_energy(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)
     }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness.
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        nondimensional_relative_helmholtz_free_energy_per_link(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)
+        self.try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_helmholtz_free_energy_per_link(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)
     }
     /// The Gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, potential_distance, potential_stiffness, temperature)
+        self.try_gibbs_free_energy(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The Gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The Gibbs free energy epr link as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn gibbs_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, potential_distance, potential_stiffness, temperature)
+        self.try_gibbs_free_energy_per_link(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The Gibbs free energy epr link as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_gibbs_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The relative Gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn relative_gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        relative_gibbs_free_energy(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature)
+        self.try_relative_gibbs_free_energy(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The relative Gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_relative_gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_relative_gibbs_free_energy(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The relative Gibbs free energy per link as a function of the applied potential distance, potential stiffness, and temperature.
     pub fn relative_gibbs_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        relative_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature)
+        self.try_relative_gibbs_free_energy_per_link(potential_distance, potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The relative Gibbs free energy per link as a function of the applied potential distance, potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_relative_gibbs_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_relative_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, potential_distance, potential_stiffness, temperature, integration_tolerance)
     }
     /// The nondimensional Gibbs free energy as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature.
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature)
+        self.try_nondimensional_gibbs_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Gibbs free energy as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_gibbs_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, integration_tolerance)
     }
     /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature.
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        nondimensional_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature)
+        self.try_nondimensional_gibbs_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional potential distance, nondimensional potential stiffness, and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_gibbs_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, nondimensional_potential_distance, nondimensional_potential_stiffness, temperature, integration_tolerance)
     }
     /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional potential distance and nondimensional potential stiffness.
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        nondimensional_relative_gibbs_free_energy(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)
+        self.try_nondimensional_relative_gibbs_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional potential distance and nondimensional potential stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_relative_gibbs_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_gibbs_free_energy(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)
     }
     /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness.
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        nondimensional_relative_gibbs_free_energy_per_link(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness)
+        self.try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional potential distance and nondimensional potential stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_gibbs_free_energy_per_link(&self.number_of_links, nondimensional_potential_distance, nondimensional_potential_stiffness, integration_tolerance)
     }
 }
@@ -1375,6 +1375,7 @@ mod weak_potential
     use super::*;
     use rand::Rng;
     use crate::math::integrate_1d;
+    use crate::physics::single_chain::POINTS;
     #[test]
     fn end_to_end_length()
     {
@@ -45,16 +45,19 @@ pub struct FJC
 impl FJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> PyResult<Self>
     {
-        FJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        Ok(FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
-            isometric: super::isometric::py::FJC::init(number_of_links, link_length, hinge_mass),
-            isotensional: super::isotensional::py::FJC::init(number_of_links, link_length, hinge_mass),
-            modified_canonical: super::modified_canonical::py::FJC::init(number_of_links, link_length, hinge_mass)
-        }
+            isometric: super::isometric::py::FJC::init(number_of_links, link_length, hinge_mass)?,
+            isotensional: super::isotensional::py::FJC::init(number_of_links, link_length, hinge_mass)?,
+            modified_canonical: super::modified_canonical::py::FJC::init(number_of_links, link_length, hinge_mass)?
+        })
     }
 }
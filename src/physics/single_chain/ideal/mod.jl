@@ -4,6 +4,7 @@ The ideal single-chain model.
 module Ideal
 
 using DocStringExtensions
+using ....Polymers: validate_number_of_links, validate_positive
 
 include("thermodynamics/mod.jl")
 
@@ -37,6 +38,9 @@ Initializes and returns an instance of the ideal chain model.
 $(TYPEDSIGNATURES)
 """
 function IDEAL(number_of_links::UInt8, link_length::Float64, hinge_mass::Float64)
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
     return IDEAL(
         number_of_links,
         link_length,
@@ -3,6 +3,12 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
 /// The ideal chain model thermodynamics.
 pub mod thermodynamics;
 
@@ -22,6 +28,9 @@ pub struct Ideal
     pub thermodynamics: thermodynamics::Ideal
 }
 
+#[cfg(feature = "serde")]
+crate::config::parameters!(Ideal, try_init, number_of_links: u8, link_length: f64, hinge_mass: f64);
+
 /// The implemented functionality of the ideal chain model.
 impl Ideal
 {
@@ -36,4 +45,33 @@ impl Ideal
             thermodynamics: thermodynamics::Ideal::init(number_of_links, link_length, hinge_mass),
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass))
+    }
+}
+
+/// The force law of the ideal chain model, using the isometric ensemble.
+impl super::ForceLaw for Ideal
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.nondimensional_force(nondimensional_end_to_end_length_per_link)
+    }
+    fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link)
+    }
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link)
+    }
 }
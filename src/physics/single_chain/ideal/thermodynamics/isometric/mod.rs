@@ -6,6 +6,11 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use std::f64::consts::PI;
 use crate::physics::
 {
@@ -38,6 +43,18 @@ pub fn nondimensional_force(nondimensional_end_to_end_length_per_link: &f64) ->
     3.0*nondimensional_end_to_end_length_per_link
 }
 
+/// The applied end-to-end length as a function of the expected force and temperature, parameterized by the number of links and link length.
+pub fn end_to_end_length(number_of_links: &u8, link_length: &f64, force: &f64, temperature: &f64) -> f64
+{
+    (*number_of_links as f64)*force/BOLTZMANN_CONSTANT/temperature*link_length.powi(2)/3.0
+}
+
+/// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force.
+pub fn nondimensional_end_to_end_length_per_link(nondimensional_force: &f64) -> f64
+{
+    nondimensional_force/3.0
+}
+
 /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and hinge mass.
 pub fn helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
@@ -111,6 +128,18 @@ pub fn nondimensional_equilibrium_radial_distribution(number_of_links: &u8, nond
     4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*(1.5/PI*(*number_of_links as f64)).powf(1.5)*(-1.5*nondimensional_end_to_end_length_per_link.powi(2)*(*number_of_links as f64)).exp()
 }
 
+/// The stiffness as a function of the applied end-to-end length and temperature, parameterized by the number of links and link length.
+pub fn stiffness(number_of_links: &u8, link_length: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    BOLTZMANN_CONSTANT*temperature/link_length.powi(2)*nondimensional_stiffness(number_of_links, &(end_to_end_length/((*number_of_links as f64)*link_length)))
+}
+
+/// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links.
+pub fn nondimensional_stiffness(number_of_links: &u8, _nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    3.0/(*number_of_links as f64)
+}
+
 /// The implemented functionality of the thermodynamics of the ideal chain model in the isometric ensemble.
 impl Ideal
 {
@@ -124,6 +153,14 @@ impl Ideal
             number_of_links
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass))
+    }
     /// The expected force as a function of the applied end-to-end length and temperature.
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
@@ -134,6 +171,26 @@ impl Ideal
     {
         nondimensional_force(nondimensional_end_to_end_length_per_link)
     }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link), nondimensional_end_to_end_length_per_link)
+    }
+    /// The applied end-to-end length as a function of the expected force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        end_to_end_length(&self.number_of_links, &self.link_length, force, temperature)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_end_to_end_length_per_link(nondimensional_force)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force), nondimensional_force)
+    }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
@@ -194,4 +251,14 @@ impl Ideal
     {
         nondimensional_equilibrium_radial_distribution(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
+    /// The stiffness as a function of the applied end-to-end length and temperature.
+    pub fn stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        stiffness(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link.
+    pub fn nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        nondimensional_stiffness(&self.number_of_links, nondimensional_end_to_end_length_per_link)
+    }
 }
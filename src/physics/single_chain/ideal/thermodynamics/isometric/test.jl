@@ -1,6 +1,7 @@
 module Test
 
 using Test
+using Polymers: ParameterError
 using Polymers.Physics: BOLTZMANN_CONSTANT
 using Polymers.Physics.SingleChain: ONE, ZERO, POINTS, integrate, parameters
 using Polymers.Physics.SingleChain.Ideal.Thermodynamics.Isometric: IDEAL
@@ -16,6 +17,24 @@ using Polymers.Physics.SingleChain.Ideal.Thermodynamics.Isometric: IDEAL
     )
 end
 
+@testset "physics::single_chain::ideal::thermodynamics::isometric::test::base::invalid" begin
+    @test_throws ParameterError IDEAL(
+        0x00,
+        parameters.link_length_reference,
+        parameters.hinge_mass_reference,
+    )
+    @test_throws ParameterError IDEAL(
+        parameters.number_of_links_minimum,
+        -parameters.link_length_reference,
+        parameters.hinge_mass_reference,
+    )
+    @test_throws ParameterError IDEAL(
+        parameters.number_of_links_minimum,
+        parameters.link_length_reference,
+        NaN,
+    )
+end
+
 @testset "physics::single_chain::ideal::thermodynamics::isometric::test::base::number_of_links" begin
     for _ = 1:parameters.number_of_loops
         number_of_links =
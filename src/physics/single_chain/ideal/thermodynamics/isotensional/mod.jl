@@ -5,7 +5,7 @@ module Isotensional
 
 using DocStringExtensions
 using Polymers_jll
-using ......Polymers: PROJECT_ROOT
+using ......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 """
 The structure of the thermodynamics of the ideal chain model in the isotensional ensemble.
@@ -459,6 +459,9 @@ Initializes and returns an instance of the thermodynamics of the ideal chain mod
 $(TYPEDSIGNATURES)
 """
 function IDEAL(number_of_links::UInt8, link_length::Float64, hinge_mass::Float64)
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
     return IDEAL(
         number_of_links,
         link_length,
@@ -6,6 +6,11 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use std::f64::consts::PI;
 use crate::physics::
 {
@@ -50,6 +55,18 @@ pub fn nondimensional_end_to_end_length_per_link(nondimensional_force: &f64) ->
     nondimensional_force/3.0
 }
 
+/// The applied force as a function of the expected end-to-end length and temperature, parameterized by the number of links and link length.
+pub fn force(number_of_links: &u8, link_length: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    3.0*end_to_end_length*BOLTZMANN_CONSTANT*temperature/(*number_of_links as f64)/link_length.powi(2)
+}
+
+/// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link.
+pub fn nondimensional_force(nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    3.0*nondimensional_end_to_end_length_per_link
+}
+
 /// The Gibbs free energy as a function of the applied nondimensional force and temperature, parameterized by the number of links and link length.
 pub fn gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, force: &f64, temperature: &f64) -> f64
 {
@@ -98,6 +115,42 @@ pub fn nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force:
     -nondimensional_force.powi(2)/6.0
 }
 
+/// The compliance as a function of the applied force and temperature, parameterized by the number of links and link length.
+pub fn compliance(number_of_links: &u8, link_length: &f64, force: &f64, temperature: &f64) -> f64
+{
+    nondimensional_compliance(number_of_links, &(force/BOLTZMANN_CONSTANT/temperature*link_length))*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature
+}
+
+/// The compliance per link as a function of the applied force and temperature, parameterized by the link length.
+pub fn compliance_per_link(link_length: &f64, force: &f64, temperature: &f64) -> f64
+{
+    nondimensional_compliance_per_link(&(force/BOLTZMANN_CONSTANT/temperature*link_length))*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature
+}
+
+/// The nondimensional compliance as a function of the applied nondimensional force, parameterized by the number of links.
+pub fn nondimensional_compliance(number_of_links: &u8, nondimensional_force: &f64) -> f64
+{
+    (*number_of_links as f64)*nondimensional_compliance_per_link(nondimensional_force)
+}
+
+/// The nondimensional compliance per link as a function of the applied nondimensional force.
+pub fn nondimensional_compliance_per_link(_nondimensional_force: &f64) -> f64
+{
+    1.0/3.0
+}
+
+/// The variance of the end-to-end length as a function of the applied force and temperature, parameterized by the number of links and link length.
+pub fn end_to_end_length_variance(number_of_links: &u8, link_length: &f64, force: &f64, temperature: &f64) -> f64
+{
+    nondimensional_end_to_end_length_variance(number_of_links, &(force/BOLTZMANN_CONSTANT/temperature*link_length))*link_length.powi(2)
+}
+
+/// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links.
+pub fn nondimensional_end_to_end_length_variance(number_of_links: &u8, nondimensional_force: &f64) -> f64
+{
+    nondimensional_compliance(number_of_links, nondimensional_force)
+}
+
 /// The implemented functionality of the thermodynamics of the ideal chain model in the isotensional ensemble.
 impl Ideal
 {
@@ -111,6 +164,14 @@ impl Ideal
             number_of_links
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass))
+    }
     /// The expected end-to-end length as a function of the applied force and temperature.
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
@@ -131,6 +192,26 @@ impl Ideal
     {
         nondimensional_end_to_end_length_per_link(nondimensional_force)
     }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force), nondimensional_force)
+    }
+    /// The applied force as a function of the expected end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        force(&self.number_of_links, &self.link_length, end_to_end_length, temperature)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link.
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        nondimensional_force(nondimensional_end_to_end_length_per_link)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link), nondimensional_end_to_end_length_per_link)
+    }
     /// The Gibbs free energy as a function of the applied force and temperature.
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
@@ -171,4 +252,34 @@ impl Ideal
     {
         nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force)
     }
+    /// The compliance as a function of the applied force and temperature.
+    pub fn compliance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        compliance(&self.number_of_links, &self.link_length, force, temperature)
+    }
+    /// The compliance per link as a function of the applied force and temperature.
+    pub fn compliance_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        compliance_per_link(&self.link_length, force, temperature)
+    }
+    /// The nondimensional compliance as a function of the applied nondimensional force.
+    pub fn nondimensional_compliance(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_compliance(&self.number_of_links, nondimensional_force)
+    }
+    /// The nondimensional compliance per link as a function of the applied nondimensional force.
+    pub fn nondimensional_compliance_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_compliance_per_link(nondimensional_force)
+    }
+    /// The variance of the end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length_variance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        end_to_end_length_variance(&self.number_of_links, &self.link_length, force, temperature)
+    }
+    /// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force.
+    pub fn nondimensional_end_to_end_length_variance(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_end_to_end_length_variance(&self.number_of_links, nondimensional_force)
+    }
 }
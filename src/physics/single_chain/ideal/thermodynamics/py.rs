@@ -40,15 +40,18 @@ pub struct Ideal
 impl Ideal
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> PyResult<Self>
     {
-        Ideal
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        Ok(Ideal
         {
             hinge_mass,
             link_length,
             number_of_links,
-            isometric: super::isometric::py::Ideal::init(number_of_links, link_length, hinge_mass),
-            isotensional: super::isotensional::py::Ideal::init(number_of_links, link_length, hinge_mass)
-        }
+            isometric: super::isometric::py::Ideal::init(number_of_links, link_length, hinge_mass)?,
+            isotensional: super::isotensional::py::Ideal::init(number_of_links, link_length, hinge_mass)?
+        })
     }
 }
@@ -0,0 +1,52 @@
+mod test;
+
+use crate::error::Error;
+use super::
+{
+    derivative,
+    inversion
+};
+use crate::physics::single_chain::ZERO;
+
+/// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, given the nondimensional Gibbs free energy per link.
+pub fn nondimensional_end_to_end_length_per_link(nondimensional_gibbs_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_force: &f64) -> f64
+{
+    -derivative(nondimensional_gibbs_free_energy_per_link, nondimensional_force)
+}
+
+/// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, given the nondimensional Gibbs free energy per link.
+pub fn nondimensional_force(nondimensional_gibbs_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_force(nondimensional_gibbs_free_energy_per_link, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, given the nondimensional Gibbs free energy per link, or an error if the nondimensional force cannot be found.
+pub fn try_nondimensional_force(nondimensional_gibbs_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    inversion(&|nondimensional_force: &f64| self::nondimensional_end_to_end_length_per_link(nondimensional_gibbs_free_energy_per_link, nondimensional_force), nondimensional_end_to_end_length_per_link)
+}
+
+/// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, given the nondimensional Gibbs free energy per link.
+pub fn nondimensional_helmholtz_free_energy_per_link(nondimensional_gibbs_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_helmholtz_free_energy_per_link(nondimensional_gibbs_free_energy_per_link, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, given the nondimensional Gibbs free energy per link, or an error if the nondimensional force cannot be found.
+pub fn try_nondimensional_helmholtz_free_energy_per_link(nondimensional_gibbs_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    let nondimensional_force = try_nondimensional_force(nondimensional_gibbs_free_energy_per_link, nondimensional_end_to_end_length_per_link)?;
+    Ok(nondimensional_gibbs_free_energy_per_link(&nondimensional_force) + nondimensional_force*nondimensional_end_to_end_length_per_link)
+}
+
+/// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, given the nondimensional Gibbs free energy per link.
+pub fn nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_gibbs_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_gibbs_free_energy_per_link, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, given the nondimensional Gibbs free energy per link, or an error if the nondimensional force cannot be found.
+pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_gibbs_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy_per_link(nondimensional_gibbs_free_energy_per_link, nondimensional_end_to_end_length_per_link)? - try_nondimensional_helmholtz_free_energy_per_link(nondimensional_gibbs_free_energy_per_link, &ZERO)?)
+}
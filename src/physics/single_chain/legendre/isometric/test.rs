@@ -0,0 +1,68 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod fjc
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::fjc::thermodynamics::
+    {
+        isotensional::nondimensional_relative_gibbs_free_energy_per_link,
+        isometric::legendre
+    };
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = super::nondimensional_force(&nondimensional_relative_gibbs_free_energy_per_link, &nondimensional_end_to_end_length_per_link);
+            let nondimensional_force_fjc = legendre::nondimensional_force(&nondimensional_end_to_end_length_per_link);
+            let residual_abs = &nondimensional_force - &nondimensional_force_fjc;
+            let residual_rel = &residual_abs/&nondimensional_force_fjc;
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn nondimensional_relative_helmholtz_free_energy_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_relative_helmholtz_free_energy_per_link = super::nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_relative_gibbs_free_energy_per_link, &nondimensional_end_to_end_length_per_link);
+            let nondimensional_relative_helmholtz_free_energy_per_link_fjc = legendre::nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link);
+            let residual_abs = &nondimensional_relative_helmholtz_free_energy_per_link - &nondimensional_relative_helmholtz_free_energy_per_link_fjc;
+            let residual_rel = &residual_abs/&nondimensional_relative_helmholtz_free_energy_per_link_fjc;
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod connection
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::ufjc::morse::thermodynamics::isotensional::asymptotic::nondimensional_relative_gibbs_free_energy_per_link;
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let nondimensional_link_stiffness = parameters.nondimensional_link_stiffness_big*(1.0 + 0.5*rng.gen::<f64>());
+            let nondimensional_link_energy = parameters.nondimensional_link_stiffness_big*(1.0 + 0.5*rng.gen::<f64>());
+            let nondimensional_gibbs_free_energy_per_link = |nondimensional_force: &f64| nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_link_stiffness, &nondimensional_link_energy, nondimensional_force);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = super::nondimensional_force(&nondimensional_gibbs_free_energy_per_link, &nondimensional_end_to_end_length_per_link);
+            let h = parameters.rel_tol;
+            let nondimensional_force_from_derivative = (super::nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_gibbs_free_energy_per_link, &(nondimensional_end_to_end_length_per_link + 0.5*h)) - super::nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_gibbs_free_energy_per_link, &(nondimensional_end_to_end_length_per_link - 0.5*h)))/h;
+            let residual_abs = &nondimensional_force - &nondimensional_force_from_derivative;
+            let residual_rel = &residual_abs/&nondimensional_force;
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
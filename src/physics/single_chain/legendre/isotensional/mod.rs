@@ -0,0 +1,52 @@
+mod test;
+
+use crate::error::Error;
+use super::
+{
+    derivative,
+    inversion
+};
+use crate::physics::single_chain::ZERO;
+
+/// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, given the nondimensional Helmholtz free energy per link.
+pub fn nondimensional_force(nondimensional_helmholtz_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    derivative(nondimensional_helmholtz_free_energy_per_link, nondimensional_end_to_end_length_per_link)
+}
+
+/// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, given the nondimensional Helmholtz free energy per link.
+pub fn nondimensional_end_to_end_length_per_link(nondimensional_helmholtz_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_end_to_end_length_per_link(nondimensional_helmholtz_free_energy_per_link, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, given the nondimensional Helmholtz free energy per link, or an error if the nondimensional end-to-end length per link cannot be found.
+pub fn try_nondimensional_end_to_end_length_per_link(nondimensional_helmholtz_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    inversion(&|nondimensional_end_to_end_length_per_link: &f64| self::nondimensional_force(nondimensional_helmholtz_free_energy_per_link, nondimensional_end_to_end_length_per_link), nondimensional_force)
+}
+
+/// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force, given the nondimensional Helmholtz free energy per link.
+pub fn nondimensional_gibbs_free_energy_per_link(nondimensional_helmholtz_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_gibbs_free_energy_per_link(nondimensional_helmholtz_free_energy_per_link, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force, given the nondimensional Helmholtz free energy per link, or an error if the nondimensional end-to-end length per link cannot be found.
+pub fn try_nondimensional_gibbs_free_energy_per_link(nondimensional_helmholtz_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    let nondimensional_end_to_end_length_per_link = try_nondimensional_end_to_end_length_per_link(nondimensional_helmholtz_free_energy_per_link, nondimensional_force)?;
+    Ok(nondimensional_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link) - nondimensional_force*nondimensional_end_to_end_length_per_link)
+}
+
+/// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force, given the nondimensional Helmholtz free energy per link.
+pub fn nondimensional_relative_gibbs_free_energy_per_link(nondimensional_helmholtz_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_helmholtz_free_energy_per_link, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force, given the nondimensional Helmholtz free energy per link, or an error if the nondimensional end-to-end length per link cannot be found.
+pub fn try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_helmholtz_free_energy_per_link: &dyn Fn(&f64) -> f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_gibbs_free_energy_per_link(nondimensional_helmholtz_free_energy_per_link, nondimensional_force)? - try_nondimensional_gibbs_free_energy_per_link(nondimensional_helmholtz_free_energy_per_link, &ZERO)?)
+}
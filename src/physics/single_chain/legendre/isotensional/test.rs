@@ -0,0 +1,69 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod fjc
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::fjc::thermodynamics::
+    {
+        isotensional,
+        isometric::legendre::nondimensional_relative_helmholtz_free_energy_per_link
+    };
+    #[test]
+    fn nondimensional_end_to_end_length_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = super::nondimensional_end_to_end_length_per_link(&nondimensional_relative_helmholtz_free_energy_per_link, &nondimensional_force);
+            let nondimensional_end_to_end_length_per_link_fjc = isotensional::nondimensional_end_to_end_length_per_link(&nondimensional_force);
+            let residual_abs = &nondimensional_end_to_end_length_per_link - &nondimensional_end_to_end_length_per_link_fjc;
+            let residual_rel = &residual_abs/&nondimensional_end_to_end_length_per_link_fjc;
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn nondimensional_relative_gibbs_free_energy_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_relative_gibbs_free_energy_per_link = super::nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_relative_helmholtz_free_energy_per_link, &nondimensional_force);
+            let nondimensional_relative_gibbs_free_energy_per_link_fjc = isotensional::nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force);
+            let residual_abs = &nondimensional_relative_gibbs_free_energy_per_link - &nondimensional_relative_gibbs_free_energy_per_link_fjc;
+            let residual_rel = &residual_abs/&nondimensional_relative_gibbs_free_energy_per_link_fjc;
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod round_trip
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::
+    {
+        legendre::isometric,
+        fjc::thermodynamics::isotensional
+    };
+    #[test]
+    fn nondimensional_relative_gibbs_free_energy_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_relative_helmholtz_free_energy_per_link = |nondimensional_end_to_end_length_per_link: &f64| isometric::nondimensional_relative_helmholtz_free_energy_per_link(&isotensional::nondimensional_relative_gibbs_free_energy_per_link, nondimensional_end_to_end_length_per_link);
+            let nondimensional_relative_gibbs_free_energy_per_link_round_trip = super::nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_relative_helmholtz_free_energy_per_link, &nondimensional_force);
+            let nondimensional_relative_gibbs_free_energy_per_link_exact = isotensional::nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force);
+            let residual_abs = &nondimensional_relative_gibbs_free_energy_per_link_round_trip - &nondimensional_relative_gibbs_free_energy_per_link_exact;
+            let residual_rel = &residual_abs/&nondimensional_relative_gibbs_free_energy_per_link_exact;
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
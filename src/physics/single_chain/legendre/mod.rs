@@ -0,0 +1,36 @@
+/// Legendre transformations from the isotensional ensemble to the isometric ensemble.
+pub mod isometric;
+
+/// Legendre transformations from the isometric ensemble to the isotensional ensemble.
+pub mod isotensional;
+
+use crate::error::Error;
+use crate::math::
+{
+    bracket,
+    inverse_brent
+};
+use crate::physics::single_chain::INVERSION_TOLERANCE;
+
+/// The derivative of a function, calculated using a central difference.
+fn derivative(function: &dyn Fn(&f64) -> f64, argument: &f64) -> f64
+{
+    let step = f64::EPSILON.cbrt()*argument.abs().max(1.0);
+    (function(&(argument + step)) - function(&(argument - step)))/(2.0*step)
+}
+
+/// The argument for which an odd and increasing function attains a given value, calculated by bracketing the solution and refining it using Brent's method,
+/// or an error if the solution cannot be bracketed.
+fn inversion(function: &dyn Fn(&f64) -> f64, value: &f64) -> Result<f64, Error>
+{
+    if value == &0.0
+    {
+        return Ok(0.0)
+    }
+    if value < &0.0
+    {
+        return Ok(-inversion(function, &(-value))?)
+    }
+    let (lower_bound, upper_bound) = bracket(value, function, &0.0, &1.0, &f64::INFINITY, &100)?;
+    Ok(inverse_brent(value, function, &lower_bound, &upper_bound, &INVERSION_TOLERANCE, &100)?)
+}
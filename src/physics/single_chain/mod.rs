@@ -18,6 +18,9 @@ pub mod swfjc;
 /// The arbitrary link potential freely-jointed chain (uFJC) single-chain model.
 pub mod ufjc;
 
+/// Numerical Legendre transformations between the isometric and isotensional ensembles.
+pub mod legendre;
+
 /// The freely-rotating chain (FRC) single-chain model.
 pub mod frc;
 
@@ -27,6 +30,118 @@ pub mod efrc;
 /// The worm-like chain (WLC) single-chain model.
 pub mod wlc;
 
+use crate::error::Error;
+use crate::math::
+{
+    bracket,
+    try_integrate_1d_gauss_kronrod,
+    try_integrate_1d_tanh_sinh,
+    inverse_brent
+};
+
 static ONE: f64 = 1.0;
 static ZERO: f64 = 1e-6;
 static POINTS: u128 = 64;
+static INVERSION_TOLERANCE: f64 = 1e-12;
+static INTEGRATION_TOLERANCE: f64 = 1e-12;
+
+/// The force law of a single-chain model, relating the nondimensional force to the nondimensional end-to-end length per link in the isometric ensemble, which the network constitutive models use for each chain.
+pub trait ForceLaw
+{
+    /// The number of links in the chain.
+    fn number_of_links(&self) -> u8;
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link and temperature.
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64;
+    /// The nondimensional root-mean-square end-to-end length per link of the chain in the absence of force.
+    fn nondimensional_reference_end_to_end_length_per_link(&self) -> f64
+    {
+        1.0/(self.number_of_links() as f64).sqrt()
+    }
+    /// The nondimensional Helmholtz free energy per link relative to the origin as a function of the applied nondimensional end-to-end length per link and temperature, which by default is obtained by integrating the force law, and is not a number if the integral cannot be evaluated.
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        integrate_singular(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature), &0.0, nondimensional_end_to_end_length_per_link, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link and temperature, up to normalization, which by default is the exponential of the negative nondimensional relative Helmholtz free energy.
+    fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        (-(self.number_of_links() as f64)*self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)).exp()
+    }
+}
+
+impl<T: ForceLaw + ?Sized> ForceLaw for Box<T>
+{
+    fn number_of_links(&self) -> u8
+    {
+        (**self).number_of_links()
+    }
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        (**self).nondimensional_force(nondimensional_end_to_end_length_per_link, temperature)
+    }
+    fn nondimensional_reference_end_to_end_length_per_link(&self) -> f64
+    {
+        (**self).nondimensional_reference_end_to_end_length_per_link()
+    }
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        (**self).nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    }
+    fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        (**self).nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link, temperature)
+    }
+}
+
+/// Adaptive Gauss-Kronrod quadrature of an integrand to the relative tolerance set for the single-chain models.
+#[cfg(test)]
+fn integrate(integrand: &dyn Fn(&f64) -> f64, lower_bound: &f64, upper_bound: &f64) -> f64
+{
+    try_integrate(integrand, lower_bound, upper_bound, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// Adaptive Gauss-Kronrod quadrature of an integrand to a given relative tolerance,
+/// or an error if the integrand is undefined or the error estimate cannot be brought within the tolerance.
+fn try_integrate(integrand: &dyn Fn(&f64) -> f64, lower_bound: &f64, upper_bound: &f64, tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_integrate_1d_gauss_kronrod(integrand, lower_bound, upper_bound, &0.0, tolerance)?.0)
+}
+
+/// Adaptive tanh-sinh quadrature of an integrand with singularities at the bounds to a given relative tolerance,
+/// or an error if the integrand is undefined or the error estimate cannot be brought within the tolerance.
+fn integrate_singular(integrand: &dyn Fn(&f64) -> f64, lower_bound: &f64, upper_bound: &f64, tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_integrate_1d_tanh_sinh(integrand, lower_bound, upper_bound, &0.0, tolerance)?.0)
+}
+
+/// Inverts an odd and monotonically increasing function that vanishes at the origin, such as the nondimensional force as a function of the nondimensional end-to-end length per link, where the solution is bracketed between the origin and the maximum of the domain before being refined using Brent's method,
+/// or an error if the solution cannot be bracketed or refined.
+fn inverse(target: &f64, function: &dyn Fn(&f64) -> f64, maximum: &f64) -> Result<f64, Error>
+{
+    if target == &0.0
+    {
+        return Ok(0.0)
+    }
+    if target < &0.0
+    {
+        return Ok(-inverse(&(-target), function, maximum)?)
+    }
+    let function_vanishing_at_origin = |argument: &f64| if argument == &0.0
+    {
+        0.0
+    }
+    else
+    {
+        function(argument)
+    };
+    let upper = if maximum.is_finite()
+    {
+        0.5*maximum
+    }
+    else
+    {
+        1.0
+    };
+    let (lower, upper) = bracket(target, &function_vanishing_at_origin, &0.0, &upper, maximum, &1000)?;
+    Ok(inverse_brent(target, &function_vanishing_at_origin, &lower, &upper, &INVERSION_TOLERANCE, &100)?)
+}
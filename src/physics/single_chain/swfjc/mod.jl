@@ -4,6 +4,7 @@ The square-well freely-jointed chain (SWFJC) single-chain model.
 module Swfjc
 
 using DocStringExtensions
+using ....Polymers: validate_number_of_links, validate_positive
 
 include("thermodynamics/mod.jl")
 
@@ -46,6 +47,10 @@ function SWFJC(
     hinge_mass::Float64,
     well_width::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("well_width", well_width)
     return SWFJC(
         number_of_links,
         link_length,
@@ -3,6 +3,12 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
 /// The square-well freely-jointed chain (SWFJC) model thermodynamics.
 pub mod thermodynamics;
 
@@ -25,6 +31,9 @@ pub struct SWFJC
     pub thermodynamics: thermodynamics::SWFJC
 }
 
+#[cfg(feature = "serde")]
+crate::config::parameters!(SWFJC, try_init, number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64);
+
 /// The implemented functionality of the SWFJC model.
 impl SWFJC
 {
@@ -40,4 +49,34 @@ impl SWFJC
             thermodynamics: self::thermodynamics::SWFJC::init(number_of_links, link_length, hinge_mass, well_width),
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("well_width", &well_width)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, well_width))
+    }
+}
+
+/// The force law of the SWFJC model, using the isometric ensemble approximated using a Legendre transformation.
+impl super::ForceLaw for SWFJC
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.legendre.nondimensional_force(nondimensional_end_to_end_length_per_link)
+    }
+    fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.legendre.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link)
+    }
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.legendre.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link)
+    }
 }
@@ -38,15 +38,19 @@ pub struct SWFJC
 impl SWFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> PyResult<Self>
     {
-        SWFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("well_width", &well_width)?;
+        Ok(SWFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             well_width,
-            thermodynamics: super::thermodynamics::py::SWFJC::init(number_of_links, link_length, hinge_mass, well_width)
-        }
+            thermodynamics: super::thermodynamics::py::SWFJC::init(number_of_links, link_length, hinge_mass, well_width)?
+        })
     }
 }
@@ -5,7 +5,7 @@ module Legendre
 
 using DocStringExtensions
 using Polymers_jll
-using .......Polymers: PROJECT_ROOT
+using .......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 using .....SingleChain: ONE, ZERO, POINTS, integrate
 
 """
@@ -667,6 +667,10 @@ function SWFJC(
     hinge_mass::Float64,
     well_width::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("well_width", well_width)
     normalization_nondimensional_equilibrium_distribution = integrate(
         nondimensional_end_to_end_length_per_link ->
             nondimensional_equilibrium_radial_distribution(
@@ -6,13 +6,12 @@ pub mod py;
 
 mod test;
 
-use std::f64::consts::PI;
-use crate::math::
+use crate::error::
 {
-    inverse_langevin,
-    inverse_newton_raphson,
-    integrate_1d
+    self,
+    Error
 };
+use std::f64::consts::PI;
 use crate::physics::
 {
     PLANCK_CONSTANT,
@@ -22,7 +21,9 @@ use crate::physics::single_chain::
 {
     ONE,
     ZERO,
-    POINTS
+    INTEGRATION_TOLERANCE,
+    try_integrate,
+    inverse
 };
 
 /// The structure of the thermodynamics of the SWFJC model in the isometric ensemble approximated using a Legendre transformation.
@@ -46,107 +47,231 @@ pub struct SWFJC
 /// The expected force as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and well width.
 pub fn force(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature/link_length*nondimensional_force(link_length, well_width, &(end_to_end_length/((*number_of_links as f64)*link_length)))
+    try_force(number_of_links, link_length, well_width, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The expected force as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and well width, or an error if the inversion does not converge.
+pub fn try_force(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length*try_nondimensional_force(link_length, well_width, &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
 }
 
 /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, parameterized by the link length and well width.
 pub fn nondimensional_force(link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_force(link_length, well_width, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, parameterized by the link length and well width, or an error if the inversion does not converge.
+pub fn try_nondimensional_force(link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
 {
     let nondimensional_well_parameter = 1.0 + well_width/link_length;
     if nondimensional_end_to_end_length_per_link <= &1e-3
     {
-        nondimensional_end_to_end_length_per_link*5.0*(nondimensional_well_parameter.powi(2) + nondimensional_well_parameter + 1.0)/(nondimensional_well_parameter.powi(4) + nondimensional_well_parameter.powi(3) + nondimensional_well_parameter.powi(2) + nondimensional_well_parameter + 1.0)
+        Ok(nondimensional_end_to_end_length_per_link*5.0*(nondimensional_well_parameter.powi(2) + nondimensional_well_parameter + 1.0)/(nondimensional_well_parameter.powi(4) + nondimensional_well_parameter.powi(3) + nondimensional_well_parameter.powi(2) + nondimensional_well_parameter + 1.0))
     }
     else
     {
-        let guess: f64 = if nondimensional_end_to_end_length_per_link/nondimensional_well_parameter < 0.9
-        {
-            inverse_langevin(&(nondimensional_end_to_end_length_per_link/nondimensional_well_parameter))
-        }
-        else
-        {
-            10.0
-        };
-        inverse_newton_raphson(nondimensional_end_to_end_length_per_link, &|nondimensional_force: &f64| (nondimensional_well_parameter.powi(2)*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.sinh())/(nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - (nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()) - 3.0/nondimensional_force, &|nondimensional_force: &f64| (nondimensional_force.sinh() + nondimensional_force*nondimensional_force.cosh() - nondimensional_well_parameter.powi(3)*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - nondimensional_well_parameter.powi(2)*(nondimensional_well_parameter*nondimensional_force).sinh())/((nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - nondimensional_force.sinh() + nondimensional_force*nondimensional_force.cosh()) - (nondimensional_force.powi(2)*(nondimensional_force.sinh() - nondimensional_well_parameter.powi(2)*(nondimensional_well_parameter*nondimensional_force).sinh()).powi(2))/((nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - nondimensional_force.sinh() + nondimensional_force*nondimensional_force.cosh()).powi(2) + 3.0/nondimensional_force.powi(2), &guess, &1e-6, &100)
+        inverse(nondimensional_end_to_end_length_per_link, &|nondimensional_force: &f64| (nondimensional_well_parameter.powi(2)*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.sinh())/(nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - (nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()) - 3.0/nondimensional_force, &f64::INFINITY)
     }
 }
 
 /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, well width, and hinge mass.
 pub fn helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, well_width, &(end_to_end_length/((*number_of_links as f64)*link_length)), temperature)*BOLTZMANN_CONSTANT*temperature
+    try_helmholtz_free_energy(number_of_links, link_length, hinge_mass, well_width, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, well width, and hinge mass, or an error if the inversion does not converge.
+pub fn try_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, well_width, &(end_to_end_length/((*number_of_links as f64)*link_length)), temperature)?*BOLTZMANN_CONSTANT*temperature)
 }
 
 /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, well width, and hinge mass.
 pub fn helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, well_width, &(end_to_end_length/((*number_of_links as f64)*link_length)), temperature)*BOLTZMANN_CONSTANT*temperature
+    try_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, well_width, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, well width, and hinge mass, or an error if the inversion does not converge.
+pub fn try_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, well_width, &(end_to_end_length/((*number_of_links as f64)*link_length)), temperature)?*BOLTZMANN_CONSTANT*temperature)
 }
 
 /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and well width.
 pub fn relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    helmholtz_free_energy(number_of_links, link_length, &1.0, well_width, end_to_end_length, temperature) - helmholtz_free_energy(number_of_links, link_length, &1.0, well_width, &(ZERO*(*number_of_links as f64)*link_length), temperature)
+    try_relative_helmholtz_free_energy(number_of_links, link_length, well_width, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and well width, or an error if the inversion does not converge.
+pub fn try_relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(try_helmholtz_free_energy(number_of_links, link_length, &1.0, well_width, end_to_end_length, temperature)? - try_helmholtz_free_energy(number_of_links, link_length, &1.0, well_width, &(ZERO*(*number_of_links as f64)*link_length), temperature)?)
 }
 
 /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and well width.
 pub fn relative_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
-    helmholtz_free_energy_per_link(number_of_links, link_length, &1.0, well_width, end_to_end_length, temperature) - helmholtz_free_energy_per_link(number_of_links, link_length, &1.0, well_width, &(ZERO*(*number_of_links as f64)*link_length), temperature)
+    try_relative_helmholtz_free_energy_per_link(number_of_links, link_length, well_width, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and well width, or an error if the inversion does not converge.
+pub fn try_relative_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(try_helmholtz_free_energy_per_link(number_of_links, link_length, &1.0, well_width, end_to_end_length, temperature)? - try_helmholtz_free_energy_per_link(number_of_links, link_length, &1.0, well_width, &(ZERO*(*number_of_links as f64)*link_length), temperature)?)
 }
 
 /// The nondimensional Helmholtz free energy as a function of the nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, well width, and hinge mass.
 pub fn nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
 {
-    (*number_of_links as f64)*nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, well_width, nondimensional_end_to_end_length_per_link, temperature)
+    try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, well_width, nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy as a function of the nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, well width, and hinge mass, or an error if the inversion does not converge.
+pub fn try_nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, well_width, nondimensional_end_to_end_length_per_link, temperature)?)
 }
 
 /// The nondimensional Helmholtz free energy per link as a function of the nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, well width, and hinge mass.
 pub fn nondimensional_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+{
+    try_nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, well_width, nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy per link as a function of the nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, well width, and hinge mass, or an error if the inversion does not converge.
+pub fn try_nondimensional_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
 {
     let nondimensional_well_parameter = 1.0 + well_width/link_length;
-    let nondimensional_force = nondimensional_force(link_length, well_width, nondimensional_end_to_end_length_per_link);
-    nondimensional_force**nondimensional_end_to_end_length_per_link + 3.0*nondimensional_force.ln() - (nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - (nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()).ln() - (1.0 - 1.0/(*number_of_links as f64))*(8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+    let nondimensional_force = try_nondimensional_force(link_length, well_width, nondimensional_end_to_end_length_per_link)?;
+    Ok(nondimensional_force**nondimensional_end_to_end_length_per_link + 3.0*nondimensional_force.ln() - (nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - (nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()).ln() - (1.0 - 1.0/(*number_of_links as f64))*(8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
 }
 
 /// The nondimensional relative Helmholtz free energy as a function of the nondimensional end-to-end length per link, parameterized by the number of links, link length, and well width.
 pub fn nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy(number_of_links, link_length, &1.0, well_width, nondimensional_end_to_end_length_per_link, &300.0) - nondimensional_helmholtz_free_energy(number_of_links, link_length, &1.0, well_width, &ZERO, &300.0)
+    try_nondimensional_relative_helmholtz_free_energy(number_of_links, link_length, well_width, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy as a function of the nondimensional end-to-end length per link, parameterized by the number of links, link length, and well width, or an error if the inversion does not converge.
+pub fn try_nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy(number_of_links, link_length, &1.0, well_width, nondimensional_end_to_end_length_per_link, &300.0)? - try_nondimensional_helmholtz_free_energy(number_of_links, link_length, &1.0, well_width, &ZERO, &300.0)?)
 }
 
 /// The nondimensional relative Helmholtz free energy per link as a function of the nondimensional end-to-end length per link, parameterized by the link length and well width.
 pub fn nondimensional_relative_helmholtz_free_energy_per_link(link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy_per_link(&8, link_length, &1.0, well_width, nondimensional_end_to_end_length_per_link, &300.0) - nondimensional_helmholtz_free_energy_per_link(&8, link_length, &1.0, well_width, &ZERO, &300.0)
+    try_nondimensional_relative_helmholtz_free_energy_per_link(link_length, well_width, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy per link as a function of the nondimensional end-to-end length per link, parameterized by the link length and well width, or an error if the inversion does not converge.
+pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy_per_link(&8, link_length, &1.0, well_width, nondimensional_end_to_end_length_per_link, &300.0)? - try_nondimensional_helmholtz_free_energy_per_link(&8, link_length, &1.0, well_width, &ZERO, &300.0)?)
 }
 
 /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length, parameterized by the number of links and link length.
 pub fn equilibrium_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64, normalization_nondimensional_equilibrium_distribution: &f64, end_to_end_length: &f64) -> f64
+{
+    try_equilibrium_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, end_to_end_length).unwrap_or(f64::NAN)
+}
+
+/// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length, parameterized by the number of links and link length, or an error if the inversion does not converge.
+pub fn try_equilibrium_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64, normalization_nondimensional_equilibrium_distribution: &f64, end_to_end_length: &f64) -> Result<f64, Error>
 {
     let contour_length = (*number_of_links as f64)*link_length;
-    nondimensional_equilibrium_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, &(end_to_end_length/contour_length))/contour_length.powi(3)
+    Ok(try_nondimensional_equilibrium_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, &(end_to_end_length/contour_length))?/contour_length.powi(3))
 }
 
 /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, parameterized by the number of links.
 pub fn nondimensional_equilibrium_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64, normalization_nondimensional_equilibrium_distribution: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_equilibrium_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, parameterized by the number of links, or an error if the inversion does not converge.
+pub fn try_nondimensional_equilibrium_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64, normalization_nondimensional_equilibrium_distribution: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
 {
     let nondimensional_well_parameter = 1.0 + well_width/link_length;
-    let nondimensional_force = nondimensional_force(link_length, well_width, nondimensional_end_to_end_length_per_link);
-    ((nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - (nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh())/nondimensional_force.powi(3)*(-nondimensional_force**nondimensional_end_to_end_length_per_link).exp()).powi(*number_of_links as i32)/normalization_nondimensional_equilibrium_distribution
+    let nondimensional_force = try_nondimensional_force(link_length, well_width, nondimensional_end_to_end_length_per_link)?;
+    if nondimensional_well_parameter*nondimensional_force < 1e2
+    {
+        Ok(((nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - (nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh())/nondimensional_force.powi(3)*(-nondimensional_force**nondimensional_end_to_end_length_per_link).exp()).powi(*number_of_links as i32)/normalization_nondimensional_equilibrium_distribution)
+    }
+    else
+    {
+        let exponential = (-2.0*nondimensional_well_parameter*nondimensional_force).exp();
+        let bracket = nondimensional_well_parameter*nondimensional_force*(1.0 + exponential) - (1.0 - exponential) - nondimensional_force*(((1.0 - nondimensional_well_parameter)*nondimensional_force).exp() + (-(1.0 + nondimensional_well_parameter)*nondimensional_force).exp()) + ((1.0 - nondimensional_well_parameter)*nondimensional_force).exp() - (-(1.0 + nondimensional_well_parameter)*nondimensional_force).exp();
+        Ok(((*number_of_links as f64)*(nondimensional_force*(nondimensional_well_parameter - nondimensional_end_to_end_length_per_link) + (0.5*bracket).ln() - 3.0*nondimensional_force.ln())).exp()/normalization_nondimensional_equilibrium_distribution)
+    }
 }
 
 /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length, parameterized by the number of links and link length.
 pub fn equilibrium_radial_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64, normalization_nondimensional_equilibrium_distribution: &f64, end_to_end_length: &f64) -> f64
+{
+    try_equilibrium_radial_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, end_to_end_length).unwrap_or(f64::NAN)
+}
+
+/// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length, parameterized by the number of links and link length, or an error if the inversion does not converge.
+pub fn try_equilibrium_radial_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64, normalization_nondimensional_equilibrium_distribution: &f64, end_to_end_length: &f64) -> Result<f64, Error>
 {
     let contour_length = (*number_of_links as f64)*link_length;
-    nondimensional_equilibrium_radial_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, &(end_to_end_length/contour_length))/contour_length
+    Ok(try_nondimensional_equilibrium_radial_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, &(end_to_end_length/contour_length))?/contour_length)
 }
 
 /// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, parameterized by the number of links.
 pub fn nondimensional_equilibrium_radial_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64, normalization_nondimensional_equilibrium_distribution: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
 {
-    4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*nondimensional_equilibrium_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
+    try_nondimensional_equilibrium_radial_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, parameterized by the number of links, or an error if the inversion does not converge.
+pub fn try_nondimensional_equilibrium_radial_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64, normalization_nondimensional_equilibrium_distribution: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*try_nondimensional_equilibrium_distribution(number_of_links, link_length, well_width, normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)?)
+}
+
+/// The stiffness as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and well width.
+pub fn stiffness(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    try_stiffness(number_of_links, link_length, well_width, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The stiffness as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and well width, or an error if the inversion does not converge.
+pub fn try_stiffness(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length.powi(2)*try_nondimensional_stiffness(number_of_links, link_length, well_width, &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
+}
+
+/// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links, link length, and well width.
+pub fn nondimensional_stiffness(number_of_links: &u8, link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_stiffness(number_of_links, link_length, well_width, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links, link length, and well width, or an error if the inversion does not converge.
+pub fn try_nondimensional_stiffness(number_of_links: &u8, link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    let nondimensional_well_parameter = 1.0 + well_width/link_length;
+    if nondimensional_end_to_end_length_per_link <= &1e-3
+    {
+        Ok(5.0*(nondimensional_well_parameter.powi(2) + nondimensional_well_parameter + 1.0)/(nondimensional_well_parameter.powi(4) + nondimensional_well_parameter.powi(3) + nondimensional_well_parameter.powi(2) + nondimensional_well_parameter + 1.0)/(*number_of_links as f64))
+    }
+    else
+    {
+        let nondimensional_force = try_nondimensional_force(link_length, well_width, nondimensional_end_to_end_length_per_link)?;
+        Ok(1.0/(*number_of_links as f64)/((nondimensional_force.sinh() + nondimensional_force*nondimensional_force.cosh() - nondimensional_well_parameter.powi(3)*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - nondimensional_well_parameter.powi(2)*(nondimensional_well_parameter*nondimensional_force).sinh())/((nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - nondimensional_force.sinh() + nondimensional_force*nondimensional_force.cosh()) - (nondimensional_force.powi(2)*(nondimensional_force.sinh() - nondimensional_well_parameter.powi(2)*(nondimensional_well_parameter*nondimensional_force).sinh()).powi(2))/((nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - nondimensional_force.sinh() + nondimensional_force*nondimensional_force.cosh()).powi(2) + 3.0/nondimensional_force.powi(2)))
+    }
+}
+
+/// The normalization of the nondimensional equilibrium radial distribution, or an error if the numerical integration does not converge.
+fn normalization_nondimensional_equilibrium_distribution(number_of_links: &u8, link_length: &f64, well_width: &f64) -> Result<f64, Error>
+{
+    try_integrate(&|nondimensional_end_to_end_length_per_link: &f64| nondimensional_equilibrium_radial_distribution(number_of_links, link_length, well_width, &1.0, nondimensional_end_to_end_length_per_link), &0.0, &(ONE*(1.0 + well_width/link_length)), &INTEGRATION_TOLERANCE)
 }
 
 /// The implemented functionality of the thermodynamics of the SWFJC model in the isotensional ensemble approximated using a Legendre transformation.
@@ -155,8 +280,7 @@ impl SWFJC
     /// Initializes and returns an instance of the thermodynamics of the SWFJC model in the isotensional ensemble approximated using a Legendre transformation.
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> Self
     {
-        let nondimensional_well_parameter = 1.0 + well_width/link_length;
-        let normalization = integrate_1d(&|nondimensional_end_to_end_length_per_link: &f64| nondimensional_equilibrium_radial_distribution(&number_of_links, &link_length, &well_width, &1.0, nondimensional_end_to_end_length_per_link), &ZERO, &(ONE*nondimensional_well_parameter), &POINTS);
+        let normalization = normalization_nondimensional_equilibrium_distribution(&number_of_links, &link_length, &well_width).unwrap_or(f64::NAN);
         SWFJC
         {
             hinge_mass,
@@ -166,74 +290,185 @@ impl SWFJC
             normalization_nondimensional_equilibrium_distribution: normalization
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("well_width", &well_width)?;
+        Ok(SWFJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            well_width,
+            normalization_nondimensional_equilibrium_distribution: normalization_nondimensional_equilibrium_distribution(&number_of_links, &link_length, &well_width)?
+        })
+    }
     /// The expected force as a function of the applied end-to-end length and temperature.
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        force(&self.number_of_links, &self.link_length, &self.well_width, end_to_end_length, temperature)
+        self.try_force(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The expected force as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_force(&self.number_of_links, &self.link_length, &self.well_width, end_to_end_length, temperature)
     }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
-        nondimensional_force(&self.link_length, &self.well_width, nondimensional_end_to_end_length_per_link)
+        self.try_nondimensional_force(nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link), nondimensional_end_to_end_length_per_link)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_force(&self.link_length, &self.well_width, nondimensional_end_to_end_length_per_link)
     }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.well_width, end_to_end_length, temperature)
+        self.try_helmholtz_free_energy(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.well_width, end_to_end_length, temperature)
     }
     /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.well_width, end_to_end_length, temperature)
+        self.try_helmholtz_free_energy_per_link(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.well_width, end_to_end_length, temperature)
     }
     /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.well_width, end_to_end_length, temperature)
+        self.try_relative_helmholtz_free_energy(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.well_width, end_to_end_length, temperature)
     }
     /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.well_width, end_to_end_length, temperature)
+        self.try_relative_helmholtz_free_energy_per_link(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.well_width, end_to_end_length, temperature)
     }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.well_width, nondimensional_end_to_end_length_per_link, temperature)
+        self.try_nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the inversion does not converge.
+    pub fn try_nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.well_width, nondimensional_end_to_end_length_per_link, temperature)
     }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.well_width, nondimensional_end_to_end_length_per_link, temperature)
+        self.try_nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the inversion does not converge.
+    pub fn try_nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.well_width, nondimensional_end_to_end_length_per_link, temperature)
     }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
-        nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.well_width, nondimensional_end_to_end_length_per_link)
+        self.try_nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.well_width, nondimensional_end_to_end_length_per_link)
     }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
-        nondimensional_relative_helmholtz_free_energy_per_link(&self.link_length, &self.well_width, nondimensional_end_to_end_length_per_link)
+        self.try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_helmholtz_free_energy_per_link(&self.link_length, &self.well_width, nondimensional_end_to_end_length_per_link)
     }
     /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length.
     pub fn equilibrium_distribution(&self, end_to_end_length: &f64) -> f64
     {
-        equilibrium_distribution(&self.number_of_links, &self.link_length, &self.well_width, &self.normalization_nondimensional_equilibrium_distribution, end_to_end_length)
+        self.try_equilibrium_distribution(end_to_end_length).unwrap_or(f64::NAN)
+    }
+    /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length, or an error if the inversion does not converge.
+    pub fn try_equilibrium_distribution(&self, end_to_end_length: &f64) -> Result<f64, Error>
+    {
+        try_equilibrium_distribution(&self.number_of_links, &self.link_length, &self.well_width, &self.normalization_nondimensional_equilibrium_distribution, end_to_end_length)
     }
     /// The equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link.
     pub fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
-        nondimensional_equilibrium_distribution(&self.number_of_links, &self.link_length, &self.well_width, &self.normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
+        self.try_nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+    }
+    /// The equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_equilibrium_distribution(&self.number_of_links, &self.link_length, &self.well_width, &self.normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
     }
     /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length.
     pub fn equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> f64
     {
-        equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.well_width, &self.normalization_nondimensional_equilibrium_distribution, end_to_end_length)
+        self.try_equilibrium_radial_distribution(end_to_end_length).unwrap_or(f64::NAN)
+    }
+    /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length, or an error if the inversion does not converge.
+    pub fn try_equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> Result<f64, Error>
+    {
+        try_equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.well_width, &self.normalization_nondimensional_equilibrium_distribution, end_to_end_length)
     }
     /// The equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link.
     pub fn nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
-        nondimensional_equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.well_width, &self.normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
+        self.try_nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+    }
+    /// The equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.well_width, &self.normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
+    }
+    /// The stiffness as a function of the applied end-to-end length and temperature.
+    pub fn stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.try_stiffness(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The stiffness as a function of the applied end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_stiffness(&self.number_of_links, &self.link_length, &self.well_width, end_to_end_length, temperature)
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link.
+    pub fn nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        self.try_nondimensional_stiffness(nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_stiffness(&self.number_of_links, &self.link_length, &self.well_width, nondimensional_end_to_end_length_per_link)
     }
 }
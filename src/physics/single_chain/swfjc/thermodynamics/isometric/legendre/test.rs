@@ -1,6 +1,7 @@
 #![cfg(test)]
 use super::*;
 use crate::physics::single_chain::test::Parameters;
+use crate::physics::single_chain::swfjc::thermodynamics::isotensional::nondimensional_end_to_end_length_per_link as isotensional_nondimensional_end_to_end_length_per_link;
 mod base
 {
     use super::*;
@@ -79,6 +80,7 @@ mod normalization
     use super::*;
     use rand::Rng;
     use crate::math::integrate_1d;
+    use crate::physics::single_chain::POINTS;
     #[test]
     fn equilibrium_distribution()
     {
@@ -687,4 +689,54 @@ mod connection
             assert!(residual_rel.abs() <= parameters.rel_tol);
         }
     }
-}
\ No newline at end of file
+}
+mod fluctuation
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_stiffness()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let well_width = parameters.well_width_reference + parameters.well_width_scale*(0.5 - rng.gen::<f64>());
+            let model = SWFJC::init(number_of_links, link_length, hinge_mass, well_width);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_stiffness = model.nondimensional_stiffness(&nondimensional_end_to_end_length_per_link);
+            let nondimensional_force = model.nondimensional_force(&nondimensional_end_to_end_length_per_link);
+            let h = parameters.rel_tol;
+            let nondimensional_stiffness_from_derivative = h/(number_of_links as f64)/(isotensional_nondimensional_end_to_end_length_per_link(&link_length, &well_width, &(nondimensional_force + 0.5*h)) - isotensional_nondimensional_end_to_end_length_per_link(&link_length, &well_width, &(nondimensional_force - 0.5*h)));
+            let residual_abs = &nondimensional_stiffness - &nondimensional_stiffness_from_derivative;
+            let residual_rel = &residual_abs/&nondimensional_stiffness;
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+    #[test]
+    fn stiffness()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let well_width = parameters.well_width_reference + parameters.well_width_scale*(0.5 - rng.gen::<f64>());
+            let model = SWFJC::init(number_of_links, link_length, hinge_mass, well_width);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + 0.5*parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_stiffness = model.nondimensional_stiffness(&nondimensional_end_to_end_length_per_link);
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let stiffness = model.stiffness(&end_to_end_length, &temperature);
+            let residual_abs = &stiffness/BOLTZMANN_CONSTANT/temperature*link_length.powi(2) - &nondimensional_stiffness;
+            let residual_rel = &residual_abs/&nondimensional_stiffness;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
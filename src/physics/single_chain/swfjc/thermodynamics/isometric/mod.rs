@@ -3,6 +3,12 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
 /// The square-well freely-jointed chain (SWFJC) model thermodynamics in the isometric ensemble approximated using a Legendre transformation.
 pub mod legendre;
 
@@ -40,4 +46,13 @@ impl SWFJC
             legendre: self::legendre::SWFJC::init(number_of_links, link_length, hinge_mass, well_width)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("well_width", &well_width)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, well_width))
+    }
 }
@@ -5,7 +5,7 @@ module Legendre
 
 using DocStringExtensions
 using Polymers_jll
-using .......Polymers: PROJECT_ROOT
+using .......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 """
 The structure of the thermodynamics of the SWFJC model in the isotensional ensemble approximated using a Legendre transformation.
@@ -371,6 +371,10 @@ function SWFJC(
     hinge_mass::Float64,
     well_width::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("well_width", well_width)
     return SWFJC(
         number_of_links,
         link_length,
@@ -5,7 +5,7 @@ module Isotensional
 
 using DocStringExtensions
 using Polymers_jll
-using ......Polymers: PROJECT_ROOT
+using ......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 include("legendre/mod.jl")
 
@@ -542,6 +542,10 @@ function SWFJC(
     hinge_mass::Float64,
     well_width::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("well_width", well_width)
     return SWFJC(
         number_of_links,
         link_length,
@@ -9,13 +9,22 @@ mod test;
 /// The square-well freely-jointed chain (SWFJC) model thermodynamics in the isotensional ensemble approximated using a Legendre transformation.
 pub mod legendre;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use std::f64::consts::PI;
 use crate::physics::
 {
     PLANCK_CONSTANT,
     BOLTZMANN_CONSTANT
 };
-use crate::physics::single_chain::ZERO;
+use crate::physics::single_chain::
+{
+    ZERO,
+    inverse
+};
 
 /// The structure of the thermodynamics of the SWFJC model in the isotensional ensemble.
 pub struct SWFJC
@@ -66,6 +75,30 @@ pub fn nondimensional_end_to_end_length_per_link(link_length: &f64, well_width:
     (nondimensional_well_parameter.powi(2)*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.sinh())/(nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - (nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()) - 3.0/nondimensional_force
 }
 
+/// The applied force as a function of the expected end-to-end length and temperature, parameterized by the number of links, link length, and well width.
+pub fn force(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    try_force(number_of_links, link_length, well_width, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The applied force as a function of the expected end-to-end length and temperature, parameterized by the number of links, link length, and well width, or an error if the inversion does not converge.
+pub fn try_force(number_of_links: &u8, link_length: &f64, well_width: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length*try_nondimensional_force(link_length, well_width, &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
+}
+
+/// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, parameterized by the link length and well width.
+pub fn nondimensional_force(link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_force(link_length, well_width, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, parameterized by the link length and well width, or an error if the inversion does not converge.
+pub fn try_nondimensional_force(link_length: &f64, well_width: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    inverse(nondimensional_end_to_end_length_per_link, &|nondimensional_force: &f64| self::nondimensional_end_to_end_length_per_link(link_length, well_width, nondimensional_force), &f64::INFINITY)
+}
+
 /// The Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, hinge mass, and well width.
 pub fn gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, well_width: &f64, force: &f64, temperature: &f64) -> f64
 {
@@ -120,6 +153,46 @@ pub fn nondimensional_relative_gibbs_free_energy_per_link(link_length: &f64, wel
     nondimensional_gibbs_free_energy_per_link(link_length, &1.0, well_width, nondimensional_force, &300.0) - nondimensional_gibbs_free_energy_per_link(link_length, &1.0, well_width, &ZERO, &300.0)
 }
 
+/// The compliance as a function of the applied force and temperature, parameterized by the number of links, link length, and well width.
+pub fn compliance(number_of_links: &u8, link_length: &f64, well_width: &f64, force: &f64, temperature: &f64) -> f64
+{
+    link_length.powi(2)/BOLTZMANN_CONSTANT/temperature*nondimensional_compliance(number_of_links, link_length, well_width, &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+}
+
+/// The compliance per link as a function of the applied force and temperature, parameterized by the link length and well width.
+pub fn compliance_per_link(link_length: &f64, well_width: &f64, force: &f64, temperature: &f64) -> f64
+{
+    link_length.powi(2)/BOLTZMANN_CONSTANT/temperature*nondimensional_compliance_per_link(link_length, well_width, &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+}
+
+/// The nondimensional compliance as a function of the applied nondimensional force, parameterized by the number of links, link length, and well width.
+pub fn nondimensional_compliance(number_of_links: &u8, link_length: &f64, well_width: &f64, nondimensional_force: &f64) -> f64
+{
+    (*number_of_links as f64)*nondimensional_compliance_per_link(link_length, well_width, nondimensional_force)
+}
+
+/// The nondimensional compliance per link as a function of the applied nondimensional force, parameterized by the link length and well width.
+pub fn nondimensional_compliance_per_link(link_length: &f64, well_width: &f64, nondimensional_force: &f64) -> f64
+{
+    let nondimensional_well_parameter = 1.0 + well_width/link_length;
+    let numerator = nondimensional_well_parameter.powi(2)*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.sinh();
+    let denominator = nondimensional_well_parameter*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - (nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh();
+    let numerator_derivative = nondimensional_well_parameter.powi(2)*(nondimensional_well_parameter*nondimensional_force).sinh() + nondimensional_well_parameter.powi(3)*nondimensional_force*(nondimensional_well_parameter*nondimensional_force).cosh() - nondimensional_force.sinh() - nondimensional_force*nondimensional_force.cosh();
+    numerator_derivative/denominator - (numerator/denominator).powi(2) + 3.0/nondimensional_force.powi(2)
+}
+
+/// The variance of the end-to-end length as a function of the applied force and temperature, parameterized by the number of links, link length, and well width.
+pub fn end_to_end_length_variance(number_of_links: &u8, link_length: &f64, well_width: &f64, force: &f64, temperature: &f64) -> f64
+{
+    BOLTZMANN_CONSTANT*temperature*compliance(number_of_links, link_length, well_width, force, temperature)
+}
+
+/// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links, link length, and well width.
+pub fn nondimensional_end_to_end_length_variance(number_of_links: &u8, link_length: &f64, well_width: &f64, nondimensional_force: &f64) -> f64
+{
+    nondimensional_compliance(number_of_links, link_length, well_width, nondimensional_force)
+}
+
 /// The implemented functionality of the thermodynamics of the SWFJC model in the isotensional ensemble.
 impl SWFJC
 {
@@ -135,6 +208,15 @@ impl SWFJC
             legendre: self::legendre::SWFJC::init(number_of_links, link_length, hinge_mass, well_width)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("well_width", &well_width)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, well_width))
+    }
     /// The expected end-to-end length as a function of the applied force and temperature.
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
@@ -155,6 +237,36 @@ impl SWFJC
     {
         nondimensional_end_to_end_length_per_link(&self.link_length, &self.well_width, nondimensional_force)
     }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force), nondimensional_force)
+    }
+    /// The applied force as a function of the expected end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.try_force(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied force as a function of the expected end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_force(&self.number_of_links, &self.link_length, &self.well_width, end_to_end_length, temperature)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link.
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        self.try_nondimensional_force(nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link), nondimensional_end_to_end_length_per_link)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_force(&self.link_length, &self.well_width, nondimensional_end_to_end_length_per_link)
+    }
     /// The Gibbs free energy as a function of the applied force and temperature.
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
@@ -195,4 +307,34 @@ impl SWFJC
     {
         nondimensional_relative_gibbs_free_energy_per_link(&self.link_length, &self.well_width, nondimensional_force)
     }
+    /// The compliance as a function of the applied force and temperature.
+    pub fn compliance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        compliance(&self.number_of_links, &self.link_length, &self.well_width, force, temperature)
+    }
+    /// The compliance per link as a function of the applied force and temperature.
+    pub fn compliance_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        compliance_per_link(&self.link_length, &self.well_width, force, temperature)
+    }
+    /// The nondimensional compliance as a function of the applied nondimensional force.
+    pub fn nondimensional_compliance(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_compliance(&self.number_of_links, &self.link_length, &self.well_width, nondimensional_force)
+    }
+    /// The nondimensional compliance per link as a function of the applied nondimensional force.
+    pub fn nondimensional_compliance_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_compliance_per_link(&self.link_length, &self.well_width, nondimensional_force)
+    }
+    /// The variance of the end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length_variance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        end_to_end_length_variance(&self.number_of_links, &self.link_length, &self.well_width, force, temperature)
+    }
+    /// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force.
+    pub fn nondimensional_end_to_end_length_variance(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_end_to_end_length_variance(&self.number_of_links, &self.link_length, &self.well_width, nondimensional_force)
+    }
 }
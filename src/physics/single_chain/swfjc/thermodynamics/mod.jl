@@ -4,6 +4,7 @@ The square-well freely-jointed chain (SWFJC) model thermodynamics.
 module Thermodynamics
 
 using DocStringExtensions
+using .....Polymers: validate_number_of_links, validate_positive
 
 include("isometric/mod.jl")
 include("isotensional/mod.jl")
@@ -51,6 +52,10 @@ function SWFJC(
     hinge_mass::Float64,
     well_width::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("well_width", well_width)
     return SWFJC(
         number_of_links,
         link_length,
@@ -3,6 +3,12 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
 /// The square-well freely-jointed chain (SWFJC) model thermodynamics in the isometric ensemble.
 pub mod isometric;
 
@@ -47,4 +53,13 @@ impl SWFJC
             isotensional: self::isotensional::SWFJC::init(number_of_links, link_length, hinge_mass, well_width)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("well_width", &well_width)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, well_width))
+    }
 }
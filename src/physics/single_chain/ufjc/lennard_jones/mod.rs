@@ -3,6 +3,15 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
+/// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model rupture kinetics.
+pub mod rupture;
+
 /// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model thermodynamics.
 pub mod thermodynamics;
 
@@ -21,10 +30,16 @@ pub struct LENNARDJONESFJC
     /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
     pub link_stiffness: f64,
 
+    /// The rupture kinetics of the model.
+    pub rupture: self::rupture::LENNARDJONESFJC,
+
     /// The thermodynamic functions of the model.
     pub thermodynamics: self::thermodynamics::LENNARDJONESFJC
 }
 
+#[cfg(feature = "serde")]
+crate::config::parameters!(LENNARDJONESFJC, try_init, number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64);
+
 /// The implemented functionality of the Lennard-Jones-FJC model.
 impl LENNARDJONESFJC
 {
@@ -37,7 +52,34 @@ impl LENNARDJONESFJC
             link_length,
             number_of_links,
             link_stiffness,
+            rupture: self::rupture::LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness),
             thermodynamics: self::thermodynamics::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness),
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
+}
+
+/// The force law of the Lennard-Jones-FJC model, using the isometric ensemble approximated using a Legendre transformation.
+impl super::super::ForceLaw for LENNARDJONESFJC
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.asymptotic.legendre.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature)
+    }
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.asymptotic.legendre.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    }
 }
@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
 {
     let lennard_jones = PyModule::new(py, "lennard_jones")?;
+    super::rupture::py::register_module(py, lennard_jones)?;
     super::thermodynamics::py::register_module(py, lennard_jones)?;
     parent_module.add_submodule(lennard_jones)?;
     lennard_jones.add_class::<LENNARDJONESFJC>()?;
@@ -29,6 +30,10 @@ pub struct LENNARDJONESFJC
     #[pyo3(get)]
     pub link_stiffness: f64,
     
+    /// The rupture kinetics of the model.
+    #[pyo3(get)]
+    pub rupture: super::rupture::py::LENNARDJONESFJC,
+
     /// The thermodynamic functions of the model.
     #[pyo3(get)]
     pub thermodynamics: super::thermodynamics::py::LENNARDJONESFJC
@@ -38,15 +43,20 @@ pub struct LENNARDJONESFJC
 impl LENNARDJONESFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> PyResult<Self>
     {
-        LENNARDJONESFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(LENNARDJONESFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness,
-            thermodynamics: super::thermodynamics::py::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
-        }
+            rupture: super::rupture::py::LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness)?,
+            thermodynamics: super::thermodynamics::py::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)?
+        })
     }
 }
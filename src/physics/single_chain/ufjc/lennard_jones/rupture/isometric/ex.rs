@@ -0,0 +1,30 @@
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isometric_breaking_rate(number_of_links: u8, link_length: f64, link_stiffness: f64, end_to_end_length: f64, temperature: f64) -> f64
+{
+    super::breaking_rate(&number_of_links, &link_length, &link_stiffness, &end_to_end_length, &temperature).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isometric_nondimensional_breaking_rate(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_end_to_end_length_per_link: f64) -> f64
+{
+    super::nondimensional_breaking_rate(&number_of_links, &nondimensional_link_stiffness, &nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isometric_survival_probability(number_of_links: u8, link_length: f64, link_stiffness: f64, end_to_end_length: f64, time: f64, temperature: f64) -> f64
+{
+    super::survival_probability(&number_of_links, &link_length, &link_stiffness, &end_to_end_length, &time, &temperature).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isometric_nondimensional_survival_probability(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_end_to_end_length_per_link: f64, nondimensional_time: f64) -> f64
+{
+    super::nondimensional_survival_probability(&number_of_links, &nondimensional_link_stiffness, &nondimensional_end_to_end_length_per_link, &nondimensional_time).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isometric_most_probable_rupture_force(number_of_links: u8, link_length: f64, link_stiffness: f64, extension_rate: f64, temperature: f64) -> f64
+{
+    super::most_probable_rupture_force(&number_of_links, &link_length, &link_stiffness, &extension_rate, &temperature).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isometric_nondimensional_most_probable_rupture_force(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_extension_rate: f64) -> f64
+{
+    super::nondimensional_most_probable_rupture_force(&number_of_links, &nondimensional_link_stiffness, &nondimensional_extension_rate).unwrap_or(f64::NAN)
+}
@@ -0,0 +1,143 @@
+#[cfg(feature = "extern")]
+pub mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use super::
+{
+    nondimensional_force_max,
+    nondimensional_link_stretches,
+    nondimensional_link_breaking_rate
+};
+use crate::physics::
+{
+    PLANCK_CONSTANT,
+    BOLTZMANN_CONSTANT,
+    single_chain::
+    {
+        ZERO,
+        INVERSION_TOLERANCE
+    }
+};
+use crate::math::inverse_brent;
+use crate::physics::single_chain::ufjc::lennard_jones::thermodynamics::isometric::asymptotic::legendre::
+{
+    try_nondimensional_force,
+    try_nondimensional_stiffness
+};
+use crate::physics::single_chain::ufjc::lennard_jones::thermodynamics::isotensional::asymptotic::try_nondimensional_end_to_end_length_per_link;
+
+/// The structure of the Lennard-Jones-FJC model rupture kinetics in the isometric ensemble.
+pub struct LENNARDJONESFJC
+{
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    pub link_stiffness: f64
+}
+
+/// The rate of breaking the chain as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and link stiffness, or an error if the end-to-end length is negative or the inversion does not converge.
+pub fn breaking_rate(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT*nondimensional_breaking_rate(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
+}
+
+/// The nondimensional rate of breaking the chain as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links and nondimensional link stiffness, or an error if the end-to-end length is negative or the inversion does not converge.
+pub fn nondimensional_breaking_rate(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*nondimensional_link_breaking_rate(nondimensional_link_stiffness, &try_nondimensional_force(nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link)?)?)
+}
+
+/// The probability that the chain survives as a function of the applied end-to-end length, time, and temperature, parameterized by the number of links, link length, and link stiffness, or an error if the end-to-end length is negative or the inversion does not converge.
+pub fn survival_probability(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, time: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    nondimensional_survival_probability(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)), &(time*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT))
+}
+
+/// The probability that the chain survives as a function of the applied nondimensional end-to-end length per link and nondimensional time, parameterized by the number of links and nondimensional link stiffness, or an error if the end-to-end length is negative or the inversion does not converge.
+pub fn nondimensional_survival_probability(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64, nondimensional_time: &f64) -> Result<f64, Error>
+{
+    Ok((-nondimensional_breaking_rate(number_of_links, nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link)?*nondimensional_time).exp())
+}
+
+/// The most probable rupture force as a function of the applied extension rate and temperature, parameterized by the number of links, link length, and link stiffness, or an error if it cannot be found below the maximum force the link potential can sustain.
+pub fn most_probable_rupture_force(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, extension_rate: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length*nondimensional_most_probable_rupture_force(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(extension_rate*PLANCK_CONSTANT/link_length/BOLTZMANN_CONSTANT/temperature))?)
+}
+
+/// The most probable nondimensional rupture force as a function of the applied nondimensional extension rate, parameterized by the number of links and nondimensional link stiffness, or an error if it cannot be found below the maximum force the link potential can sustain.
+pub fn nondimensional_most_probable_rupture_force(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_extension_rate: &f64) -> Result<f64, Error>
+{
+    let rupture_condition = |nondimensional_force: &f64| -> Result<f64, Error>
+    {
+        let [nondimensional_link_stretch, nondimensional_link_stretch_transition] = nondimensional_link_stretches(nondimensional_link_stiffness, nondimensional_force)?;
+        Ok((nondimensional_link_stretch_transition - nondimensional_link_stretch)*try_nondimensional_stiffness(number_of_links, nondimensional_link_stiffness, &try_nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force)?)? - (*number_of_links as f64)*nondimensional_link_breaking_rate(nondimensional_link_stiffness, nondimensional_force)?/nondimensional_extension_rate)
+    };
+    Ok(inverse_brent(&0.0, &|nondimensional_force: &f64| rupture_condition(nondimensional_force).unwrap_or(f64::NAN), &ZERO, &nondimensional_force_max(nondimensional_link_stiffness), &INVERSION_TOLERANCE, &100)?)
+}
+
+/// The implemented functionality of the Lennard-Jones-FJC model rupture kinetics in the isometric ensemble.
+impl LENNARDJONESFJC
+{
+    /// Initializes and returns an instance of the Lennard-Jones-FJC model rupture kinetics in the isometric ensemble.
+    pub fn init(number_of_links: u8, link_length: f64, link_stiffness: f64) -> Self
+    {
+        LENNARDJONESFJC
+        {
+            link_length,
+            number_of_links,
+            link_stiffness
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, link_stiffness))
+    }
+    /// The rate of breaking the chain as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is negative or the inversion does not converge.
+    pub fn breaking_rate(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        breaking_rate(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
+    }
+    /// The nondimensional rate of breaking the chain as a function of the applied nondimensional end-to-end length per link, or an error if the end-to-end length is negative or the inversion does not converge.
+    pub fn nondimensional_breaking_rate(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_breaking_rate(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The probability that the chain survives as a function of the applied end-to-end length, time, and temperature, or an error if the end-to-end length is negative or the inversion does not converge.
+    pub fn survival_probability(&self, end_to_end_length: &f64, time: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        survival_probability(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, time, temperature)
+    }
+    /// The probability that the chain survives as a function of the applied nondimensional end-to-end length per link and nondimensional time, or an error if the end-to-end length is negative or the inversion does not converge.
+    pub fn nondimensional_survival_probability(&self, nondimensional_end_to_end_length_per_link: &f64, nondimensional_time: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_survival_probability(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link, nondimensional_time)
+    }
+    /// The most probable rupture force as a function of the applied extension rate and temperature, or an error if it cannot be found below the maximum force the link potential can sustain.
+    pub fn most_probable_rupture_force(&self, extension_rate: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        most_probable_rupture_force(&self.number_of_links, &self.link_length, &self.link_stiffness, extension_rate, temperature)
+    }
+    /// The most probable nondimensional rupture force as a function of the applied nondimensional extension rate, or an error if it cannot be found below the maximum force the link potential can sustain.
+    pub fn nondimensional_most_probable_rupture_force(&self, nondimensional_extension_rate: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_most_probable_rupture_force(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_extension_rate)
+    }
+}
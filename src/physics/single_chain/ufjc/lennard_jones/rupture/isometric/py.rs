@@ -0,0 +1,149 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    PyArrayDyn,
+    PyReadonlyArrayDyn
+};
+use crate::physics::BOLTZMANN_CONSTANT;
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let isometric = PyModule::new(py, "isometric")?;
+    parent_module.add_submodule(isometric)?;
+    isometric.add_class::<LENNARDJONESFJC>()?;
+    Ok(())
+}
+
+/// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model rupture kinetics in the isometric ensemble.
+#[pyclass]
+#[derive(Copy, Clone)]
+pub struct LENNARDJONESFJC
+{
+    /// The length of each link in the chain in units of nm.
+    #[pyo3(get)]
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    #[pyo3(get)]
+    pub number_of_links: u8,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    #[pyo3(get)]
+    pub link_stiffness: f64
+}
+
+#[pymethods]
+impl LENNARDJONESFJC
+{
+    #[new]
+    pub fn init(number_of_links: u8, link_length: f64, link_stiffness: f64) -> PyResult<Self>
+    {
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(LENNARDJONESFJC
+        {
+            link_length,
+            number_of_links,
+            link_stiffness
+        })
+    }
+    /// The rate of breaking the chain as a function of the applied end-to-end length and temperature.
+    ///
+    /// Args:
+    ///     end_to_end_length (numpy.ndarray): The end-to-end length :math:`\xi`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The rate of breaking the chain :math:`N_bk`.
+    ///
+    /// Raises:
+    ///     ValueError: If the end-to-end length is negative or the inversion does not converge.
+    ///
+    pub fn breaking_rate<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, end_to_end_length, |end_to_end_length: f64| super::breaking_rate(&self.number_of_links, &self.link_length, &self.link_stiffness, &end_to_end_length, &temperature))
+    }
+    /// The nondimensional rate of breaking the chain as a function of the applied nondimensional end-to-end length per link.
+    ///
+    /// Args:
+    ///     nondimensional_end_to_end_length_per_link (numpy.ndarray): The nondimensional end-to-end length per link :math:`\gamma\equiv \xi/N_b\ell_b`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional rate of breaking the chain :math:`N_bhk/k_BT`.
+    ///
+    /// Raises:
+    ///     ValueError: If the end-to-end length is negative or the inversion does not converge.
+    ///
+    pub fn nondimensional_breaking_rate<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_breaking_rate(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link))
+    }
+    /// The probability that the chain survives as a function of the applied end-to-end length, time, and temperature.
+    ///
+    /// Args:
+    ///     end_to_end_length (numpy.ndarray): The end-to-end length :math:`\xi`.
+    ///     time (float): The time :math:`t`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The probability that the chain survives :math:`P_s`.
+    ///
+    /// Raises:
+    ///     ValueError: If the end-to-end length is negative or the inversion does not converge.
+    ///
+    pub fn survival_probability<'py>(&self, py: Python<'py>, end_to_end_length: PyReadonlyArrayDyn<f64>, time: f64, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, end_to_end_length, |end_to_end_length: f64| super::survival_probability(&self.number_of_links, &self.link_length, &self.link_stiffness, &end_to_end_length, &time, &temperature))
+    }
+    /// The probability that the chain survives as a function of the applied nondimensional end-to-end length per link and nondimensional time.
+    ///
+    /// Args:
+    ///     nondimensional_end_to_end_length_per_link (numpy.ndarray): The nondimensional end-to-end length per link :math:`\gamma\equiv \xi/N_b\ell_b`.
+    ///     nondimensional_time (float): The nondimensional time :math:`k_BTt/h`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The probability that the chain survives :math:`P_s`.
+    ///
+    /// Raises:
+    ///     ValueError: If the end-to-end length is negative or the inversion does not converge.
+    ///
+    pub fn nondimensional_survival_probability<'py>(&self, py: Python<'py>, nondimensional_end_to_end_length_per_link: PyReadonlyArrayDyn<f64>, nondimensional_time: f64, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_end_to_end_length_per_link, |nondimensional_end_to_end_length_per_link: f64| super::nondimensional_survival_probability(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_end_to_end_length_per_link, &nondimensional_time))
+    }
+    /// The most probable rupture force as a function of the applied extension rate and temperature.
+    ///
+    /// Args:
+    ///     extension_rate (numpy.ndarray): The extension rate :math:`\dot{\xi}`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The most probable rupture force :math:`f^*`.
+    ///
+    /// Raises:
+    ///     ValueError: If it cannot be found below the maximum force the link potential can sustain.
+    ///
+    pub fn most_probable_rupture_force<'py>(&self, py: Python<'py>, extension_rate: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, extension_rate, |extension_rate: f64| super::most_probable_rupture_force(&self.number_of_links, &self.link_length, &self.link_stiffness, &extension_rate, &temperature))
+    }
+    /// The most probable nondimensional rupture force as a function of the applied nondimensional extension rate.
+    ///
+    /// Args:
+    ///     nondimensional_extension_rate (numpy.ndarray): The nondimensional extension rate :math:`h\dot{\xi}/\ell_b k_BT`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The most probable nondimensional rupture force :math:`\eta^*\equiv\beta f^*\ell_b`.
+    ///
+    /// Raises:
+    ///     ValueError: If it cannot be found below the maximum force the link potential can sustain.
+    ///
+    pub fn nondimensional_most_probable_rupture_force<'py>(&self, py: Python<'py>, nondimensional_extension_rate: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_extension_rate, |nondimensional_extension_rate: f64| super::nondimensional_most_probable_rupture_force(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_extension_rate))
+    }
+}
@@ -0,0 +1,30 @@
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isotensional_breaking_rate(number_of_links: u8, link_length: f64, link_stiffness: f64, force: f64, temperature: f64) -> f64
+{
+    super::breaking_rate(&number_of_links, &link_length, &link_stiffness, &force, &temperature).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isotensional_nondimensional_breaking_rate(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_force: f64) -> f64
+{
+    super::nondimensional_breaking_rate(&number_of_links, &nondimensional_link_stiffness, &nondimensional_force).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isotensional_survival_probability(number_of_links: u8, link_length: f64, link_stiffness: f64, force: f64, time: f64, temperature: f64) -> f64
+{
+    super::survival_probability(&number_of_links, &link_length, &link_stiffness, &force, &time, &temperature).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isotensional_nondimensional_survival_probability(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_force: f64, nondimensional_time: f64) -> f64
+{
+    super::nondimensional_survival_probability(&number_of_links, &nondimensional_link_stiffness, &nondimensional_force, &nondimensional_time).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isotensional_most_probable_rupture_force(number_of_links: u8, link_length: f64, link_stiffness: f64, loading_rate: f64, temperature: f64) -> f64
+{
+    super::most_probable_rupture_force(&number_of_links, &link_length, &link_stiffness, &loading_rate, &temperature).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_lennard_jones_rupture_isotensional_nondimensional_most_probable_rupture_force(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_loading_rate: f64) -> f64
+{
+    super::nondimensional_most_probable_rupture_force(&number_of_links, &nondimensional_link_stiffness, &nondimensional_loading_rate).unwrap_or(f64::NAN)
+}
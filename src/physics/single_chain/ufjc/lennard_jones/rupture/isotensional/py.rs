@@ -0,0 +1,149 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    PyArrayDyn,
+    PyReadonlyArrayDyn
+};
+use crate::physics::BOLTZMANN_CONSTANT;
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let isotensional = PyModule::new(py, "isotensional")?;
+    parent_module.add_submodule(isotensional)?;
+    isotensional.add_class::<LENNARDJONESFJC>()?;
+    Ok(())
+}
+
+/// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model rupture kinetics in the isotensional ensemble.
+#[pyclass]
+#[derive(Copy, Clone)]
+pub struct LENNARDJONESFJC
+{
+    /// The length of each link in the chain in units of nm.
+    #[pyo3(get)]
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    #[pyo3(get)]
+    pub number_of_links: u8,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    #[pyo3(get)]
+    pub link_stiffness: f64
+}
+
+#[pymethods]
+impl LENNARDJONESFJC
+{
+    #[new]
+    pub fn init(number_of_links: u8, link_length: f64, link_stiffness: f64) -> PyResult<Self>
+    {
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(LENNARDJONESFJC
+        {
+            link_length,
+            number_of_links,
+            link_stiffness
+        })
+    }
+    /// The rate of breaking the chain as a function of the applied force and temperature.
+    ///
+    /// Args:
+    ///     force (numpy.ndarray): The force :math:`f`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The rate of breaking the chain :math:`N_bk`.
+    ///
+    /// Raises:
+    ///     ValueError: If the force is negative.
+    ///
+    pub fn breaking_rate<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, force, |force: f64| super::breaking_rate(&self.number_of_links, &self.link_length, &self.link_stiffness, &force, &temperature))
+    }
+    /// The nondimensional rate of breaking the chain as a function of the applied nondimensional force.
+    ///
+    /// Args:
+    ///     nondimensional_force (numpy.ndarray): The nondimensional force :math:`\eta\equiv\beta f\ell_b`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional rate of breaking the chain :math:`N_bhk/k_BT`.
+    ///
+    /// Raises:
+    ///     ValueError: If the force is negative.
+    ///
+    pub fn nondimensional_breaking_rate<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_breaking_rate(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
+    }
+    /// The probability that the chain survives as a function of the applied force, time, and temperature.
+    ///
+    /// Args:
+    ///     force (numpy.ndarray): The force :math:`f`.
+    ///     time (float): The time :math:`t`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The probability that the chain survives :math:`P_s`.
+    ///
+    /// Raises:
+    ///     ValueError: If the force is negative.
+    ///
+    pub fn survival_probability<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, time: f64, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, force, |force: f64| super::survival_probability(&self.number_of_links, &self.link_length, &self.link_stiffness, &force, &time, &temperature))
+    }
+    /// The probability that the chain survives as a function of the applied nondimensional force and nondimensional time.
+    ///
+    /// Args:
+    ///     nondimensional_force (numpy.ndarray): The nondimensional force :math:`\eta\equiv\beta f\ell_b`.
+    ///     nondimensional_time (float): The nondimensional time :math:`k_BTt/h`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The probability that the chain survives :math:`P_s`.
+    ///
+    /// Raises:
+    ///     ValueError: If the force is negative.
+    ///
+    pub fn nondimensional_survival_probability<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, nondimensional_time: f64, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_survival_probability(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force, &nondimensional_time))
+    }
+    /// The most probable rupture force as a function of the applied loading rate and temperature.
+    ///
+    /// Args:
+    ///     loading_rate (numpy.ndarray): The loading rate :math:`\dot{f}`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The most probable rupture force :math:`f^*`.
+    ///
+    /// Raises:
+    ///     ValueError: If it cannot be found below the maximum force the link potential can sustain.
+    ///
+    pub fn most_probable_rupture_force<'py>(&self, py: Python<'py>, loading_rate: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, loading_rate, |loading_rate: f64| super::most_probable_rupture_force(&self.number_of_links, &self.link_length, &self.link_stiffness, &loading_rate, &temperature))
+    }
+    /// The most probable nondimensional rupture force as a function of the applied nondimensional loading rate.
+    ///
+    /// Args:
+    ///     nondimensional_loading_rate (numpy.ndarray): The nondimensional loading rate :math:`h\ell_b\dot{f}/(k_BT)^2`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The most probable nondimensional rupture force :math:`\eta^*\equiv\beta f^*\ell_b`.
+    ///
+    /// Raises:
+    ///     ValueError: If it cannot be found below the maximum force the link potential can sustain.
+    ///
+    pub fn nondimensional_most_probable_rupture_force<'py>(&self, py: Python<'py>, nondimensional_loading_rate: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_loading_rate, |nondimensional_loading_rate: f64| super::nondimensional_most_probable_rupture_force(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_loading_rate))
+    }
+}
@@ -0,0 +1,157 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = LENNARDJONESFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.link_stiffness_reference);
+    }
+}
+mod nondimensional
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn breaking_rate()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = nondimensional_force_max(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature))*rng.gen::<f64>();
+            let nondimensional_breaking_rate = model.nondimensional_breaking_rate(&nondimensional_force, &temperature).unwrap();
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let breaking_rate = model.breaking_rate(&force, &temperature).unwrap();
+            let residual_abs = &breaking_rate*PLANCK_CONSTANT/BOLTZMANN_CONSTANT/temperature - &nondimensional_breaking_rate;
+            let residual_rel = &residual_abs/&nondimensional_breaking_rate;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn survival_probability()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = nondimensional_force_max(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature))*rng.gen::<f64>();
+            let nondimensional_time = rng.gen::<f64>()/model.nondimensional_breaking_rate(&nondimensional_force, &temperature).unwrap();
+            let nondimensional_survival_probability = model.nondimensional_survival_probability(&nondimensional_force, &nondimensional_time, &temperature).unwrap();
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let time = nondimensional_time*PLANCK_CONSTANT/BOLTZMANN_CONSTANT/temperature;
+            let survival_probability = model.survival_probability(&force, &time, &temperature).unwrap();
+            let residual_abs = &survival_probability - &nondimensional_survival_probability;
+            let residual_rel = &residual_abs/&nondimensional_survival_probability;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn most_probable_rupture_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_link_stiffness = link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_force = nondimensional_force_max(&nondimensional_link_stiffness)*(0.25 + 0.5*rng.gen::<f64>());
+            let [nondimensional_link_stretch, nondimensional_link_stretch_transition] = nondimensional_link_stretches(&nondimensional_link_stiffness, &nondimensional_force).unwrap();
+            let nondimensional_loading_rate = nondimensional_breaking_rate(&number_of_links, &nondimensional_link_stiffness, &nondimensional_force).unwrap()/(nondimensional_link_stretch_transition - nondimensional_link_stretch);
+            let nondimensional_most_probable_rupture_force = model.nondimensional_most_probable_rupture_force(&nondimensional_loading_rate, &temperature).unwrap();
+            let loading_rate = nondimensional_loading_rate*(BOLTZMANN_CONSTANT*temperature).powi(2)/link_length/PLANCK_CONSTANT;
+            let most_probable_rupture_force = model.most_probable_rupture_force(&loading_rate, &temperature).unwrap();
+            let residual_abs = &most_probable_rupture_force/BOLTZMANN_CONSTANT/temperature*link_length - &nondimensional_most_probable_rupture_force;
+            let residual_rel = &residual_abs/&nondimensional_most_probable_rupture_force;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod zero
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn survival_probability()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = nondimensional_force_max(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature))*rng.gen::<f64>();
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let survival_probability_0 = model.survival_probability(&force, &0.0, &temperature).unwrap();
+            assert_eq!(survival_probability_0, 1.0);
+        }
+    }
+}
+mod connection
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn most_probable_rupture_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_link_stiffness = link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_force = nondimensional_force_max(&nondimensional_link_stiffness)*(0.25 + 0.5*rng.gen::<f64>());
+            let h = parameters.rel_tol;
+            let nondimensional_activation_length = (nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &(nondimensional_force + 0.5*h)).unwrap().ln() - nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &(nondimensional_force - 0.5*h)).unwrap().ln())/h;
+            let nondimensional_loading_rate = nondimensional_breaking_rate(&number_of_links, &nondimensional_link_stiffness, &nondimensional_force).unwrap()/nondimensional_activation_length;
+            let nondimensional_most_probable_rupture_force = nondimensional_most_probable_rupture_force(&number_of_links, &nondimensional_link_stiffness, &nondimensional_loading_rate).unwrap();
+            let residual_abs = &nondimensional_most_probable_rupture_force - &nondimensional_force;
+            let residual_rel = &residual_abs/&nondimensional_force;
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn most_probable_rupture_force()
+    {
+        let parameters = Parameters::default();
+        let nondimensional_link_stiffness = parameters.link_stiffness_reference*parameters.link_length_reference.powi(2)/BOLTZMANN_CONSTANT/parameters.temperature_reference;
+        assert!(matches!(nondimensional_most_probable_rupture_force(&parameters.number_of_links_minimum, &nondimensional_link_stiffness, &1e-300), Err(Error::Math(_))));
+    }
+    #[test]
+    fn breaking_rate()
+    {
+        let parameters = Parameters::default();
+        let nondimensional_link_stiffness = parameters.link_stiffness_reference*parameters.link_length_reference.powi(2)/BOLTZMANN_CONSTANT/parameters.temperature_reference;
+        assert!(matches!(nondimensional_breaking_rate(&parameters.number_of_links_minimum, &nondimensional_link_stiffness, &-1.0), Err(Error::Domain(..))));
+    }
+}
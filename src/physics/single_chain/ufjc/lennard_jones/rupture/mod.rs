@@ -0,0 +1,149 @@
+#[cfg(feature = "extern")]
+pub mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+/// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model rupture kinetics in the isometric ensemble.
+pub mod isometric;
+
+/// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model rupture kinetics in the isotensional ensemble.
+pub mod isotensional;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::math::inverse_brent;
+use crate::physics::
+{
+    PLANCK_CONSTANT,
+    BOLTZMANN_CONSTANT,
+    single_chain::INVERSION_TOLERANCE
+};
+
+/// The structure of the Lennard-Jones-FJC model rupture kinetics.
+pub struct LENNARDJONESFJC
+{
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    pub link_stiffness: f64,
+
+    /// The rupture kinetics of the model in the isometric ensemble.
+    pub isometric: self::isometric::LENNARDJONESFJC,
+
+    /// The rupture kinetics of the model in the isotensional ensemble.
+    pub isotensional: self::isotensional::LENNARDJONESFJC
+}
+
+/// The energy barrier of the force-tilted link potential as a function of the applied force and temperature, parameterized by the link length and link stiffness, or an error if the force is negative.
+pub fn energy_barrier(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*nondimensional_energy_barrier(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))?)
+}
+
+/// The nondimensional energy barrier of the force-tilted link potential as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness, or an error if the force is negative.
+pub fn nondimensional_energy_barrier(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    if nondimensional_force == &0.0
+    {
+        return Ok(nondimensional_link_stiffness/72.0)
+    }
+    let [nondimensional_link_stretch, nondimensional_link_stretch_transition] = nondimensional_link_stretches(nondimensional_link_stiffness, nondimensional_force)?;
+    let nondimensional_link_potential = |nondimensional_link_stretch: &f64| nondimensional_link_stiffness/72.0*(nondimensional_link_stretch.powi(-12) - 2.0*nondimensional_link_stretch.powi(-6));
+    Ok(nondimensional_link_potential(&nondimensional_link_stretch_transition) - nondimensional_link_potential(&nondimensional_link_stretch) - nondimensional_force*(nondimensional_link_stretch_transition - nondimensional_link_stretch))
+}
+
+/// The rate of breaking a single link as a function of the applied force and temperature, parameterized by the link length and link stiffness, or an error if the force is negative.
+pub fn link_breaking_rate(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT*nondimensional_link_breaking_rate(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))?)
+}
+
+/// The nondimensional rate of breaking a single link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness, or an error if the force is negative.
+pub fn nondimensional_link_breaking_rate(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    Ok((-nondimensional_energy_barrier(nondimensional_link_stiffness, nondimensional_force)?).exp())
+}
+
+/// The maximum nondimensional force the link potential can sustain, parameterized by the nondimensional link stiffness.
+fn nondimensional_force_max(nondimensional_link_stiffness: &f64) -> f64
+{
+    let nondimensional_link_stretch_max = (13.0/7.0_f64).powf(1.0/6.0);
+    nondimensional_link_stiffness/6.0*(nondimensional_link_stretch_max.powi(-7) - nondimensional_link_stretch_max.powi(-13))
+}
+
+/// The nondimensional link stretches at the minimum and at the transition state of the force-tilted link potential, or an error if the force is negative.
+fn nondimensional_link_stretches(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> Result<[f64; 2], Error>
+{
+    let nondimensional_link_stretch_max = (13.0/7.0_f64).powf(1.0/6.0);
+    if nondimensional_force < &0.0
+    {
+        Err(Error::Domain("nondimensional_force", *nondimensional_force, "nonnegative"))
+    }
+    else if nondimensional_force >= &nondimensional_force_max(nondimensional_link_stiffness)
+    {
+        Ok([nondimensional_link_stretch_max, nondimensional_link_stretch_max])
+    }
+    else
+    {
+        let nondimensional_link_force = |nondimensional_link_stretch: &f64| nondimensional_link_stiffness/6.0*(nondimensional_link_stretch.powi(-7) - nondimensional_link_stretch.powi(-13));
+        Ok([
+            inverse_brent(nondimensional_force, &nondimensional_link_force, &1.0, &nondimensional_link_stretch_max, &INVERSION_TOLERANCE, &100)?,
+            inverse_brent(nondimensional_force, &nondimensional_link_force, &nondimensional_link_stretch_max, &(nondimensional_link_stiffness/6.0/nondimensional_force).powf(1.0/7.0), &INVERSION_TOLERANCE, &100)?
+        ])
+    }
+}
+
+/// The implemented functionality of the Lennard-Jones-FJC model rupture kinetics.
+impl LENNARDJONESFJC
+{
+    /// Initializes and returns an instance of the Lennard-Jones-FJC model rupture kinetics.
+    pub fn init(number_of_links: u8, link_length: f64, link_stiffness: f64) -> Self
+    {
+        LENNARDJONESFJC
+        {
+            link_length,
+            number_of_links,
+            link_stiffness,
+            isometric: self::isometric::LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness),
+            isotensional: self::isotensional::LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness)
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, link_stiffness))
+    }
+    /// The energy barrier of the force-tilted link potential as a function of the applied force and temperature, or an error if the force is negative.
+    pub fn energy_barrier(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        energy_barrier(&self.link_length, &self.link_stiffness, force, temperature)
+    }
+    /// The nondimensional energy barrier of the force-tilted link potential as a function of the applied nondimensional force, or an error if the force is negative.
+    pub fn nondimensional_energy_barrier(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_energy_barrier(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+    }
+    /// The rate of breaking a single link as a function of the applied force and temperature, or an error if the force is negative.
+    pub fn link_breaking_rate(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        link_breaking_rate(&self.link_length, &self.link_stiffness, force, temperature)
+    }
+    /// The nondimensional rate of breaking a single link as a function of the applied nondimensional force, or an error if the force is negative.
+    pub fn nondimensional_link_breaking_rate(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_link_breaking_rate(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+    }
+}
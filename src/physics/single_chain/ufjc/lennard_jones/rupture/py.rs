@@ -0,0 +1,127 @@
+use pyo3::prelude::*;
+use numpy::
+{
+    PyArrayDyn,
+    PyReadonlyArrayDyn
+};
+use crate::physics::BOLTZMANN_CONSTANT;
+
+pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
+{
+    let rupture = PyModule::new(py, "rupture")?;
+    super::isometric::py::register_module(py, rupture)?;
+    super::isotensional::py::register_module(py, rupture)?;
+    parent_module.add_submodule(rupture)?;
+    rupture.add_class::<LENNARDJONESFJC>()?;
+    Ok(())
+}
+
+/// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model rupture kinetics.
+#[pyclass]
+#[derive(Copy, Clone)]
+pub struct LENNARDJONESFJC
+{
+    /// The length of each link in the chain in units of nm.
+    #[pyo3(get)]
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    #[pyo3(get)]
+    pub number_of_links: u8,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    #[pyo3(get)]
+    pub link_stiffness: f64,
+
+    /// The rupture kinetics of the model in the isometric ensemble.
+    #[pyo3(get)]
+    pub isometric: super::isometric::py::LENNARDJONESFJC,
+
+    /// The rupture kinetics of the model in the isotensional ensemble.
+    #[pyo3(get)]
+    pub isotensional: super::isotensional::py::LENNARDJONESFJC
+}
+
+#[pymethods]
+impl LENNARDJONESFJC
+{
+    #[new]
+    pub fn init(number_of_links: u8, link_length: f64, link_stiffness: f64) -> PyResult<Self>
+    {
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(LENNARDJONESFJC
+        {
+            link_length,
+            number_of_links,
+            link_stiffness,
+            isometric: super::isometric::py::LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness)?,
+            isotensional: super::isotensional::py::LENNARDJONESFJC::init(number_of_links, link_length, link_stiffness)?
+        })
+    }
+    /// The energy barrier of the force-tilted link potential as a function of the applied force and temperature.
+    ///
+    /// Args:
+    ///     force (numpy.ndarray): The force :math:`f`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The energy barrier :math:`\Delta u^\ddagger`.
+    ///
+    /// Raises:
+    ///     ValueError: If the force is negative.
+    ///
+    pub fn energy_barrier<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, force, |force: f64| super::energy_barrier(&self.link_length, &self.link_stiffness, &force, &temperature))
+    }
+    /// The nondimensional energy barrier of the force-tilted link potential as a function of the applied nondimensional force.
+    ///
+    /// Args:
+    ///     nondimensional_force (numpy.ndarray): The nondimensional force :math:`\eta\equiv\beta f\ell_b`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional energy barrier :math:`\beta\Delta u^\ddagger`.
+    ///
+    /// Raises:
+    ///     ValueError: If the force is negative.
+    ///
+    pub fn nondimensional_energy_barrier<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_energy_barrier(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
+    }
+    /// The rate of breaking a single link as a function of the applied force and temperature.
+    ///
+    /// Args:
+    ///     force (numpy.ndarray): The force :math:`f`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The rate of breaking a single link :math:`k`.
+    ///
+    /// Raises:
+    ///     ValueError: If the force is negative.
+    ///
+    pub fn link_breaking_rate<'py>(&self, py: Python<'py>, force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, force, |force: f64| super::link_breaking_rate(&self.link_length, &self.link_stiffness, &force, &temperature))
+    }
+    /// The nondimensional rate of breaking a single link as a function of the applied nondimensional force.
+    ///
+    /// Args:
+    ///     nondimensional_force (numpy.ndarray): The nondimensional force :math:`\eta\equiv\beta f\ell_b`.
+    ///     temperature (float): The temperature :math:`T`.
+    ///
+    /// Returns:
+    ///     numpy.ndarray: The nondimensional rate of breaking a single link :math:`hk/k_BT`.
+    ///
+    /// Raises:
+    ///     ValueError: If the force is negative.
+    ///
+    pub fn nondimensional_link_breaking_rate<'py>(&self, py: Python<'py>, nondimensional_force: PyReadonlyArrayDyn<f64>, temperature: f64) -> PyResult<&'py PyArrayDyn<f64>>
+    {
+        crate::py::try_batch(py, nondimensional_force, |nondimensional_force: f64| super::nondimensional_link_breaking_rate(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &nondimensional_force))
+    }
+}
@@ -4,7 +4,7 @@ The Lennard-Jones potential freely-jointed chain (Lennard-Jones-FJC) model therm
 module Asymptotic
 
 using DocStringExtensions
-using ........Polymers: PROJECT_ROOT
+using ........Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 include("reduced/mod.jl")
 include("legendre/mod.jl")
@@ -52,6 +52,10 @@ function LENNARDJONESFJC(
     hinge_mass::Float64,
     link_stiffness::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("link_stiffness", link_stiffness)
     return LENNARDJONESFJC(
         number_of_links,
         link_length,
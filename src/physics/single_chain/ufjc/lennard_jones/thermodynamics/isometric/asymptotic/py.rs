@@ -44,16 +44,20 @@ pub struct LENNARDJONESFJC
 impl LENNARDJONESFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> PyResult<Self>
     {
-        LENNARDJONESFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(LENNARDJONESFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness,
-            reduced: super::reduced::py::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness),
-            legendre: super::legendre::py::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
-        }
+            reduced: super::reduced::py::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)?,
+            legendre: super::legendre::py::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)?
+        })
     }
 }
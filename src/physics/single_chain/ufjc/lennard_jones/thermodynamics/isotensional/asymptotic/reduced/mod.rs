@@ -9,6 +9,11 @@ mod test;
 /// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model thermodynamics in the isotensional ensemble approximated using a reduced asymptotic approach and a Legendre transformation.
 pub mod legendre;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use super::nondimensional_link_stretch;
 use std::f64::consts::PI;
 use crate::physics::
@@ -40,74 +45,146 @@ pub struct LENNARDJONESFJC
 /// The expected end-to-end length as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness.
 pub fn end_to_end_length(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    link_length*nondimensional_end_to_end_length(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+    try_end_to_end_length(number_of_links, link_length, link_stiffness, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The expected end-to-end length as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_end_to_end_length(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(link_length*try_nondimensional_end_to_end_length(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))?)
 }
 
 /// The expected end-to-end length per link as a function of the applied force and temperature, parameterized by the link length and link stiffness.
 pub fn end_to_end_length_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    link_length*nondimensional_end_to_end_length_per_link(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+    try_end_to_end_length_per_link(link_length, link_stiffness, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The expected end-to-end length per link as a function of the applied force and temperature, parameterized by the link length and link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_end_to_end_length_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(link_length*try_nondimensional_end_to_end_length_per_link(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))?)
 }
 
 /// The expected nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness.
 pub fn nondimensional_end_to_end_length(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    (*number_of_links as f64)*nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force)
+    try_nondimensional_end_to_end_length(number_of_links, nondimensional_link_stiffness, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_nondimensional_end_to_end_length(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*try_nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force)?)
 }
 
 /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness.
 pub fn nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_link_stretch(nondimensional_link_stiffness, nondimensional_force) - 1.0
+    try_nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    Ok(1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_link_stretch(nondimensional_link_stiffness, nondimensional_force)? - 1.0)
 }
 
 /// The Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, hinge mass, and link stiffness.
 pub fn gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_gibbs_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), temperature)
+    try_gibbs_free_energy(number_of_links, link_length, hinge_mass, link_stiffness, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, hinge mass, and link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_gibbs_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), temperature)?)
 }
 
 /// The Gibbs free energy per link as a function of the applied force and temperature, parameterized by the link length, hinge mass, and link stiffness.
 pub fn gibbs_free_energy_per_link(link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), temperature)
+    try_gibbs_free_energy_per_link(link_length, hinge_mass, link_stiffness, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The Gibbs free energy per link as a function of the applied force and temperature, parameterized by the link length, hinge mass, and link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_gibbs_free_energy_per_link(link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), temperature)?)
 }
 
 /// The relative Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness.
 pub fn relative_gibbs_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    gibbs_free_energy(number_of_links, link_length, &1.0, link_stiffness, force, temperature) - gibbs_free_energy(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature)
+    try_relative_gibbs_free_energy(number_of_links, link_length, link_stiffness, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The relative Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_relative_gibbs_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(try_gibbs_free_energy(number_of_links, link_length, &1.0, link_stiffness, force, temperature)? - try_gibbs_free_energy(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature)?)
 }
 
 /// The relative Gibbs free energy per link as a function of the applied force and temperature, parameterized by the link length and link stiffness,.
 pub fn relative_gibbs_free_energy_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    gibbs_free_energy_per_link(link_length, &1.0, link_stiffness, force, temperature) - gibbs_free_energy_per_link(link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature)
+    try_relative_gibbs_free_energy_per_link(link_length, link_stiffness, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The relative Gibbs free energy per link as a function of the applied force and temperature, parameterized by the link length and link stiffness,, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_relative_gibbs_free_energy_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(try_gibbs_free_energy_per_link(link_length, &1.0, link_stiffness, force, temperature)? - try_gibbs_free_energy_per_link(link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature)?)
 }
 
 /// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature, parameterized by the number of links, link length, hinge mass, and nondimensional link stiffness.
 pub fn nondimensional_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64) -> f64
 {
-    (*number_of_links as f64)*nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature)
+    try_nondimensional_gibbs_free_energy(number_of_links, link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature, parameterized by the number of links, link length, hinge mass, and nondimensional link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_nondimensional_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*try_nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature)?)
 }
 
 /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature, parameterized by the link length, hinge mass, and nondimensional link stiffness.
 pub fn nondimensional_gibbs_free_energy_per_link(link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64) -> f64
 {
-    let lambda = nondimensional_link_stretch(nondimensional_link_stiffness, nondimensional_force);
-    -(nondimensional_force.sinh()/nondimensional_force).ln() + nondimensional_link_stiffness/72.0*(lambda.powi(-12) - 2.0*lambda.powi(-6)) - nondimensional_force*(lambda - 1.0) - 0.5*(2.0*PI*link_length.powi(2)/nondimensional_link_stiffness).ln() - (8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+    try_nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature, parameterized by the link length, hinge mass, and nondimensional link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_nondimensional_gibbs_free_energy_per_link(link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    let lambda = nondimensional_link_stretch(nondimensional_link_stiffness, nondimensional_force)?;
+    Ok(-(nondimensional_force.sinh()/nondimensional_force).ln() + nondimensional_link_stiffness/72.0*(lambda.powi(-12) - 2.0*lambda.powi(-6)) - nondimensional_force*(lambda - 1.0) - 0.5*(2.0*PI*link_length.powi(2)/nondimensional_link_stiffness).ln() - (8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
 }
 
 /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness.
 pub fn nondimensional_relative_gibbs_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    nondimensional_gibbs_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0) - nondimensional_gibbs_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
+    try_nondimensional_relative_gibbs_free_energy(number_of_links, nondimensional_link_stiffness, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_nondimensional_relative_gibbs_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_gibbs_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0)? - try_nondimensional_gibbs_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)?)
 }
 
 /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness.
 pub fn nondimensional_relative_gibbs_free_energy_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0) - nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
+    try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_link_stiffness, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+pub fn try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0)? - try_nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)?)
 }
 
 /// The implemented functionality of the Lennard-Jones-FJC model thermodynamics in the isotensional ensemble approximated using a reduced asymptotic approach.
@@ -125,64 +202,138 @@ impl LENNARDJONESFJC
             legendre: self::legendre::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
     /// The expected end-to-end length as a function of the applied force and temperature.
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
-        end_to_end_length(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
+        self.try_end_to_end_length(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_end_to_end_length(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
     }
     /// The expected end-to-end length per link as a function of the applied force and temperature.
     pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        end_to_end_length_per_link(&self.link_length, &self.link_stiffness, force, temperature)
+        self.try_end_to_end_length_per_link(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The expected end-to-end length per link as a function of the applied force and temperature, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length_per_link(&self.link_length, &self.link_stiffness, force, temperature)
     }
     /// The expected nondimensional end-to-end length as a function of the applied nondimensional force.
     pub fn nondimensional_end_to_end_length(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_end_to_end_length(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_end_to_end_length(nondimensional_force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional end-to-end length as a function of the applied nondimensional force, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_nondimensional_end_to_end_length(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
     }
     /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force.
     pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_end_to_end_length_per_link(nondimensional_force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64], temperature: &f64) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature), nondimensional_force)
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
     }
     /// The Gibbs free energy as a function of the applied force and temperature.
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature)
+        self.try_gibbs_free_energy(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The Gibbs free energy as a function of the applied force and temperature, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature)
     }
     /// The Gibbs free energy per link as a function of the applied force and temperature.
     pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature)
+        self.try_gibbs_free_energy_per_link(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The Gibbs free energy per link as a function of the applied force and temperature, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature)
     }
     /// The relative Gibbs free energy as a function of the applied force and temperature.
     pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
+        self.try_relative_gibbs_free_energy(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The relative Gibbs free energy as a function of the applied force and temperature, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
     }
     /// The relative Gibbs free energy per link as a function of the applied force and temperature.
     pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        relative_gibbs_free_energy_per_link(&self.link_length, &self.link_stiffness, force, temperature)
+        self.try_relative_gibbs_free_energy_per_link(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The relative Gibbs free energy per link as a function of the applied force and temperature, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_relative_gibbs_free_energy_per_link(&self.link_length, &self.link_stiffness, force, temperature)
     }
     /// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature.
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature)
+        self.try_nondimensional_gibbs_free_energy(nondimensional_force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature)
     }
     /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature.
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature)
+        self.try_nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature)
     }
     /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force.
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_relative_gibbs_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_gibbs_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
     }
     /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force.
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_relative_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force, or an error if the nondimensional force exceeds the maximum the link potential can sustain.
+    pub fn try_nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
     }
 }
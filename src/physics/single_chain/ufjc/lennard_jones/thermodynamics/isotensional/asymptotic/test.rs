@@ -1089,3 +1089,20 @@ mod asymptotic_reduced
         }
     }
 }
+mod error
+{
+    use super::*;
+    #[test]
+    fn nondimensional_link_stretch()
+    {
+        let parameters = Parameters::default();
+        let model = LENNARDJONESFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference);
+        let temperature = parameters.temperature_reference;
+        let nondimensional_link_stiffness = parameters.link_stiffness_reference*parameters.link_length_reference.powi(2)/BOLTZMANN_CONSTANT/temperature;
+        let lambda_max = (13.0/7.0_f64).powf(1.0/6.0);
+        let nondimensional_force_max = nondimensional_link_stiffness/6.0*(lambda_max.powi(-7) - lambda_max.powi(-13));
+        assert!(model.try_nondimensional_end_to_end_length_per_link(&(0.5*nondimensional_force_max), &temperature).is_ok());
+        assert!(model.try_nondimensional_end_to_end_length_per_link(&(-0.5*nondimensional_force_max), &temperature).is_ok());
+        assert!(model.try_nondimensional_end_to_end_length_per_link(&(2.0*nondimensional_force_max), &temperature).is_err());
+    }
+}
@@ -4,6 +4,7 @@ The Lennard-Jones potential freely-jointed chain (Lennard-Jones-FJC) single-chai
 module Thermodynamics
 
 using DocStringExtensions
+using ......Polymers: validate_number_of_links, validate_positive
 
 include("isometric/mod.jl")
 include("isotensional/mod.jl")
@@ -51,6 +52,10 @@ function LENNARDJONESFJC(
     hinge_mass::Float64,
     link_stiffness::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("link_stiffness", link_stiffness)
     return LENNARDJONESFJC(
         number_of_links,
         link_length,
@@ -3,7 +3,13 @@ pub mod py;
 
 mod test;
 
-use crate::math::inverse_newton_raphson;
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::math::inverse_brent;
+use crate::physics::single_chain::INVERSION_TOLERANCE;
 
 /// The Lennard-Jones link potential freely-jointed chain (Lennard-Jones-FJC) model thermodynamics in the isometric ensemble.
 pub mod isometric;
@@ -49,9 +55,23 @@ impl LENNARDJONESFJC
             isotensional: self::isotensional::LENNARDJONESFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
 }
 
-fn nondimensional_link_stretch(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
+/// The nondimensional link stretch at the minimum of the force-tilted link potential, bracketed between where the compressive force is sustained and the maximum stretch the link potential can sustain,
+/// or an error if the force exceeds the maximum force the link potential can sustain.
+fn nondimensional_link_stretch(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> Result<f64, Error>
 {
-    inverse_newton_raphson(&(6.0*nondimensional_force/nondimensional_link_stiffness), &|x: &f64| x.powi(-7) - x.powi(-13), &|x: &f64| 13.0*x.powi(-14) - 7.0*x.powi(-8), &1.0, &1e-6, &100)
+    let nondimensional_link_stretch_max = (13.0/7.0_f64).powf(1.0/6.0);
+    let nondimensional_link_stretch_min = (1.0 - 6.0*nondimensional_force.min(0.0)/nondimensional_link_stiffness).powf(-1.0/6.0);
+    let nondimensional_link_force = |nondimensional_link_stretch: &f64| nondimensional_link_stiffness/6.0*(nondimensional_link_stretch.powi(-7) - nondimensional_link_stretch.powi(-13));
+    Ok(inverse_brent(nondimensional_force, &nondimensional_link_force, &nondimensional_link_stretch_min, &nondimensional_link_stretch_max, &INVERSION_TOLERANCE, &100)?)
 }
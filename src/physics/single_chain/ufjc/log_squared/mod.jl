@@ -4,6 +4,7 @@ The log-squared potential freely-jointed chain (log-squared-FJC) single-chain mo
 module LogSquared
 
 using DocStringExtensions
+using .....Polymers: validate_number_of_links, validate_positive
 
 include("thermodynamics/mod.jl")
 
@@ -46,6 +47,10 @@ function LOGSQUAREDFJC(
     hinge_mass::Float64,
     link_stiffness::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("link_stiffness", link_stiffness)
     return LOGSQUAREDFJC(
         number_of_links,
         link_length,
@@ -3,6 +3,12 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
 /// The log-squared link potential freely-jointed chain (log-squared-FJC) model thermodynamics.
 pub mod thermodynamics;
 
@@ -25,6 +31,9 @@ pub struct LOGSQUAREDFJC
     pub thermodynamics: self::thermodynamics::LOGSQUAREDFJC
 }
 
+#[cfg(feature = "serde")]
+crate::config::parameters!(LOGSQUAREDFJC, try_init, number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64);
+
 /// The implemented functionality of the log-squared-FJC model.
 impl LOGSQUAREDFJC
 {
@@ -40,4 +49,30 @@ impl LOGSQUAREDFJC
             thermodynamics: self::thermodynamics::LOGSQUAREDFJC::init(number_of_links, link_length, hinge_mass, link_stiffness),
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
+}
+
+/// The force law of the log-squared-FJC model, using the isometric ensemble approximated using a Legendre transformation.
+impl super::super::ForceLaw for LOGSQUAREDFJC
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.asymptotic.legendre.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature)
+    }
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.asymptotic.legendre.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    }
 }
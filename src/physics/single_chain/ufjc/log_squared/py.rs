@@ -38,15 +38,19 @@ pub struct LOGSQUAREDFJC
 impl LOGSQUAREDFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> PyResult<Self>
     {
-        LOGSQUAREDFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        Ok(LOGSQUAREDFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness,
-            thermodynamics: super::thermodynamics::py::LOGSQUAREDFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
-        }
+            thermodynamics: super::thermodynamics::py::LOGSQUAREDFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)?
+        })
     }
 }
@@ -6,8 +6,13 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use std::f64::consts::PI;
-use crate::math::integrate_1d;
+use crate::math::langevin;
 use crate::physics::
 {
     PLANCK_CONSTANT,
@@ -15,7 +20,8 @@ use crate::physics::
     single_chain::
     {
         ZERO,
-        POINTS
+        try_integrate,
+        INTEGRATION_TOLERANCE
     }
 };
 
@@ -38,66 +44,110 @@ pub struct LOGSQUAREDFJC
 /// The Helmholtz free energy as a function of the applied force and temperature, parameterized by the number of links, link length, hinge mass, link stiffness, and link energy.
 pub fn helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force/BOLTZMANN_CONSTANT/temperature*link_length), temperature)
+    try_helmholtz_free_energy(number_of_links, link_length, hinge_mass, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The Helmholtz free energy as a function of the applied force and temperature, parameterized by the number of links, link length, hinge mass, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force/BOLTZMANN_CONSTANT/temperature*link_length), temperature, integration_tolerance)?)
 }
 
 /// The Helmholtz free energy per link as a function of the applied force and temperature, parameterized by the link length, hinge mass, link stiffness, and link energy.
 pub fn helmholtz_free_energy_per_link(link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_helmholtz_free_energy_per_link(link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force/BOLTZMANN_CONSTANT/temperature*link_length), temperature)
+    try_helmholtz_free_energy_per_link(link_length, hinge_mass, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The Helmholtz free energy per link as a function of the applied force and temperature, parameterized by the link length, hinge mass, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_helmholtz_free_energy_per_link(link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_helmholtz_free_energy_per_link(link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force/BOLTZMANN_CONSTANT/temperature*link_length), temperature, integration_tolerance)?)
 }
 
 /// The relative Helmholtz free energy as a function of the applied force and temperature, parameterized by the number of links, link length, link stiffness, and link energy.
 pub fn relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    helmholtz_free_energy(number_of_links, link_length, &1.0, link_stiffness, force, temperature) - helmholtz_free_energy(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature)
+    try_relative_helmholtz_free_energy(number_of_links, link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The relative Helmholtz free energy as a function of the applied force and temperature, parameterized by the number of links, link length, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_helmholtz_free_energy(number_of_links, link_length, &1.0, link_stiffness, force, temperature, integration_tolerance)? - try_helmholtz_free_energy(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature, integration_tolerance)?)
 }
 
 /// The relative Helmholtz free energy per link as a function of the applied force and temperature, parameterized by the link length, link stiffness, and link energy.
 pub fn relative_helmholtz_free_energy_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    helmholtz_free_energy_per_link(link_length, &1.0, link_stiffness, force, temperature) - helmholtz_free_energy_per_link(link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature)
+    try_relative_helmholtz_free_energy_per_link(link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The relative Helmholtz free energy per link as a function of the applied force and temperature, parameterized by the link length, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_relative_helmholtz_free_energy_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_helmholtz_free_energy_per_link(link_length, &1.0, link_stiffness, force, temperature, integration_tolerance)? - try_helmholtz_free_energy_per_link(link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature, integration_tolerance)?)
 }
 
 /// The nondimensional Helmholtz free energy as a function of the applied nondimensional force and temperature, parameterized by the number of links, link length, hinge mass, nondimensional link stiffness, and nondimensional link energy.
 pub fn nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64) -> f64
 {
-    (*number_of_links as f64)*nondimensional_helmholtz_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature)
+    try_nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy as a function of the applied nondimensional force and temperature, parameterized by the number of links, link length, hinge mass, nondimensional link stiffness, and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*try_nondimensional_helmholtz_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature, integration_tolerance)?)
 }
 
 /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional force and temperature, parameterized by the link length, hinge mass, nondimensional link stiffness, and nondimensional link energy.
 pub fn nondimensional_helmholtz_free_energy_per_link(link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64) -> f64
+{
+    try_nondimensional_helmholtz_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional force and temperature, parameterized by the link length, hinge mass, nondimensional link stiffness, and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_helmholtz_free_energy_per_link(link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
 {
     let nondimensional_link_stretch_max = 1.0_f64.exp();
     let rescaled_partition_function_integrand = |nondimensional_link_stretch: &f64|
     {
         let exponent_1 = nondimensional_force*nondimensional_link_stretch - 0.5*nondimensional_link_stiffness*nondimensional_link_stretch.ln().powi(2) + nondimensional_link_stretch.ln() - nondimensional_force.ln();
-        let exponent_2 = exponent_1 - 2.0*nondimensional_force*nondimensional_link_stretch;
-        exponent_1.exp() - exponent_2.exp()
+        -exponent_1.exp()*(-2.0*nondimensional_force*nondimensional_link_stretch).exp_m1()
     };
-    let rescaled_partition_function = integrate_1d(&rescaled_partition_function_integrand, &ZERO, &nondimensional_link_stretch_max, &POINTS);
+    let rescaled_partition_function = try_integrate(&rescaled_partition_function_integrand, &0.0, &nondimensional_link_stretch_max, integration_tolerance)?;
     let nondimensional_end_to_end_length_per_link_integrand = |nondimensional_link_stretch: &f64|
     {
         let exponent_1 = nondimensional_force*nondimensional_link_stretch - 0.5*nondimensional_link_stiffness*nondimensional_link_stretch.ln().powi(2) + 2.0*nondimensional_link_stretch.ln() - nondimensional_force.ln();
-        let exponent_2 = exponent_1 - 2.0*nondimensional_force*nondimensional_link_stretch;
-        let exponent_3 = exponent_1 - nondimensional_link_stretch.ln() - nondimensional_force.ln();
-        let exponent_4 = exponent_3 - 2.0*nondimensional_force*nondimensional_link_stretch;
-        (exponent_1.exp() + exponent_2.exp() - exponent_3.exp() + exponent_4.exp())/rescaled_partition_function
+        -exponent_1.exp()*(-2.0*nondimensional_force*nondimensional_link_stretch).exp_m1()*langevin(&(nondimensional_force*nondimensional_link_stretch))/rescaled_partition_function
     };
-    let nondimensional_end_to_end_length_per_link = integrate_1d(&nondimensional_end_to_end_length_per_link_integrand, &ZERO, &nondimensional_link_stretch_max, &POINTS);
-    -rescaled_partition_function.ln() - (8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln() + nondimensional_force*nondimensional_end_to_end_length_per_link
+    let nondimensional_end_to_end_length_per_link = try_integrate(&nondimensional_end_to_end_length_per_link_integrand, &0.0, &nondimensional_link_stretch_max, integration_tolerance)?;
+    Ok(-rescaled_partition_function.ln() - (8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln() + nondimensional_force*nondimensional_end_to_end_length_per_link)
 }
 
 /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional force, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy.
 pub fn nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0) - nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
+    try_nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional force, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0, integration_tolerance)? - try_nondimensional_helmholtz_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0, integration_tolerance)?)
 }
 
 /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness and nondimensional link energy.
 pub fn nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    nondimensional_helmholtz_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0) - nondimensional_helmholtz_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
+    try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_helmholtz_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0, integration_tolerance)? - try_nondimensional_helmholtz_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0, integration_tolerance)?)
 }
 
 /// The implemented functionality of the log-squared-FJC model thermodynamics in the isotensional ensemble approximated using a Legendre transformation.
@@ -114,44 +164,93 @@ impl LOGSQUAREDFJC
             link_stiffness
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
     /// The Helmholtz free energy as a function of the applied force and temperature.
     pub fn helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature)
+        self.try_helmholtz_free_energy(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The Helmholtz free energy as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_helmholtz_free_energy(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The Helmholtz free energy per link as a function of the applied force and temperature.
     pub fn helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        helmholtz_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature)
+        self.try_helmholtz_free_energy_per_link(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The Helmholtz free energy per link as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_helmholtz_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The relative Helmholtz free energy as a function of the applied force and temperature.
     pub fn relative_helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
+        self.try_relative_helmholtz_free_energy(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The relative Helmholtz free energy as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_relative_helmholtz_free_energy(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The relative Helmholtz free energy per link as a function of the applied force and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        relative_helmholtz_free_energy_per_link(&self.link_length, &self.link_stiffness, force, temperature)
+        self.try_relative_helmholtz_free_energy_per_link(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The relative Helmholtz free energy per link as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_relative_helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_relative_helmholtz_free_energy_per_link(&self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional force and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature)
+        self.try_nondimensional_helmholtz_free_energy(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Helmholtz free energy as a function of the applied nondimensional force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature, integration_tolerance)
     }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional force and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_helmholtz_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature)
+        self.try_nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_helmholtz_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature, integration_tolerance)
     }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional force.
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_relative_helmholtz_free_energy(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_relative_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
     }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional force.
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_relative_helmholtz_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_helmholtz_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
     }
 }
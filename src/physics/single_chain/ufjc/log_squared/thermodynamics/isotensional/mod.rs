@@ -12,8 +12,13 @@ pub mod asymptotic;
 /// The log-squared link potential freely-jointed chain (log-squared-FJC) model thermodynamics in the isotensional ensemble approximated using a Legendre transformation.
 pub mod legendre;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use std::f64::consts::PI;
-use crate::math::integrate_1d;
+use crate::math::langevin;
 use crate::physics::
 {
     PLANCK_CONSTANT,
@@ -21,7 +26,9 @@ use crate::physics::
     single_chain::
     {
         ZERO,
-        POINTS
+        try_integrate,
+        inverse,
+        INTEGRATION_TOLERANCE
     }
 };
 
@@ -50,97 +57,274 @@ pub struct LOGSQUAREDFJC
 /// The expected end-to-end length as a function of the applied force and temperature, parameterized by the number of links, link length, link stiffness, and link energy.
 pub fn end_to_end_length(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    link_length*nondimensional_end_to_end_length(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+    try_end_to_end_length(number_of_links, link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected end-to-end length as a function of the applied force and temperature, parameterized by the number of links, link length, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_end_to_end_length(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(link_length*try_nondimensional_end_to_end_length(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), integration_tolerance)?)
 }
 
 /// The expected end-to-end length per link as a function of the applied force and temperature, parameterized by the link length, link stiffness, and link energy.
 pub fn end_to_end_length_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    link_length*nondimensional_end_to_end_length_per_link(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+    try_end_to_end_length_per_link(link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected end-to-end length per link as a function of the applied force and temperature, parameterized by the link length, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_end_to_end_length_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(link_length*try_nondimensional_end_to_end_length_per_link(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), integration_tolerance)?)
 }
 
 /// The expected nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links, the nondimensional link stiffness, and nondimensional link energy.
 pub fn nondimensional_end_to_end_length(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    (*number_of_links as f64)*nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force)
+    try_nondimensional_end_to_end_length(number_of_links, nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links, the nondimensional link stiffness, and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_end_to_end_length(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*try_nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force, integration_tolerance)?)
 }
 
 /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness and nondimensional link energy.
 pub fn nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
 {
     let nondimensional_link_stretch_max = 1.0_f64.exp();
     let rescaled_partition_function_integrand = |nondimensional_link_stretch: &f64|
     {
         let exponent_1 = nondimensional_force*nondimensional_link_stretch - 0.5*nondimensional_link_stiffness*nondimensional_link_stretch.ln().powi(2) + nondimensional_link_stretch.ln() - nondimensional_force.ln();
-        let exponent_2 = exponent_1 - 2.0*nondimensional_force*nondimensional_link_stretch;
-        exponent_1.exp() - exponent_2.exp()
+        -exponent_1.exp()*(-2.0*nondimensional_force*nondimensional_link_stretch).exp_m1()
     };
-    let rescaled_partition_function = integrate_1d(&rescaled_partition_function_integrand, &ZERO, &nondimensional_link_stretch_max, &POINTS);
+    let rescaled_partition_function = try_integrate(&rescaled_partition_function_integrand, &0.0, &nondimensional_link_stretch_max, integration_tolerance)?;
     let nondimensional_end_to_end_length_per_link_integrand = |nondimensional_link_stretch: &f64|
     {
         let exponent_1 = nondimensional_force*nondimensional_link_stretch - 0.5*nondimensional_link_stiffness*nondimensional_link_stretch.ln().powi(2) + 2.0*nondimensional_link_stretch.ln() - nondimensional_force.ln();
-        let exponent_2 = exponent_1 - 2.0*nondimensional_force*nondimensional_link_stretch;
-        let exponent_3 = exponent_1 - nondimensional_link_stretch.ln() - nondimensional_force.ln();
-        let exponent_4 = exponent_3 - 2.0*nondimensional_force*nondimensional_link_stretch;
-        (exponent_1.exp() + exponent_2.exp() - exponent_3.exp() + exponent_4.exp())/rescaled_partition_function
+        -exponent_1.exp()*(-2.0*nondimensional_force*nondimensional_link_stretch).exp_m1()*langevin(&(nondimensional_force*nondimensional_link_stretch))/rescaled_partition_function
     };
-    integrate_1d(&nondimensional_end_to_end_length_per_link_integrand, &ZERO, &nondimensional_link_stretch_max, &POINTS)
+    try_integrate(&nondimensional_end_to_end_length_per_link_integrand, &0.0, &nondimensional_link_stretch_max, integration_tolerance)
+}
+
+/// The applied force as a function of the expected end-to-end length and temperature, parameterized by the number of links, link length, link stiffness, and link energy.
+pub fn force(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    try_force(number_of_links, link_length, link_stiffness, end_to_end_length, temperature).unwrap_or(f64::NAN)
+}
+
+/// The applied force as a function of the expected end-to-end length and temperature, parameterized by the number of links, link length, link stiffness, and link energy, or an error if the inversion does not converge.
+pub fn try_force(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length*try_nondimensional_force(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
+}
+
+/// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, parameterized by the nondimensional link stiffness and nondimensional link energy.
+pub fn nondimensional_force(nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    try_nondimensional_force(nondimensional_link_stiffness, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN)
+}
+
+/// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, parameterized by the nondimensional link stiffness and nondimensional link energy, or an error if the inversion does not converge.
+pub fn try_nondimensional_force(nondimensional_link_stiffness: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    inverse(nondimensional_end_to_end_length_per_link, &|nondimensional_force: &f64| self::nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force), &f64::INFINITY)
 }
 
 /// The Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, hinge mass, link stiffness, and link energy.
 pub fn gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_gibbs_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), temperature)
+    try_gibbs_free_energy(number_of_links, link_length, hinge_mass, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, hinge mass, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_gibbs_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), temperature, integration_tolerance)?)
 }
 
 /// The Gibbs free energy per link as a function of the applied force and temperature, parameterized by the link length, hinge mass, link stiffness, and link energy.
 pub fn gibbs_free_energy_per_link(link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    BOLTZMANN_CONSTANT*temperature*nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), temperature)
+    try_gibbs_free_energy_per_link(link_length, hinge_mass, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The Gibbs free energy per link as a function of the applied force and temperature, parameterized by the link length, hinge mass, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_gibbs_free_energy_per_link(link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), temperature, integration_tolerance)?)
 }
 
 /// The relative Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, link stiffness, and link energy.
 pub fn relative_gibbs_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    gibbs_free_energy(number_of_links, link_length, &1.0, link_stiffness, force, temperature) - gibbs_free_energy(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature)
+    try_relative_gibbs_free_energy(number_of_links, link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The relative Gibbs free energy as a function of the applied force and temperature, parameterized by the number of links, link length, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_relative_gibbs_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_gibbs_free_energy(number_of_links, link_length, &1.0, link_stiffness, force, temperature, integration_tolerance)? - try_gibbs_free_energy(number_of_links, link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature, integration_tolerance)?)
 }
 
 /// The relative Gibbs free energy per link as a function of the applied force and temperature, parameterized by the link length, link stiffness, and link energy.
 pub fn relative_gibbs_free_energy_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
 {
-    gibbs_free_energy_per_link(link_length, &1.0, link_stiffness, force, temperature) - gibbs_free_energy_per_link(link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature)
+    try_relative_gibbs_free_energy_per_link(link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The relative Gibbs free energy per link as a function of the applied force and temperature, parameterized by the link length, link stiffness, and link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_relative_gibbs_free_energy_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_gibbs_free_energy_per_link(link_length, &1.0, link_stiffness, force, temperature, integration_tolerance)? - try_gibbs_free_energy_per_link(link_length, &1.0, link_stiffness, &(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), temperature, integration_tolerance)?)
 }
 
 /// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature, parameterized by the number of links, link length, hinge mass, nondimensional link stiffness, and nondimensional link energy.
 pub fn nondimensional_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64) -> f64
 {
-    (*number_of_links as f64)*nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature)
+    try_nondimensional_gibbs_free_energy(number_of_links, link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature, parameterized by the number of links, link length, hinge mass, nondimensional link stiffness, and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_gibbs_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*try_nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature, integration_tolerance)?)
 }
 
 /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature, parameterized by the link length, hinge mass, nondimensional link stiffness, and nondimensional link energy.
 pub fn nondimensional_gibbs_free_energy_per_link(link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64) -> f64
+{
+    try_nondimensional_gibbs_free_energy_per_link(link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature, parameterized by the link length, hinge mass, nondimensional link stiffness, and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_gibbs_free_energy_per_link(link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
 {
     let nondimensional_link_stretch_max = 1.0_f64.exp();
     let rescaled_partition_function_integrand = |nondimensional_link_stretch: &f64|
     {
         let exponent_1 = nondimensional_force*nondimensional_link_stretch - 0.5*nondimensional_link_stiffness*nondimensional_link_stretch.ln().powi(2) + nondimensional_link_stretch.ln() - nondimensional_force.ln();
-        let exponent_2 = exponent_1 - 2.0*nondimensional_force*nondimensional_link_stretch;
-        exponent_1.exp() - exponent_2.exp()
+        -exponent_1.exp()*(-2.0*nondimensional_force*nondimensional_link_stretch).exp_m1()
     };
-    let rescaled_partition_function = integrate_1d(&rescaled_partition_function_integrand, &ZERO, &nondimensional_link_stretch_max, &POINTS);
-    -rescaled_partition_function.ln() - (8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+    let rescaled_partition_function = try_integrate(&rescaled_partition_function_integrand, &0.0, &nondimensional_link_stretch_max, integration_tolerance)?;
+    Ok(-rescaled_partition_function.ln() - (8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
 }
 
 /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy.
 pub fn nondimensional_relative_gibbs_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    nondimensional_gibbs_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0) - nondimensional_gibbs_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
+    try_nondimensional_relative_gibbs_free_energy(number_of_links, nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_relative_gibbs_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_gibbs_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0, integration_tolerance)? - try_nondimensional_gibbs_free_energy(number_of_links, &1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0, integration_tolerance)?)
 }
 
 /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness and nondimensional link energy.
 pub fn nondimensional_relative_gibbs_free_energy_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
 {
-    nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0) - nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0)
+    try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness and nondimensional link energy to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(try_nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, nondimensional_force, &300.0, integration_tolerance)? - try_nondimensional_gibbs_free_energy_per_link(&1.0, &1.0, nondimensional_link_stiffness, &ZERO, &300.0, integration_tolerance)?)
+}
+
+/// The compliance as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness.
+pub fn compliance(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
+{
+    try_compliance(number_of_links, link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The compliance as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_compliance(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(link_length.powi(2)/BOLTZMANN_CONSTANT/temperature*try_nondimensional_compliance(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), integration_tolerance)?)
+}
+
+/// The compliance per link as a function of the applied force and temperature, parameterized by the link length and link stiffness.
+pub fn compliance_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
+{
+    try_compliance_per_link(link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The compliance per link as a function of the applied force and temperature, parameterized by the link length and link stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_compliance_per_link(link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(link_length.powi(2)/BOLTZMANN_CONSTANT/temperature*try_nondimensional_compliance_per_link(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature), integration_tolerance)?)
+}
+
+/// The nondimensional compliance as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness.
+pub fn nondimensional_compliance(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_compliance(number_of_links, nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional compliance as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_compliance(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*try_nondimensional_compliance_per_link(nondimensional_link_stiffness, nondimensional_force, integration_tolerance)?)
+}
+
+/// The nondimensional compliance per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness.
+pub fn nondimensional_compliance_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_compliance_per_link(nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The nondimensional compliance per link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_compliance_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    let nondimensional_link_stretch_max = 1.0_f64.exp();
+    let rescaled_partition_function_integrand = |nondimensional_link_stretch: &f64|
+    {
+        let exponent_1 = nondimensional_force*nondimensional_link_stretch - 0.5*nondimensional_link_stiffness*nondimensional_link_stretch.ln().powi(2) + nondimensional_link_stretch.ln() - nondimensional_force.ln();
+        -exponent_1.exp()*(-2.0*nondimensional_force*nondimensional_link_stretch).exp_m1()
+    };
+    let rescaled_partition_function = try_integrate(&rescaled_partition_function_integrand, &0.0, &nondimensional_link_stretch_max, integration_tolerance)?;
+    let nondimensional_end_to_end_length_per_link = try_nondimensional_end_to_end_length_per_link(nondimensional_link_stiffness, nondimensional_force, integration_tolerance)?;
+    let nondimensional_second_moment_per_link_integrand = |nondimensional_link_stretch: &f64|
+    {
+        let exponent_1 = nondimensional_force*nondimensional_link_stretch - 0.5*nondimensional_link_stiffness*nondimensional_link_stretch.ln().powi(2) - nondimensional_force.ln();
+        let argument = nondimensional_force*nondimensional_link_stretch;
+        -exponent_1.exp()*(-2.0*argument).exp_m1()*nondimensional_link_stretch.powi(3)*(1.0 - 2.0*langevin(&argument)/argument)/rescaled_partition_function
+    };
+    Ok(try_integrate(&nondimensional_second_moment_per_link_integrand, &0.0, &nondimensional_link_stretch_max, integration_tolerance)? - nondimensional_end_to_end_length_per_link.powi(2))
+}
+
+/// The variance of the end-to-end length as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness.
+pub fn end_to_end_length_variance(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64) -> f64
+{
+    try_end_to_end_length_variance(number_of_links, link_length, link_stiffness, force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The variance of the end-to-end length as a function of the applied force and temperature, parameterized by the number of links, link length, and link stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_end_to_end_length_variance(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*try_compliance(number_of_links, link_length, link_stiffness, force, temperature, integration_tolerance)?)
+}
+
+/// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness.
+pub fn nondimensional_end_to_end_length_variance(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_end_to_end_length_variance(number_of_links, nondimensional_link_stiffness, nondimensional_force, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+}
+
+/// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force, parameterized by the number of links and nondimensional link stiffness to the given integration tolerance, or an error if the numerical integration does not converge.
+pub fn try_nondimensional_end_to_end_length_variance(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_force: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+{
+    try_nondimensional_compliance(number_of_links, nondimensional_link_stiffness, nondimensional_force, integration_tolerance)
 }
 
 /// The implemented functionality of the log-squared-FJC model thermodynamics in the isotensional ensemble.
@@ -159,64 +343,223 @@ impl LOGSQUAREDFJC
             legendre: self::legendre::LOGSQUAREDFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
     /// The expected end-to-end length as a function of the applied force and temperature.
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
-        end_to_end_length(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
+        self.try_end_to_end_length(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_end_to_end_length(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The expected end-to-end length per link as a function of the applied force and temperature.
     pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        end_to_end_length_per_link(&self.link_length, &self.link_stiffness, force, temperature)
+        self.try_end_to_end_length_per_link(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected end-to-end length per link as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_end_to_end_length_per_link(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length_per_link(&self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The expected nondimensional end-to-end length as a function of the applied nondimensional force.
     pub fn nondimensional_end_to_end_length(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_end_to_end_length(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_end_to_end_length(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional end-to-end length as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_end_to_end_length(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
     }
     /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force.
     pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_end_to_end_length_per_link(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64], temperature: &f64) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature), nondimensional_force)
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
+    }
+    /// The applied force as a function of the expected end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.try_force(end_to_end_length, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied force as a function of the expected end-to-end length and temperature, or an error if the inversion does not converge.
+    pub fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_force(&self.number_of_links, &self.link_length, &self.link_stiffness, end_to_end_length, temperature)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link.
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.try_nondimensional_force(nondimensional_end_to_end_length_per_link, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64], temperature: &f64) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The applied nondimensional force as a function of the expected nondimensional end-to-end length per link, or an error if the inversion does not converge.
+    pub fn try_nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_force(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
     }
     /// The Gibbs free energy as a function of the applied force and temperature.
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature)
+        self.try_gibbs_free_energy(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The Gibbs free energy as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_gibbs_free_energy(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The Gibbs free energy per link as a function of the applied force and temperature.
     pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature)
+        self.try_gibbs_free_energy_per_link(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The Gibbs free energy per link as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The relative Gibbs free energy as a function of the applied force and temperature.
     pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature)
+        self.try_relative_gibbs_free_energy(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The relative Gibbs free energy as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_relative_gibbs_free_energy(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The relative Gibbs free energy per link as a function of the applied force and temperature.
     pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        relative_gibbs_free_energy_per_link(&self.link_length, &self.link_stiffness, force, temperature)
+        self.try_relative_gibbs_free_energy_per_link(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The relative Gibbs free energy per link as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_relative_gibbs_free_energy_per_link(&self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
     }
     /// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature.
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature)
+        self.try_nondimensional_gibbs_free_energy(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Gibbs free energy as a function of the applied nondimensional force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature, integration_tolerance)
     }
     /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature.
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature)
+        self.try_nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_gibbs_free_energy_per_link(&self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, temperature, integration_tolerance)
     }
     /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force.
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_relative_gibbs_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_relative_gibbs_free_energy(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_gibbs_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
     }
     /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force.
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_relative_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+        self.try_nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_relative_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
+    }
+    /// The compliance as a function of the applied force and temperature.
+    pub fn compliance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.try_compliance(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The compliance as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_compliance(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_compliance(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
+    }
+    /// The compliance per link as a function of the applied force and temperature.
+    pub fn compliance_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.try_compliance_per_link(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The compliance per link as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_compliance_per_link(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_compliance_per_link(&self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
+    }
+    /// The nondimensional compliance as a function of the applied nondimensional force.
+    pub fn nondimensional_compliance(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        self.try_nondimensional_compliance(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional compliance as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_compliance(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_compliance(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
+    }
+    /// The nondimensional compliance per link as a function of the applied nondimensional force.
+    pub fn nondimensional_compliance_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        self.try_nondimensional_compliance_per_link(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The nondimensional compliance per link as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_compliance_per_link(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_compliance_per_link(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
+    }
+    /// The variance of the end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length_variance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.try_end_to_end_length_variance(force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The variance of the end-to-end length as a function of the applied force and temperature to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_end_to_end_length_variance(&self, force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length_variance(&self.number_of_links, &self.link_length, &self.link_stiffness, force, temperature, integration_tolerance)
+    }
+    /// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force.
+    pub fn nondimensional_end_to_end_length_variance(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        self.try_nondimensional_end_to_end_length_variance(nondimensional_force, temperature, &INTEGRATION_TOLERANCE).unwrap_or(f64::NAN)
+    }
+    /// The variance of the nondimensional end-to-end length as a function of the applied nondimensional force to the given integration tolerance, or an error if the numerical integration does not converge.
+    pub fn try_nondimensional_end_to_end_length_variance(&self, nondimensional_force: &f64, temperature: &f64, integration_tolerance: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length_variance(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), nondimensional_force, integration_tolerance)
     }
 }
@@ -9,3 +9,140 @@ pub mod log_squared;
 
 /// The uFJC single-chain model with the Morse link potential.
 pub mod morse;
+
+use std::f64::consts::PI;
+use crate::error::Error;
+use crate::math::
+{
+    bracket,
+    try_integrate_1d_gauss_kronrod,
+    inverse_brent,
+    langevin
+};
+use crate::physics::single_chain::
+{
+    ZERO,
+    POINTS,
+    INVERSION_TOLERANCE,
+    INTEGRATION_TOLERANCE,
+    try_integrate
+};
+
+/// The nondimensional end-to-end length per link below which the isometric functions are continued to zero from their values there.
+static SMALL: f64 = 1e-4;
+
+/// The natural logarithm of the hyperbolic sine of the argument divided by the argument.
+fn ln_sinh_over_argument(argument: &f64) -> f64
+{
+    if argument < &1e-3
+    {
+        argument.powi(2)/6.0
+    }
+    else
+    {
+        argument + (-(-2.0*argument).exp()).ln_1p() - (2.0*argument).ln()
+    }
+}
+
+/// The bounds of nondimensional link stretch outside of which the exponential of the given exponent is negligible compared to its maximum, located by scanning the exponent, along with the scanned maximum.
+fn nondimensional_link_stretch_support(exponent: &dyn Fn(&f64) -> f64, nondimensional_link_stretch_max: &f64) -> (f64, f64, f64)
+{
+    let number_of_scan_points = POINTS as usize;
+    let scan_spacing = (nondimensional_link_stretch_max - ZERO)/(number_of_scan_points as f64);
+    let scan_exponents: Vec<f64> = (0..number_of_scan_points).map(|index| exponent(&(ZERO + (0.5 + index as f64)*scan_spacing))).collect();
+    let maximum_exponent = scan_exponents.iter().fold(f64::NEG_INFINITY, |maximum, exponent| maximum.max(*exponent));
+    let lower_index = scan_exponents.iter().position(|exponent| exponent > &(maximum_exponent - 50.0)).unwrap_or(0);
+    let upper_index = scan_exponents.iter().rposition(|exponent| exponent > &(maximum_exponent - 50.0)).unwrap_or(number_of_scan_points - 1);
+    (
+        ZERO + (lower_index.saturating_sub(1) as f64)*scan_spacing,
+        ZERO + ((upper_index + 2).min(number_of_scan_points) as f64)*scan_spacing,
+        maximum_exponent
+    )
+}
+
+/// The nondimensional force for which the isotensional nondimensional end-to-end length per link equals the one applied,
+/// or an error if the nondimensional end-to-end length per link is negative or no force within the maximum link stretch produces it.
+fn nondimensional_saddle_point_force(nondimensional_link_potential: &dyn Fn(&f64) -> f64, nondimensional_link_stretch_max: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    if nondimensional_end_to_end_length_per_link < &0.0
+    {
+        return Err(Error::Domain("nondimensional_end_to_end_length_per_link", *nondimensional_end_to_end_length_per_link, "nonnegative"))
+    }
+    let nondimensional_end_to_end_length_per_link_isotensional = |nondimensional_force: &f64| -> Result<f64, Error>
+    {
+        let exponent = |nondimensional_link_stretch: &f64| 2.0*nondimensional_link_stretch.ln() - nondimensional_link_potential(nondimensional_link_stretch) + ln_sinh_over_argument(&(nondimensional_force*nondimensional_link_stretch));
+        let (lower_bound, upper_bound, maximum_exponent) = nondimensional_link_stretch_support(&exponent, nondimensional_link_stretch_max);
+        let numerator = try_integrate(&|nondimensional_link_stretch: &f64| (exponent(nondimensional_link_stretch) - maximum_exponent).exp()*nondimensional_link_stretch*langevin(&(nondimensional_force*nondimensional_link_stretch)), &lower_bound, &upper_bound, &INTEGRATION_TOLERANCE)?;
+        let denominator = try_integrate(&|nondimensional_link_stretch: &f64| (exponent(nondimensional_link_stretch) - maximum_exponent).exp(), &lower_bound, &upper_bound, &INTEGRATION_TOLERANCE)?;
+        Ok(numerator/denominator)
+    };
+    let function = |nondimensional_force: &f64| nondimensional_end_to_end_length_per_link_isotensional(nondimensional_force).unwrap_or(f64::NAN);
+    let (lower_bound, upper_bound) = bracket(nondimensional_end_to_end_length_per_link, &function, &0.0, &1.0, &f64::INFINITY, &100)?;
+    Ok(inverse_brent(nondimensional_end_to_end_length_per_link, &function, &lower_bound, &upper_bound, &INVERSION_TOLERANCE, &100)?)
+}
+
+/// The natural logarithm of the nondimensional equilibrium distribution and the expected nondimensional force in the isometric ensemble,
+/// or an error if the saddle point cannot be found or the numerical integration does not converge.
+/// Below a small nondimensional end-to-end length per link, where the saddle point force vanishes and the inversion loses precision,
+/// the force is continued linearly to zero and the logarithm of the distribution quadratically to its maximum.
+fn nondimensional_equilibrium_distribution_and_force(number_of_links: &u8, nondimensional_link_potential: &dyn Fn(&f64) -> f64, nondimensional_link_stretch_max: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<[f64; 2], Error>
+{
+    if nondimensional_end_to_end_length_per_link < &0.0 || nondimensional_end_to_end_length_per_link >= &SMALL
+    {
+        return nondimensional_equilibrium_distribution_and_force_saddle_point(number_of_links, nondimensional_link_potential, nondimensional_link_stretch_max, nondimensional_end_to_end_length_per_link)
+    }
+    let [ln_distribution_small, nondimensional_force_small] = nondimensional_equilibrium_distribution_and_force_saddle_point(number_of_links, nondimensional_link_potential, nondimensional_link_stretch_max, &SMALL)?;
+    let nondimensional_stiffness = nondimensional_force_small/SMALL;
+    Ok([
+        ln_distribution_small + 0.5*(*number_of_links as f64)*nondimensional_stiffness*(SMALL.powi(2) - nondimensional_end_to_end_length_per_link.powi(2)),
+        nondimensional_stiffness*nondimensional_end_to_end_length_per_link
+    ])
+}
+
+/// The natural logarithm of the nondimensional equilibrium distribution and the expected nondimensional force in the isometric ensemble, obtained by inverting the isotensional single-link partition function along a contour shifted to the saddle point,
+/// or an error if the saddle point cannot be found or the numerical integration does not converge.
+fn nondimensional_equilibrium_distribution_and_force_saddle_point(number_of_links: &u8, nondimensional_link_potential: &dyn Fn(&f64) -> f64, nondimensional_link_stretch_max: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<[f64; 2], Error>
+{
+    let number_of_links_f64 = *number_of_links as f64;
+    let nondimensional_end_to_end_length = number_of_links_f64*nondimensional_end_to_end_length_per_link;
+    let nondimensional_force = nondimensional_saddle_point_force(nondimensional_link_potential, nondimensional_link_stretch_max, nondimensional_end_to_end_length_per_link)?;
+    let exponent_0 = |nondimensional_link_stretch: &f64| 2.0*nondimensional_link_stretch.ln() - nondimensional_link_potential(nondimensional_link_stretch);
+    let (lower_bound_0, upper_bound_0, maximum_exponent_0) = nondimensional_link_stretch_support(&exponent_0, nondimensional_link_stretch_max);
+    let ln_partition_function_0 = try_integrate(&|nondimensional_link_stretch: &f64| (exponent_0(nondimensional_link_stretch) - maximum_exponent_0).exp(), &lower_bound_0, &upper_bound_0, &INTEGRATION_TOLERANCE)?.ln() + maximum_exponent_0;
+    let exponent = |nondimensional_link_stretch: &f64| exponent_0(nondimensional_link_stretch) + nondimensional_force*nondimensional_link_stretch;
+    let (lower_bound, upper_bound, maximum_exponent) = nondimensional_link_stretch_support(&exponent, nondimensional_link_stretch_max);
+    let weight = |nondimensional_link_stretch: &f64| 0.5*(exponent(nondimensional_link_stretch) - maximum_exponent).exp()/nondimensional_link_stretch;
+    let weight_sinh = |nondimensional_link_stretch: &f64| -weight(nondimensional_link_stretch)*(-2.0*nondimensional_force*nondimensional_link_stretch).exp_m1();
+    let weight_cosh = |nondimensional_link_stretch: &f64| weight(nondimensional_link_stretch)*(1.0 + (-2.0*nondimensional_force*nondimensional_link_stretch).exp());
+    let transform_0 = try_integrate(&weight_sinh, &lower_bound, &upper_bound, &INTEGRATION_TOLERANCE)?;
+    let transform = |wavenumber: &f64| -> Result<(f64, f64), Error>
+    {
+        let real = try_integrate_1d_gauss_kronrod(&|nondimensional_link_stretch: &f64| weight_sinh(nondimensional_link_stretch)*(wavenumber*nondimensional_link_stretch).cos(), &lower_bound, &upper_bound, &(INTEGRATION_TOLERANCE*transform_0), &INTEGRATION_TOLERANCE)?.0;
+        let imaginary = try_integrate_1d_gauss_kronrod(&|nondimensional_link_stretch: &f64| -weight_cosh(nondimensional_link_stretch)*(wavenumber*nondimensional_link_stretch).sin(), &lower_bound, &upper_bound, &(INTEGRATION_TOLERANCE*transform_0), &INTEGRATION_TOLERANCE)?.0;
+        Ok((real, imaginary))
+    };
+    let ln_partition_function = (transform_0/nondimensional_force).ln() + maximum_exponent;
+    let mean = try_integrate(&|nondimensional_link_stretch: &f64| weight_sinh(nondimensional_link_stretch)*nondimensional_link_stretch, &lower_bound, &upper_bound, &INTEGRATION_TOLERANCE)?/transform_0;
+    let variance = try_integrate(&|nondimensional_link_stretch: &f64| weight_sinh(nondimensional_link_stretch)*(nondimensional_link_stretch - mean).powi(2), &lower_bound, &upper_bound, &INTEGRATION_TOLERANCE)?/transform_0;
+    let wavenumber_max = (100.0/number_of_links_f64/variance).sqrt();
+    let wavenumber_spacing = 0.5*PI/(nondimensional_end_to_end_length + number_of_links_f64*upper_bound);
+    let (integral_1, integral_2) = (0..(wavenumber_max/wavenumber_spacing).ceil() as usize).try_fold((0.0, 0.0), |(integral_1, integral_2), index| -> Result<(f64, f64), Error>
+    {
+        let wavenumber = (0.5 + index as f64)*wavenumber_spacing;
+        let (real, imaginary) = transform(&wavenumber)?;
+        let denominator = (nondimensional_force.powi(2) + wavenumber.powi(2))*transform_0/nondimensional_force;
+        let ratio_real = (real*nondimensional_force - imaginary*wavenumber)/denominator;
+        let ratio_imaginary = (imaginary*nondimensional_force + real*wavenumber)/denominator;
+        let modulus = (ratio_real.powi(2) + ratio_imaginary.powi(2)).sqrt().powi(*number_of_links as i32);
+        let phase = number_of_links_f64*ratio_imaginary.atan2(ratio_real) + wavenumber*nondimensional_end_to_end_length;
+        let (term_real, term_imaginary) = (modulus*phase.cos(), modulus*phase.sin());
+        Ok((
+            integral_1 + wavenumber*term_imaginary + nondimensional_force*term_real,
+            integral_2 + (wavenumber.powi(2) - nondimensional_force.powi(2))*term_real - 2.0*wavenumber*nondimensional_force*term_imaginary
+        ))
+    })?;
+    Ok([
+        3.0*number_of_links_f64.ln() - nondimensional_force*nondimensional_end_to_end_length + number_of_links_f64*(ln_partition_function - ln_partition_function_0) + (integral_1*wavenumber_spacing).ln() - (2.0*PI.powi(2)*nondimensional_end_to_end_length).ln(),
+        1.0/nondimensional_end_to_end_length - integral_2/integral_1
+    ])
+}
@@ -4,6 +4,7 @@ The Morse potential freely-jointed chain (Morse-FJC) single-chain model.
 module Morse
 
 using DocStringExtensions
+using .....Polymers: validate_number_of_links, validate_positive
 
 include("thermodynamics/mod.jl")
 
@@ -51,6 +52,11 @@ function MORSEFJC(
     link_stiffness::Float64,
     link_energy::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("link_stiffness", link_stiffness)
+    validate_positive("link_energy", link_energy)
     return MORSEFJC(
         number_of_links,
         link_length,
@@ -3,6 +3,15 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
+/// The Morse link potential freely-jointed chain (Morse-FJC) model rupture kinetics.
+pub mod rupture;
+
 /// The Morse link potential freely-jointed chain (Morse-FJC) model thermodynamics.
 pub mod thermodynamics;
 
@@ -24,10 +33,16 @@ pub struct MORSEFJC
     /// The energy of each link in the chain in units of J/mol.
     pub link_energy: f64,
 
+    /// The rupture kinetics of the model.
+    pub rupture: self::rupture::MORSEFJC,
+
     /// The thermodynamic functions of the model.
     pub thermodynamics: self::thermodynamics::MORSEFJC
 }
 
+#[cfg(feature = "serde")]
+crate::config::parameters!(MORSEFJC, try_init, number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, link_energy: f64);
+
 /// The implemented functionality of the Morse-FJC model.
 impl MORSEFJC
 {
@@ -41,7 +56,35 @@ impl MORSEFJC
             number_of_links,
             link_stiffness,
             link_energy,
+            rupture: self::rupture::MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy),
             thermodynamics: self::thermodynamics::MORSEFJC::init(number_of_links, link_length, hinge_mass, link_stiffness, link_energy),
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, link_energy: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        error::positive("link_energy", &link_energy)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness, link_energy))
+    }
+}
+
+/// The force law of the Morse-FJC model, using the isometric ensemble approximated using a Legendre transformation.
+impl super::super::ForceLaw for MORSEFJC
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.asymptotic.legendre.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature)
+    }
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.asymptotic.legendre.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature)
+    }
 }
@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 pub fn register_module(py: Python<'_>, parent_module: &PyModule) -> PyResult<()>
 {
     let morse = PyModule::new(py, "morse")?;
+    super::rupture::py::register_module(py, morse)?;
     super::thermodynamics::py::register_module(py, morse)?;
     parent_module.add_submodule(morse)?;
     morse.add_class::<MORSEFJC>()?;
@@ -33,6 +34,10 @@ pub struct MORSEFJC
     #[pyo3(get)]
     pub link_energy: f64,
     
+    /// The rupture kinetics of the model.
+    #[pyo3(get)]
+    pub rupture: super::rupture::py::MORSEFJC,
+
     /// The thermodynamic functions of the model.
     #[pyo3(get)]
     pub thermodynamics: super::thermodynamics::py::MORSEFJC
@@ -42,16 +47,22 @@ pub struct MORSEFJC
 impl MORSEFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, link_energy: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, link_energy: f64) -> PyResult<Self>
     {
-        MORSEFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        crate::error::positive("link_energy", &link_energy)?;
+        Ok(MORSEFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness,
             link_energy,
-            thermodynamics: super::thermodynamics::py::MORSEFJC::init(number_of_links, link_length, hinge_mass, link_stiffness, link_energy)
-        }
+            rupture: super::rupture::py::MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy)?,
+            thermodynamics: super::thermodynamics::py::MORSEFJC::init(number_of_links, link_length, hinge_mass, link_stiffness, link_energy)?
+        })
     }
 }
@@ -0,0 +1,20 @@
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_energy_barrier(link_length: f64, link_stiffness: f64, link_energy: f64, force: f64, temperature: f64) -> f64
+{
+    super::energy_barrier(&link_length, &link_stiffness, &link_energy, &force, &temperature)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_nondimensional_energy_barrier(nondimensional_link_stiffness: f64, nondimensional_link_energy: f64, nondimensional_force: f64) -> f64
+{
+    super::nondimensional_energy_barrier(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_link_breaking_rate(link_length: f64, link_stiffness: f64, link_energy: f64, force: f64, temperature: f64) -> f64
+{
+    super::link_breaking_rate(&link_length, &link_stiffness, &link_energy, &force, &temperature)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_nondimensional_link_breaking_rate(nondimensional_link_stiffness: f64, nondimensional_link_energy: f64, nondimensional_force: f64) -> f64
+{
+    super::nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force)
+}
@@ -0,0 +1,30 @@
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_isometric_breaking_rate(number_of_links: u8, link_length: f64, link_stiffness: f64, link_energy: f64, end_to_end_length: f64, temperature: f64) -> f64
+{
+    super::breaking_rate(&number_of_links, &link_length, &link_stiffness, &link_energy, &end_to_end_length, &temperature)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_isometric_nondimensional_breaking_rate(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_link_energy: f64, nondimensional_end_to_end_length_per_link: f64) -> f64
+{
+    super::nondimensional_breaking_rate(&number_of_links, &nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_end_to_end_length_per_link)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_isometric_survival_probability(number_of_links: u8, link_length: f64, link_stiffness: f64, link_energy: f64, end_to_end_length: f64, time: f64, temperature: f64) -> f64
+{
+    super::survival_probability(&number_of_links, &link_length, &link_stiffness, &link_energy, &end_to_end_length, &time, &temperature)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_isometric_nondimensional_survival_probability(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_link_energy: f64, nondimensional_end_to_end_length_per_link: f64, nondimensional_time: f64) -> f64
+{
+    super::nondimensional_survival_probability(&number_of_links, &nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_end_to_end_length_per_link, &nondimensional_time)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_isometric_most_probable_rupture_force(number_of_links: u8, link_length: f64, link_stiffness: f64, link_energy: f64, extension_rate: f64, temperature: f64) -> f64
+{
+    super::most_probable_rupture_force(&number_of_links, &link_length, &link_stiffness, &link_energy, &extension_rate, &temperature).unwrap_or(f64::NAN)
+}
+#[no_mangle]
+pub extern fn physics_single_chain_ufjc_morse_rupture_isometric_nondimensional_most_probable_rupture_force(number_of_links: u8, nondimensional_link_stiffness: f64, nondimensional_link_energy: f64, nondimensional_extension_rate: f64) -> f64
+{
+    super::nondimensional_most_probable_rupture_force(&number_of_links, &nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_extension_rate).unwrap_or(f64::NAN)
+}
@@ -0,0 +1,153 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+fn nondimensional_end_to_end_length_per_link_max(link_stiffness: &f64, link_length: &f64, link_energy: &f64, temperature: &f64) -> f64
+{
+    let nondimensional_link_stiffness = link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
+    let nondimensional_link_energy = link_energy/BOLTZMANN_CONSTANT/temperature;
+    nondimensional_end_to_end_length_per_link(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force_max(&nondimensional_link_stiffness, &nondimensional_link_energy))
+}
+mod base
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = MORSEFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.link_stiffness_reference, parameters.link_energy_reference);
+    }
+}
+mod nondimensional
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn breaking_rate()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let model = MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = nondimensional_end_to_end_length_per_link_max(&link_stiffness, &link_length, &link_energy, &temperature)*rng.gen::<f64>();
+            let nondimensional_breaking_rate = model.nondimensional_breaking_rate(&nondimensional_end_to_end_length_per_link, &temperature);
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let breaking_rate = model.breaking_rate(&end_to_end_length, &temperature);
+            let residual_abs = &breaking_rate*PLANCK_CONSTANT/BOLTZMANN_CONSTANT/temperature - &nondimensional_breaking_rate;
+            let residual_rel = &residual_abs/&nondimensional_breaking_rate;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn survival_probability()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let model = MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = nondimensional_end_to_end_length_per_link_max(&link_stiffness, &link_length, &link_energy, &temperature)*rng.gen::<f64>();
+            let nondimensional_time = rng.gen::<f64>()/model.nondimensional_breaking_rate(&nondimensional_end_to_end_length_per_link, &temperature);
+            let nondimensional_survival_probability = model.nondimensional_survival_probability(&nondimensional_end_to_end_length_per_link, &nondimensional_time, &temperature);
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let time = nondimensional_time*PLANCK_CONSTANT/BOLTZMANN_CONSTANT/temperature;
+            let survival_probability = model.survival_probability(&end_to_end_length, &time, &temperature);
+            let residual_abs = &survival_probability - &nondimensional_survival_probability;
+            let residual_rel = &residual_abs/&nondimensional_survival_probability;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn most_probable_rupture_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let model = MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_link_stiffness = link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_link_energy = link_energy/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_force = nondimensional_force_max(&nondimensional_link_stiffness, &nondimensional_link_energy)*(0.25 + 0.5*rng.gen::<f64>());
+            let [nondimensional_link_stretch, nondimensional_link_stretch_transition] = nondimensional_link_stretches(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force);
+            let nondimensional_extension_rate = (number_of_links as f64)*nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force)/(nondimensional_link_stretch_transition - nondimensional_link_stretch)/nondimensional_stiffness(&number_of_links, &nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_end_to_end_length_per_link(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force));
+            let nondimensional_most_probable_rupture_force = model.nondimensional_most_probable_rupture_force(&nondimensional_extension_rate, &temperature).unwrap();
+            let extension_rate = nondimensional_extension_rate*link_length*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT;
+            let most_probable_rupture_force = model.most_probable_rupture_force(&extension_rate, &temperature).unwrap();
+            let residual_abs = &most_probable_rupture_force/BOLTZMANN_CONSTANT/temperature*link_length - &nondimensional_most_probable_rupture_force;
+            let residual_rel = &residual_abs/&nondimensional_most_probable_rupture_force;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod zero
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn survival_probability()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let model = MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = nondimensional_end_to_end_length_per_link_max(&link_stiffness, &link_length, &link_energy, &temperature)*rng.gen::<f64>();
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let survival_probability_0 = model.survival_probability(&end_to_end_length, &0.0, &temperature);
+            assert_eq!(survival_probability_0, 1.0);
+        }
+    }
+}
+mod connection
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn most_probable_rupture_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*0.5*rng.gen::<f64>();
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_link_stiffness = link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_link_energy = link_energy/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_force = nondimensional_force_max(&nondimensional_link_stiffness, &nondimensional_link_energy)*(0.25 + 0.5*rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = nondimensional_end_to_end_length_per_link(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force);
+            let h = parameters.rel_tol;
+            let nondimensional_activation_length = (nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &nondimensional_link_energy, &(nondimensional_force + 0.5*h)).ln() - nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &nondimensional_link_energy, &(nondimensional_force - 0.5*h)).ln())/h;
+            let nondimensional_extension_rate = (number_of_links as f64)*nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force)/nondimensional_activation_length/nondimensional_stiffness(&number_of_links, &nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_end_to_end_length_per_link);
+            let nondimensional_most_probable_rupture_force = nondimensional_most_probable_rupture_force(&number_of_links, &nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_extension_rate).unwrap();
+            let residual_abs = &nondimensional_most_probable_rupture_force - &nondimensional_force;
+            let residual_rel = &residual_abs/&nondimensional_force;
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+}
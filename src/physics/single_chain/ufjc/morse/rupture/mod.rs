@@ -0,0 +1,137 @@
+#[cfg(feature = "extern")]
+pub mod ex;
+
+#[cfg(feature = "python")]
+pub mod py;
+
+mod test;
+
+/// The Morse link potential freely-jointed chain (Morse-FJC) model rupture kinetics in the isometric ensemble.
+pub mod isometric;
+
+/// The Morse link potential freely-jointed chain (Morse-FJC) model rupture kinetics in the isotensional ensemble.
+pub mod isotensional;
+
+use crate::error::
+{
+    self,
+    Error
+};
+use crate::physics::
+{
+    PLANCK_CONSTANT,
+    BOLTZMANN_CONSTANT
+};
+
+/// The structure of the Morse-FJC model rupture kinetics.
+pub struct MORSEFJC
+{
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    pub link_stiffness: f64,
+
+    /// The energy of each link in the chain in units of J/mol.
+    pub link_energy: f64,
+
+    /// The rupture kinetics of the model in the isometric ensemble.
+    pub isometric: self::isometric::MORSEFJC,
+
+    /// The rupture kinetics of the model in the isotensional ensemble.
+    pub isotensional: self::isotensional::MORSEFJC
+}
+
+/// The energy barrier of the force-tilted link potential as a function of the applied force and temperature, parameterized by the link length, link stiffness, and link energy.
+pub fn energy_barrier(link_length: &f64, link_stiffness: &f64, link_energy: &f64, force: &f64, temperature: &f64) -> f64
+{
+    BOLTZMANN_CONSTANT*temperature*nondimensional_energy_barrier(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+}
+
+/// The nondimensional energy barrier of the force-tilted link potential as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness and nondimensional link energy.
+pub fn nondimensional_energy_barrier(nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_force: &f64) -> f64
+{
+    let [nondimensional_link_stretch, nondimensional_link_stretch_transition] = nondimensional_link_stretches(nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_force);
+    let nondimensional_morse_parameter = (nondimensional_link_stiffness/nondimensional_link_energy/2.0).sqrt();
+    let nondimensional_link_potential = |nondimensional_link_stretch: &f64| nondimensional_link_energy*(1.0 - (-nondimensional_morse_parameter*(nondimensional_link_stretch - 1.0)).exp()).powi(2);
+    nondimensional_link_potential(&nondimensional_link_stretch_transition) - nondimensional_link_potential(&nondimensional_link_stretch) - nondimensional_force*(nondimensional_link_stretch_transition - nondimensional_link_stretch)
+}
+
+/// The rate of breaking a single link as a function of the applied force and temperature, parameterized by the link length, link stiffness, and link energy.
+pub fn link_breaking_rate(link_length: &f64, link_stiffness: &f64, link_energy: &f64, force: &f64, temperature: &f64) -> f64
+{
+    BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT*nondimensional_link_breaking_rate(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))
+}
+
+/// The nondimensional rate of breaking a single link as a function of the applied nondimensional force, parameterized by the nondimensional link stiffness and nondimensional link energy.
+pub fn nondimensional_link_breaking_rate(nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_force: &f64) -> f64
+{
+    (-nondimensional_energy_barrier(nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_force)).exp()
+}
+
+/// The maximum nondimensional force the link potential can sustain, parameterized by the nondimensional link stiffness and nondimensional link energy.
+fn nondimensional_force_max(nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64) -> f64
+{
+    (nondimensional_link_stiffness*nondimensional_link_energy/8.0).sqrt()
+}
+
+/// The nondimensional link stretches at the minimum and at the transition state of the force-tilted link potential.
+fn nondimensional_link_stretches(nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_force: &f64) -> [f64; 2]
+{
+    let nondimensional_morse_parameter = (nondimensional_link_stiffness/nondimensional_link_energy/2.0).sqrt();
+    let root = (1.0 - nondimensional_force/nondimensional_force_max(nondimensional_link_stiffness, nondimensional_link_energy)).max(0.0).sqrt();
+    [
+        1.0 - (0.5*(1.0 + root)).ln()/nondimensional_morse_parameter,
+        1.0 - (0.5*(1.0 - root)).ln()/nondimensional_morse_parameter
+    ]
+}
+
+/// The implemented functionality of the Morse-FJC model rupture kinetics.
+impl MORSEFJC
+{
+    /// Initializes and returns an instance of the Morse-FJC model rupture kinetics.
+    pub fn init(number_of_links: u8, link_length: f64, link_stiffness: f64, link_energy: f64) -> Self
+    {
+        MORSEFJC
+        {
+            link_length,
+            number_of_links,
+            link_stiffness,
+            link_energy,
+            isometric: self::isometric::MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy),
+            isotensional: self::isotensional::MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy)
+        }
+    }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, link_stiffness: f64, link_energy: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        error::positive("link_energy", &link_energy)?;
+        Ok(Self::init(number_of_links, link_length, link_stiffness, link_energy))
+    }
+    /// The energy barrier of the force-tilted link potential as a function of the applied force and temperature.
+    pub fn energy_barrier(&self, force: &f64, temperature: &f64) -> f64
+    {
+        energy_barrier(&self.link_length, &self.link_stiffness, &self.link_energy, force, temperature)
+    }
+    /// The nondimensional energy barrier of the force-tilted link potential as a function of the applied nondimensional force.
+    pub fn nondimensional_energy_barrier(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        nondimensional_energy_barrier(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+    }
+    /// The rate of breaking a single link as a function of the applied force and temperature.
+    pub fn link_breaking_rate(&self, force: &f64, temperature: &f64) -> f64
+    {
+        link_breaking_rate(&self.link_length, &self.link_stiffness, &self.link_energy, force, temperature)
+    }
+    /// The nondimensional rate of breaking a single link as a function of the applied nondimensional force.
+    pub fn nondimensional_link_breaking_rate(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        nondimensional_link_breaking_rate(&(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+    }
+}
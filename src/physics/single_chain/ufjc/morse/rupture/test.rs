@@ -0,0 +1,133 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = MORSEFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.link_stiffness_reference, parameters.link_energy_reference);
+    }
+    #[test]
+    fn all_parameters()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let model = MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy);
+            assert_eq!(number_of_links, model.number_of_links);
+            assert_eq!(link_length, model.link_length);
+            assert_eq!(link_stiffness, model.link_stiffness);
+            assert_eq!(link_energy, model.link_energy);
+        }
+    }
+}
+mod nondimensional
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn energy_barrier()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let model = MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = nondimensional_force_max(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature))*rng.gen::<f64>();
+            let nondimensional_energy_barrier = model.nondimensional_energy_barrier(&nondimensional_force, &temperature);
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let energy_barrier = model.energy_barrier(&force, &temperature);
+            let residual_abs = &energy_barrier/BOLTZMANN_CONSTANT/temperature - &nondimensional_energy_barrier;
+            let residual_rel = &residual_abs/&nondimensional_energy_barrier;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn link_breaking_rate()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let model = MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = nondimensional_force_max(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature))*rng.gen::<f64>();
+            let nondimensional_link_breaking_rate = model.nondimensional_link_breaking_rate(&nondimensional_force, &temperature);
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let link_breaking_rate = model.link_breaking_rate(&force, &temperature);
+            let residual_abs = &link_breaking_rate*PLANCK_CONSTANT/BOLTZMANN_CONSTANT/temperature - &nondimensional_link_breaking_rate;
+            let residual_rel = &residual_abs/&nondimensional_link_breaking_rate;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_rel.abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod limit
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn energy_barrier()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let model = MORSEFJC::init(number_of_links, link_length, link_stiffness, link_energy);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force_max = nondimensional_force_max(&(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature));
+            let nondimensional_energy_barrier = model.nondimensional_energy_barrier(&nondimensional_force_max, &temperature);
+            assert!(nondimensional_energy_barrier.abs() <= parameters.abs_tol);
+        }
+    }
+}
+mod connection
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn link_breaking_rate()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let link_energy = parameters.link_energy_reference + parameters.link_energy_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_link_stiffness = link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_link_energy = link_energy/BOLTZMANN_CONSTANT/temperature;
+            let nondimensional_force = nondimensional_force_max(&nondimensional_link_stiffness, &nondimensional_link_energy)*(0.25 + 0.5*rng.gen::<f64>());
+            let [nondimensional_link_stretch, nondimensional_link_stretch_transition] = nondimensional_link_stretches(&nondimensional_link_stiffness, &nondimensional_link_energy, &nondimensional_force);
+            let h = parameters.rel_tol;
+            let nondimensional_activation_length_from_derivative = ((nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &nondimensional_link_energy, &(nondimensional_force + 0.5*h))).ln() - (nondimensional_link_breaking_rate(&nondimensional_link_stiffness, &nondimensional_link_energy, &(nondimensional_force - 0.5*h))).ln())/h;
+            let residual_abs = nondimensional_link_stretch_transition - nondimensional_link_stretch - &nondimensional_activation_length_from_derivative;
+            let residual_rel = &residual_abs/(nondimensional_link_stretch_transition - nondimensional_link_stretch);
+            assert!(residual_rel.abs() <= h);
+        }
+    }
+}
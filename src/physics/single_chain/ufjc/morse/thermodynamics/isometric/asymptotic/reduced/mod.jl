@@ -4,7 +4,7 @@ The Morse potential freely-jointed chain (Morse-FJC) model thermodynamics in the
 module Reduced
 
 using DocStringExtensions
-using .........Polymers: PROJECT_ROOT
+using .........Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 include("legendre/mod.jl")
 
@@ -52,6 +52,11 @@ function MORSEFJC(
     link_stiffness::Float64,
     link_energy::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("link_stiffness", link_stiffness)
+    validate_positive("link_energy", link_energy)
     return MORSEFJC(
         number_of_links,
         link_length,
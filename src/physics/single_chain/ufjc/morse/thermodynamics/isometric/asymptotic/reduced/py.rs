@@ -43,16 +43,21 @@ pub struct MORSEFJC
 impl MORSEFJC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, link_energy: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, link_energy: f64) -> PyResult<Self>
     {
-        MORSEFJC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("link_stiffness", &link_stiffness)?;
+        crate::error::positive("link_energy", &link_energy)?;
+        Ok(MORSEFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
             link_stiffness,
             link_energy,
-            legendre: super::legendre::py::MORSEFJC::init(number_of_links, link_length, hinge_mass, link_stiffness, link_energy)
-        }
+            legendre: super::legendre::py::MORSEFJC::init(number_of_links, link_length, hinge_mass, link_stiffness, link_energy)?
+        })
     }
 }
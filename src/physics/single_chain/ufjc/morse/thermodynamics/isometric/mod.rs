@@ -6,6 +6,24 @@ mod test;
 /// The Morse link potential freely-jointed chain (Morse-FJC) model thermodynamics in the isometric ensemble approximated using an asymptotic approach.
 pub mod asymptotic;
 
+use crate::error::
+{
+    self,
+    Error
+};
+use std::f64::consts::PI;
+use crate::physics::
+{
+    PLANCK_CONSTANT,
+    BOLTZMANN_CONSTANT,
+    single_chain::
+    {
+        ZERO,
+        inverse,
+        ufjc::nondimensional_equilibrium_distribution_and_force
+    }
+};
+
 /// The structure of the Morse-FJC model thermodynamics in the isometric ensemble.
 pub struct MORSEFJC
 {
@@ -28,6 +46,124 @@ pub struct MORSEFJC
     pub asymptotic: self::asymptotic::MORSEFJC
 }
 
+/// The expected force as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, link stiffness, and link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn force(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, link_energy: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature/link_length*nondimensional_force(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
+}
+
+/// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn nondimensional_force(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(nondimensional_equilibrium_distribution_and_force_exact(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_end_to_end_length_per_link)?[1])
+}
+
+/// The applied end-to-end length as a function of the expected force and temperature, parameterized by the number of links, link length, link stiffness, and link energy.
+pub fn end_to_end_length(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, link_energy: &f64, force: &f64, temperature: &f64) -> f64
+{
+    try_end_to_end_length(number_of_links, link_length, link_stiffness, link_energy, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The applied end-to-end length as a function of the expected force and temperature, parameterized by the number of links, link length, link stiffness, and link energy, or an error if the inversion does not converge.
+pub fn try_end_to_end_length(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, link_energy: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*link_length*try_nondimensional_end_to_end_length_per_link(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(force*link_length/BOLTZMANN_CONSTANT/temperature))?)
+}
+
+/// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy.
+pub fn nondimensional_end_to_end_length_per_link(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_end_to_end_length_per_link(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy, or an error if the inversion does not converge.
+pub fn try_nondimensional_end_to_end_length_per_link(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    inverse(nondimensional_force, &|nondimensional_end_to_end_length_per_link: &f64| self::nondimensional_force(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_end_to_end_length_per_link).unwrap_or(f64::NAN), &f64::INFINITY)
+}
+
+/// The Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, hinge mass, link stiffness, and link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, link_energy: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)), temperature)?)
+}
+
+/// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, hinge mass, link stiffness, and link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, link_stiffness: &f64, link_energy: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*nondimensional_helmholtz_free_energy_per_link(number_of_links, link_length, hinge_mass, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)), temperature)?)
+}
+
+/// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, link stiffness, and link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn relative_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, link_energy: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*nondimensional_relative_helmholtz_free_energy(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
+}
+
+/// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, link stiffness, and link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn relative_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, link_energy: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(BOLTZMANN_CONSTANT*temperature*nondimensional_relative_helmholtz_free_energy_per_link(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/((*number_of_links as f64)*link_length)))?)
+}
+
+/// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, hinge mass, nondimensional link stiffness, and nondimensional link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn nondimensional_helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(3.0*((*number_of_links as f64)*link_length).ln() - nondimensional_equilibrium_distribution_and_force_exact(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_end_to_end_length_per_link)?[0] - ((*number_of_links as f64) - 1.0)*(8.0*PI.powi(2)*hinge_mass*link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+}
+
+/// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, parameterized by the number of links, link length, hinge mass, nondimensional link stiffness, and nondimensional link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn nondimensional_helmholtz_free_energy_per_link(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok(nondimensional_helmholtz_free_energy(number_of_links, link_length, hinge_mass, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_end_to_end_length_per_link, temperature)?/(*number_of_links as f64))
+}
+
+/// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn nondimensional_relative_helmholtz_free_energy(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(nondimensional_equilibrium_distribution_and_force_exact(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, &ZERO)?[0] - nondimensional_equilibrium_distribution_and_force_exact(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_end_to_end_length_per_link)?[0])
+}
+
+/// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn nondimensional_relative_helmholtz_free_energy_per_link(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(nondimensional_relative_helmholtz_free_energy(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_end_to_end_length_per_link)?/(*number_of_links as f64))
+}
+
+/// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length and temperature, parameterized by the number of links, link length, link stiffness, and link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn equilibrium_distribution(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, link_energy: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    let contour_length = (*number_of_links as f64)*link_length;
+    Ok(nondimensional_equilibrium_distribution(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/contour_length))?/contour_length.powi(3))
+}
+
+/// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn nondimensional_equilibrium_distribution(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(nondimensional_equilibrium_distribution_and_force_exact(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_end_to_end_length_per_link)?[0].exp())
+}
+
+/// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length and temperature, parameterized by the number of links, link length, link stiffness, and link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn equilibrium_radial_distribution(number_of_links: &u8, link_length: &f64, link_stiffness: &f64, link_energy: &f64, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    let contour_length = (*number_of_links as f64)*link_length;
+    Ok(nondimensional_equilibrium_radial_distribution(number_of_links, &(link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(link_energy/BOLTZMANN_CONSTANT/temperature), &(end_to_end_length/contour_length))?/contour_length)
+}
+
+/// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, parameterized by the number of links, nondimensional link stiffness, and nondimensional link energy, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+pub fn nondimensional_equilibrium_radial_distribution(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+{
+    Ok(4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*nondimensional_equilibrium_distribution(number_of_links, nondimensional_link_stiffness, nondimensional_link_energy, nondimensional_end_to_end_length_per_link)?)
+}
+
+/// The natural logarithm of the nondimensional equilibrium distribution and the expected nondimensional force, calculated by inverting the isotensional partition function of a single link, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+fn nondimensional_equilibrium_distribution_and_force_exact(number_of_links: &u8, nondimensional_link_stiffness: &f64, nondimensional_link_energy: &f64, nondimensional_end_to_end_length_per_link: &f64) -> Result<[f64; 2], Error>
+{
+    let nondimensional_morse_parameter = (nondimensional_link_stiffness/nondimensional_link_energy/2.0).sqrt();
+    let nondimensional_link_potential = |nondimensional_link_stretch: &f64| nondimensional_link_energy*(1.0 - (-nondimensional_morse_parameter*(nondimensional_link_stretch - 1.0)).exp()).powi(2);
+    nondimensional_equilibrium_distribution_and_force(number_of_links, &nondimensional_link_potential, &(1.0 + 2.0_f64.ln()/nondimensional_morse_parameter), nondimensional_end_to_end_length_per_link)
+}
+
 /// The implemented functionality of the Morse-FJC model thermodynamics in the isometric ensemble.
 impl MORSEFJC
 {
@@ -44,4 +180,114 @@ impl MORSEFJC
             asymptotic: self::asymptotic::MORSEFJC::init(number_of_links, link_length, hinge_mass, link_stiffness, link_energy)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, link_energy: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("link_stiffness", &link_stiffness)?;
+        error::positive("link_energy", &link_energy)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, link_stiffness, link_energy))
+    }
+    /// The expected force as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        force(&self.number_of_links, &self.link_length, &self.link_stiffness, &self.link_energy, end_to_end_length, temperature)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_force(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link for each element of a slice, or the first error encountered if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64], temperature: &f64) -> Result<Vec<f64>, Error>
+    {
+        crate::math::try_batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The applied end-to-end length as a function of the expected force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.try_end_to_end_length(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied end-to-end length as a function of the expected force and temperature, or an error if the inversion does not converge.
+    pub fn try_end_to_end_length(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length(&self.number_of_links, &self.link_length, &self.link_stiffness, &self.link_energy, force, temperature)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        self.try_nondimensional_end_to_end_length_per_link(nondimensional_force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64], temperature: &f64) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature), nondimensional_force)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, or an error if the inversion does not converge.
+    pub fn try_nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length_per_link(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_force)
+    }
+    /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &self.link_energy, end_to_end_length, temperature)
+    }
+    /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.link_stiffness, &self.link_energy, end_to_end_length, temperature)
+    }
+    /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.link_stiffness, &self.link_energy, end_to_end_length, temperature)
+    }
+    /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.link_stiffness, &self.link_energy, end_to_end_length, temperature)
+    }
+    /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link, temperature)
+    }
+    /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link, temperature)
+    }
+    /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_relative_helmholtz_free_energy(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_relative_helmholtz_free_energy_per_link(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn equilibrium_distribution(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        equilibrium_distribution(&self.number_of_links, &self.link_length, &self.link_stiffness, &self.link_energy, end_to_end_length, temperature)
+    }
+    /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_equilibrium_distribution(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+    }
+    /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length and temperature, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn equilibrium_radial_distribution(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.link_stiffness, &self.link_energy, end_to_end_length, temperature)
+    }
+    /// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, or an error if the end-to-end length is negative or exceeds the maximum link stretch.
+    pub fn nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        nondimensional_equilibrium_radial_distribution(&self.number_of_links, &(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature), &(self.link_energy/BOLTZMANN_CONSTANT/temperature), nondimensional_end_to_end_length_per_link)
+    }
 }
@@ -4,6 +4,7 @@ The worm-like chain (WLC) single-chain model.
 module Wlc
 
 using DocStringExtensions
+using ....Polymers: validate_number_of_links, validate_positive
 
 include("thermodynamics/mod.jl")
 
@@ -46,6 +47,10 @@ function WLC(
     hinge_mass::Float64,
     persistance_length::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("persistance_length", persistance_length)
     return WLC(
         number_of_links,
         link_length,
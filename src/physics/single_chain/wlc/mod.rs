@@ -3,6 +3,12 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
+
 /// The worm-like chain (WLC) model thermodynamics.
 pub mod thermodynamics;
 
@@ -25,6 +31,9 @@ pub struct WLC
     pub thermodynamics: self::thermodynamics::WLC
 }
 
+#[cfg(feature = "serde")]
+crate::config::parameters!(WLC, try_init, number_of_links: u8, link_length: f64, hinge_mass: f64, persistance_length: f64);
+
 /// The implemented functionality of the WLC model.
 impl WLC
 {
@@ -40,4 +49,41 @@ impl WLC
             thermodynamics: self::thermodynamics::WLC::init(number_of_links, link_length, hinge_mass, persistance_length),
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, persistance_length: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("persistance_length", &persistance_length)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, persistance_length))
+    }
+}
+
+/// The force law of the WLC model, using the isometric ensemble.
+impl super::ForceLaw for WLC
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.nondimensional_force(nondimensional_end_to_end_length_per_link)
+    }
+    /// The nondimensional root-mean-square end-to-end length per link of the chain in the absence of force,
+    /// which depends on the nondimensional persistance length rather than only the number of links.
+    fn nondimensional_reference_end_to_end_length_per_link(&self) -> f64
+    {
+        let nondimensional_persistance_length = self.persistance_length/(self.number_of_links as f64)/self.link_length;
+        (2.0*nondimensional_persistance_length*(1.0 - nondimensional_persistance_length*(1.0 - (-1.0/nondimensional_persistance_length).exp()))).sqrt()
+    }
+    fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link)
+    }
+    fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, _temperature: &f64) -> f64
+    {
+        self.thermodynamics.isometric.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link)
+    }
 }
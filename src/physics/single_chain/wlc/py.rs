@@ -38,15 +38,19 @@ pub struct WLC
 impl WLC
 {
     #[new]
-    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, persistance_length: f64) -> Self
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, persistance_length: f64) -> PyResult<Self>
     {
-        WLC
+        crate::error::number_of_links(&number_of_links)?;
+        crate::error::positive("link_length", &link_length)?;
+        crate::error::positive("hinge_mass", &hinge_mass)?;
+        crate::error::positive("persistance_length", &persistance_length)?;
+        Ok(WLC
         {
             hinge_mass,
             link_length,
             number_of_links,
             persistance_length,
-            thermodynamics: super::thermodynamics::py::WLC::init(number_of_links, link_length, hinge_mass, persistance_length)
-        }
+            thermodynamics: super::thermodynamics::py::WLC::init(number_of_links, link_length, hinge_mass, persistance_length)?
+        })
     }
 }
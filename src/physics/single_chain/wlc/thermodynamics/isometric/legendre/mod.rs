@@ -6,6 +6,11 @@ pub mod py;
 
 mod test;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use super::
 {
     nondimensional_force,
@@ -99,44 +104,101 @@ impl WLC
             nondimensional_persistance_length: persistance_length/(number_of_links as f64)/link_length
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, persistance_length: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("persistance_length", &persistance_length)?;
+        Ok(Self::init(number_of_links, link_length, hinge_mass, persistance_length))
+    }
     /// The Gibbs free energy as a function of the applied end-to-end length and temperature.
     pub fn gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The Gibbs free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.gibbs_free_energy(end_to_end_length, temperature))
+    }
     /// The Gibbs free energy per link as a function of the applied end-to-end length and temperature.
     pub fn gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The Gibbs free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.gibbs_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The relative Gibbs free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The relative Gibbs free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_gibbs_free_energy(end_to_end_length, temperature))
+    }
     /// The relative Gibbs free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The relative Gibbs free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_gibbs_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The nondimensional Gibbs free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_gibbs_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Gibbs free energy as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_gibbs_free_energy(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_gibbs_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_gibbs_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_gibbs_free_energy(&self.number_of_links, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Gibbs free energy as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_gibbs_free_energy(nondimensional_end_to_end_length_per_link))
+    }
     /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_gibbs_free_energy_per_link(&self.number_of_links, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Gibbs free energy per link as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_gibbs_free_energy_per_link(nondimensional_end_to_end_length_per_link))
+    }
 }
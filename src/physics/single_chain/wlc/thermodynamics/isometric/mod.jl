@@ -5,7 +5,7 @@ module Isometric
 
 using DocStringExtensions
 using Polymers_jll
-using ......Polymers: PROJECT_ROOT
+using ......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 using ....SingleChain: ONE, ZERO, POINTS, integrate
 
 include("legendre/mod.jl")
@@ -687,6 +687,10 @@ function WLC(
     hinge_mass::Float64,
     persistance_length::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("persistance_length", persistance_length)
     nondimensional_persistance_length = persistance_length / number_of_links / link_length
     normalization_nondimensional_equilibrium_distribution = integrate(
         nondimensional_end_to_end_length_per_link ->
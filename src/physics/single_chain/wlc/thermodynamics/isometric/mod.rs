@@ -9,11 +9,16 @@ mod test;
 /// The worm-like chain (WLC) model thermodynamics in the isometric ensemble approximated using a Legendre transformation.
 pub mod legendre;
 
+use crate::error::
+{
+    self,
+    Error
+};
 use std::f64::consts::PI;
 use crate::math::
 {
     bessel_i,
-    integrate_1d
+    inverse_brent
 };
 use crate::physics::
 {
@@ -24,7 +29,9 @@ use crate::physics::single_chain::
 {
     ONE,
     ZERO,
-    POINTS
+    INVERSION_TOLERANCE,
+    INTEGRATION_TOLERANCE,
+    try_integrate
 };
 
 /// The structure of the thermodynamics of the WLC model in the isometric ensemble.
@@ -84,6 +91,54 @@ pub fn nondimensional_force(number_of_links: &u8, nondimensional_persistance_len
     -((5.0*nondimensional_end_to_end_length_per_link*(1.0 - c0/f) + d_sum_dg + 2.0*nondimensional_end_to_end_length_per_link*sum/(1.0 - g2))/(1.0 - g2) + 2.0*b*arg *(1.0 + nondimensional_end_to_end_length_per_link*b.powi(2)*h) + arg*bessel_i(&1, &arg)/bessel_i(&0, &arg)*(1.0/nondimensional_end_to_end_length_per_link + 2.0*b.powi(2)*h))/(*number_of_links as f64)
 }
 
+/// The applied end-to-end length as a function of the expected force and temperature, parameterized by the number of links, link length, and persistance length.
+pub fn end_to_end_length(number_of_links: &u8, link_length: &f64, persistance_length: &f64, force: &f64, temperature: &f64) -> f64
+{
+    try_end_to_end_length(number_of_links, link_length, persistance_length, force, temperature).unwrap_or(f64::NAN)
+}
+
+/// The applied end-to-end length as a function of the expected force and temperature, parameterized by the number of links, link length, and persistance length, or an error if the inversion does not converge.
+pub fn try_end_to_end_length(number_of_links: &u8, link_length: &f64, persistance_length: &f64, force: &f64, temperature: &f64) -> Result<f64, Error>
+{
+    Ok((*number_of_links as f64)*link_length*try_nondimensional_end_to_end_length_per_link(number_of_links, &(persistance_length/((*number_of_links as f64)*link_length)), &(force*link_length/BOLTZMANN_CONSTANT/temperature))?)
+}
+
+/// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, parameterized by the number of links and nondimensional persistance length.
+/// Since the nondimensional force is not monotonic for sufficiently stiff chains, the largest solution is returned, which lies on the stable branch where the nondimensional force increases.
+pub fn nondimensional_end_to_end_length_per_link(number_of_links: &u8, nondimensional_persistance_length: &f64, nondimensional_force: &f64) -> f64
+{
+    try_nondimensional_end_to_end_length_per_link(number_of_links, nondimensional_persistance_length, nondimensional_force).unwrap_or(f64::NAN)
+}
+
+/// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, parameterized by the number of links and nondimensional persistance length.
+/// Since the nondimensional force is not monotonic for sufficiently stiff chains, the largest solution is returned, which lies on the stable branch where the nondimensional force increases, or an error if the solution cannot be refined.
+pub fn try_nondimensional_end_to_end_length_per_link(number_of_links: &u8, nondimensional_persistance_length: &f64, nondimensional_force: &f64) -> Result<f64, Error>
+{
+    let function = |nondimensional_end_to_end_length_per_link: &f64| if nondimensional_end_to_end_length_per_link == &0.0
+    {
+        0.0
+    }
+    else
+    {
+        self::nondimensional_force(number_of_links, nondimensional_persistance_length, nondimensional_end_to_end_length_per_link)
+    };
+    let mut upper = 0.5;
+    while function(&upper) <= *nondimensional_force
+    {
+        upper = 0.5*(1.0 + upper);
+    }
+    let mut lower = upper;
+    while lower > 0.0 && function(&lower) > *nondimensional_force
+    {
+        lower = (lower - 1e-2).max(0.0);
+    }
+    if function(&lower) > *nondimensional_force
+    {
+        return Ok(-try_nondimensional_end_to_end_length_per_link(number_of_links, nondimensional_persistance_length, &(-nondimensional_force))?)
+    }
+    Ok(inverse_brent(nondimensional_force, &function, &lower, &upper, &INVERSION_TOLERANCE, &100)?)
+}
+
 /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, hinge mass, and persistance length.
 pub fn helmholtz_free_energy(number_of_links: &u8, link_length: &f64, hinge_mass: &f64, persistance_length: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
 {
@@ -213,6 +268,24 @@ pub fn nondimensional_equilibrium_radial_distribution(nondimensional_persistance
     4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*nondimensional_equilibrium_distribution(nondimensional_persistance_length, normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
 }
 
+/// The stiffness as a function of the applied end-to-end length and temperature, parameterized by the number of links, link length, and persistance length.
+pub fn stiffness(number_of_links: &u8, link_length: &f64, persistance_length: &f64, end_to_end_length: &f64, temperature: &f64) -> f64
+{
+    BOLTZMANN_CONSTANT*temperature/link_length.powi(2)*nondimensional_stiffness(number_of_links, &(persistance_length/((*number_of_links as f64)*link_length)), &(end_to_end_length/((*number_of_links as f64)*link_length)))
+}
+
+/// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, parameterized by the number of links and nondimensional persistance length.
+pub fn nondimensional_stiffness(number_of_links: &u8, nondimensional_persistance_length: &f64, nondimensional_end_to_end_length_per_link: &f64) -> f64
+{
+    (nondimensional_force(number_of_links, nondimensional_persistance_length, &(nondimensional_end_to_end_length_per_link + 1e-6)) - nondimensional_force(number_of_links, nondimensional_persistance_length, &(nondimensional_end_to_end_length_per_link - 1e-6)))/2e-6/(*number_of_links as f64)
+}
+
+/// The normalization of the nondimensional equilibrium radial distribution, or an error if the numerical integration does not converge.
+fn normalization_nondimensional_equilibrium_distribution(nondimensional_persistance_length: &f64) -> Result<f64, Error>
+{
+    try_integrate(&|nondimensional_end_to_end_length_per_link: &f64| nondimensional_equilibrium_radial_distribution(nondimensional_persistance_length, &1.0, nondimensional_end_to_end_length_per_link), &0.0, &ONE, &INTEGRATION_TOLERANCE)
+}
+
 /// The implemented functionality of the thermodynamics of the WLC model in the isometric ensemble.
 impl WLC
 {
@@ -220,7 +293,7 @@ impl WLC
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, persistance_length: f64) -> Self
     {
         let nondimensional_persistance_length = persistance_length/(number_of_links as f64)/link_length;
-        let normalization_nondimensional_equilibrium_distribution = integrate_1d(&|nondimensional_end_to_end_length_per_link: &f64| nondimensional_equilibrium_radial_distribution(&nondimensional_persistance_length, &1.0, nondimensional_end_to_end_length_per_link), &ZERO, &ONE, &POINTS);
+        let normalization_nondimensional_equilibrium_distribution = normalization_nondimensional_equilibrium_distribution(&nondimensional_persistance_length).unwrap_or(f64::NAN);
         WLC
         {
             hinge_mass,
@@ -232,74 +305,229 @@ impl WLC
             legendre: self::legendre::WLC::init(number_of_links, link_length, hinge_mass, persistance_length)
         }
     }
+    /// Initializes and returns an instance of the model, or an error if any of the parameters are invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, persistance_length: f64) -> Result<Self, Error>
+    {
+        error::number_of_links(&number_of_links)?;
+        error::positive("link_length", &link_length)?;
+        error::positive("hinge_mass", &hinge_mass)?;
+        error::positive("persistance_length", &persistance_length)?;
+        let nondimensional_persistance_length = persistance_length/(number_of_links as f64)/link_length;
+        Ok(WLC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            persistance_length,
+            nondimensional_persistance_length,
+            normalization_nondimensional_equilibrium_distribution: normalization_nondimensional_equilibrium_distribution(&nondimensional_persistance_length)?,
+            legendre: self::legendre::WLC::init(number_of_links, link_length, hinge_mass, persistance_length)
+        })
+    }
     /// The expected force as a function of the applied end-to-end length and temperature.
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         force(&self.number_of_links, &self.link_length, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The expected force as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.force(end_to_end_length, temperature))
+    }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_force(&self.number_of_links, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link)
     }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link for each element of a slice.
+    pub fn nondimensional_force_batch(&self, nondimensional_end_to_end_length_per_link: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link), nondimensional_end_to_end_length_per_link)
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_force(nondimensional_end_to_end_length_per_link))
+    }
+    /// The applied end-to-end length as a function of the expected force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.try_end_to_end_length(force, temperature).unwrap_or(f64::NAN)
+    }
+    /// The applied end-to-end length as a function of the expected force and temperature, or an error if the inversion does not converge.
+    pub fn try_end_to_end_length(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        try_end_to_end_length(&self.number_of_links, &self.link_length, &self.persistance_length, force, temperature)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        self.try_nondimensional_end_to_end_length_per_link(nondimensional_force).unwrap_or(f64::NAN)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force for each element of a slice.
+    pub fn nondimensional_end_to_end_length_per_link_batch(&self, nondimensional_force: &[f64]) -> Vec<f64>
+    {
+        crate::math::batch(&|nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force), nondimensional_force)
+    }
+    /// The applied nondimensional end-to-end length per link as a function of the expected nondimensional force, or an error if the inversion does not converge.
+    pub fn try_nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> Result<f64, Error>
+    {
+        try_nondimensional_end_to_end_length_per_link(&self.number_of_links, &self.nondimensional_persistance_length, nondimensional_force)
+    }
     /// The Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.helmholtz_free_energy(end_to_end_length, temperature))
+    }
     /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.helmholtz_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The relative Helmholtz free energy as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_helmholtz_free_energy(end_to_end_length, temperature))
+    }
     /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.persistance_length, end_to_end_length, temperature)
     }
+    /// The relative Helmholtz free energy per link as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.relative_helmholtz_free_energy_per_link(end_to_end_length, temperature))
+    }
     /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_helmholtz_free_energy(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         nondimensional_helmholtz_free_energy_per_link(&self.number_of_links, &self.link_length, &self.hinge_mass, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link, temperature)
     }
+    /// The nondimensional Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link, temperature))
+    }
     /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_helmholtz_free_energy(&self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Helmholtz free energy as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link))
+    }
     /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_relative_helmholtz_free_energy_per_link(&self.number_of_links, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional relative Helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link))
+    }
     /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length.
     pub fn equilibrium_distribution(&self, end_to_end_length: &f64) -> f64
     {
         equilibrium_distribution(&self.number_of_links, &self.link_length, &self.persistance_length, &self.normalization_nondimensional_equilibrium_distribution, end_to_end_length)
     }
+    /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length, or an error if the end-to-end length is outside the domain.
+    pub fn try_equilibrium_distribution(&self, end_to_end_length: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.equilibrium_distribution(end_to_end_length))
+    }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link.
     pub fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_equilibrium_distribution(&self.nondimensional_persistance_length, &self.normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link))
+    }
     /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length.
     pub fn equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> f64
     {
         equilibrium_radial_distribution(&self.number_of_links, &self.link_length, &self.persistance_length, &self.normalization_nondimensional_equilibrium_distribution, end_to_end_length)
     }
+    /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length, or an error if the end-to-end length is outside the domain.
+    pub fn try_equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.equilibrium_radial_distribution(end_to_end_length))
+    }
     /// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link.
     pub fn nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         nondimensional_equilibrium_radial_distribution(&self.nondimensional_persistance_length, &self.normalization_nondimensional_equilibrium_distribution, nondimensional_end_to_end_length_per_link)
     }
+    /// The nondimensional equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_equilibrium_radial_distribution(nondimensional_end_to_end_length_per_link))
+    }
+    /// The stiffness as a function of the applied end-to-end length and temperature.
+    pub fn stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        stiffness(&self.number_of_links, &self.link_length, &self.persistance_length, end_to_end_length, temperature)
+    }
+    /// The stiffness as a function of the applied end-to-end length and temperature, or an error if the end-to-end length is outside the domain.
+    pub fn try_stiffness(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("end_to_end_length", end_to_end_length, &(self.number_of_links as f64*self.link_length))?;
+        Ok(self.stiffness(end_to_end_length, temperature))
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link.
+    pub fn nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        nondimensional_stiffness(&self.number_of_links, &self.nondimensional_persistance_length, nondimensional_end_to_end_length_per_link)
+    }
+    /// The nondimensional stiffness as a function of the applied nondimensional end-to-end length per link, or an error if the nondimensional end-to-end length per link is outside the domain.
+    pub fn try_nondimensional_stiffness(&self, nondimensional_end_to_end_length_per_link: &f64) -> Result<f64, Error>
+    {
+        error::inextensible("nondimensional_end_to_end_length_per_link", nondimensional_end_to_end_length_per_link, &1.0)?;
+        Ok(self.nondimensional_stiffness(nondimensional_end_to_end_length_per_link))
+    }
 }
@@ -5,7 +5,7 @@ module Legendre
 
 using DocStringExtensions
 using Polymers_jll
-using .......Polymers: PROJECT_ROOT
+using .......Polymers: PROJECT_ROOT, validate_number_of_links, validate_positive
 
 """
 The structure of the thermodynamics of the WLC model in the isotensional ensemble approximated using a Legendre transformation.
@@ -390,6 +390,10 @@ function WLC(
     hinge_mass::Float64,
     persistance_length::Float64,
 )
+    validate_number_of_links(number_of_links)
+    validate_positive("link_length", link_length)
+    validate_positive("hinge_mass", hinge_mass)
+    validate_positive("persistance_length", persistance_length)
     nondimensional_persistance_length = persistance_length / number_of_links / link_length
     return WLC(
         number_of_links,
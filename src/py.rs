@@ -1,8 +1,65 @@
-use pyo3::prelude::*;
+use pyo3::
+{
+    exceptions::PyValueError,
+    prelude::*
+};
+use numpy::
+{
+    IntoPyArray,
+    PyArrayDyn,
+    PyReadonlyArrayDyn,
+    ndarray::
+    {
+        ArrayD,
+        ArrayViewD
+    }
+};
+use crate::error::Error;
 
 #[pymodule]
 pub fn polymers(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     super::constitutive::py::register_module(py, m)?;
+    #[cfg(feature = "data")]
+    super::data::py::register_module(py, m)?;
+    super::fit::py::register_module(py, m)?;
+    super::math::py::register_module(py, m)?;
     super::physics::py::register_module(py, m)?;
     Ok(())
 }
+
+/// Evaluates a function over every element of an array using [`crate::math::batch`] with the global interpreter lock released, preserving the shape of the array.
+pub fn batch<'py>(py: Python<'py>, arguments: PyReadonlyArrayDyn<f64>, function: impl Fn(f64) -> f64 + Sync) -> PyResult<&'py PyArrayDyn<f64>>
+{
+    let arguments = arguments.as_array();
+    let evaluate = |argument: &f64| function(*argument);
+    let values = py.allow_threads(|| match arguments.as_slice()
+    {
+        Some(slice) => crate::math::batch(&evaluate, slice),
+        None => crate::math::batch(&evaluate, &arguments.iter().copied().collect::<Vec<f64>>())
+    });
+    shaped(py, &arguments, values)
+}
+
+/// Evaluates a function that can fail over every element of an array using [`crate::math::try_batch`] with the global interpreter lock released, preserving the shape of the array, or returning the first error encountered.
+pub fn try_batch<'py>(py: Python<'py>, arguments: PyReadonlyArrayDyn<f64>, function: impl Fn(f64) -> Result<f64, Error> + Sync) -> PyResult<&'py PyArrayDyn<f64>>
+{
+    let arguments = arguments.as_array();
+    let evaluate = |argument: &f64| function(*argument);
+    let values = py.allow_threads(|| match arguments.as_slice()
+    {
+        Some(slice) => crate::math::try_batch(&evaluate, slice),
+        None => crate::math::try_batch(&evaluate, &arguments.iter().copied().collect::<Vec<f64>>())
+    })?;
+    shaped(py, &arguments, values)
+}
+
+fn shaped<'py>(py: Python<'py>, arguments: &ArrayViewD<f64>, values: Vec<f64>) -> PyResult<&'py PyArrayDyn<f64>>
+{
+    ArrayD::from_shape_vec(arguments.raw_dim(), values).map(|values| values.into_pyarray(py)).map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+/// Validates every element of an array, returning the first error encountered.
+pub fn validate(arguments: &PyReadonlyArrayDyn<f64>, validation: impl Fn(&f64) -> Result<(), Error>) -> Result<(), Error>
+{
+    arguments.as_array().iter().try_for_each(validation)
+}
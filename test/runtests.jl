@@ -1,6 +1,7 @@
 using Test
 using Polymers
 
+include("../src/math/test.jl")
 include("../src/physics/single_chain/test.jl")
 include("../src/physics/single_chain/ideal/test.jl")
 include("../src/physics/single_chain/ideal/thermodynamics/test.jl")
@@ -167,3 +168,6 @@ include("../src/physics/single_chain/wlc/thermodynamics/isometric/test.jl")
 include("../src/physics/single_chain/wlc/thermodynamics/isometric/legendre/test.jl")
 include("../src/physics/single_chain/wlc/thermodynamics/isotensional/test.jl")
 include("../src/physics/single_chain/wlc/thermodynamics/isotensional/legendre/test.jl")
+include("../src/constitutive/gel/test.jl")
+include("../src/constitutive/hyperelastic/test.jl")
+include("../src/data/test.jl")